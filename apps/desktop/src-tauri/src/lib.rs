@@ -84,8 +84,10 @@ pub async fn main() {
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_webhook::init())
         .plugin(tauri_plugin_mcp::init())
+        .plugin(tauri_plugin_notion::init())
         .plugin(tauri_plugin_obsidian::init())
         .plugin(tauri_plugin_sfx::init())
+        .plugin(tauri_plugin_slack::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_window_state::Builder::default().build())
         .plugin(tauri_plugin_deep_link::init())
@@ -196,6 +198,13 @@ pub async fn main() {
                     tracing::error!("failed_to_setup_db_for_local: {}", e);
                 }
 
+                {
+                    use tauri_plugin_template::TemplatePluginExt;
+                    if let Err(e) = app_clone.load_template_overrides().await {
+                        tracing::error!("failed_to_load_template_overrides: {}", e);
+                    }
+                }
+
                 {
                     use tauri_plugin_db::DatabasePluginExt;
                     let user_id = app_clone.db_user_id().await;
@@ -7,8 +7,21 @@ import { z } from "zod";
 import { useHypr } from "@/contexts";
 import { commands as connectorCommands } from "@hypr/plugin-connector";
 import { commands as dbCommands } from "@hypr/plugin-db";
+import { commands as templateCommands } from "@hypr/plugin-template";
 import { toast } from "@hypr/ui/components/ui/toast";
-import { modelProvider, smoothStream, streamText, tool } from "@hypr/utils/ai";
+import { generateText, modelProvider } from "@hypr/utils/ai";
+
+const annotationSchema = z.object({
+  blocks: z.array(z.discriminatedUnion("type", [
+    z.object({ type: z.literal("explanation"), content: z.string() }),
+    z.object({
+      type: z.literal("quote"),
+      transcript: z.object({ speaker: z.string(), content: z.string() }),
+    }),
+  ])),
+});
+
+type AnnotationBlock = z.infer<typeof annotationSchema>["blocks"][number];
 
 interface AnnotationBoxProps {
   selectedText: string;
@@ -19,8 +32,8 @@ interface AnnotationBoxProps {
 
 export function AnnotationBox({ selectedText, selectedRect, sessionId, onCancel }: AnnotationBoxProps) {
   const boxRef = useRef<HTMLDivElement>(null);
-  const [streamedContent, setStreamedContent] = useState("");
-  const [isStreaming, setIsStreaming] = useState(false);
+  const [blocks, setBlocks] = useState<AnnotationBlock[]>([]);
+  const [isLoading, setIsLoading] = useState(false);
   const { onboardingSessionId } = useHypr();
 
   // Store abort controller reference
@@ -43,46 +56,23 @@ export function AnnotationBox({ selectedText, selectedRect, sessionId, onCancel
 
   const sourceAnalysisMutation = useMutation({
     mutationFn: async () => {
-      setIsStreaming(true);
-      setStreamedContent("");
+      setIsLoading(true);
+      setBlocks([]);
 
       // Create NEW abort controller for this request
       const abortController = new AbortController();
       abortControllerRef.current = abortController;
 
       const getWordsFunc = sessionId === onboardingSessionId ? dbCommands.getWordsOnboarding : dbCommands.getWords;
-      const [{ type }, words] = await Promise.all([
-        connectorCommands.getLlmConnection(),
-        getWordsFunc(sessionId),
-      ]);
-
-      const freshIsLocalLlm = type === "HyprLocal";
-
-      // Create transcript text from words
-      const transcriptText = words.map(word => word.text).join(" ");
-
-      const systemMessage =
-        `You are an assistant that helps users understand where content in their notes came from in the original transcript.
-
-            Your task:
-            1. Look at the provided transcript
-            2. Find where the selected text would have been derived from
-            3. Quote the specific section from the transcript that relates to the selected text. If it doesn't exist, say so. 
-            4. Provide a simple explanation (max 3 sentences)
-
-            Format your response as:
-            **Source Section:**
-            "[exact quote from transcript (only if it exists)]"
-
-            **Explanation:**
-            [Your brief explanation of how this content relates to the selected text]`;
+      const words = await getWordsFunc(sessionId);
 
-      const userMessage = `Selected text from notes: "${selectedText}"
-
-        Full transcript:
-        ${transcriptText}
-
-        Please find the source section in the transcript that this selected text was derived from and explain the connection.`;
+      const [systemMessage, userMessage] = await Promise.all([
+        templateCommands.render("show_annotation.system", {}),
+        templateCommands.render("show_annotation.user", {
+          sentence: selectedText,
+          words: JSON.stringify(words),
+        }),
+      ]);
 
       // CRITICAL: Create abort signal that combines controller + timeout
       const abortSignal = AbortSignal.any([
@@ -95,55 +85,32 @@ export function AnnotationBox({ selectedText, selectedRect, sessionId, onCancel
         ? provider.languageModel("onboardingModel")
         : provider.languageModel("defaultModel");
 
-      // CRITICAL: Pass abortSignal to streamText
-      const { fullStream } = streamText({
-        abortSignal, // ← This makes cancellation actually work!
+      const { text } = await generateText({
+        abortSignal,
         model,
-        ...(freshIsLocalLlm && {
-          tools: {
-            update_progress: tool({ inputSchema: z.any() }),
-          },
-        }),
-        onError: (error) => {
-          toast({
-            id: "source-analysis-error",
-            title: "🚨 Failed to analyze source",
-            content: "Please try again or contact the team.",
-            dismissible: true,
-            duration: 5000,
-          });
-          throw error;
-        },
         messages: [
           { role: "system", content: systemMessage },
           { role: "user", content: userMessage },
         ],
-        experimental_transform: [
-          smoothStream({ delayInMs: 40, chunking: "word" }),
-        ],
       });
 
-      let acc = "";
-
-      for await (const chunk of fullStream) {
-        if (chunk.type === "text-delta") {
-          acc += chunk.text;
-          setStreamedContent(acc);
-        }
-        if (chunk.type === "error") {
-          throw new Error(String(chunk.error));
-        }
+      const parsed = annotationSchema.safeParse(
+        JSON.parse(text.slice(text.indexOf("{"), text.lastIndexOf("}") + 1)),
+      );
+      if (!parsed.success) {
+        throw new Error(`Unexpected annotation: ${text}`);
       }
 
-      return acc;
+      setBlocks(parsed.data.blocks);
+      return parsed.data.blocks;
     },
     onSuccess: () => {
-      setIsStreaming(false);
+      setIsLoading(false);
       abortControllerRef.current = null; // Clear reference
     },
     onError: (error) => {
       console.error("Source analysis error:", error);
-      setIsStreaming(false);
+      setIsLoading(false);
       abortControllerRef.current = null; // Clear reference
 
       // Check if it was cancelled (not a real error)
@@ -152,7 +119,13 @@ export function AnnotationBox({ selectedText, selectedRect, sessionId, onCancel
         || String(error).includes("aborted");
 
       if (!wasCancelled) {
-        setStreamedContent("Failed to analyze source. Please try again.");
+        toast({
+          id: "source-analysis-error",
+          title: "🚨 Failed to analyze source",
+          content: "Please try again or contact the team.",
+          dismissible: true,
+          duration: 5000,
+        });
       }
     },
   });
@@ -293,9 +266,9 @@ export function AnnotationBox({ selectedText, selectedRect, sessionId, onCancel
           </button>
         </div>
 
-        {/* Streaming content - takes remaining space */}
+        {/* Annotation content - takes remaining space */}
         <div className="flex-1 overflow-hidden">
-          {isStreaming && !streamedContent
+          {isLoading
             ? (
               <div className="flex items-center gap-2 text-neutral-500">
                 <Loader2 className="h-3 w-3 animate-spin" />
@@ -303,24 +276,22 @@ export function AnnotationBox({ selectedText, selectedRect, sessionId, onCancel
               </div>
             )
             : (
-              <div className="text-xs text-neutral-700 leading-relaxed h-full overflow-y-auto">
-                {streamedContent
-                  ? (
-                    <div
-                      className="prose prose-xs max-w-none"
-                      dangerouslySetInnerHTML={{
-                        __html: streamedContent
-                          .replace(/\*\*(.*?)\*\*/g, "<strong>$1</strong>")
-                          .replace(/\n/g, "<br>"),
-                      }}
-                    />
+              <div className="text-xs text-neutral-700 leading-relaxed h-full overflow-y-auto space-y-2">
+                {blocks.length
+                  ? blocks.map((block, index) =>
+                    block.type === "explanation"
+                      ? <p key={index}>{block.content}</p>
+                      : (
+                        <blockquote key={index} className="border-l-2 border-neutral-300 pl-2 text-neutral-600">
+                          <span className="font-medium">{block.transcript.speaker}:</span> "{block.transcript.content}"
+                        </blockquote>
+                      )
                   )
                   : (
                     <div className="text-neutral-400 italic">
                       Waiting for analysis...
                     </div>
                   )}
-                {isStreaming && <span className="inline-block w-2 h-3 bg-neutral-400 animate-pulse ml-1" />}
               </div>
             )}
         </div>
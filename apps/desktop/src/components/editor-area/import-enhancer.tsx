@@ -0,0 +1,76 @@
+import { useQuery } from "@tanstack/react-query";
+import { message } from "@tauri-apps/plugin-dialog";
+import { useEffect, useRef } from "react";
+
+import { useSessionEnhance } from "@/components/editor-area";
+import { useNewNote } from "@/contexts";
+import { commands as dbCommands, type Session } from "@hypr/plugin-db";
+import { commands as taskCommands } from "@hypr/plugin-task";
+import { useSession } from "@hypr/utils/contexts";
+
+// Imported recordings are transcribed in the background, and enhanced once that finishes,
+// whether or not their note is open.
+export function ImportEnhancer() {
+  const { importTasks } = useNewNote();
+
+  return (
+    <>
+      {Object.entries(importTasks).map(([sessionId, taskId]) => (
+        <ImportTask key={sessionId} sessionId={sessionId} taskId={taskId} />
+      ))}
+    </>
+  );
+}
+
+function ImportTask({ sessionId, taskId }: { sessionId: string; taskId: string }) {
+  const sessionQuery = useQuery({
+    queryKey: ["import-session", sessionId],
+    queryFn: () => dbCommands.getSession({ id: sessionId }),
+  });
+
+  if (!sessionQuery.data) {
+    return null;
+  }
+
+  return <ImportTaskEnhance session={sessionQuery.data} taskId={taskId} />;
+}
+
+function ImportTaskEnhance({ session, taskId }: { session: Session; taskId: string }) {
+  const sessionId = session.id;
+  const { finishImport } = useNewNote();
+
+  // Passing the session itself puts it into the store when its note was never opened.
+  const refresh = useSession(session, (s) => s.refresh);
+  const setShowRaw = useSession(session, (s) => s.setShowRaw);
+  const { enhance } = useSessionEnhance(sessionId);
+  const started = useRef(false);
+
+  const taskQuery = useQuery({
+    queryKey: ["import-task", taskId],
+    queryFn: () => taskCommands.getTask(taskId),
+    refetchInterval: 1000,
+  });
+  const status = taskQuery.data?.status;
+
+  useEffect(() => {
+    if (!status || (typeof status === "object" && "Running" in status) || started.current) {
+      return;
+    }
+    started.current = true;
+
+    if (status === "Completed") {
+      refresh().then(() => {
+        setShowRaw(false);
+        enhance.mutate({ triggerType: "auto" }, { onSettled: () => finishImport(sessionId) });
+      });
+    } else {
+      finishImport(sessionId);
+
+      if (typeof status === "object" && "Failed" in status) {
+        message(status.Failed.error, { title: "Transcription failed", kind: "error" });
+      }
+    }
+  }, [status, sessionId, finishImport, refresh, setShowRaw, enhance]);
+
+  return null;
+}
@@ -1,4 +1,4 @@
-import { type QueryClient, useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { type QueryClient, useIsMutating, useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import usePreviousValue from "beautiful-react-hooks/usePreviousValue";
 import { diffWords } from "diff";
 import { motion } from "motion/react";
@@ -12,13 +12,16 @@ import { autoTagGeneration } from "@/utils/tag-generation";
 import { TemplateService } from "@/utils/template-service";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { commands as connectorCommands } from "@hypr/plugin-connector";
-import { commands as dbCommands } from "@hypr/plugin-db";
+import { type Bookmark, commands as dbCommands, type Word2 } from "@hypr/plugin-db";
 import { commands as miscCommands } from "@hypr/plugin-misc";
+import { commands as obsidianCommands } from "@hypr/plugin-obsidian";
+import { commands as slackCommands } from "@hypr/plugin-slack";
 import { commands as templateCommands, type Grammar } from "@hypr/plugin-template";
 import Editor, { type TiptapEditor } from "@hypr/tiptap/editor";
 import Renderer from "@hypr/tiptap/renderer";
 import { extractHashtags } from "@hypr/tiptap/shared";
-import { toast } from "@hypr/ui/components/ui/toast";
+import { Button } from "@hypr/ui/components/ui/button";
+import { sonnerToast, toast } from "@hypr/ui/components/ui/toast";
 import { cn } from "@hypr/ui/lib/utils";
 import { generateText, localProviderName, modelProvider, smoothStream, streamText, tool } from "@hypr/utils/ai";
 import { useOngoingSession, useSession, useSessions } from "@hypr/utils/contexts";
@@ -28,6 +31,125 @@ import { FloatingButton } from "./floating-button";
 import { NoteHeader } from "./note-header";
 import { TextSelectionPopover } from "./text-selection-popover";
 
+// hypr-llama caps the prompt at 16k tokens, so leave room for the raw note and the instructions.
+const LOCAL_MAX_TRANSCRIPT_TOKENS = 1024 * 8;
+const REMOTE_MAX_TRANSCRIPT_TOKENS = 1024 * 64;
+
+// What was said from a little before a bookmark was hit until shortly after it.
+const BOOKMARK_CONTEXT_BEFORE_MS = 20 * 1000;
+const BOOKMARK_CONTEXT_AFTER_MS = 10 * 1000;
+
+function bookmarkContexts(bookmarks: Bookmark[], words: Word2[]) {
+  return bookmarks.map((bookmark) => ({
+    offset_ms: bookmark.offset_ms,
+    label: bookmark.label,
+    context: words
+      .filter((w) =>
+        w.start_ms !== null
+        && w.start_ms >= bookmark.offset_ms - BOOKMARK_CONTEXT_BEFORE_MS
+        && w.start_ms <= bookmark.offset_ms + BOOKMARK_CONTEXT_AFTER_MS
+      )
+      .map((w) => w.text)
+      .join(" "),
+  }));
+}
+
+// Lines of the enhanced note are compared by their text, ignoring markdown syntax.
+function normalizeNoteLine(line: string) {
+  return line
+    .replace(/^\s*(#+|[-*+]|\d+\.)\s+/, "")
+    .replace(/[^\p{L}\p{N}]+/gu, " ")
+    .trim()
+    .toLowerCase();
+}
+
+// Words said around the terms of the raw note, which is all the influence check needs to see.
+const RAW_NOTE_CONTEXT_WORDS = 20;
+
+function rawNoteExcerpt(rawNote: string, words: Word2[]) {
+  const terms = new Set(
+    normalizeNoteLine(rawNote)
+      .split(" ")
+      .filter((term) => term.length >= 4),
+  );
+
+  const keep = new Array<boolean>(words.length).fill(false);
+  words.forEach((word, index) => {
+    if (terms.has(normalizeNoteLine(word.text))) {
+      const end = Math.min(words.length, index + RAW_NOTE_CONTEXT_WORDS + 1);
+      for (let i = Math.max(0, index - RAW_NOTE_CONTEXT_WORDS); i < end; i++) {
+        keep[i] = true;
+      }
+    }
+  });
+
+  return words.filter((_, index) => keep[index]);
+}
+
+// Highlights the parts of the enhanced note that came from what the user wrote themselves.
+async function markRawNoteInfluence({
+  model,
+  abortSignal,
+  enhancedMarkdown,
+  enhancedHtml,
+  preMeetingNote,
+  rawNote,
+  words,
+}: {
+  model: Parameters<typeof generateText>[0]["model"];
+  abortSignal: AbortSignal;
+  enhancedMarkdown: string;
+  enhancedHtml: string;
+  preMeetingNote: string;
+  rawNote: string;
+  words: Word2[];
+}) {
+  const lines = enhancedMarkdown.split("\n");
+
+  const [systemMessage, userMessage] = await Promise.all([
+    templateCommands.render("postprocess_enhance.system", {}),
+    templateCommands.render("postprocess_enhance.user", {
+      enhanced_note: lines.map((line, index) => `${index + 1}: ${line}`).join("\n"),
+      pre_meeting_editor: preMeetingNote,
+      in_meeting_editor: rawNote,
+      words: JSON.stringify(rawNoteExcerpt(`${preMeetingNote}\n${rawNote}`, words)),
+    }),
+  ]);
+
+  const { text } = await generateText({
+    abortSignal,
+    model,
+    messages: [
+      { role: "system", content: systemMessage },
+      { role: "user", content: userMessage },
+    ],
+  });
+
+  const { mark } = JSON.parse(text.slice(text.indexOf("{"), text.lastIndexOf("}") + 1));
+  if (!Array.isArray(mark)) {
+    return enhancedHtml;
+  }
+
+  const marked = new Set(
+    mark
+      .map((n: number) => lines[n - 1])
+      .filter((line?: string) => !!line)
+      .map(normalizeNoteLine)
+      .filter(Boolean),
+  );
+
+  const doc = new DOMParser().parseFromString(enhancedHtml, "text/html");
+  doc.body.querySelectorAll("h1, h2, h3, h4, h5, h6, p").forEach((el) => {
+    if (marked.has(normalizeNoteLine(el.textContent ?? "")) && !el.querySelector("mark")) {
+      const highlight = doc.createElement("mark");
+      highlight.append(...Array.from(el.childNodes));
+      el.append(highlight);
+    }
+  });
+
+  return doc.body.innerHTML;
+}
+
 async function generateTitleDirect(
   enhancedContent: string,
   targetSessionId: string,
@@ -102,6 +224,92 @@ async function generateTitleDirect(
   }
 }
 
+async function extractActionItemsDirect(
+  targetSessionId: string,
+  queryClient: QueryClient,
+) {
+  const [config, { type }, provider, session, participants] = await Promise.all([
+    dbCommands.getConfig(),
+    connectorCommands.getLlmConnection(),
+    modelProvider(),
+    dbCommands.getSession({ id: targetSessionId }),
+    dbCommands.sessionListParticipants(targetSessionId),
+  ]);
+
+  if (!session || session.words.length === 0) {
+    return;
+  }
+
+  const [systemMessage, userMessage] = await Promise.all([
+    templateCommands.render("extract_action_items.system", { config, type }),
+    templateCommands.render("extract_action_items.user", {
+      type,
+      editor: session.raw_memo_html,
+      words: JSON.stringify(session.words),
+      participants,
+      meeting_date: (session.record_start ?? session.created_at).slice(0, 10),
+    }),
+  ]);
+
+  const model = provider.languageModel("defaultModel");
+  const abortSignal = AbortSignal.timeout(120_000);
+
+  const { text } = await generateText({
+    abortSignal,
+    model,
+    messages: [
+      { role: "system", content: systemMessage },
+      { role: "user", content: userMessage },
+    ],
+    providerOptions: {
+      [localProviderName]: { metadata: { grammar: { task: "action-items" } satisfies Grammar } },
+    },
+  });
+
+  const extraction = JSON.parse(text.slice(text.indexOf("{"), text.lastIndexOf("}") + 1));
+  await dbCommands.sessionApplyExtraction(targetSessionId, {
+    action_items: extraction.action_items ?? [],
+    decisions: extraction.decisions ?? [],
+    open_questions: extraction.open_questions ?? [],
+  });
+
+  queryClient.invalidateQueries({ queryKey: ["action-items"] });
+}
+
+// Notes edited in Obsidian since the last sync are left alone, and the user decides what to do.
+async function syncToObsidian(targetSessionId: string) {
+  if (!(await obsidianCommands.getEnabled())) {
+    return;
+  }
+
+  const result = await obsidianCommands.syncSession(targetSessionId, null, false, false);
+  if (result.status !== "conflict") {
+    return;
+  }
+
+  const id = `obsidian-conflict-${targetSessionId}`;
+  toast({
+    id,
+    title: "Note changed in Obsidian",
+    content: (
+      <div className="space-y-1">
+        <div>"{result.path}" was edited in Obsidian, so it was not updated.</div>
+        <Button
+          variant="default"
+          onClick={() => {
+            sonnerToast.dismiss(id);
+            obsidianCommands.syncSession(targetSessionId, null, false, true).catch(console.error);
+          }}
+        >
+          Overwrite
+        </Button>
+      </div>
+    ),
+    dismissible: true,
+    duration: 10000,
+  });
+}
+
 export default function EditorArea({
   editable,
   sessionId,
@@ -139,28 +347,9 @@ export default function EditorArea({
     refetchOnWindowFocus: true,
   });
 
-  const preMeetingNote = useSession(sessionId, (s) => s.session.pre_meeting_memo_html) ?? "";
-  const hasTranscriptWords = useSession(sessionId, (s) => s.session.words.length > 0);
-
-  const llmConnectionQuery = useQuery({
-    queryKey: ["llm-connection"],
-    queryFn: () => connectorCommands.getLlmConnection(),
-    refetchOnWindowFocus: true,
-  });
-
-  const sessionsStore = useSessions((s) => s.sessions);
-  const queryClient = useQueryClient();
-  const { enhance, progress, isCancelled } = useEnhanceMutation({
-    sessionId,
-    preMeetingNote,
-    rawContent,
-    isLocalLlm: llmConnectionQuery.data?.type === "HyprLocal",
-    onSuccess: (content) => {
-      if (hasTranscriptWords) {
-        generateTitleDirect(content, sessionId, sessionsStore, queryClient).catch(console.error);
-      }
-    },
-  });
+  const { enhance, progress, isCancelled, isLocalLlm } = useSessionEnhance(sessionId);
+  // Imported recordings are enhanced in the background, by a mutation of their own.
+  const isEnhancing = useIsMutating({ mutationKey: ["enhance", sessionId] }) > 0;
 
   useAutoEnhance({
     sessionId,
@@ -270,8 +459,8 @@ export default function EditorArea({
               ref={editorRef}
               handleChange={handleChangeNote}
               initialContent={noteContent}
-              editable={enhance.status !== "pending"}
-              setContentFromOutside={!showRaw && enhance.status === "pending"}
+              editable={!isEnhancing}
+              setContentFromOutside={!showRaw && isEnhancing}
               mentionConfig={{
                 trigger: "@",
                 handleSearch: handleMentionSearch,
@@ -316,7 +505,7 @@ export default function EditorArea({
               session={sessionStore.session}
               isError={enhance.status === "error" && !isCancelled}
               progress={progress}
-              showProgress={llmConnectionQuery.data?.type === "HyprLocal" && sessionId !== onboardingSessionId}
+              showProgress={isLocalLlm && sessionId !== onboardingSessionId}
             />
           </div>
         </motion.div>
@@ -325,6 +514,51 @@ export default function EditorArea({
   );
 }
 
+// Enhancement together with everything that follows it (title, action items, integrations).
+export function useSessionEnhance(sessionId: string) {
+  const { onboardingSessionId } = useHypr();
+
+  const rawContent = useSession(sessionId, (s) => s.session?.raw_memo_html ?? "");
+  const preMeetingNote = useSession(sessionId, (s) => s.session.pre_meeting_memo_html) ?? "";
+  const hasTranscriptWords = useSession(sessionId, (s) => s.session.words.length > 0);
+
+  const llmConnectionQuery = useQuery({
+    queryKey: ["llm-connection"],
+    queryFn: () => connectorCommands.getLlmConnection(),
+    refetchOnWindowFocus: true,
+  });
+  const isLocalLlm = llmConnectionQuery.data?.type === "HyprLocal";
+
+  const sessionsStore = useSessions((s) => s.sessions);
+  const queryClient = useQueryClient();
+  const { enhance, progress, isCancelled } = useEnhanceMutation({
+    sessionId,
+    preMeetingNote,
+    rawContent,
+    isLocalLlm,
+    onSuccess: (content) => {
+      if (hasTranscriptWords) {
+        generateTitleDirect(content, sessionId, sessionsStore, queryClient).catch(console.error);
+
+        if (sessionId !== onboardingSessionId) {
+          extractActionItemsDirect(sessionId, queryClient)
+            .catch(console.error)
+            .then(() => sessionsStore[sessionId]?.getState().persistSession(undefined, true))
+            .then(() =>
+              Promise.all([
+                slackCommands.postSessionIfTagged(sessionId).catch(console.error),
+                syncToObsidian(sessionId).catch(console.error),
+              ])
+            )
+            .catch(console.error);
+        }
+      }
+    },
+  });
+
+  return { enhance, progress, isCancelled, isLocalLlm };
+}
+
 export function useEnhanceMutation({
   sessionId,
   preMeetingNote,
@@ -445,6 +679,7 @@ export function useEnhanceMutation({
       const grammarSections = selectedTemplate?.sections.map(s => s.title) || null;
 
       const participants = await dbCommands.sessionListParticipants(sessionId);
+      const bookmarks = bookmarkContexts(await dbCommands.listBookmarks(sessionId), words);
 
       let customInstruction = selectedTemplate?.description;
 
@@ -460,23 +695,89 @@ export function useEnhanceMutation({
         },
       );
 
+      const chunks = await templateCommands.chunkTranscript(
+        words,
+        freshIsLocalLlm ? LOCAL_MAX_TRANSCRIPT_TOKENS : REMOTE_MAX_TRANSCRIPT_TOKENS,
+      );
+
+      // Long meetings are summarized chunk by chunk, then merged in a final step.
+      const stepCount = chunks.length > 1 ? chunks.length + 1 : 1;
+      const reportProgress = (step: number, stepProgress: number) => {
+        if (freshIsLocalLlm) {
+          setProgress((step + stepProgress) / stepCount);
+        }
+      };
+
+      const abortSignal = AbortSignal.any([
+        abortController.signal,
+        AbortSignal.timeout(120 * 1000 * stepCount),
+      ]);
+
+      const provider = await modelProvider();
+      const model = sessionId === onboardingSessionId
+        ? provider.languageModel("onboardingModel")
+        : provider.languageModel("defaultModel");
+
+      let summaries: string[] | null = null;
+
+      if (chunks.length > 1) {
+        summaries = [];
+
+        const chunkSystemMessage = await templateCommands.render(
+          "enhance_chunk.system",
+          { config, sections: grammarSections },
+        );
+
+        for (const [index, chunk] of chunks.entries()) {
+          const chunkUserMessage = await templateCommands.render(
+            "enhance_chunk.user",
+            {
+              participants,
+              index: index + 1,
+              total: chunks.length,
+              words: JSON.stringify(chunk),
+            },
+          );
+
+          const { text: chunkText, fullStream: chunkStream } = streamText({
+            abortSignal,
+            model,
+            ...(freshIsLocalLlm && {
+              tools: {
+                update_progress: tool({ inputSchema: z.any() }),
+              },
+            }),
+            messages: [
+              { role: "system", content: chunkSystemMessage },
+              { role: "user", content: chunkUserMessage },
+            ],
+          });
+
+          for await (const part of chunkStream) {
+            if (part.type === "error") {
+              throw new Error(String(part.error));
+            }
+            if (part.type === "tool-call") {
+              reportProgress(index, part.input?.progress ?? 0);
+            }
+          }
+
+          summaries.push(await chunkText);
+          reportProgress(index + 1, 0);
+        }
+      }
+
       const userMessage = await templateCommands.render(
         "enhance.user",
         {
           type,
           editor: finalInput,
-          words: JSON.stringify(words),
           participants,
+          bookmarks,
+          ...(summaries ? { summaries } : { words: JSON.stringify(words) }),
         },
       );
 
-      const abortSignal = AbortSignal.any([abortController.signal, AbortSignal.timeout(120 * 1000)]);
-
-      const provider = await modelProvider();
-      const model = sessionId === onboardingSessionId
-        ? provider.languageModel("onboardingModel")
-        : provider.languageModel("defaultModel");
-
       console.log("model: ", model);
       console.log("provider: ", provider);
 
@@ -549,14 +850,34 @@ export function useEnhanceMutation({
         }
         if (chunk.type === "tool-call" && freshIsLocalLlm) {
           const chunkProgress = chunk.input?.progress ?? 0;
-          setProgress(chunkProgress);
+          reportProgress(stepCount - 1, chunkProgress);
         }
 
         const html = await miscCommands.opinionatedMdToHtml(acc);
         setEnhancedContent(html);
       }
 
-      return text.then(miscCommands.opinionatedMdToHtml);
+      const enhancedMarkdown = await text;
+      const enhancedHtml = await miscCommands.opinionatedMdToHtml(enhancedMarkdown);
+
+      if (sessionId === onboardingSessionId || !finalInput.trim()) {
+        return enhancedHtml;
+      }
+
+      try {
+        return await markRawNoteInfluence({
+          model,
+          abortSignal,
+          enhancedMarkdown,
+          enhancedHtml,
+          preMeetingNote: preMeetingText,
+          rawNote: finalInput,
+          words,
+        });
+      } catch (error) {
+        console.error("Failed to mark raw note influence:", error);
+        return enhancedHtml;
+      }
     },
     onSuccess: (enhancedContent: string | undefined) => {
       setIsCancelled(false);
@@ -10,7 +10,7 @@ import { useEffect, useRef, useState } from "react";
 import { useHypr } from "@/contexts";
 import { commands as appleCalendarCommands } from "@hypr/plugin-apple-calendar";
 import { commands as dbCommands, type Event } from "@hypr/plugin-db";
-import { commands as miscCommands } from "@hypr/plugin-misc";
+import { commands as miscCommands, type MeetingLink } from "@hypr/plugin-misc";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Popover, PopoverContent, PopoverTrigger } from "@hypr/ui/components/ui/popover";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@hypr/ui/components/ui/tabs";
@@ -64,8 +64,9 @@ export function EventChip({ sessionId, isVeryNarrow = false, isNarrow = false }:
         return null;
       }
 
-      const meetingLink = await miscCommands.parseMeetingLink(eventData.note);
-      return { ...eventData, meetingLink };
+      const stored = eventData.meeting_link ? JSON.parse(eventData.meeting_link) as MeetingLink : null;
+      const meetingLink = stored ?? await miscCommands.parseMeetingLink(eventData.note);
+      return { ...eventData, meetingLink: meetingLink?.url ?? null };
     },
   });
 
@@ -14,6 +14,7 @@ import {
 } from "lucide-react";
 import { useEffect, useState } from "react";
 import { useForm } from "react-hook-form";
+import { useHotkeys } from "react-hotkeys-hook";
 import { z } from "zod";
 
 import SoundIndicator from "@/components/sound-indicator";
@@ -282,9 +283,38 @@ function WhenActive({ sessionId }: { sessionId: string }) {
     stop: s.stop,
     setAutoEnhanceTemplate: s.setAutoEnhanceTemplate,
   }));
+  const reconnecting = useOngoingSession((s) => s.reconnecting);
   const sessionWords = useSession(ongoingSessionId!, (s) => s.session.words);
   const [isPopoverOpen, setIsPopoverOpen] = useState(false);
 
+  useHotkeys(
+    "mod+shift+b",
+    (event) => {
+      event.preventDefault();
+      listenerCommands.addBookmark(null).then(() => {
+        toast({
+          id: "bookmark-added",
+          title: "Bookmarked",
+          content: "This moment will be highlighted in your notes.",
+          dismissible: true,
+          duration: 2000,
+        });
+      }).catch((error) => {
+        toast({
+          id: "bookmark-failed",
+          title: "🚨 Failed to bookmark",
+          content: String(error),
+          dismissible: true,
+          duration: 5000,
+        });
+      });
+    },
+    {
+      enableOnFormTags: true,
+      enableOnContentEditable: true,
+    },
+  );
+
   const handlePauseSession = () => {
     ongoingSessionStore.pause();
     setIsPopoverOpen(false);
@@ -307,13 +337,15 @@ function WhenActive({ sessionId }: { sessionId: string }) {
     <Popover open={isPopoverOpen} onOpenChange={setIsPopoverOpen}>
       <PopoverTrigger asChild>
         <button
+          title={reconnecting ? "Transcription reconnecting, still recording" : undefined}
           className={cn([
             isPopoverOpen && "hover:scale-95",
-            "w-14 h-9 rounded-full bg-red-100 border-2 transition-all border-red-400 cursor-pointer outline-none p-0 flex items-center justify-center",
+            "w-14 h-9 rounded-full border-2 transition-all cursor-pointer outline-none p-0 flex items-center justify-center",
+            reconnecting ? "bg-amber-100 border-amber-400" : "bg-red-100 border-red-400",
             "shadow-[0_0_0_2px_rgba(255,255,255,0.8)_inset]",
           ])}
         >
-          <SoundIndicator color="#ef4444" size="long" />
+          <SoundIndicator color={reconnecting ? "#f59e0b" : "#ef4444"} size="long" />
         </button>
       </PopoverTrigger>
       <PopoverContent className="w-64" align="end">
@@ -566,7 +598,7 @@ function MicrophoneSelector({
     queryFn: () => listenerCommands.getCurrentMicrophoneDevice(),
   });
 
-  const handleSelectDevice = (device: string) => {
+  const handleSelectDevice = (device: string | null) => {
     listenerCommands.setMicrophoneDevice(device).then(() => {
       currentDeviceQuery.refetch();
     });
@@ -631,6 +663,25 @@ function MicrophoneSelector({
               )
               : (
                 <div className="space-y-1">
+                  <Button
+                    variant="ghost"
+                    className={cn(
+                      "w-full justify-start text-left h-8 px-2",
+                      currentDeviceQuery.data === null && "bg-neutral-100",
+                    )}
+                    onClick={() => {
+                      handleSelectDevice(null);
+                      setIsOpen(false);
+                    }}
+                  >
+                    <Icon className="w-4 h-4 mr-2 flex-shrink-0 text-neutral-600" />
+                    <span className="text-sm truncate flex-1">
+                      <Trans>System default</Trans>
+                    </span>
+                    {currentDeviceQuery.data === null && (
+                      <CheckIcon className="w-4 h-4 ml-auto flex-shrink-0 text-green-600" />
+                    )}
+                  </Button>
                   {allDevicesQuery.data?.map((device) => {
                     const isSelected = device === currentDeviceQuery.data;
                     return (
@@ -1,7 +1,6 @@
 import { useMutation, useQuery } from "@tanstack/react-query";
 import { useParams } from "@tanstack/react-router";
-import { join } from "@tauri-apps/api/path";
-import { message } from "@tauri-apps/plugin-dialog";
+import { confirm, message } from "@tauri-apps/plugin-dialog";
 import { fetch as tauriFetch } from "@tauri-apps/plugin-http";
 import { openPath, openUrl } from "@tauri-apps/plugin-opener";
 import { BookText, Check, ChevronDown, ChevronUp, Copy, FileText, HelpCircle, Mail } from "lucide-react";
@@ -11,13 +10,8 @@ import { useHypr } from "@/contexts";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { Session, Tag } from "@hypr/plugin-db";
 import { commands as dbCommands } from "@hypr/plugin-db";
-import {
-  client,
-  commands as obsidianCommands,
-  getVault,
-  patchVaultByFilename,
-  putVaultByFilename,
-} from "@hypr/plugin-obsidian";
+import { commands as notionCommands } from "@hypr/plugin-notion";
+import { client, commands as obsidianCommands, getVault } from "@hypr/plugin-obsidian";
 import { html2md } from "@hypr/tiptap/shared";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { useSession } from "@hypr/utils/contexts";
@@ -31,7 +25,7 @@ interface DirectAction {
 }
 
 interface ExportCard {
-  id: "pdf" | "email" | "obsidian";
+  id: "pdf" | "email" | "obsidian" | "notion";
   title: string;
   icon: React.ReactNode;
   description: string;
@@ -39,7 +33,7 @@ interface ExportCard {
 }
 
 interface ExportResult {
-  type: "copy" | "pdf" | "email" | "obsidian";
+  type: "copy" | "pdf" | "email" | "obsidian" | "notion";
   path?: string;
   url?: string;
   success?: boolean;
@@ -123,84 +117,29 @@ const exportHandlers = {
   obsidian: async (
     session: Session,
     selectedFolder: string,
-    sessionTags: Tag[] | undefined,
-    sessionParticipants: Array<{ full_name: string | null }> | undefined,
     includeTranscript: boolean = false,
   ): Promise<ExportResult> => {
-    const [baseFolder, apiKey, baseUrl] = await Promise.all([
-      obsidianCommands.getBaseFolder(),
-      obsidianCommands.getApiKey(),
-      obsidianCommands.getBaseUrl(),
-    ]);
+    const folder = selectedFolder === "default" ? null : selectedFolder;
 
-    client.setConfig({
-      fetch: tauriFetch,
-      auth: apiKey!,
-      baseUrl: baseUrl!,
-    });
+    let result = await obsidianCommands.syncSession(session.id, folder, includeTranscript, false);
 
-    const filename = `${session.title.replace(/[^a-zA-Z0-9 ]/g, "").replace(/\s+/g, "-")}.md`;
+    if (result.status === "conflict") {
+      const overwrite = await confirm(
+        `"${result.path}" was edited in Obsidian since it was last exported. Overwrite it with the current note?`,
+        { title: "Note changed in Obsidian", kind: "warning" },
+      );
 
-    let finalPath: string;
-    if (selectedFolder === "default") {
-      finalPath = baseFolder ? await join(baseFolder!, filename) : filename;
-    } else {
-      finalPath = await join(selectedFolder, filename);
-    }
-
-    let convertedMarkdown = session.enhanced_memo_html ? html2md(session.enhanced_memo_html) : "";
-
-    // Add transcript if requested
-    if (includeTranscript && session.words && session.words.length > 0) {
-      const transcriptText = convertWordsToTranscript(session.words);
-      if (transcriptText) {
-        convertedMarkdown += "\n\n---\n\n## Full Transcript\n\n" + transcriptText;
+      if (overwrite) {
+        result = await obsidianCommands.syncSession(session.id, folder, includeTranscript, true);
       }
     }
 
-    await putVaultByFilename({
-      client,
-      path: { filename: finalPath },
-      body: convertedMarkdown,
-      bodySerializer: null,
-      headers: {
-        "Content-Type": "text/markdown",
-      },
-    });
-
-    // Update frontmatter
-    const targets = [
-      { target: "date", value: session?.created_at ?? new Date().toISOString() },
-      ...(sessionTags && sessionTags.length > 0
-        ? [{
-          target: "tags",
-          value: sessionTags.map(tag => tag.name),
-        }]
-        : []),
-      ...(sessionParticipants && sessionParticipants.filter(participant => participant.full_name).length > 0
-        ? [{
-          target: "attendees",
-          value: sessionParticipants.map(participant => participant.full_name).filter(Boolean),
-        }]
-        : []),
-    ];
-
-    for (const { target, value } of targets) {
-      await patchVaultByFilename({
-        client,
-        path: { filename: finalPath },
-        headers: {
-          "Operation": "replace",
-          "Target-Type": "frontmatter",
-          "Target": target,
-          "Create-Target-If-Missing": "true",
-        },
-        body: value as any,
-      });
-    }
+    return { type: "obsidian", url: result.deep_link_url ?? undefined };
+  },
 
-    const url = await obsidianCommands.getDeepLinkUrl(finalPath);
-    return { type: "obsidian", url };
+  notion: async (session: Session): Promise<ExportResult> => {
+    const url = await notionCommands.exportSession(session.id);
+    return { type: "notion", url: url ?? undefined };
   },
 };
 
@@ -230,6 +169,11 @@ function getDefaultSelectedFolder(folders: ObsidianFolder[], sessionTags: Tag[])
 
 async function fetchObsidianFolders(): Promise<ObsidianFolder[]> {
   try {
+    // Folders are only listed through the Local REST API.
+    if (await obsidianCommands.getVaultPath()) {
+      return [{ value: "default", label: "Default (Root)" }];
+    }
+
     const [apiKey, baseUrl] = await Promise.all([
       obsidianCommands.getApiKey(),
       obsidianCommands.getBaseUrl(),
@@ -267,62 +211,6 @@ async function fetchObsidianFolders(): Promise<ObsidianFolder[]> {
   }
 }
 
-function convertWordsToTranscript(words: any[]): string {
-  if (!words || words.length === 0) {
-    return "";
-  }
-
-  const lines: string[] = [];
-  let currentSpeaker: any = null;
-  let currentText = "";
-
-  for (const word of words) {
-    const isSameSpeaker = (!currentSpeaker && !word.speaker)
-      || (currentSpeaker?.type === "unassigned" && word.speaker?.type === "unassigned"
-        && currentSpeaker.value?.index === word.speaker.value?.index)
-      || (currentSpeaker?.type === "assigned" && word.speaker?.type === "assigned"
-        && currentSpeaker.value?.id === word.speaker.value?.id);
-
-    if (!isSameSpeaker) {
-      if (currentText.trim()) {
-        const speakerLabel = getSpeakerLabel(currentSpeaker);
-        lines.push(`[${speakerLabel}]\n${currentText.trim()}`);
-      }
-
-      currentSpeaker = word.speaker;
-      currentText = word.text;
-    } else {
-      currentText += " " + word.text;
-    }
-  }
-
-  if (currentText.trim()) {
-    const speakerLabel = getSpeakerLabel(currentSpeaker);
-    lines.push(`[${speakerLabel}]\n${currentText.trim()}`);
-  }
-
-  return lines.join("\n\n");
-}
-
-function getSpeakerLabel(speaker: any): string {
-  if (!speaker) {
-    return "Speaker";
-  }
-
-  if (speaker.type === "assigned" && speaker.value?.label) {
-    return speaker.value.label;
-  }
-
-  if (speaker.type === "unassigned" && typeof speaker.value?.index === "number") {
-    if (speaker.value.index === 0) {
-      return "You";
-    }
-    return `Speaker ${speaker.value.index}`;
-  }
-
-  return "Speaker";
-}
-
 // Custom hook for share functionality
 export function useShareLogic() {
   const { userId } = useHypr();
@@ -339,12 +227,24 @@ export function useShareLogic() {
   const isObsidianConfigured = useQuery({
     queryKey: ["integration", "obsidian", "enabled"],
     queryFn: async () => {
-      const [enabled, apiKey, baseUrl] = await Promise.all([
+      const [enabled, apiKey, baseUrl, vaultPath] = await Promise.all([
         obsidianCommands.getEnabled(),
         obsidianCommands.getApiKey(),
         obsidianCommands.getBaseUrl(),
+        obsidianCommands.getVaultPath(),
       ]);
-      return enabled && apiKey && baseUrl;
+      return enabled && ((apiKey && baseUrl) || vaultPath);
+    },
+  });
+
+  const isNotionConfigured = useQuery({
+    queryKey: ["integration", "notion", "enabled"],
+    queryFn: async () => {
+      const [token, settings] = await Promise.all([
+        notionCommands.getToken(),
+        notionCommands.getSettings(),
+      ]);
+      return !!token && !!(settings.parent_page_id || settings.database_id);
     },
   });
 
@@ -401,6 +301,15 @@ export function useShareLogic() {
         docsUrl: "https://docs.hyprnote.com/sharing#obsidian",
       }
       : null,
+    isNotionConfigured.data
+      ? {
+        id: "notion",
+        title: "Notion",
+        icon: <BookText size={16} />,
+        description: "Export to Notion",
+        docsUrl: "https://docs.hyprnote.com/sharing#notion",
+      }
+      : null,
   ].filter(Boolean) as ExportCard[];
 
   const toggleExpanded = (id: string) => {
@@ -444,29 +353,13 @@ export function useShareLogic() {
           result = await exportHandlers.email(session, undefined);
         }
       } else if (optionId === "obsidian") {
-        sessionTags.refetch();
-        sessionParticipants.refetch();
-
-        let sessionTagsData = sessionTags.data;
-        let sessionParticipantsData = sessionParticipants.data;
-
-        if (!sessionTagsData) {
-          const tagsResult = await sessionTags.refetch();
-          sessionTagsData = tagsResult.data;
-        }
-
-        if (!sessionParticipantsData) {
-          const participantsResult = await sessionParticipants.refetch();
-          sessionParticipantsData = participantsResult.data;
-        }
-
         result = await exportHandlers.obsidian(
           session,
           selectedObsidianFolder,
-          sessionTagsData,
-          sessionParticipantsData,
           includeTranscript,
         );
+      } else if (optionId === "notion") {
+        result = await exportHandlers.notion(session);
       }
 
       const elapsed = performance.now() - start;
@@ -494,6 +387,8 @@ export function useShareLogic() {
         openUrl(result.url);
       } else if (result?.type === "obsidian" && result.url) {
         openUrl(result.url);
+      } else if (result?.type === "notion" && result.url) {
+        openUrl(result.url);
       }
     },
     onError: (error) => {
@@ -513,6 +408,7 @@ export function useShareLogic() {
 
   const handleOpenStateChange = (isOpen: boolean) => {
     if (isOpen) {
+      isNotionConfigured.refetch();
       isObsidianConfigured.refetch().then((configResult) => {
         if (configResult.data) {
           obsidianFolders.refetch();
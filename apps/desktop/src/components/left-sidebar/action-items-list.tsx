@@ -0,0 +1,69 @@
+import { Trans } from "@lingui/react/macro";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { useNavigate } from "@tanstack/react-router";
+
+import { useHypr } from "@/contexts";
+import { type ActionItem, commands as dbCommands } from "@hypr/plugin-db";
+import { Checkbox } from "@hypr/ui/components/ui/checkbox";
+import { format } from "@hypr/utils/datetime";
+
+export default function ActionItemsList() {
+  const { userId } = useHypr();
+  const queryClient = useQueryClient();
+
+  const actionItems = useQuery({
+    queryKey: ["action-items"],
+    queryFn: () => dbCommands.listActionItems({ type: "open", user_id: userId, limit: 10 }),
+  });
+
+  const setDone = useMutation({
+    mutationFn: (item: ActionItem) => dbCommands.setActionItemDone(item.id, true),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["action-items"] });
+    },
+  });
+
+  if (!actionItems.data?.length) {
+    return null;
+  }
+
+  return (
+    <section className="border-b mb-4 border-border">
+      <h2 className="font-bold text-neutral-600 mb-1">
+        <Trans>Action items</Trans>
+      </h2>
+
+      <div className="pb-2">
+        {actionItems.data.map((item) => (
+          <ActionItemRow
+            key={item.id}
+            item={item}
+            onDone={() => setDone.mutate(item)}
+          />
+        ))}
+      </div>
+    </section>
+  );
+}
+
+function ActionItemRow({ item, onDone }: { item: ActionItem; onDone: () => void }) {
+  const navigate = useNavigate();
+
+  return (
+    <div className="flex items-start gap-2 py-1.5 px-2 rounded-lg hover:bg-neutral-100">
+      <Checkbox className="mt-0.5" checked={item.done} onCheckedChange={onDone} />
+
+      <button
+        className="flex-1 flex flex-col items-start text-left truncate"
+        onClick={() => navigate({ to: "/app/note/$id", params: { id: item.session_id } })}
+      >
+        <span className="text-sm line-clamp-2">{item.text}</span>
+        {item.due_date && (
+          <span className="text-xs text-neutral-500">
+            <Trans>Due {format(new Date(item.due_date), "MMM d")}</Trans>
+          </span>
+        )}
+      </button>
+    </div>
+  );
+}
@@ -7,6 +7,7 @@ import { useHypr, useHyprSearch, useLeftSidebar } from "@/contexts";
 import { commands as dbCommands } from "@hypr/plugin-db";
 import { getCurrentWebviewWindowLabel } from "@hypr/plugin-windows";
 import { useOngoingSession, useSessions } from "@hypr/utils/contexts";
+import ActionItemsList from "./action-items-list";
 import EventsList from "./events-list";
 import NotesList from "./notes-list";
 import OngoingSession from "./ongoing-session";
@@ -120,6 +121,7 @@ export default function LeftSidebar() {
                     )}
                     activeSessionId={activeSessionId}
                   />
+                  <ActionItemsList />
                   <NotesList
                     filter={(session) => {
                       const hasActiveEvent = events.data?.some((event) => {
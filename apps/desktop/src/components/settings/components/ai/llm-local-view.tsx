@@ -1,11 +1,12 @@
-import { useQuery, useQueryClient } from "@tanstack/react-query";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { open as selectFile } from "@tauri-apps/plugin-dialog";
 import { openPath } from "@tauri-apps/plugin-opener";
 import { open } from "@tauri-apps/plugin-shell";
-import { CloudIcon, DownloadIcon, FolderIcon, HelpCircleIcon } from "lucide-react";
+import { CloudIcon, DownloadIcon, FolderIcon, HelpCircleIcon, PlusIcon, TrashIcon } from "lucide-react";
 import { useEffect } from "react";
 
 import { useLicense } from "@/hooks/use-license";
-import { commands as localLlmCommands, type SupportedModel } from "@hypr/plugin-local-llm";
+import { commands as localLlmCommands, type CustomModel, type SupportedModel } from "@hypr/plugin-local-llm";
 import { Button } from "@hypr/ui/components/ui/button";
 import { cn } from "@hypr/ui/lib/utils";
 import { type LLMModel, SharedLLMProps } from "./shared";
@@ -31,9 +32,37 @@ export function LLMLocalView({
   const isPro = !!getLicense.data?.valid;
   const queryClient = useQueryClient();
 
+  // Either a supported model key or a custom model id.
   const currentLLMModel = useQuery({
-    queryKey: ["current-llm-model"],
-    queryFn: () => localLlmCommands.getCurrentModel(),
+    queryKey: ["current-llm-model-id"],
+    queryFn: () => localLlmCommands.getCurrentModelId(),
+  });
+
+  const customModels = useQuery({
+    queryKey: ["custom-llm-models"],
+    queryFn: () => localLlmCommands.listCustomModels(),
+  });
+
+  const addCustomModel = useMutation({
+    mutationFn: async () => {
+      const path = await selectFile({
+        multiple: false,
+        directory: false,
+        filters: [{ name: "GGUF", extensions: ["gguf"] }],
+      });
+      if (typeof path === "string") {
+        await localLlmCommands.addCustomModel(path);
+      }
+    },
+    onSuccess: () => customModels.refetch(),
+  });
+
+  const removeCustomModel = useMutation({
+    mutationFn: (id: string) => localLlmCommands.removeCustomModel(id),
+    onSuccess: () => {
+      customModels.refetch();
+      queryClient.invalidateQueries({ queryKey: ["current-llm-model-id"] });
+    },
   });
 
   const handleShowFileLocation = async () => {
@@ -55,6 +84,7 @@ export function LLMLocalView({
       // Then update backend state
       await localLlmCommands.setCurrentModel(model.key as SupportedModel);
       queryClient.invalidateQueries({ queryKey: ["current-llm-model"] });
+      queryClient.invalidateQueries({ queryKey: ["current-llm-model-id"] });
 
       // Disable BOTH HyprCloud and custom when selecting local
       setCustomLLMEnabledMutation.mutate(false);
@@ -66,6 +96,19 @@ export function LLMLocalView({
     }
   };
 
+  const handleCustomModelSelection = async (model: CustomModel) => {
+    setSelectedLLMModel(model.id);
+
+    await localLlmCommands.setCurrentCustomModel(model.id);
+    queryClient.invalidateQueries({ queryKey: ["current-llm-model-id"] });
+
+    setCustomLLMEnabledMutation.mutate(false);
+    setHyprCloudEnabledMutation.mutate(false);
+    setOpenAccordion(null);
+
+    localLlmCommands.restartServer();
+  };
+
   const handleHyprCloudSelection = () => {
     setSelectedLLMModel("hyprcloud");
     // Just use the configureCustomEndpoint which handles the flags
@@ -232,6 +275,48 @@ export function LLMLocalView({
               </div>
             </button>
           ))}
+
+          {/* Custom GGUF Models */}
+          {(customModels.data ?? []).map((model) => (
+            <div
+              key={model.id}
+              className={cn(
+                "group relative p-3 rounded-lg border-2 transition-all flex items-center justify-between",
+                selectedLLMModel === model.id && !customLLMEnabled.data
+                  ? "border-solid border-blue-500 bg-blue-50"
+                  : "border-dashed border-gray-300 hover:border-gray-400 bg-white",
+              )}
+            >
+              <button
+                onClick={() => handleCustomModelSelection(model)}
+                className={cn(buttonResetClass, "flex-1 min-w-0 block cursor-pointer")}
+              >
+                <h3 className="font-semibold text-base text-gray-900 truncate">{model.name}</h3>
+                <p className="text-sm text-gray-600 truncate">{model.path}</p>
+              </button>
+              <Button
+                size="sm"
+                variant="ghost"
+                onClick={() => removeCustomModel.mutate(model.id)}
+                disabled={removeCustomModel.isPending}
+                className="text-xs h-7 px-2 ml-2"
+              >
+                <TrashIcon className="w-3 h-3" />
+              </Button>
+            </div>
+          ))}
+
+          <Button
+            size="sm"
+            variant="outline"
+            onClick={() => addCustomModel.mutate()}
+            disabled={addCustomModel.isPending}
+            className="text-xs h-7 px-2 flex items-center gap-1"
+          >
+            <PlusIcon className="w-3 h-3" />
+            Add GGUF model
+          </Button>
+          {addCustomModel.error && <p className="text-xs text-red-500">{String(addCustomModel.error)}</p>}
         </div>
       </div>
     </div>
@@ -0,0 +1,84 @@
+import { Trans } from "@lingui/react/macro";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import { openUrl } from "@tauri-apps/plugin-opener";
+import { type as getOsType } from "@tauri-apps/plugin-os";
+import { useEffect } from "react";
+
+import { commands as appleCalendarCommands } from "@hypr/plugin-apple-calendar";
+import { type CalendarProvider, commands as authCommands, events as authEvents } from "@hypr/plugin-auth";
+import { Button } from "@hypr/ui/components/ui/button";
+
+export function CloudCalendarIntegrationDetails({ provider }: { provider: CalendarProvider }) {
+  const connected = useQuery({
+    queryKey: ["settings", "calendarConnected", provider],
+    queryFn: async () => {
+      const token = await authCommands.getFromVault(
+        provider === "google" ? "google-calendar-token" : "outlook-calendar-token",
+      );
+      return token !== null;
+    },
+  });
+
+  useEffect(() => {
+    const unlisten = authEvents.authEvent.listen(({ payload }) => {
+      if (typeof payload === "object" && "calendar-connected" in payload && payload["calendar-connected"] === provider) {
+        connected.refetch();
+
+        // Otherwise the new calendars only show up on the next scheduled sync.
+        if (getOsType() === "macos") {
+          appleCalendarCommands.syncCalendars().then(() => appleCalendarCommands.syncEvents());
+        }
+      }
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [provider]);
+
+  const connect = useMutation({
+    mutationFn: async () => {
+      const url = await authCommands.startCalendarOauth(provider);
+      await openUrl(url);
+    },
+  });
+
+  const disconnect = useMutation({
+    mutationFn: () => authCommands.disconnectCalendar(provider),
+    onSuccess: () => connected.refetch(),
+  });
+
+  return (
+    <div className="flex flex-col rounded-lg border p-4 space-y-2">
+      <div className="flex items-center justify-between">
+        <div className="text-sm font-medium">
+          {connected.data ? <Trans>Connected</Trans> : <Trans>Connect your calendar and track events</Trans>}
+        </div>
+        {connected.data
+          ? (
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() => disconnect.mutate()}
+              disabled={disconnect.isPending}
+            >
+              <Trans>Disconnect</Trans>
+            </Button>
+          )
+          : (
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() => connect.mutate()}
+              disabled={connect.isPending}
+            >
+              <Trans>Connect</Trans>
+            </Button>
+          )}
+      </div>
+      {(connect.error || disconnect.error) && (
+        <div className="text-xs text-red-500">{String(connect.error ?? disconnect.error)}</div>
+      )}
+    </div>
+  );
+}
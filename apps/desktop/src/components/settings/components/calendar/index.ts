@@ -1,3 +1,4 @@
 export * from "./apple-calendar-integration-details";
 export * from "./calendar-icon-with-text";
 export * from "./calendar-selector";
+export * from "./cloud-calendar-integration-details";
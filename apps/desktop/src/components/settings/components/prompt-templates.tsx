@@ -0,0 +1,171 @@
+import { Trans } from "@lingui/react/macro";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import { ChevronDownIcon, ChevronRightIcon, HistoryIcon, RotateCcwIcon } from "lucide-react";
+import { useEffect, useState } from "react";
+
+import { commands as templateCommands } from "@hypr/plugin-template";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Textarea } from "@hypr/ui/components/ui/textarea";
+import { cn } from "@hypr/ui/lib/utils";
+
+// The prompts behind enhance, titles, tags and the rest, which can be edited and reset.
+export function PromptTemplates() {
+  const [expanded, setExpanded] = useState<string | null>(null);
+
+  const names = useQuery({
+    queryKey: ["template", "predefined"],
+    queryFn: () => templateCommands.listPredefinedTemplates(),
+  });
+
+  return (
+    <div>
+      <div className="text-sm font-medium mb-2">
+        <Trans>Prompt Templates</Trans>
+      </div>
+      <div className="space-y-2">
+        {(names.data ?? []).map((name) => (
+          <PromptTemplate
+            key={name}
+            name={name}
+            expanded={expanded === name}
+            onToggle={() => setExpanded(expanded === name ? null : name)}
+          />
+        ))}
+      </div>
+    </div>
+  );
+}
+
+function PromptTemplate({ name, expanded, onToggle }: {
+  name: string;
+  expanded: boolean;
+  onToggle: () => void;
+}) {
+  const [draft, setDraft] = useState("");
+  const [showHistory, setShowHistory] = useState(false);
+
+  const defaultSource = useQuery({
+    enabled: expanded,
+    queryKey: ["template", "default", name],
+    queryFn: () => templateCommands.getDefaultTemplate(name),
+  });
+
+  const versions = useQuery({
+    enabled: expanded,
+    queryKey: ["template", "versions", name],
+    queryFn: () => templateCommands.listTemplateVersions(name),
+  });
+
+  // The latest version is the one in use, and `null` content means it was reset to default.
+  const current = versions.data?.[0]?.content ?? defaultSource.data ?? "";
+  const overridden = versions.data?.[0]?.content != null;
+
+  useEffect(() => {
+    setDraft(current);
+  }, [current]);
+
+  const save = useMutation({
+    mutationFn: (content: string | null) =>
+      content === null
+        ? templateCommands.resetTemplate(name)
+        : templateCommands.registerTemplate(name, content),
+    onSuccess: () => versions.refetch(),
+  });
+
+  return (
+    <div className="rounded-lg border border-neutral-200 bg-white">
+      <button
+        type="button"
+        onClick={onToggle}
+        className="w-full flex items-center justify-between p-3 text-left"
+      >
+        <div className="flex items-center gap-2">
+          {expanded ? <ChevronDownIcon className="h-4 w-4" /> : <ChevronRightIcon className="h-4 w-4" />}
+          <span className="font-mono text-sm">{name}</span>
+        </div>
+        {overridden && (
+          <span className="text-xs text-neutral-500">
+            <Trans>Edited</Trans>
+          </span>
+        )}
+      </button>
+
+      {expanded && (
+        <div className="space-y-3 border-t border-neutral-200 p-3">
+          <Textarea
+            value={draft}
+            onChange={(e) => setDraft(e.target.value)}
+            className="min-h-[200px] font-mono text-xs"
+          />
+
+          {save.error && <div className="text-xs text-red-500">{String(save.error)}</div>}
+
+          <div className="flex items-center justify-between">
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => setShowHistory(!showHistory)}
+              disabled={!versions.data?.length}
+            >
+              <HistoryIcon className="h-4 w-4 mr-1" />
+              <Trans>History</Trans>
+            </Button>
+            <div className="flex items-center gap-2">
+              <Button
+                variant="outline"
+                size="sm"
+                onClick={() => save.mutate(null)}
+                disabled={!overridden || save.isPending}
+              >
+                <RotateCcwIcon className="h-4 w-4 mr-1" />
+                <Trans>Reset to default</Trans>
+              </Button>
+              <Button
+                size="sm"
+                onClick={() => save.mutate(draft)}
+                disabled={draft === current || save.isPending}
+              >
+                <Trans>Save</Trans>
+              </Button>
+            </div>
+          </div>
+
+          {showHistory && (
+            <div className="space-y-1">
+              {(versions.data ?? []).map((version, index) => (
+                <div
+                  key={version.id}
+                  className={cn(
+                    "flex items-center justify-between rounded px-2 py-1 text-xs",
+                    index === 0 ? "bg-neutral-100" : "hover:bg-neutral-50",
+                  )}
+                >
+                  <span>
+                    v{version.version} · {new Date(version.created_at).toLocaleString()}
+                    {version.content === null && (
+                      <span className="text-neutral-500">
+                        {" · "}
+                        <Trans>Default</Trans>
+                      </span>
+                    )}
+                  </span>
+                  {index > 0 && (
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      className="h-auto px-2 py-0.5 text-xs"
+                      onClick={() => save.mutate(version.content)}
+                      disabled={save.isPending}
+                    >
+                      <Trans>Restore</Trans>
+                    </Button>
+                  )}
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -1,11 +1,15 @@
 import { type CalendarIntegration } from "@/types";
 import { Accordion, AccordionContent, AccordionItem, AccordionTrigger } from "@hypr/ui/components/ui/accordion";
-import { AppleCalendarIntegrationDetails, CalendarIconWithText } from "../components/calendar";
+import {
+  AppleCalendarIntegrationDetails,
+  CalendarIconWithText,
+  CloudCalendarIntegrationDetails,
+} from "../components/calendar";
 
 const supportedIntegrations: CalendarIntegration[] = [
   "apple-calendar",
-  // "google-calendar",
-  // "outlook-calendar",
+  "google-calendar",
+  "outlook-calendar",
 ];
 
 export default function Calendar() {
@@ -25,12 +29,14 @@ export default function Calendar() {
 function Integration({ type }: { type: CalendarIntegration }) {
   return (
     <Accordion type="single" collapsible defaultValue={"apple"}>
-      <AccordionItem value="apple">
+      <AccordionItem value={type === "apple-calendar" ? "apple" : type}>
         <AccordionTrigger>
           <CalendarIconWithText type={type} />
         </AccordionTrigger>
         <AccordionContent className="px-2">
-          <AppleCalendarIntegrationDetails />
+          {type === "apple-calendar"
+            ? <AppleCalendarIntegrationDetails />
+            : <CloudCalendarIntegrationDetails provider={type === "google-calendar" ? "google" : "outlook"} />}
         </AccordionContent>
       </AccordionItem>
     </Accordion>
@@ -2,6 +2,7 @@ import { zodResolver } from "@hookform/resolvers/zod";
 import { LANGUAGES_ISO_639_1 } from "@huggingface/languages";
 import { Trans, useLingui } from "@lingui/react/macro";
 import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { message } from "@tauri-apps/plugin-dialog";
 import { Plus, X } from "lucide-react";
 import { useEffect } from "react";
 import { useForm } from "react-hook-form";
@@ -10,6 +11,7 @@ import { z } from "zod";
 import { showModelSelectToast } from "@/components/toast/model-select";
 import { commands } from "@/types";
 import { commands as dbCommands, type ConfigGeneral } from "@hypr/plugin-db";
+import { commands as listenerCommands } from "@hypr/plugin-listener";
 import { Badge } from "@hypr/ui/components/ui/badge";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem } from "@hypr/ui/components/ui/command";
@@ -81,6 +83,10 @@ const schema = z.object({
   telemetryConsent: z.boolean().optional(),
   jargons: z.string(),
   saveRecordings: z.boolean().optional(),
+  recordingFormat: z.enum(["wav", "flac", "opus"]),
+  recordingTracks: z.enum(["mixed", "stereo", "separate"]),
+  recordingBitrateKbps: z.number(),
+  noiseSuppression: z.enum(["off", "low", "medium", "high"]),
   summaryLanguage: z.enum(SUPPORTED_LANGUAGES as [string, ...string[]]),
 });
 
@@ -107,6 +113,10 @@ export default function General() {
       telemetryConsent: true,
       jargons: "",
       saveRecordings: true,
+      recordingFormat: "wav",
+      recordingTracks: "mixed",
+      recordingBitrateKbps: 32,
+      noiseSuppression: "medium",
       summaryLanguage: "en",
     },
   });
@@ -120,6 +130,10 @@ export default function General() {
         telemetryConsent: config.data.general.telemetry_consent ?? true,
         jargons: (config.data.general.jargons ?? []).join(", "),
         saveRecordings: config.data.general.save_recordings ?? true,
+        recordingFormat: config.data.general.recording_format ?? "wav",
+        recordingTracks: config.data.general.recording_tracks ?? "mixed",
+        recordingBitrateKbps: config.data.general.recording_bitrate_kbps ?? 32,
+        noiseSuppression: config.data.general.noise_suppression ?? "medium",
         summaryLanguage: config.data.general.summary_language ?? "en",
       });
     }
@@ -141,6 +155,10 @@ export default function General() {
         save_recordings: v.saveRecordings ?? true,
         selected_template_id: config.data.general.selected_template_id,
         summary_language: v.summaryLanguage,
+        recording_format: v.recordingFormat,
+        recording_tracks: v.recordingTracks,
+        recording_bitrate_kbps: v.recordingBitrateKbps,
+        noise_suppression: v.noiseSuppression,
       };
 
       await dbCommands.setConfig({
@@ -154,6 +172,12 @@ export default function General() {
     onError: console.error,
   });
 
+  const convertRecordings = useMutation({
+    mutationFn: () => listenerCommands.convertRecordings(),
+    onSuccess: () => message(t`Existing recordings are being converted in the background.`),
+    onError: (error) => message(String(error), { title: t`Conversion failed`, kind: "error" }),
+  });
+
   useEffect(() => {
     const subscription = form.watch((value, { name }) => {
       if (name === "jargons") {
@@ -204,6 +228,169 @@ export default function General() {
             )}
           />
 
+          {form.watch("saveRecordings") && (
+            <>
+              <FormField
+                control={form.control}
+                name="recordingFormat"
+                render={({ field }) => (
+                  <FormItem className="flex flex-row items-center justify-between">
+                    <div className="space-y-0.5">
+                      <FormLabel>
+                        <Trans>Recording format</Trans>
+                      </FormLabel>
+                      <FormDescription>
+                        <Trans>FLAC is lossless, Opus is a fraction of the size</Trans>
+                      </FormDescription>
+                    </div>
+                    <FormControl>
+                      <Select value={field.value} onValueChange={field.onChange}>
+                        <SelectTrigger className="w-[200px]">
+                          <SelectValue />
+                        </SelectTrigger>
+                        <SelectContent>
+                          <SelectItem value="wav">WAV</SelectItem>
+                          <SelectItem value="flac">FLAC</SelectItem>
+                          <SelectItem value="opus">Opus</SelectItem>
+                        </SelectContent>
+                      </Select>
+                    </FormControl>
+                  </FormItem>
+                )}
+              />
+
+              {form.watch("recordingFormat") === "opus" && (
+                <FormField
+                  control={form.control}
+                  name="recordingBitrateKbps"
+                  render={({ field }) => (
+                    <FormItem className="flex flex-row items-center justify-between">
+                      <div className="space-y-0.5">
+                        <FormLabel>
+                          <Trans>Recording bitrate</Trans>
+                        </FormLabel>
+                        <FormDescription>
+                          <Trans>Higher bitrates sound better and take more space</Trans>
+                        </FormDescription>
+                      </div>
+                      <FormControl>
+                        <Select
+                          value={String(field.value)}
+                          onValueChange={(value) => field.onChange(Number(value))}
+                        >
+                          <SelectTrigger className="w-[200px]">
+                            <SelectValue />
+                          </SelectTrigger>
+                          <SelectContent>
+                            {[16, 24, 32, 64, 96].map((kbps) => (
+                              <SelectItem key={kbps} value={String(kbps)}>
+                                {kbps} kbps
+                              </SelectItem>
+                            ))}
+                          </SelectContent>
+                        </Select>
+                      </FormControl>
+                    </FormItem>
+                  )}
+                />
+              )}
+
+              <FormField
+                control={form.control}
+                name="recordingTracks"
+                render={({ field }) => (
+                  <FormItem className="flex flex-row items-center justify-between">
+                    <div className="space-y-0.5">
+                      <FormLabel>
+                        <Trans>Recording tracks</Trans>
+                      </FormLabel>
+                      <FormDescription>
+                        <Trans>Keep your microphone and system audio apart</Trans>
+                      </FormDescription>
+                    </div>
+                    <FormControl>
+                      <Select value={field.value} onValueChange={field.onChange}>
+                        <SelectTrigger className="w-[200px]">
+                          <SelectValue />
+                        </SelectTrigger>
+                        <SelectContent>
+                          <SelectItem value="mixed">
+                            <Trans>Mixed</Trans>
+                          </SelectItem>
+                          <SelectItem value="stereo">
+                            <Trans>Stereo</Trans>
+                          </SelectItem>
+                          <SelectItem value="separate">
+                            <Trans>Separate files</Trans>
+                          </SelectItem>
+                        </SelectContent>
+                      </Select>
+                    </FormControl>
+                  </FormItem>
+                )}
+              />
+
+              {form.watch("recordingFormat") !== "wav" && (
+                <div className="flex flex-row items-center justify-between">
+                  <div className="space-y-0.5">
+                    <p className="text-sm font-medium">
+                      <Trans>Convert existing recordings</Trans>
+                    </p>
+                    <p className="text-sm text-muted-foreground">
+                      <Trans>Re-encode recordings saved as WAV in the format above</Trans>
+                    </p>
+                  </div>
+                  <Button
+                    type="button"
+                    variant="outline"
+                    disabled={convertRecordings.isPending}
+                    onClick={() => convertRecordings.mutate()}
+                  >
+                    <Trans>Convert</Trans>
+                  </Button>
+                </div>
+              )}
+            </>
+          )}
+
+          <FormField
+            control={form.control}
+            name="noiseSuppression"
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between">
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Noise suppression</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>Remove keyboard and fan noise from your microphone. Applies to all recordings, starting with the next one</Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Select value={field.value} onValueChange={field.onChange}>
+                    <SelectTrigger className="w-[200px]">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value="off">
+                        <Trans>Off</Trans>
+                      </SelectItem>
+                      <SelectItem value="low">
+                        <Trans>Low</Trans>
+                      </SelectItem>
+                      <SelectItem value="medium">
+                        <Trans>Medium</Trans>
+                      </SelectItem>
+                      <SelectItem value="high">
+                        <Trans>High</Trans>
+                      </SelectItem>
+                    </SelectContent>
+                  </Select>
+                </FormControl>
+              </FormItem>
+            )}
+          />
+
           <FormField
             control={form.control}
             name="telemetryConsent"
@@ -1,11 +1,15 @@
 import { zodResolver } from "@hookform/resolvers/zod";
 import { Trans } from "@lingui/react/macro";
-import { useQuery } from "@tanstack/react-query";
-import { useEffect } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { useEffect, useState } from "react";
 import { useForm } from "react-hook-form";
 import { z } from "zod";
 
+import { commands as dbCommands } from "@hypr/plugin-db";
+import { commands as notionCommands, type NotionSettings } from "@hypr/plugin-notion";
 import { commands as obsidianCommands } from "@hypr/plugin-obsidian";
+import { commands as slackCommands } from "@hypr/plugin-slack";
+import { Checkbox } from "@hypr/ui/components/ui/checkbox";
 import {
   Form,
   FormControl,
@@ -16,6 +20,7 @@ import {
   FormMessage,
 } from "@hypr/ui/components/ui/form";
 import { Input } from "@hypr/ui/components/ui/input";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { Switch } from "@hypr/ui/components/ui/switch";
 
 const schema = z.object({
@@ -23,9 +28,13 @@ const schema = z.object({
   baseUrl: z.string().url().refine((url) => url.startsWith("http://") && !url.startsWith("https://"), {
     message: "URL must start with http://, not https://",
   }),
-  apiKey: z.string().min(1, "API key is required"),
+  apiKey: z.string(),
   vaultName: z.string().min(1, "Vault name is required"),
   baseFolder: z.string().optional(),
+  vaultPath: z.string().optional(),
+}).refine((values) => values.apiKey.length > 0 || !!values.vaultPath?.trim(), {
+  message: "API key is required, unless a vault folder is set",
+  path: ["apiKey"],
 });
 
 type FormValues = z.infer<typeof schema>;
@@ -56,6 +65,11 @@ export default function IntegrationsComponent() {
     queryFn: () => obsidianCommands.getVaultName(),
   });
 
+  const getVaultPath = useQuery({
+    queryKey: ["obsidian-vault-path"],
+    queryFn: () => obsidianCommands.getVaultPath(),
+  });
+
   const form = useForm<FormValues>({
     resolver: zodResolver(schema),
     mode: "onChange",
@@ -65,6 +79,7 @@ export default function IntegrationsComponent() {
       apiKey: "",
       vaultName: "",
       baseFolder: "",
+      vaultPath: "",
     },
   });
 
@@ -75,6 +90,7 @@ export default function IntegrationsComponent() {
       apiKey: getApiKey.data || "",
       vaultName: getVaultName.data || "",
       baseFolder: getBaseFolder.data || "",
+      vaultPath: getVaultPath.data || "",
     });
   }, [
     form,
@@ -83,6 +99,7 @@ export default function IntegrationsComponent() {
     getApiKey.data,
     getVaultName.data,
     getBaseFolder.data,
+    getVaultPath.data,
   ]);
 
   useEffect(() => {
@@ -106,6 +123,10 @@ export default function IntegrationsComponent() {
       if (name === "baseFolder") {
         obsidianCommands.setBaseFolder(value.baseFolder ?? "");
       }
+
+      if (name === "vaultPath") {
+        obsidianCommands.setVaultPath(value.vaultPath?.trim() || null);
+      }
     });
 
     // store initial base url even if it's not by the user
@@ -266,12 +287,40 @@ export default function IntegrationsComponent() {
                       </FormItem>
                     )}
                   />
+
+                  <FormField
+                    control={form.control}
+                    name="vaultPath"
+                    render={({ field }) => (
+                      <FormItem>
+                        <FormLabel>
+                          <Trans>Vault Folder</Trans>
+                        </FormLabel>
+                        <FormControl>
+                          <Input
+                            placeholder="e.g., /Users/me/Documents/MyVault"
+                            {...field}
+                          />
+                        </FormControl>
+                        <FormDescription>
+                          <Trans>
+                            Optional. Write notes directly into this vault folder instead of using the Local REST API.
+                          </Trans>
+                        </FormDescription>
+                        <FormMessage />
+                      </FormItem>
+                    )}
+                  />
                 </div>
               )}
             </form>
           </Form>
         </div>
 
+        <SlackIntegration />
+
+        <NotionIntegration />
+
         <div className="rounded-lg border border-dashed p-6 text-center">
           <div className="text-muted-foreground">
             <p className="text-sm">
@@ -286,3 +335,260 @@ export default function IntegrationsComponent() {
     </div>
   );
 }
+
+function SlackIntegration() {
+  const queryClient = useQueryClient();
+  const [token, setToken] = useState("");
+
+  const getToken = useQuery({
+    queryKey: ["slack-token"],
+    queryFn: () => slackCommands.getToken(),
+  });
+
+  const getChannel = useQuery({
+    queryKey: ["slack-channel"],
+    queryFn: () => slackCommands.getChannel(),
+  });
+
+  const getTagIds = useQuery({
+    queryKey: ["slack-tag-ids"],
+    queryFn: () => slackCommands.getTagIds(),
+  });
+
+  const listChannels = useQuery({
+    queryKey: ["slack-channels", getToken.data],
+    queryFn: () => slackCommands.listChannels(),
+    enabled: !!getToken.data,
+    retry: false,
+  });
+
+  const listTags = useQuery({
+    queryKey: ["all-tags"],
+    queryFn: () => dbCommands.listAllTags(),
+  });
+
+  useEffect(() => {
+    setToken(getToken.data ?? "");
+  }, [getToken.data]);
+
+  const saveToken = useMutation({
+    mutationFn: (token: string) => slackCommands.setToken(token),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["slack-token"] }),
+  });
+
+  const saveChannel = useMutation({
+    mutationFn: (channelId: string) => {
+      const channel = listChannels.data?.find((channel) => channel.id === channelId) ?? null;
+      return slackCommands.setChannel(channel);
+    },
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["slack-channel"] }),
+  });
+
+  const saveTagIds = useMutation({
+    mutationFn: (tagIds: string[]) => slackCommands.setTagIds(tagIds),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["slack-tag-ids"] }),
+  });
+
+  const toggleTag = (tagId: string, checked: boolean) => {
+    const current = getTagIds.data ?? [];
+    saveTagIds.mutate(checked ? [...current, tagId] : current.filter((id) => id !== tagId));
+  };
+
+  return (
+    <div className="rounded-lg border p-6 space-y-4">
+      <div>
+        <h4 className="text-base font-medium">
+          <Trans>Slack</Trans>
+        </h4>
+        <p className="text-sm text-muted-foreground">
+          <Trans>Post meeting summaries to a Slack channel after they are enhanced</Trans>
+        </p>
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-sm font-medium">
+          <Trans>Bot Token</Trans>
+        </label>
+        <Input
+          type="password"
+          placeholder="xoxb-..."
+          value={token}
+          onChange={(e) => setToken(e.target.value)}
+          onBlur={() => {
+            if (token !== (getToken.data ?? "")) {
+              saveToken.mutate(token);
+            }
+          }}
+        />
+        <p className="text-sm text-muted-foreground">
+          <Trans>
+            A bot token with the channels:read, groups:read and chat:write scopes.
+          </Trans>
+        </p>
+      </div>
+
+      {getToken.data && (
+        <div className="space-y-4 pt-4 border-t">
+          <div className="space-y-2">
+            <label className="text-sm font-medium">
+              <Trans>Channel</Trans>
+            </label>
+            <Select
+              value={getChannel.data?.id}
+              onValueChange={(channelId) => saveChannel.mutate(channelId)}
+              disabled={!listChannels.data}
+            >
+              <SelectTrigger className="w-[240px]">
+                <SelectValue placeholder="Select a channel">
+                  {getChannel.data && `#${getChannel.data.name}`}
+                </SelectValue>
+              </SelectTrigger>
+              <SelectContent className="max-h-[250px] overflow-auto">
+                {(listChannels.data ?? []).map((channel) => (
+                  <SelectItem key={channel.id} value={channel.id}>
+                    #{channel.name}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+            {listChannels.isError && (
+              <p className="text-sm text-destructive">
+                <Trans>Failed to load channels. Check that the token is valid.</Trans>
+              </p>
+            )}
+          </div>
+
+          <div className="space-y-2">
+            <label className="text-sm font-medium">
+              <Trans>Tags</Trans>
+            </label>
+            <p className="text-sm text-muted-foreground">
+              <Trans>Notes with any of these tags are posted automatically.</Trans>
+            </p>
+            {(listTags.data ?? []).length === 0
+              ? (
+                <p className="text-sm text-muted-foreground">
+                  <Trans>No tags yet.</Trans>
+                </p>
+              )
+              : (
+                <div className="flex flex-wrap gap-3">
+                  {(listTags.data ?? []).map((tag) => (
+                    <label key={tag.id} className="flex items-center gap-2 text-sm">
+                      <Checkbox
+                        checked={(getTagIds.data ?? []).includes(tag.id)}
+                        onCheckedChange={(checked) => toggleTag(tag.id, checked === true)}
+                      />
+                      {tag.name}
+                    </label>
+                  ))}
+                </div>
+              )}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
+
+function NotionIntegration() {
+  const queryClient = useQueryClient();
+  const [token, setToken] = useState("");
+  const [parentPageId, setParentPageId] = useState("");
+
+  const getToken = useQuery({
+    queryKey: ["notion-token"],
+    queryFn: () => notionCommands.getToken(),
+  });
+
+  const getSettings = useQuery({
+    queryKey: ["notion-settings"],
+    queryFn: () => notionCommands.getSettings(),
+  });
+
+  useEffect(() => {
+    setToken(getToken.data ?? "");
+  }, [getToken.data]);
+
+  useEffect(() => {
+    setParentPageId(getSettings.data?.parent_page_id ?? "");
+  }, [getSettings.data?.parent_page_id]);
+
+  const saveToken = useMutation({
+    mutationFn: (token: string) => notionCommands.setToken(token),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["notion-token"] }),
+  });
+
+  const saveSettings = useMutation({
+    mutationFn: (settings: NotionSettings) => notionCommands.setSettings(settings),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["notion-settings"] }),
+  });
+
+  return (
+    <div className="rounded-lg border p-6 space-y-4">
+      <div>
+        <h4 className="text-base font-medium">
+          <Trans>Notion</Trans>
+        </h4>
+        <p className="text-sm text-muted-foreground">
+          <Trans>Export notes to a Notion database from the share menu</Trans>
+        </p>
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-sm font-medium">
+          <Trans>Integration Token</Trans>
+        </label>
+        <Input
+          type="password"
+          placeholder="secret_..."
+          value={token}
+          onChange={(e) => setToken(e.target.value)}
+          onBlur={() => {
+            if (token !== (getToken.data ?? "")) {
+              saveToken.mutate(token);
+            }
+          }}
+        />
+        <p className="text-sm text-muted-foreground">
+          <Trans>The secret of an internal Notion integration.</Trans>
+        </p>
+      </div>
+
+      {getToken.data && (
+        <div className="space-y-2 pt-4 border-t">
+          <label className="text-sm font-medium">
+            <Trans>Parent Page</Trans>
+          </label>
+          <Input
+            placeholder="https://www.notion.so/..."
+            value={parentPageId}
+            onChange={(e) => setParentPageId(e.target.value)}
+            onBlur={() => {
+              if (parentPageId !== (getSettings.data?.parent_page_id ?? "")) {
+                saveSettings.mutate({
+                  parent_page_id: parentPageId.trim() || null,
+                  database_id: getSettings.data?.database_id ?? null,
+                });
+              }
+            }}
+          />
+          <p className="text-sm text-muted-foreground">
+            {getSettings.data?.database_id
+              ? <Trans>Notes are exported to the Hyprnote database under this page.</Trans>
+              : (
+                <Trans>
+                  A page shared with the integration. A Hyprnote database is created under it on the first export.
+                </Trans>
+              )}
+          </p>
+          {saveSettings.isError && (
+            <p className="text-sm text-destructive">
+              {String(saveSettings.error)}
+            </p>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
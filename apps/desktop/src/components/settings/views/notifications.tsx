@@ -1,22 +1,35 @@
 import { zodResolver } from "@hookform/resolvers/zod";
 import { Trans } from "@lingui/react/macro";
-import { useMutation, useQuery } from "@tanstack/react-query";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import { useEffect } from "react";
 import { useForm } from "react-hook-form";
 import { z } from "zod";
 
+import { commands as dbCommands } from "@hypr/plugin-db";
 import { commands as notificationCommands } from "@hypr/plugin-notification";
 import { Form, FormControl, FormDescription, FormField, FormItem, FormLabel } from "@hypr/ui/components/ui/form";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { Switch } from "@hypr/ui/components/ui/switch";
 
+const LEAD_MINUTES = ["1", "5", "10", "15", "30"];
+
 const schema = z.object({
   detect: z.boolean().optional(),
   event: z.boolean().optional(),
+  leadMinutes: z.string().optional(),
+  autoStart: z.boolean().optional(),
 });
 
 type Schema = z.infer<typeof schema>;
 
 export default function NotificationsComponent() {
+  const queryClient = useQueryClient();
+
+  const config = useQuery({
+    queryKey: ["config", "notification"],
+    queryFn: () => dbCommands.getConfig(),
+  });
+
   const eventNotification = useQuery({
     queryKey: ["notification", "event"],
     queryFn: () => notificationCommands.getEventNotification(),
@@ -32,7 +45,31 @@ export default function NotificationsComponent() {
     values: {
       detect: detectNotification.data ?? false,
       event: eventNotification.data ?? false,
+      leadMinutes: String(config.data?.notification.lead_minutes?.[0] ?? 5),
+      autoStart: config.data?.notification.auto_start ?? false,
+    },
+  });
+
+  const configMutation = useMutation({
+    mutationFn: async (v: Schema) => {
+      if (!config.data) {
+        console.error("cannot mutate config because it is not loaded");
+        return;
+      }
+
+      await dbCommands.setConfig({
+        ...config.data,
+        notification: {
+          ...config.data.notification,
+          lead_minutes: [Number(v.leadMinutes ?? 5)],
+          auto_start: v.autoStart ?? false,
+        },
+      });
     },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["config", "notification"] });
+    },
+    onError: console.error,
   });
 
   const eventMutation = useMutation({
@@ -85,10 +122,13 @@ export default function NotificationsComponent() {
       if (name === "event") {
         eventMutation.mutate(value);
       }
+      if (name === "leadMinutes" || name === "autoStart") {
+        configMutation.mutate(value);
+      }
     });
 
     return () => subscription.unsubscribe();
-  }, [eventMutation, detectMutation]);
+  }, [eventMutation, detectMutation, configMutation]);
 
   return (
     <div>
@@ -148,6 +188,72 @@ export default function NotificationsComponent() {
               </FormItem>
             )}
           />
+          {form.watch("event") && (
+            <FormField
+              control={form.control}
+              name="leadMinutes"
+              render={({ field }) => (
+                <FormItem className="space-y-6">
+                  <div className="flex flex-row items-center justify-between">
+                    <div>
+                      <FormLabel>
+                        <Trans>Remind me before meetings</Trans>
+                      </FormLabel>
+                      <FormDescription>
+                        <Trans>
+                          How many minutes before a meeting starts to show the notification.
+                        </Trans>
+                      </FormDescription>
+                    </div>
+
+                    <Select value={field.value} onValueChange={field.onChange}>
+                      <FormControl>
+                        <SelectTrigger className="w-[120px]">
+                          <SelectValue />
+                        </SelectTrigger>
+                      </FormControl>
+                      <SelectContent>
+                        {LEAD_MINUTES.map((minutes) => (
+                          <SelectItem key={minutes} value={minutes}>
+                            <Trans>{minutes} min</Trans>
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                  </div>
+                </FormItem>
+              )}
+            />
+          )}
+          {form.watch("event") && (
+            <FormField
+              control={form.control}
+              name="autoStart"
+              render={({ field }) => (
+                <FormItem className="space-y-6">
+                  <div className="flex flex-row items-center justify-between">
+                    <div>
+                      <FormLabel>
+                        <Trans>(Beta) Start recording automatically</Trans>
+                      </FormLabel>
+                      <FormDescription>
+                        <Trans>
+                          Start listening when a meeting from a selected calendar begins, or when it is detected.
+                        </Trans>
+                      </FormDescription>
+                    </div>
+
+                    <FormControl>
+                      <Switch
+                        checked={field.value}
+                        onCheckedChange={field.onChange}
+                      />
+                    </FormControl>
+                  </div>
+                </FormItem>
+              )}
+            />
+          )}
         </form>
       </Form>
     </div>
@@ -14,6 +14,7 @@ import { commands as dbCommands } from "@hypr/plugin-db";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Tooltip, TooltipContent, TooltipTrigger } from "@hypr/ui/components/ui/tooltip";
 import { cn } from "@hypr/ui/lib/utils";
+import { PromptTemplates } from "../components/prompt-templates";
 import TemplateEditor from "./template";
 
 type ViewState = "list" | "editor" | "new";
@@ -333,6 +334,8 @@ export default function TemplatesView() {
             </div>
           </div>
         )}
+
+        <PromptTemplates />
       </div>
     </div>
   );
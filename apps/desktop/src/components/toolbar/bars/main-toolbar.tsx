@@ -1,6 +1,7 @@
 import { useMatch } from "@tanstack/react-router";
 
 import { DeleteNoteButton } from "@/components/toolbar/buttons/delete-note-button";
+import { ImportAudioButton } from "@/components/toolbar/buttons/import-audio-button";
 import { NewNoteButton } from "@/components/toolbar/buttons/new-note-button";
 import { NewWindowButton } from "@/components/toolbar/buttons/new-window-button";
 // import { ShareButton } from "@/components/toolbar/buttons/share-button";
@@ -43,6 +44,7 @@ export function MainToolbar() {
           <>
             <LeftSidebarButton type="toolbar" />
             <NewNoteButton />
+            {isMain && <ImportAudioButton />}
             <DeleteNoteButton />
           </>
         )}
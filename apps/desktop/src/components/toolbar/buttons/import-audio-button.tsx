@@ -0,0 +1,30 @@
+import { Trans } from "@lingui/react/macro";
+import { FileAudioIcon } from "lucide-react";
+
+import { useNewNote } from "@/contexts";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Tooltip, TooltipContent, TooltipTrigger } from "@hypr/ui/components/ui/tooltip";
+
+export function ImportAudioButton() {
+  const { importAudio } = useNewNote();
+
+  return (
+    <Tooltip>
+      <TooltipTrigger asChild>
+        <Button
+          size="icon"
+          className="hover:bg-neutral-200 bg-transparent text-black"
+          onClick={importAudio}
+          aria-label="Import Recording"
+        >
+          <FileAudioIcon className="size-4" />
+        </Button>
+      </TooltipTrigger>
+      <TooltipContent>
+        <p>
+          <Trans>Import a recording</Trans>
+        </p>
+      </TooltipContent>
+    </Tooltip>
+  );
+}
@@ -1,20 +1,53 @@
 import { useRouter } from "@tanstack/react-router";
-import { createContext, useCallback, useContext } from "react";
+import { message, open } from "@tauri-apps/plugin-dialog";
+import { createContext, useCallback, useContext, useState } from "react";
 import { useHotkeys } from "react-hotkeys-hook";
 
+import { commands as listenerCommands } from "@hypr/plugin-listener";
+
+const IMPORT_EXTENSIONS = ["mp3", "m4a", "wav", "ogg", "mp4"];
+
 interface NewNoteContextType {
   createNewNote: () => void;
+  importAudio: () => Promise<void>;
+  // Transcription task of each imported session, until it is enhanced.
+  importTasks: Record<string, string>;
+  finishImport: (sessionId: string) => void;
 }
 
 const NewNoteContext = createContext<NewNoteContextType | null>(null);
 
 export function NewNoteProvider({ children }: { children: React.ReactNode }) {
   const { navigate } = useRouter();
+  const [importTasks, setImportTasks] = useState<Record<string, string>>({});
 
   const createNewNote = useCallback(() => {
     navigate({ to: "/app/new" });
   }, [navigate]);
 
+  const importAudio = useCallback(async () => {
+    const path = await open({
+      multiple: false,
+      directory: false,
+      filters: [{ name: "Audio", extensions: IMPORT_EXTENSIONS }],
+    });
+    if (!path) {
+      return;
+    }
+
+    try {
+      const { session_id, task_id } = await listenerCommands.importAudio(path);
+      setImportTasks((prev) => ({ ...prev, [session_id]: task_id }));
+      navigate({ to: "/app/note/$id", params: { id: session_id } });
+    } catch (error) {
+      await message(String(error), { title: "Import failed", kind: "error" });
+    }
+  }, [navigate]);
+
+  const finishImport = useCallback((sessionId: string) => {
+    setImportTasks(({ [sessionId]: _, ...rest }) => rest);
+  }, []);
+
   useHotkeys(
     "mod+n",
     (event) => {
@@ -28,7 +61,7 @@ export function NewNoteProvider({ children }: { children: React.ReactNode }) {
   );
 
   return (
-    <NewNoteContext.Provider value={{ createNewNote }}>
+    <NewNoteContext.Provider value={{ createNewNote, importAudio, importTasks, finishImport }}>
       {children}
     </NewNoteContext.Provider>
   );
@@ -529,7 +529,7 @@ function useRecordingState() {
         }
       } else {
         const newSessionId = `control-session-${Date.now()}`;
-        await listenerCommands.startSession(newSessionId);
+        await listenerCommands.startSession(newSessionId, null);
       }
     } catch (error) {
       console.error("[Control Bar] Recording error:", error);
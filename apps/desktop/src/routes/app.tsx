@@ -5,6 +5,7 @@ import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
 import { watch } from "@tauri-apps/plugin-fs";
 import { useEffect, useRef, useState } from "react";
 
+import { ImportEnhancer } from "@/components/editor-area/import-enhancer";
 import { IndividualizationModal } from "@/components/individualization-modal";
 import LeftSidebar from "@/components/left-sidebar";
 import { LicenseRefreshProvider } from "@/components/license";
@@ -132,6 +133,7 @@ function Component() {
                 <MainWindowStateEventSupport />
                 <SettingsProvider>
                   <NewNoteProvider>
+                    <ImportEnhancer />
                     <SearchProvider>
                       <EditModeProvider>
                         <div className="flex h-screen w-screen overflow-hidden">
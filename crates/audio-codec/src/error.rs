@@ -0,0 +1,21 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    WavError(#[from] hound::Error),
+    #[error(transparent)]
+    AudioError(#[from] hypr_audio_utils::Error),
+    #[error(transparent)]
+    OpusError(#[from] opus::Error),
+    #[error(transparent)]
+    OggError(#[from] ogg::OggReadError),
+    #[error("flac: {0}")]
+    FlacError(String),
+    #[error("invalid opus stream")]
+    InvalidOpusStream,
+    #[error("unsupported channel count: {0}")]
+    UnsupportedChannels(u16),
+    #[error("recordings to merge differ in sample rate")]
+    FormatMismatch,
+}
@@ -0,0 +1,361 @@
+// https://www.rfc-editor.org/rfc/rfc9639
+
+use std::{
+    fs::OpenOptions,
+    io::{Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use flacenc::{component::BitRepr, error::Verify};
+
+const BITS_PER_SAMPLE: usize = 16;
+const MAGIC: &[u8; 4] = b"fLaC";
+// Offset of the STREAMINFO body, which always comes first.
+const STREAMINFO: usize = 8;
+const STREAMINFO_LEN: usize = 34;
+
+// Frames are numbered by their first sample instead of by their index, so that the short last
+// frame of one recording can be followed by the frames of the next.
+pub fn encode(
+    path: &Path,
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+) -> Result<(), crate::Error> {
+    let mut stream = encode_stream(samples, channels, sample_rate)?;
+    let frames_at = frames_offset(&stream)?;
+    let frames = renumber_frames(&stream[frames_at..], 0)?;
+
+    stream.truncate(frames_at);
+    update_stream_info(
+        &mut stream[STREAMINFO..STREAMINFO + STREAMINFO_LEN],
+        &frames,
+        0,
+    );
+    stream.extend(frames.iter().flat_map(|frame| frame.bytes.iter()));
+
+    std::fs::write(path, stream)?;
+    Ok(())
+}
+
+// Files written by `encode` can be appended to, others have to be encoded again.
+pub fn is_appendable(path: &Path) -> bool {
+    // Enough for the metadata blocks written by `encode`, and the start of the first frame.
+    let mut data = Vec::new();
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+    if file.take(8192).read_to_end(&mut data).is_err() {
+        return false;
+    }
+
+    frames_offset(&data).is_ok_and(|at| data.len() == at || data.get(at + 1) == Some(&0xF9))
+}
+
+// Only the new frames are encoded, the existing ones are left as they are.
+pub fn append(
+    path: &Path,
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+) -> Result<(), crate::Error> {
+    let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+
+    let mut head = [0u8; STREAMINFO + STREAMINFO_LEN];
+    file.read_exact(&mut head)?;
+    if &head[..4] != MAGIC {
+        return Err(crate::Error::FlacError("not a flac file".to_string()));
+    }
+    let mut info = head[STREAMINFO..].to_vec();
+    let (existing_rate, existing_channels, total_samples) = parse_stream_info(&info);
+    if existing_rate != sample_rate {
+        return Err(crate::Error::FormatMismatch);
+    }
+
+    let samples = crate::remix(samples, channels, existing_channels);
+    let stream = encode_stream(&samples, existing_channels, sample_rate)?;
+    let frames = renumber_frames(&stream[frames_offset(&stream)?..], total_samples)?;
+
+    file.seek(SeekFrom::End(0))?;
+    for frame in &frames {
+        file.write_all(&frame.bytes)?;
+    }
+
+    update_stream_info(&mut info, &frames, total_samples);
+    file.seek(SeekFrom::Start(STREAMINFO as u64))?;
+    file.write_all(&info)?;
+    file.flush()?;
+
+    Ok(())
+}
+
+fn encode_stream(
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+) -> Result<Vec<u8>, crate::Error> {
+    let samples = samples
+        .iter()
+        .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i32)
+        .collect::<Vec<_>>();
+
+    let config = flacenc::config::Encoder::default()
+        .into_verified()
+        .map_err(|(_, e)| crate::Error::FlacError(e.to_string()))?;
+    let source = flacenc::source::MemSource::from_samples(
+        &samples,
+        channels as usize,
+        BITS_PER_SAMPLE,
+        sample_rate as usize,
+    );
+    let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+        .map_err(|e| crate::Error::FlacError(e.to_string()))?;
+
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    stream
+        .write(&mut sink)
+        .map_err(|e| crate::Error::FlacError(e.to_string()))?;
+
+    Ok(sink.as_slice().to_vec())
+}
+
+fn frames_offset(data: &[u8]) -> Result<usize, crate::Error> {
+    if data.get(..4) != Some(MAGIC) {
+        return Err(crate::Error::FlacError("not a flac file".to_string()));
+    }
+
+    let mut at = 4;
+    loop {
+        let header = data
+            .get(at..at + 4)
+            .ok_or_else(|| crate::Error::FlacError("truncated metadata".to_string()))?;
+        let len = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+        at += 4 + len;
+
+        if header[0] & 0x80 != 0 {
+            return Ok(at);
+        }
+    }
+}
+
+// (sample rate, channels, total samples)
+fn parse_stream_info(info: &[u8]) -> (u32, u16, u64) {
+    let packed = u64::from_be_bytes(info[10..18].try_into().unwrap());
+
+    (
+        (packed >> 44) as u32,
+        ((packed >> 41) & 0x7) as u16 + 1,
+        packed & 0xF_FFFF_FFFF,
+    )
+}
+
+// The MD5 of the decoded audio is cleared rather than recomputed, which means "unknown".
+fn update_stream_info(info: &mut [u8], frames: &[Frame], previous_samples: u64) {
+    let mut min_block = u16::from_be_bytes([info[0], info[1]]) as u32;
+    let mut max_block = u16::from_be_bytes([info[2], info[3]]) as u32;
+    let mut min_frame = u32::from_be_bytes([0, info[4], info[5], info[6]]);
+    let mut max_frame = u32::from_be_bytes([0, info[7], info[8], info[9]]);
+
+    if previous_samples == 0 {
+        (min_block, max_block, min_frame, max_frame) = (u32::MAX, 0, u32::MAX, 0);
+    }
+
+    // Short last frames count too, since they end up in the middle once more audio is appended.
+    for frame in frames {
+        min_block = min_block.min(frame.block_size);
+        max_block = max_block.max(frame.block_size);
+        min_frame = min_frame.min(frame.bytes.len() as u32);
+        max_frame = max_frame.max(frame.bytes.len() as u32);
+    }
+    if min_block == u32::MAX {
+        (min_block, max_block, min_frame, max_frame) = (16, 16, 0, 0);
+    }
+
+    let total = previous_samples + frames.iter().map(|f| f.block_size as u64).sum::<u64>();
+
+    info[0..2].copy_from_slice(&(min_block.max(16) as u16).to_be_bytes());
+    info[2..4].copy_from_slice(&(max_block.max(16) as u16).to_be_bytes());
+    info[4..7].copy_from_slice(&min_frame.to_be_bytes()[1..]);
+    info[7..10].copy_from_slice(&max_frame.to_be_bytes()[1..]);
+
+    let packed = u64::from_be_bytes(info[10..18].try_into().unwrap());
+    let packed = (packed & !0xF_FFFF_FFFF) | (total & 0xF_FFFF_FFFF);
+    info[10..18].copy_from_slice(&packed.to_be_bytes());
+    info[18..34].fill(0);
+}
+
+struct Frame {
+    bytes: Vec<u8>,
+    block_size: u32,
+}
+
+struct FrameHeader {
+    len: usize,
+    block_size: u32,
+    // Bytes after the coded number, up to but excluding the CRC-8.
+    tail: std::ops::Range<usize>,
+}
+
+// Splits the frames of a stream apart, and rewrites their headers to carry the number of their
+// first sample, counted from `first_sample`.
+fn renumber_frames(data: &[u8], first_sample: u64) -> Result<Vec<Frame>, crate::Error> {
+    let mut frames = Vec::new();
+    let mut sample = first_sample;
+    let mut start = 0;
+
+    while start < data.len() {
+        let header = parse_frame_header(&data[start..])
+            .ok_or_else(|| crate::Error::FlacError("invalid frame header".to_string()))?;
+        let end = frame_end(data, start, header.len)
+            .ok_or_else(|| crate::Error::FlacError("invalid frame".to_string()))?;
+
+        let mut bytes = vec![0xFF, 0xF9, data[start + 2], data[start + 3]];
+        bytes.extend(encode_number(sample));
+        bytes.extend_from_slice(&data[start + header.tail.start..start + header.tail.end]);
+        bytes.push(crc8(&bytes));
+        bytes.extend_from_slice(&data[start + header.len..end - 2]);
+        bytes.extend(crc16(&bytes).to_be_bytes());
+
+        frames.push(Frame {
+            bytes,
+            block_size: header.block_size,
+        });
+        sample += header.block_size as u64;
+        start = end;
+    }
+
+    Ok(frames)
+}
+
+// Frames do not store their length, so the end is the next valid header at which the CRC-16
+// of everything before it checks out.
+fn frame_end(data: &[u8], start: usize, header_len: usize) -> Option<usize> {
+    let mut end = start + header_len;
+
+    while end < data.len() {
+        end += 1;
+        let at_boundary = end == data.len() || parse_frame_header(&data[end..]).is_some();
+        if at_boundary && crc16(&data[start..end]) == 0 {
+            return Some(end);
+        }
+    }
+
+    None
+}
+
+fn parse_frame_header(data: &[u8]) -> Option<FrameHeader> {
+    if data.len() < 6 || data[0] != 0xFF || data[1] & 0xFE != 0xF8 {
+        return None;
+    }
+
+    let block_code = data[2] >> 4;
+    let rate_code = data[2] & 0x0F;
+    if block_code == 0 || rate_code == 0x0F || data[3] & 0x01 != 0 {
+        return None;
+    }
+
+    let number_len = match data[4].leading_ones() {
+        0 => 1,
+        n @ 2..=7 => n as usize,
+        _ => return None,
+    };
+    let mut at = 4 + number_len;
+    let tail_start = at;
+
+    let block_size = match block_code {
+        1 => 192,
+        2..=5 => 576 << (block_code - 2),
+        6 => {
+            at += 1;
+            *data.get(at - 1)? as u32 + 1
+        }
+        7 => {
+            at += 2;
+            u16::from_be_bytes([*data.get(at - 2)?, *data.get(at - 1)?]) as u32 + 1
+        }
+        _ => 256 << (block_code - 8),
+    };
+    at += match rate_code {
+        12 => 1,
+        13 | 14 => 2,
+        _ => 0,
+    };
+
+    if crc8(data.get(..at)?) != *data.get(at)? {
+        return None;
+    }
+
+    Some(FrameHeader {
+        len: at + 1,
+        block_size,
+        tail: tail_start..at,
+    })
+}
+
+// The UTF-8 like coding of frame and sample numbers, extended to 36 bits.
+fn encode_number(n: u64) -> Vec<u8> {
+    if n < 0x80 {
+        return vec![n as u8];
+    }
+
+    let len = match n {
+        ..0x800 => 2,
+        ..0x1_0000 => 3,
+        ..0x20_0000 => 4,
+        ..0x400_0000 => 5,
+        ..0x8000_0000 => 6,
+        _ => 7,
+    };
+
+    let mut bytes = (1..len)
+        .rev()
+        .map(|i| 0x80 | ((n >> (6 * (i - 1))) & 0x3F) as u8)
+        .collect::<Vec<_>>();
+    let lead = (0xFF00u16 >> len) as u8 | (n >> (6 * (len - 1))) as u8;
+    bytes.insert(0, lead);
+    bytes
+}
+
+fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |mut crc, byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |mut crc, byte| {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_number() {
+        assert_eq!(encode_number(0x41), vec![0x41]);
+        assert_eq!(encode_number(0x7FF), vec![0xDF, 0xBF]);
+        assert_eq!(encode_number(4096), vec![0xE1, 0x80, 0x80]);
+        assert_eq!(
+            encode_number(0xF_FFFF_FFFF),
+            vec![0xFE, 0xBF, 0xBF, 0xBF, 0xBF, 0xBF, 0xBF]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+mod error;
+mod flac;
+mod opus;
+
+pub use error::*;
+
+use std::path::{Path, PathBuf};
+
+// Recordings are written as WAV while a session is live, and encoded once it stops.
+const EXTENSIONS: &[&str] = &["opus", "flac", "wav"];
+const ENCODED_EXTENSIONS: &[&str] = &["opus", "flac"];
+
+// File stems of a session recording: the mix, then the optional per-source tracks.
+pub const RECORDING_TRACKS: &[&str] = &["audio", "audio_mic", "audio_speaker"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Flac,
+    Opus { bitrate_kbps: u32 },
+}
+
+impl Encoding {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Encoding::Flac => "flac",
+            Encoding::Opus { .. } => "opus",
+        }
+    }
+}
+
+// Interleaved samples, as they were recorded.
+#[derive(Debug, Clone)]
+pub struct Audio {
+    pub samples: Vec<f32>,
+    pub channels: u16,
+    pub sample_rate: u32,
+}
+
+pub fn find_recording(dir: impl AsRef<Path>, stem: &str) -> Option<PathBuf> {
+    EXTENSIONS
+        .iter()
+        .map(|ext| dir.as_ref().join(format!("{}.{}", stem, ext)))
+        .find(|path| path.exists())
+}
+
+pub fn decode(path: impl AsRef<Path>) -> Result<Audio, Error> {
+    use rodio::Source;
+
+    let path = path.as_ref();
+    if is_opus(path) {
+        return opus::decode(path);
+    }
+
+    let source = hypr_audio_utils::source_from_path(path)?;
+    let channels = source.channels();
+    let sample_rate = source.sample_rate();
+
+    Ok(Audio {
+        samples: source.convert_samples::<f32>().collect(),
+        channels,
+        sample_rate,
+    })
+}
+
+pub fn decode_mono(path: impl AsRef<Path>, sample_rate: u32) -> Result<Vec<f32>, Error> {
+    let path = path.as_ref();
+    if !is_opus(path) {
+        return Ok(hypr_audio_utils::decode_mono(path, sample_rate)?);
+    }
+
+    let audio = opus::decode(path)?;
+    let mono = hypr_audio_utils::downmix(&audio.samples, audio.channels as usize);
+
+    Ok(hypr_audio_utils::resample_audio(
+        rodio::buffer::SamplesBuffer::new(1, audio.sample_rate, mono),
+        sample_rate,
+    )?)
+}
+
+// Replaces `<stem>.wav` with `<stem>.flac` or `<stem>.opus`. When the session was recorded
+// before and already has an encoded file, the new audio is appended to it. Only a file in the
+// other format, left from before the setting changed, is decoded and encoded again.
+pub fn encode(wav_path: impl AsRef<Path>, encoding: Encoding) -> Result<PathBuf, Error> {
+    let wav_path = wav_path.as_ref();
+    let output = wav_path.with_extension(encoding.extension());
+
+    let reader = hound::WavReader::open(wav_path)?;
+    let spec = reader.spec();
+    let recorded = read_wav(reader)?;
+
+    let previous = ENCODED_EXTENSIONS
+        .iter()
+        .map(|ext| wav_path.with_extension(ext))
+        .filter(|path| path.exists())
+        .collect::<Vec<_>>();
+
+    let appendable = match encoding {
+        Encoding::Flac => flac::is_appendable(&output),
+        Encoding::Opus { .. } => true,
+    };
+    if matches!(previous.as_slice(), [path] if *path == output) && appendable {
+        match encoding {
+            Encoding::Flac => flac::append(&output, &recorded, spec.channels, spec.sample_rate)?,
+            Encoding::Opus { bitrate_kbps } => opus::append(
+                &output,
+                &recorded,
+                spec.channels,
+                spec.sample_rate,
+                bitrate_kbps,
+            )?,
+        }
+        std::fs::remove_file(wav_path)?;
+
+        return Ok(output);
+    }
+
+    let mut samples = Vec::new();
+    for path in &previous {
+        let audio = decode(path)?;
+        if audio.sample_rate != spec.sample_rate {
+            return Err(Error::FormatMismatch);
+        }
+        samples.extend(remix(&audio.samples, audio.channels, spec.channels));
+    }
+    samples.extend(recorded);
+
+    // Written next to the output first, so a failed encode never loses the previous file.
+    let tmp = output.with_extension(format!("{}.tmp", encoding.extension()));
+    match encoding {
+        Encoding::Flac => flac::encode(&tmp, &samples, spec.channels, spec.sample_rate)?,
+        Encoding::Opus { bitrate_kbps } => opus::encode(
+            &tmp,
+            &samples,
+            spec.channels,
+            spec.sample_rate,
+            bitrate_kbps,
+        )?,
+    }
+    std::fs::rename(&tmp, &output)?;
+
+    for path in previous.iter().filter(|path| **path != output) {
+        std::fs::remove_file(path)?;
+    }
+    std::fs::remove_file(wav_path)?;
+
+    Ok(output)
+}
+
+// Converts between the mono and stereo layouts, for when the track setting changed between
+// two recordings of the same session.
+pub fn remix(samples: &[f32], from: u16, to: u16) -> Vec<f32> {
+    if from == to {
+        return samples.to_vec();
+    }
+
+    hypr_audio_utils::downmix(samples, from as usize)
+        .into_iter()
+        .flat_map(|sample| std::iter::repeat_n(sample, to.max(1) as usize))
+        .collect()
+}
+
+fn read_wav<R: std::io::Read>(reader: hound::WavReader<R>) -> Result<Vec<f32>, Error> {
+    let spec = reader.spec();
+
+    let samples = match spec.sample_format {
+        hound::SampleFormat::Float => reader.into_samples::<f32>().collect::<Result<_, _>>()?,
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .into_samples::<i32>()
+                .map(|sample| sample.map(|sample| sample as f32 / scale))
+                .collect::<Result<_, _>>()?
+        }
+    };
+
+    Ok(samples)
+}
+
+fn is_opus(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("opus"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 16000;
+
+    fn write_wav(path: &Path, seconds: f32) {
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: SAMPLE_RATE,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for i in 0..(SAMPLE_RATE as f32 * seconds) as usize {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            writer
+                .write_sample((t * 440.0 * std::f32::consts::TAU).sin() * 0.5)
+                .unwrap();
+            writer.write_sample(0.0f32).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn test_encode_flac() {
+        let dir = tempfile::tempdir().unwrap();
+        let wav = dir.path().join("audio.wav");
+        write_wav(&wav, 1.0);
+
+        let flac = encode(&wav, Encoding::Flac).unwrap();
+        assert!(!wav.exists());
+        assert_eq!(find_recording(dir.path(), "audio"), Some(flac.clone()));
+
+        let audio = decode(&flac).unwrap();
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.sample_rate, SAMPLE_RATE);
+        assert_eq!(audio.samples.len(), SAMPLE_RATE as usize * 2);
+        assert!(audio.samples.iter().skip(1).step_by(2).all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_encode_flac_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let wav = dir.path().join("audio.wav");
+
+        write_wav(&wav, 1.0);
+        let flac = encode(&wav, Encoding::Flac).unwrap();
+        let first = std::fs::read(&flac).unwrap();
+
+        write_wav(&wav, 0.5);
+        encode(&wav, Encoding::Flac).unwrap();
+
+        // The frames of the first recording are kept as they were.
+        let appended = std::fs::read(&flac).unwrap();
+        assert_eq!(appended[42..first.len()], first[42..]);
+
+        let audio = decode(&flac).unwrap();
+        assert_eq!(audio.samples.len(), SAMPLE_RATE as usize * 3);
+        assert!(audio.samples.iter().skip(1).step_by(2).all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_encode_opus_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let wav = dir.path().join("audio.wav");
+
+        write_wav(&wav, 1.0);
+        encode(&wav, Encoding::Opus { bitrate_kbps: 32 }).unwrap();
+        write_wav(&wav, 0.5);
+        let opus = encode(&wav, Encoding::Opus { bitrate_kbps: 32 }).unwrap();
+
+        let audio = decode(&opus).unwrap();
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.samples.len(), SAMPLE_RATE as usize * 3);
+
+        let mono = decode_mono(&opus, SAMPLE_RATE).unwrap();
+        assert_eq!(mono.len(), SAMPLE_RATE as usize * 3 / 2);
+    }
+
+    #[test]
+    fn test_remix() {
+        assert_eq!(remix(&[0.25, 0.75], 2, 1), vec![0.5]);
+        assert_eq!(remix(&[0.5], 1, 2), vec![0.5, 0.5]);
+        assert_eq!(remix(&[0.1, 0.2], 1, 1), vec![0.1, 0.2]);
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use ogg::{PacketReader, PacketWriteEndInfo, PacketWriter};
+
+use crate::Audio;
+
+// Ogg Opus counts granule positions at 48kHz, whatever the input rate was.
+const GRANULE_RATE: u32 = 48000;
+const FRAME_MS: usize = 20;
+// Longest frame a decoder can be handed, 120ms at 48kHz.
+const MAX_FRAME_SAMPLES: usize = 5760;
+const MAX_PACKET_BYTES: usize = 4000;
+const SERIAL: u32 = 1;
+
+pub fn encode(
+    path: &Path,
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+    bitrate_kbps: u32,
+) -> Result<(), crate::Error> {
+    write_stream(
+        &mut BufWriter::new(File::create(path)?),
+        SERIAL,
+        samples,
+        channels,
+        sample_rate,
+        bitrate_kbps,
+    )
+}
+
+// The new audio becomes another logical stream chained after the existing ones, so nothing
+// that was encoded before is touched.
+// https://www.rfc-editor.org/rfc/rfc7845#section-3
+pub fn append(
+    path: &Path,
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+    bitrate_kbps: u32,
+) -> Result<(), crate::Error> {
+    let serial = last_serial(path)?.wrapping_add(1);
+
+    let mut file = OpenOptions::new().append(true).open(path)?;
+    let len = file.metadata()?.len();
+
+    let result = write_stream(
+        &mut BufWriter::new(&mut file),
+        serial,
+        samples,
+        channels,
+        sample_rate,
+        bitrate_kbps,
+    );
+
+    // A half written stream would not decode, so leave the file as it was.
+    if result.is_err() {
+        file.set_len(len)?;
+    }
+    result
+}
+
+fn write_stream<W: Write>(
+    writer: &mut W,
+    serial: u32,
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+    bitrate_kbps: u32,
+) -> Result<(), crate::Error> {
+    let mut encoder =
+        opus::Encoder::new(sample_rate, to_channels(channels)?, opus::Application::Voip)?;
+    encoder.set_bitrate(opus::Bitrate::Bits(bitrate_kbps as i32 * 1000))?;
+
+    let scale = (GRANULE_RATE / sample_rate) as u64;
+    let pre_skip = encoder.get_lookahead()? as u64 * scale;
+    let total_frames = (samples.len() / channels as usize) as u64;
+
+    let mut writer = PacketWriter::new(writer);
+    writer.write_packet(
+        head(channels, pre_skip as u16, sample_rate),
+        serial,
+        PacketWriteEndInfo::EndPage,
+        0,
+    )?;
+    writer.write_packet(tags(), serial, PacketWriteEndInfo::EndPage, 0)?;
+
+    let frame_len = sample_rate as usize * FRAME_MS / 1000 * channels as usize;
+    let mut chunks = samples.chunks(frame_len).peekable();
+    let mut output = vec![0u8; MAX_PACKET_BYTES];
+    let mut encoded_frames = 0u64;
+
+    // An empty recording still needs one packet to end the stream with.
+    let silence = vec![0.0; frame_len];
+    if chunks.peek().is_none() {
+        chunks = silence.chunks(frame_len).peekable();
+    }
+
+    while let Some(chunk) = chunks.next() {
+        let mut frame = chunk.to_vec();
+        frame.resize(frame_len, 0.0);
+
+        let len = encoder.encode_float(&frame, &mut output)?;
+        encoded_frames += (frame_len / channels as usize) as u64;
+
+        // The last granule position tells decoders where the padding starts.
+        let granule = pre_skip + encoded_frames.min(total_frames) * scale;
+        let end = if chunks.peek().is_none() {
+            PacketWriteEndInfo::EndStream
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+        writer.write_packet(output[..len].to_vec(), serial, end, granule)?;
+    }
+
+    writer.into_inner().flush()?;
+    Ok(())
+}
+
+// Chained streams are decoded one after the other, and mixed to the layout of the first one.
+pub fn decode(path: &Path) -> Result<Audio, crate::Error> {
+    let mut reader = PacketReader::new(BufReader::new(File::open(path)?));
+
+    let mut audio: Option<Audio> = None;
+    while let Some(stream) = decode_stream(&mut reader)? {
+        match audio.as_mut() {
+            None => audio = Some(stream),
+            Some(audio) => {
+                if audio.sample_rate != stream.sample_rate {
+                    return Err(crate::Error::FormatMismatch);
+                }
+                audio.samples.extend(crate::remix(
+                    &stream.samples,
+                    stream.channels,
+                    audio.channels,
+                ));
+            }
+        }
+    }
+
+    audio.ok_or(crate::Error::InvalidOpusStream)
+}
+
+fn decode_stream<R: Read + Seek>(
+    reader: &mut PacketReader<R>,
+) -> Result<Option<Audio>, crate::Error> {
+    let Some(head) = reader.read_packet()? else {
+        return Ok(None);
+    };
+    if head.data.len() < 19 || &head.data[..8] != b"OpusHead" {
+        return Err(crate::Error::InvalidOpusStream);
+    }
+    let channels = head.data[9] as u16;
+    let pre_skip = u16::from_le_bytes([head.data[10], head.data[11]]) as u64;
+    let sample_rate =
+        u32::from_le_bytes([head.data[12], head.data[13], head.data[14], head.data[15]]);
+
+    reader
+        .read_packet()?
+        .ok_or(crate::Error::InvalidOpusStream)?;
+
+    let mut decoder = opus::Decoder::new(sample_rate, to_channels(channels)?)?;
+    let scale = (GRANULE_RATE / sample_rate) as u64;
+
+    let mut samples = Vec::new();
+    let mut buffer = vec![0.0; MAX_FRAME_SAMPLES * channels as usize];
+    let mut last_granule = 0;
+
+    while let Some(packet) = reader.read_packet()? {
+        let frames = decoder.decode_float(&packet.data, &mut buffer, false)?;
+        samples.extend_from_slice(&buffer[..frames * channels as usize]);
+        last_granule = packet.absgp_page();
+
+        if packet.last_in_stream() {
+            break;
+        }
+    }
+
+    let skip = (pre_skip / scale) as usize * channels as usize;
+    let len = (last_granule.saturating_sub(pre_skip) / scale) as usize * channels as usize;
+
+    Ok(Some(Audio {
+        samples: samples.into_iter().skip(skip).take(len).collect(),
+        channels,
+        sample_rate,
+    }))
+}
+
+// Ogg pages are at most 65307 bytes, so the last one starts somewhere in the tail.
+// https://www.rfc-editor.org/rfc/rfc3533#section-6
+fn last_serial(path: &Path) -> Result<u32, crate::Error> {
+    const MAX_PAGE_BYTES: u64 = 65307;
+
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(MAX_PAGE_BYTES)))?;
+
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail)?;
+
+    let page = tail
+        .windows(4)
+        .rposition(|w| w == b"OggS")
+        .ok_or(crate::Error::InvalidOpusStream)?;
+    let serial = tail
+        .get(page + 14..page + 18)
+        .ok_or(crate::Error::InvalidOpusStream)?;
+
+    Ok(u32::from_le_bytes(serial.try_into().unwrap()))
+}
+
+fn to_channels(channels: u16) -> Result<opus::Channels, crate::Error> {
+    match channels {
+        1 => Ok(opus::Channels::Mono),
+        2 => Ok(opus::Channels::Stereo),
+        _ => Err(crate::Error::UnsupportedChannels(channels)),
+    }
+}
+
+// https://www.rfc-editor.org/rfc/rfc7845#section-5.1
+fn head(channels: u16, pre_skip: u16, sample_rate: u32) -> Vec<u8> {
+    let mut head = b"OpusHead".to_vec();
+    head.push(1);
+    head.push(channels as u8);
+    head.extend(pre_skip.to_le_bytes());
+    head.extend(sample_rate.to_le_bytes());
+    head.extend(0i16.to_le_bytes());
+    head.push(0);
+    head
+}
+
+// https://www.rfc-editor.org/rfc/rfc7845#section-5.2
+fn tags() -> Vec<u8> {
+    let vendor = b"hyprnote";
+
+    let mut tags = b"OpusTags".to_vec();
+    tags.extend((vendor.len() as u32).to_le_bytes());
+    tags.extend(vendor);
+    tags.extend(0u32.to_le_bytes());
+    tags
+}
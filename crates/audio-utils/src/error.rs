@@ -6,4 +6,6 @@ pub enum Error {
     ResamplerConstructionError(#[from] rubato::ResamplerConstructionError),
     #[error(transparent)]
     DecoderError(#[from] rodio::decoder::DecoderError),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
 }
@@ -72,12 +72,38 @@ pub fn bytes_to_f32_samples(data: &[u8]) -> Vec<f32> {
 pub fn source_from_path(
     path: impl AsRef<std::path::Path>,
 ) -> Result<rodio::Decoder<std::io::BufReader<std::fs::File>>, crate::Error> {
-    let decoder = rodio::Decoder::new(std::io::BufReader::new(
-        std::fs::File::open(path.as_ref()).unwrap(),
-    ))?;
+    let decoder =
+        rodio::Decoder::new(std::io::BufReader::new(std::fs::File::open(path.as_ref())?))?;
     Ok(decoder)
 }
 
+// Channels are averaged, so both sides of a stereo call recording are kept.
+pub fn decode_mono(
+    path: impl AsRef<std::path::Path>,
+    sample_rate: u32,
+) -> Result<Vec<f32>, crate::Error> {
+    use rodio::Source;
+
+    let source = source_from_path(path)?;
+    let channels = source.channels().max(1) as usize;
+    let original_sample_rate = source.sample_rate();
+
+    let samples = source.convert_samples::<f32>().collect::<Vec<_>>();
+    let mono = downmix(&samples, channels);
+
+    resample_audio(
+        rodio::buffer::SamplesBuffer::new(1, original_sample_rate, mono),
+        sample_rate,
+    )
+}
+
+pub fn downmix(samples: &[f32], channels: usize) -> Vec<f32> {
+    samples
+        .chunks(channels.max(1))
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
 pub fn resample_audio<S, T>(source: S, to_rate: u32) -> Result<Vec<f32>, crate::Error>
 where
     S: rodio::Source<Item = T> + Iterator<Item = T>,
@@ -115,7 +141,32 @@ where
         input_channels[i % channels].push(sample);
     }
 
-    let output_channels = resampler.process(&input_channels, None)?;
+    // The resampler takes a fixed number of frames at a time, and whatever is left at the end.
+    let mut output_channels: Vec<Vec<f32>> = vec![Vec::new(); channels];
+    let mut position = 0;
+
+    loop {
+        let end = position + resampler.input_frames_next();
+        let chunk = input_channels
+            .iter()
+            .map(|samples| &samples[position..end.min(frames_per_channel)])
+            .collect::<Vec<_>>();
+
+        let processed = if end <= frames_per_channel {
+            resampler.process(&chunk, None)?
+        } else {
+            resampler.process_partial(Some(&chunk), None)?
+        };
+
+        for (output, samples) in output_channels.iter_mut().zip(processed) {
+            output.extend(samples);
+        }
+
+        if end >= frames_per_channel {
+            break;
+        }
+        position = end;
+    }
 
     let mut output = Vec::new();
     let output_frames = output_channels[0].len();
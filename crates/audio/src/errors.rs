@@ -2,4 +2,6 @@
 pub enum Error {
     #[error("no input device found")]
     NoInputDevice,
+    #[error("speaker input unavailable: {0}")]
+    SpeakerUnavailable(String),
 }
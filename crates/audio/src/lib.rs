@@ -69,10 +69,8 @@ pub struct AudioInput {
 }
 
 impl AudioInput {
-    pub fn get_default_mic_device_name() -> String {
-        let host = cpal::default_host();
-        let device = host.default_input_device().unwrap();
-        device.name().unwrap_or("Unknown Microphone".to_string())
+    pub fn get_default_mic_device_name() -> Option<String> {
+        MicInput::resolve_device_name(None)
     }
 
     pub fn list_mic_devices() -> Vec<String> {
@@ -101,13 +99,16 @@ impl AudioInput {
         })
     }
 
-    pub fn from_speaker() -> Self {
-        Self {
+    pub fn from_speaker() -> Result<Self, crate::Error> {
+        let speaker =
+            SpeakerInput::new().map_err(|e| crate::Error::SpeakerUnavailable(e.to_string()))?;
+
+        Ok(Self {
             source: AudioSource::RealtimeSpeaker,
             mic: None,
-            speaker: Some(SpeakerInput::new().unwrap()),
+            speaker: Some(speaker),
             data: None,
-        }
+        })
     }
 
     pub fn from_recording(data: Vec<u8>) -> Self {
@@ -31,19 +31,20 @@ impl MicInput {
             .collect()
     }
 
-    pub fn new(device_name: Option<String>) -> Result<Self, crate::Error> {
-        let host = cpal::default_host();
+    // The device `new` would open for `device_name`, which can change as devices come and go.
+    pub fn resolve_device_name(device_name: Option<String>) -> Option<String> {
+        Self::find_device(&cpal::default_host(), device_name).and_then(|d| d.name().ok())
+    }
 
+    fn find_device(host: &cpal::Host, device_name: Option<String>) -> Option<cpal::Device> {
         let default_input_device = host.default_input_device();
         let input_devices: Vec<cpal::Device> = host
             .input_devices()
             .map(|devices| devices.collect())
             .unwrap_or_else(|_| Vec::new());
 
-        let device = match device_name {
-            None => default_input_device
-                .or_else(|| input_devices.into_iter().next())
-                .ok_or(crate::Error::NoInputDevice)?,
+        match device_name {
+            None => default_input_device.or_else(|| input_devices.into_iter().next()),
             Some(name) => input_devices
                 .into_iter()
                 .find(|d| d.name().unwrap_or_default() == name)
@@ -52,11 +53,17 @@ impl MicInput {
                     host.input_devices()
                         .ok()
                         .and_then(|mut devices| devices.next())
-                })
-                .ok_or(crate::Error::NoInputDevice)?,
-        };
+                }),
+        }
+    }
+
+    pub fn new(device_name: Option<String>) -> Result<Self, crate::Error> {
+        let host = cpal::default_host();
 
-        let config = device.default_input_config().unwrap();
+        let device = Self::find_device(&host, device_name).ok_or(crate::Error::NoInputDevice)?;
+        let config = device
+            .default_input_config()
+            .map_err(|_| crate::Error::NoInputDevice)?;
 
         Ok(Self {
             host,
@@ -158,7 +165,7 @@ pub struct MicStream {
 
 impl Drop for MicStream {
     fn drop(&mut self) {
-        self.drop_tx.send(()).unwrap();
+        let _ = self.drop_tx.send(());
     }
 }
 
@@ -215,6 +215,7 @@ impl CalendarSource for Handle {
                 let id = unsafe { event.eventIdentifier() }.unwrap();
                 let title = unsafe { event.title() };
                 let note = unsafe { event.notes().unwrap_or_default() };
+                let location = unsafe { event.location() }.map(|l| l.to_string());
                 let start_date = unsafe { event.startDate() };
                 let end_date = unsafe { event.endDate() };
 
@@ -254,6 +255,8 @@ impl CalendarSource for Handle {
                     end_date: offset_date_time_from(end_date),
                     google_event_url: None,
                     is_recurring,
+                    location,
+                    meeting_hints: vec![],
                 })
             })
             .sorted_by(|a, b| a.start_date.cmp(&b.start_date))
@@ -0,0 +1,196 @@
+// https://datatracker.ietf.org/doc/html/rfc4791
+// https://datatracker.ietf.org/doc/html/rfc6764#section-6
+
+use chrono::{DateTime, Utc};
+use quick_xml::events::Event as XmlEvent;
+
+pub const PROPFIND_PRINCIPAL: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+  <d:prop>
+    <d:current-user-principal/>
+  </d:prop>
+</d:propfind>"#;
+
+pub const PROPFIND_CALENDAR_HOME: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <c:calendar-home-set/>
+  </d:prop>
+</d:propfind>"#;
+
+pub const PROPFIND_CALENDARS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+  <d:prop>
+    <d:resourcetype/>
+    <d:displayname/>
+  </d:prop>
+</d:propfind>"#;
+
+pub fn calendar_query(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <d:getetag/>
+    <c:calendar-data/>
+  </d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VEVENT">
+        <c:time-range start="{}" end="{}"/>
+      </c:comp-filter>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#,
+        from.format("%Y%m%dT%H%M%SZ"),
+        to.format("%Y%m%dT%H%M%SZ"),
+    )
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Response {
+    pub href: String,
+    pub display_name: Option<String>,
+    pub is_calendar: bool,
+    pub current_user_principal: Option<String>,
+    pub calendar_home_set: Option<String>,
+    pub calendar_data: Option<String>,
+}
+
+// Servers disagree on namespace prefixes, so elements are matched by local name only.
+pub fn parse_multistatus(xml: &str) -> Result<Vec<Response>, quick_xml::Error> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+
+    let mut responses = Vec::new();
+    let mut current: Option<Response> = None;
+    let mut stack: Vec<String> = Vec::new();
+
+    loop {
+        match reader.read_event()? {
+            XmlEvent::Start(e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+
+                if name == "response" {
+                    current = Some(Response::default());
+                }
+
+                mark_calendar(&stack, current.as_mut(), e.local_name().as_ref());
+                stack.push(name);
+            }
+            XmlEvent::Empty(e) => {
+                mark_calendar(&stack, current.as_mut(), e.local_name().as_ref());
+            }
+            XmlEvent::Text(e) => {
+                let text = e.unescape()?;
+                handle_text(&stack, current.as_mut(), &text);
+            }
+            XmlEvent::CData(e) => {
+                let text = String::from_utf8_lossy(&e.into_inner()).into_owned();
+                handle_text(&stack, current.as_mut(), &text);
+            }
+            XmlEvent::End(_) => {
+                let name = stack.pop();
+                if name.as_deref() == Some("response") {
+                    responses.extend(current.take());
+                }
+            }
+            XmlEvent::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(responses)
+}
+
+fn mark_calendar(stack: &[String], current: Option<&mut Response>, name: &[u8]) {
+    if name == b"calendar" && parent(stack, 0) == Some("resourcetype") {
+        if let Some(current) = current {
+            current.is_calendar = true;
+        }
+    }
+}
+
+fn handle_text(stack: &[String], current: Option<&mut Response>, text: &str) {
+    let Some(current) = current else {
+        return;
+    };
+
+    match (parent(stack, 0), parent(stack, 1)) {
+        (Some("href"), Some("response")) => current.href = text.trim().to_string(),
+        (Some("href"), Some("current-user-principal")) => {
+            current.current_user_principal = Some(text.trim().to_string())
+        }
+        (Some("href"), Some("calendar-home-set")) => {
+            current.calendar_home_set = Some(text.trim().to_string())
+        }
+        (Some("displayname"), _) => current.display_name = Some(text.trim().to_string()),
+        (Some("calendar-data"), _) => current
+            .calendar_data
+            .get_or_insert_with(String::new)
+            .push_str(text),
+        _ => {}
+    }
+}
+
+fn parent(stack: &[String], depth: usize) -> Option<&str> {
+    stack
+        .len()
+        .checked_sub(depth + 1)
+        .map(|i| stack[i].as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multistatus() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<D:multistatus xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:response>
+    <D:href>/calendars/user/</D:href>
+    <D:propstat>
+      <D:prop>
+        <D:resourcetype><D:collection/></D:resourcetype>
+        <D:displayname>Home</D:displayname>
+      </D:prop>
+      <D:status>HTTP/1.1 200 OK</D:status>
+    </D:propstat>
+  </D:response>
+  <D:response>
+    <D:href>/calendars/user/work/</D:href>
+    <D:propstat>
+      <D:prop>
+        <D:resourcetype><D:collection/><C:calendar/></D:resourcetype>
+        <D:displayname>Work &amp; Life</D:displayname>
+        <D:current-user-principal><D:href>/principals/user/</D:href></D:current-user-principal>
+        <C:calendar-data><![CDATA[BEGIN:VCALENDAR
+END:VCALENDAR
+]]></C:calendar-data>
+      </D:prop>
+    </D:propstat>
+  </D:response>
+</D:multistatus>"#;
+
+        let responses = parse_multistatus(xml).unwrap();
+
+        assert_eq!(
+            responses,
+            vec![
+                Response {
+                    href: "/calendars/user/".to_string(),
+                    display_name: Some("Home".to_string()),
+                    ..Default::default()
+                },
+                Response {
+                    href: "/calendars/user/work/".to_string(),
+                    display_name: Some("Work & Life".to_string()),
+                    is_calendar: true,
+                    current_user_principal: Some("/principals/user/".to_string()),
+                    calendar_data: Some("BEGIN:VCALENDAR\nEND:VCALENDAR\n".to_string()),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,979 @@
+// https://datatracker.ietf.org/doc/html/rfc5545
+
+use std::collections::HashMap;
+
+use chrono::{
+    DateTime, Datelike, Days, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
+use ical::parser::ical::component::{IcalCalendar, IcalEvent};
+use ical::property::Property;
+
+use hypr_calendar_interface::{Error, Event, Participant, Platform};
+
+// Guards against unbounded rules like `FREQ=DAILY` without `COUNT` or `UNTIL` over a wide window.
+const MAX_OCCURRENCES: usize = 1000;
+
+pub fn parse(ics: &str) -> Result<Vec<IcalCalendar>, Error> {
+    let calendars = ical::IcalParser::new(ics.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| anyhow::anyhow!("invalid_ics: {}", e))?;
+
+    Ok(calendars)
+}
+
+pub fn calendar_name(calendars: &[IcalCalendar]) -> Option<String> {
+    calendars
+        .iter()
+        .find_map(|c| property(&c.properties, "X-WR-CALNAME"))
+        .and_then(|p| p.value.as_deref())
+        .map(unescape)
+}
+
+// Expands every VEVENT into the occurrences that overlap `from..to`.
+// Recurring events keep their UID as id, the sync engine disambiguates them by start date.
+pub fn expand(
+    calendar_id: &str,
+    events: &[IcalEvent],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<Event> {
+    let mut masters = Vec::new();
+    let mut overrides = HashMap::<String, Vec<&IcalEvent>>::new();
+
+    for event in events {
+        let Some(uid) = text(event, "UID") else {
+            continue;
+        };
+
+        if property(&event.properties, "RECURRENCE-ID").is_some() {
+            overrides.entry(uid).or_default().push(event);
+        } else {
+            masters.push((uid, event));
+        }
+    }
+
+    let mut results = Vec::new();
+
+    for (uid, master) in masters {
+        let Some((start, end)) = time_range(master) else {
+            tracing::warn!(uid = %uid, "ics_event_without_dtstart");
+            continue;
+        };
+
+        let event_overrides = overrides.remove(&uid).unwrap_or_default();
+
+        if property(&master.properties, "RRULE").is_none() {
+            if !is_cancelled(master) {
+                results.push(to_event(calendar_id, &uid, master, start, end, false));
+            }
+            continue;
+        }
+
+        let overridden = event_overrides
+            .iter()
+            .filter_map(|e| parse_time(property(&e.properties, "RECURRENCE-ID")?))
+            .collect::<Vec<_>>();
+        let excluded = date_list(master, "EXDATE");
+
+        let mut starts = occurrences(master, start, end - start, from, to);
+        starts.extend(date_list(master, "RDATE"));
+        starts.sort();
+        starts.dedup();
+
+        for occurrence in starts {
+            if excluded.contains(&occurrence) || overridden.contains(&occurrence) {
+                continue;
+            }
+
+            let occurrence_end = occurrence + (end - start);
+            results.push(to_event(
+                calendar_id,
+                &uid,
+                master,
+                occurrence,
+                occurrence_end,
+                true,
+            ));
+        }
+
+        for event in event_overrides {
+            if is_cancelled(event) {
+                continue;
+            }
+
+            if let Some((start, end)) = time_range(event) {
+                results.push(to_event(calendar_id, &uid, event, start, end, true));
+            }
+        }
+    }
+
+    // Overrides whose master is not part of the feed, e.g. a single invited occurrence.
+    for (uid, events) in overrides {
+        for event in events {
+            if is_cancelled(event) {
+                continue;
+            }
+
+            if let Some((start, end)) = time_range(event) {
+                results.push(to_event(calendar_id, &uid, event, start, end, true));
+            }
+        }
+    }
+
+    results.retain(|e| e.start_date < to && e.end_date > from);
+    results.sort_by_key(|e| e.start_date);
+    results
+}
+
+fn occurrences(
+    master: &IcalEvent,
+    start: DateTime<Utc>,
+    duration: Duration,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<DateTime<Utc>> {
+    // Keeps the original time zone, so that occurrences follow DST transitions.
+    let Some((dtstart, zone)) = property(&master.properties, "DTSTART")
+        .and_then(|p| parse_local(p.value.as_deref()?, param(p, "TZID")))
+    else {
+        return vec![start];
+    };
+
+    let mut dates = Vec::new();
+
+    for value in master
+        .properties
+        .iter()
+        .filter(|p| p.name == "RRULE")
+        .filter_map(|p| p.value.as_deref())
+    {
+        let Some(rule) = Rule::parse(value, zone) else {
+            tracing::warn!(rrule = %value, "ics_invalid_rrule");
+            return vec![start];
+        };
+
+        // Occurrences that started before `from` can still be in progress.
+        let (found, limited) = rule.expand(dtstart, zone, from - duration, to);
+        if limited {
+            tracing::warn!(limit = MAX_OCCURRENCES, "ics_rrule_limited");
+        }
+
+        dates.extend(found);
+    }
+
+    dates
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+// The subset of RRULE that calendar servers emit in practice. Sub-daily frequencies,
+// `BYSETPOS`, `BYWEEKNO` and `BYYEARDAY` are rejected.
+#[derive(Debug)]
+struct Rule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<DateTime<Utc>>,
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+    week_start: Weekday,
+}
+
+impl Rule {
+    fn parse(value: &str, zone: Zone) -> Option<Self> {
+        let mut frequency = None;
+        let mut rule = Rule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+
+        for part in value.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=')?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return None,
+                    })
+                }
+                "INTERVAL" => rule.interval = value.parse().ok().filter(|n| *n > 0)?,
+                "COUNT" => rule.count = Some(value.parse().ok()?),
+                "UNTIL" => rule.until = Some(parse_until(value, zone)?),
+                "BYDAY" => {
+                    rule.by_day = value.split(',').map(parse_by_day).collect::<Option<_>>()?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = value
+                        .split(',')
+                        .map(|v| v.parse().ok().filter(|n: &i32| (1..=31).contains(&n.abs())))
+                        .collect::<Option<_>>()?
+                }
+                "BYMONTH" => {
+                    rule.by_month = value
+                        .split(',')
+                        .map(|v| v.parse().ok().filter(|n| (1..=12).contains(n)))
+                        .collect::<Option<_>>()?
+                }
+                "WKST" => rule.week_start = parse_weekday(value)?,
+                "BYSETPOS" | "BYWEEKNO" | "BYYEARDAY" | "BYHOUR" | "BYMINUTE" | "BYSECOND" => {
+                    return None
+                }
+                // Unknown parts are ignored, as RFC 5545 allows x-name extensions.
+                _ => {}
+            }
+        }
+
+        rule.frequency = frequency?;
+        Some(rule)
+    }
+
+    // Returns the occurrences in `after..to`, and whether `MAX_OCCURRENCES` cut them short.
+    // `COUNT` is applied from `dtstart`, so earlier occurrences are walked but not returned.
+    fn expand(
+        &self,
+        dtstart: NaiveDateTime,
+        zone: Zone,
+        after: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> (Vec<DateTime<Utc>>, bool) {
+        let mut dates = Vec::new();
+        let mut seen = 0;
+        // Every candidate of a period is on or after its first day, so periods that begin past
+        // `to` (with a day of slack for the time zone) can not produce anything.
+        let last_day = (to + Duration::days(1)).date_naive();
+
+        for index in 0.. {
+            let Some((first, days)) = self.period(dtstart.date(), index) else {
+                break;
+            };
+            if first > last_day {
+                break;
+            }
+
+            for day in days {
+                let local = day.and_time(dtstart.time());
+                if local < dtstart {
+                    continue;
+                }
+                // Local times skipped by a DST transition do not occur.
+                let Some(occurrence) = zone.localize(local) else {
+                    continue;
+                };
+
+                if occurrence >= to || self.until.is_some_and(|until| occurrence > until) {
+                    return (dates, false);
+                }
+
+                if occurrence >= after {
+                    if dates.len() == MAX_OCCURRENCES {
+                        return (dates, true);
+                    }
+                    dates.push(occurrence);
+                }
+
+                seen += 1;
+                if self.count.is_some_and(|count| seen >= count) {
+                    return (dates, false);
+                }
+            }
+        }
+
+        (dates, false)
+    }
+
+    // The first day of the `index`-th period, and the days in it that match the rule.
+    fn period(&self, start: NaiveDate, index: u32) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let step = index.checked_mul(self.interval)?;
+
+        let (first, days) = match self.frequency {
+            Frequency::Daily => {
+                let day = start.checked_add_days(Days::new(step.into()))?;
+                (day, vec![day])
+            }
+            Frequency::Weekly => {
+                let offset = (7 + start.weekday().num_days_from_monday()
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                let first = start
+                    .checked_sub_days(Days::new(offset.into()))?
+                    .checked_add_days(Days::new(u64::from(step) * 7))?;
+                (first, days_between(first, first + Duration::days(7)))
+            }
+            Frequency::Monthly => {
+                let first = add_months(start.with_day(1)?, step)?;
+                (first, days_between(first, add_months(first, 1)?))
+            }
+            Frequency::Yearly => {
+                let year = start.year().checked_add(i32::try_from(step).ok()?)?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                (first, days_between(first, first.with_year(year + 1)?))
+            }
+        };
+
+        Some((first, self.select(start, days)))
+    }
+
+    // Without any BYxxx part, occurrences repeat the weekday, day or date of `DTSTART`.
+    fn select(&self, start: NaiveDate, days: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        let implicit = self.by_day.is_empty() && self.by_month_day.is_empty();
+
+        let by_month =
+            if implicit && self.by_month.is_empty() && self.frequency == Frequency::Yearly {
+                vec![start.month()]
+            } else {
+                self.by_month.clone()
+            };
+        let by_month_day = match self.frequency {
+            Frequency::Monthly | Frequency::Yearly if implicit => vec![start.day() as i32],
+            _ => self.by_month_day.clone(),
+        };
+        let by_day = match self.frequency {
+            Frequency::Weekly if implicit => vec![(None, start.weekday())],
+            _ => self.by_day.clone(),
+        };
+
+        // Ordinals like `-1FR` count within the month, or the year for yearly rules without BYMONTH.
+        let yearly_ordinals = self.frequency == Frequency::Yearly && by_month.is_empty();
+
+        days.into_iter()
+            .filter(|d| by_month.is_empty() || by_month.contains(&d.month()))
+            .filter(|d| {
+                by_month_day.is_empty()
+                    || by_month_day.iter().any(|n| {
+                        let day = if *n > 0 {
+                            *n
+                        } else {
+                            days_in_month(*d) as i32 + n + 1
+                        };
+                        d.day() as i32 == day
+                    })
+            })
+            .filter(|d| {
+                by_day.is_empty()
+                    || by_day.iter().any(|(ordinal, weekday)| {
+                        if d.weekday() != *weekday {
+                            return false;
+                        }
+
+                        let Some(n) = ordinal.filter(|_| {
+                            matches!(self.frequency, Frequency::Monthly | Frequency::Yearly)
+                        }) else {
+                            return true;
+                        };
+
+                        let (first, last) = if yearly_ordinals {
+                            (
+                                d.with_ordinal(1),
+                                d.with_month(12).and_then(|d| d.with_day(31)),
+                            )
+                        } else {
+                            (d.with_day(1), d.with_day(days_in_month(*d)))
+                        };
+                        let (Some(first), Some(last)) = (first, last) else {
+                            return false;
+                        };
+
+                        if n > 0 {
+                            (*d - first).num_days() / 7 + 1 == n as i64
+                        } else {
+                            (last - *d).num_days() / 7 + 1 == -n as i64
+                        }
+                    })
+            })
+            .collect()
+    }
+}
+
+// A date-only `UNTIL` includes the whole day, a floating one is in the time zone of `DTSTART`.
+fn parse_until(value: &str, zone: Zone) -> Option<DateTime<Utc>> {
+    if value.len() == 8 {
+        let next = NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()?
+            .succ_opt()?;
+        return Some(zone.localize(next.and_hms_opt(0, 0, 0)?)? - Duration::seconds(1));
+    }
+
+    match parse_local(value, None)? {
+        (naive, Zone::Utc) => Some(naive.and_utc()),
+        (naive, _) => zone.localize(naive),
+    }
+}
+
+// e.g. `MO`, `2TU`, `-1FR`
+fn parse_by_day(value: &str) -> Option<(Option<i32>, Weekday)> {
+    let split = value.len().checked_sub(2)?;
+    let (ordinal, weekday) = (value.get(..split)?, value.get(split..)?);
+
+    let ordinal = match ordinal.trim_start_matches('+') {
+        "" => None,
+        n => Some(n.parse::<i32>().ok().filter(|n| *n != 0 && n.abs() <= 53)?),
+    };
+
+    Some((ordinal, parse_weekday(weekday)?))
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn days_between(first: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    first.iter_days().take_while(|d| *d < end).collect()
+}
+
+fn add_months(first: NaiveDate, months: u32) -> Option<NaiveDate> {
+    let total = first.year() * 12 + first.month0() as i32 + i32::try_from(months).ok()?;
+    NaiveDate::from_ymd_opt(total.div_euclid(12), total.rem_euclid(12) as u32 + 1, 1)
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    date.with_day(1)
+        .and_then(|first| add_months(first, 1))
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(31)
+}
+
+fn to_event(
+    calendar_id: &str,
+    uid: &str,
+    event: &IcalEvent,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    is_recurring: bool,
+) -> Event {
+    Event {
+        id: uid.to_string(),
+        calendar_id: calendar_id.to_string(),
+        platform: Platform::CalDav,
+        name: text(event, "SUMMARY").unwrap_or_default(),
+        note: text(event, "DESCRIPTION").unwrap_or_default(),
+        participants: participants(event),
+        start_date: start,
+        end_date: end,
+        google_event_url: None,
+        is_recurring,
+        location: text(event, "LOCATION"),
+        meeting_hints: meeting_hints(event),
+    }
+}
+
+// e.g. `X-GOOGLE-CONFERENCE` or `X-MICROSOFT-SKYPETEAMSMEETINGURL`, followed by the generic `URL`.
+fn meeting_hints(event: &IcalEvent) -> Vec<String> {
+    let vendor = event.properties.iter().filter(|p| p.name.starts_with("X-"));
+    let url = event.properties.iter().filter(|p| p.name == "URL");
+
+    vendor
+        .chain(url)
+        .filter_map(|p| p.value.as_deref())
+        .filter(|v| v.contains("://"))
+        .map(unescape)
+        .collect()
+}
+
+fn participants(event: &IcalEvent) -> Vec<Participant> {
+    let mut participants = Vec::<Participant>::new();
+
+    for p in event
+        .properties
+        .iter()
+        .filter(|p| p.name == "ORGANIZER" || p.name == "ATTENDEE")
+    {
+        let email = p
+            .value
+            .as_deref()
+            .and_then(|v| {
+                v.strip_prefix("mailto:")
+                    .or_else(|| v.strip_prefix("MAILTO:"))
+            })
+            .map(|v| v.to_string());
+        let name = param(p, "CN").map(|n| unescape(n.trim_matches('"')));
+
+        if email.is_some() && participants.iter().any(|existing| existing.email == email) {
+            continue;
+        }
+
+        match (name, email) {
+            (Some(name), email) => participants.push(Participant { name, email }),
+            (None, Some(email)) => participants.push(Participant {
+                name: email.clone(),
+                email: Some(email),
+            }),
+            (None, None) => {}
+        }
+    }
+
+    participants
+}
+
+fn time_range(event: &IcalEvent) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let dtstart = property(&event.properties, "DTSTART")?;
+    let start = parse_time(dtstart)?;
+
+    let end = property(&event.properties, "DTEND")
+        .and_then(parse_time)
+        .or_else(|| {
+            let duration = property(&event.properties, "DURATION")?.value.as_deref()?;
+            Some(start + parse_duration(duration)?)
+        })
+        .unwrap_or_else(|| {
+            // All-day events without an end last one day, others are instantaneous.
+            if is_date(dtstart) {
+                start + Duration::days(1)
+            } else {
+                start
+            }
+        });
+
+    Some((start, end))
+}
+
+fn date_list(event: &IcalEvent, name: &str) -> Vec<DateTime<Utc>> {
+    event
+        .properties
+        .iter()
+        .filter(|p| p.name == name)
+        .flat_map(|p| {
+            let tzid = param(p, "TZID");
+            p.value
+                .as_deref()
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(move |v| parse_value(v.trim(), tzid))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn parse_time(property: &Property) -> Option<DateTime<Utc>> {
+    parse_value(property.value.as_deref()?, param(property, "TZID"))
+}
+
+fn parse_value(value: &str, tzid: Option<&str>) -> Option<DateTime<Utc>> {
+    let (naive, zone) = parse_local(value, tzid)?;
+    zone.localize(naive)
+}
+
+fn parse_local(value: &str, tzid: Option<&str>) -> Option<(NaiveDateTime, Zone)> {
+    if value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some((date.and_hms_opt(0, 0, 0)?, Zone::Local));
+    }
+
+    if let Some(value) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+        return Some((naive, Zone::Utc));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+
+    // Floating times, and zones we do not know about, are interpreted as local time.
+    let zone = tzid
+        .and_then(|tz| tz.parse::<chrono_tz::Tz>().ok())
+        .map(Zone::Named)
+        .unwrap_or(Zone::Local);
+
+    Some((naive, zone))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Zone {
+    Utc,
+    Named(chrono_tz::Tz),
+    Local,
+}
+
+impl Zone {
+    fn localize(self, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            Zone::Utc => Some(naive.and_utc()),
+            Zone::Named(tz) => tz
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|d| d.with_timezone(&Utc)),
+            Zone::Local => Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|d| d.with_timezone(&Utc)),
+        }
+    }
+}
+
+// e.g. `PT1H30M`, `P1D`, `-PT15M`, `P1W`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+
+    for c in value.strip_prefix('P')?.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n = number.parse::<i64>().ok()?;
+                number.clear();
+
+                total += match (unit, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+
+    Some(if negative { -total } else { total })
+}
+
+fn is_date(property: &Property) -> bool {
+    param(property, "VALUE") == Some("DATE")
+        || property.value.as_deref().is_some_and(|v| v.len() == 8)
+}
+
+fn is_cancelled(event: &IcalEvent) -> bool {
+    text(event, "STATUS").is_some_and(|s| s.eq_ignore_ascii_case("CANCELLED"))
+}
+
+fn property<'a>(properties: &'a [Property], name: &str) -> Option<&'a Property> {
+    properties.iter().find(|p| p.name == name)
+}
+
+fn param<'a>(property: &'a Property, name: &str) -> Option<&'a str> {
+    property
+        .params
+        .as_ref()?
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .and_then(|(_, values)| values.first())
+        .map(|v| v.as_str())
+}
+
+fn text(event: &IcalEvent, name: &str) -> Option<String> {
+    property(&event.properties, name)?
+        .value
+        .as_deref()
+        .map(unescape)
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') | Some('N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn events(ics: &str) -> Vec<IcalEvent> {
+        parse(ics)
+            .unwrap()
+            .into_iter()
+            .flat_map(|c| c.events)
+            .collect()
+    }
+
+    const WEEKLY: &str = "BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//hyprnote//test//EN
+X-WR-CALNAME:Team
+BEGIN:VEVENT
+UID:standup@example.com
+DTSTART:20250106T100000Z
+DTEND:20250106T103000Z
+SUMMARY:Standup
+DESCRIPTION:Daily sync\\, quickly\\nBring notes
+RRULE:FREQ=WEEKLY;COUNT=5
+EXDATE:20250113T100000Z
+ORGANIZER;CN=Jane Doe:mailto:jane@example.com
+ATTENDEE;CN=\"John Roe\";PARTSTAT=ACCEPTED:mailto:john@example.com
+ATTENDEE:mailto:jane@example.com
+ATTENDEE:mailto:sam@example.com
+END:VEVENT
+BEGIN:VEVENT
+UID:standup@example.com
+RECURRENCE-ID:20250120T100000Z
+DTSTART:20250120T150000Z
+DTEND:20250120T153000Z
+SUMMARY:Standup (moved)
+END:VEVENT
+BEGIN:VEVENT
+UID:standup@example.com
+RECURRENCE-ID:20250127T100000Z
+DTSTART:20250127T100000Z
+STATUS:CANCELLED
+END:VEVENT
+END:VCALENDAR
+";
+
+    #[test]
+    fn test_calendar_name() {
+        assert_eq!(
+            calendar_name(&parse(WEEKLY).unwrap()),
+            Some("Team".to_string())
+        );
+    }
+
+    #[test]
+    fn test_expand_recurring() {
+        let expanded = expand(
+            "team",
+            &events(WEEKLY),
+            utc("2025-01-01T00:00:00Z"),
+            utc("2025-03-01T00:00:00Z"),
+        );
+
+        let starts = expanded.iter().map(|e| e.start_date).collect::<Vec<_>>();
+        assert_eq!(
+            starts,
+            vec![
+                utc("2025-01-06T10:00:00Z"),
+                utc("2025-01-20T15:00:00Z"),
+                utc("2025-02-03T10:00:00Z"),
+            ]
+        );
+
+        assert!(expanded.iter().all(|e| e.is_recurring));
+        assert!(expanded.iter().all(|e| e.id == "standup@example.com"));
+        assert_eq!(expanded[1].name, "Standup (moved)");
+        assert_eq!(expanded[0].end_date, utc("2025-01-06T10:30:00Z"));
+        assert_eq!(expanded[0].note, "Daily sync, quickly\nBring notes");
+    }
+
+    #[test]
+    fn test_expand_window() {
+        let expanded = expand(
+            "team",
+            &events(WEEKLY),
+            utc("2025-02-03T10:15:00Z"),
+            utc("2025-02-04T00:00:00Z"),
+        );
+
+        assert_eq!(expanded.len(), 1);
+        assert_eq!(expanded[0].start_date, utc("2025-02-03T10:00:00Z"));
+    }
+
+    #[test]
+    fn test_participants() {
+        let expanded = expand(
+            "team",
+            &events(WEEKLY),
+            utc("2025-01-01T00:00:00Z"),
+            utc("2025-01-07T00:00:00Z"),
+        );
+
+        assert_eq!(
+            expanded[0].participants,
+            vec![
+                Participant {
+                    name: "Jane Doe".to_string(),
+                    email: Some("jane@example.com".to_string()),
+                },
+                Participant {
+                    name: "John Roe".to_string(),
+                    email: Some("john@example.com".to_string()),
+                },
+                Participant {
+                    name: "sam@example.com".to_string(),
+                    email: Some("sam@example.com".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_time_zone() {
+        let ics = "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:review@example.com
+DTSTART;TZID=Europe/Berlin:20250320T090000
+DURATION:PT1H
+RRULE:FREQ=WEEKLY;BYDAY=TH;COUNT=3
+SUMMARY:Review
+END:VEVENT
+END:VCALENDAR
+";
+
+        let expanded = expand(
+            "work",
+            &events(ics),
+            utc("2025-03-01T00:00:00Z"),
+            utc("2025-04-10T00:00:00Z"),
+        );
+
+        // Berlin switches to summer time on 2025-03-30.
+        assert_eq!(
+            expanded
+                .iter()
+                .map(|e| (e.start_date, e.end_date))
+                .collect::<Vec<_>>(),
+            vec![
+                (utc("2025-03-20T08:00:00Z"), utc("2025-03-20T09:00:00Z")),
+                (utc("2025-03-27T08:00:00Z"), utc("2025-03-27T09:00:00Z")),
+                (utc("2025-04-03T07:00:00Z"), utc("2025-04-03T08:00:00Z")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_monthly() {
+        let ics = "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:retro@example.com
+DTSTART:20250131T160000Z
+DTEND:20250131T170000Z
+RRULE:FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20250530T160000Z
+SUMMARY:Retro
+END:VEVENT
+BEGIN:VEVENT
+UID:payroll@example.com
+DTSTART:20250131T090000Z
+DTEND:20250131T093000Z
+RRULE:FREQ=MONTHLY;COUNT=3
+SUMMARY:Payroll
+END:VEVENT
+END:VCALENDAR
+";
+
+        let expanded = expand(
+            "work",
+            &events(ics),
+            utc("2025-01-01T00:00:00Z"),
+            utc("2026-01-01T00:00:00Z"),
+        );
+
+        let starts = |id: &str| {
+            expanded
+                .iter()
+                .filter(|e| e.id == id)
+                .map(|e| e.start_date)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            starts("retro@example.com"),
+            vec![
+                utc("2025-01-31T16:00:00Z"),
+                utc("2025-02-28T16:00:00Z"),
+                utc("2025-03-28T16:00:00Z"),
+                utc("2025-04-25T16:00:00Z"),
+                utc("2025-05-30T16:00:00Z"),
+            ]
+        );
+
+        // Months without a 31st are skipped, not clamped to their last day.
+        assert_eq!(
+            starts("payroll@example.com"),
+            vec![
+                utc("2025-01-31T09:00:00Z"),
+                utc("2025-03-31T09:00:00Z"),
+                utc("2025-05-31T09:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_single() {
+        let ics = "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:lunch@example.com
+DTSTART:20250110T120000Z
+DTEND:20250110T130000Z
+SUMMARY:Lunch
+LOCATION:Cafe\\, 2nd floor
+URL:https://example.com/lunch
+X-GOOGLE-CONFERENCE:https://meet.google.com/xkf-xcmo-rwh
+X-APPLE-TRAVEL-ADVISORY-BEHAVIOR:AUTOMATIC
+END:VEVENT
+BEGIN:VEVENT
+UID:cancelled@example.com
+DTSTART:20250110T140000Z
+DTEND:20250110T150000Z
+STATUS:CANCELLED
+END:VEVENT
+END:VCALENDAR
+";
+
+        let expanded = expand(
+            "work",
+            &events(ics),
+            utc("2025-01-01T00:00:00Z"),
+            utc("2025-02-01T00:00:00Z"),
+        );
+
+        assert_eq!(expanded.len(), 1);
+        assert_eq!(expanded[0].id, "lunch@example.com");
+        assert!(!expanded[0].is_recurring);
+        assert_eq!(expanded[0].platform, Platform::CalDav);
+        assert_eq!(expanded[0].location.as_deref(), Some("Cafe, 2nd floor"));
+        assert_eq!(
+            expanded[0].meeting_hints,
+            vec![
+                "https://meet.google.com/xkf-xcmo-rwh".to_string(),
+                "https://example.com/lunch".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1W"), Some(Duration::weeks(1)));
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("-PT15M"), Some(Duration::minutes(-15)));
+        assert_eq!(parse_duration("1H"), None);
+    }
+}
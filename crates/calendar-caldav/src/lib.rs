@@ -0,0 +1,412 @@
+// https://datatracker.ietf.org/doc/html/rfc4791
+// https://datatracker.ietf.org/doc/html/rfc5545
+
+mod caldav;
+mod ics;
+
+use hypr_calendar_interface::{Calendar, CalendarSource, Error, Event, EventFilter, Platform};
+use reqwest::{header, Method};
+
+pub enum Source {
+    // Read-only feed: `https://`, `webcal://`, `file://` or a local path.
+    Ics {
+        url: String,
+    },
+    CalDav {
+        url: String,
+        username: String,
+        password: String,
+    },
+}
+
+pub struct Handle {
+    client: reqwest::Client,
+    source: Source,
+}
+
+impl Handle {
+    pub fn ics(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            source: Source::Ics { url: url.into() },
+        }
+    }
+
+    pub fn caldav(
+        url: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            source: Source::CalDav {
+                url: url.into(),
+                username: username.into(),
+                password: password.into(),
+            },
+        }
+    }
+
+    async fn fetch_ics(&self, url: &str) -> Result<String, Error> {
+        if let Some(path) = url.strip_prefix("file://") {
+            return Ok(tokio::fs::read_to_string(path).await?);
+        }
+
+        if !url.contains("://") {
+            return Ok(tokio::fs::read_to_string(url).await?);
+        }
+
+        let url = match url.strip_prefix("webcal://") {
+            Some(rest) => format!("https://{}", rest),
+            None => url.to_string(),
+        };
+
+        let text = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        Ok(text)
+    }
+
+    async fn dav(
+        &self,
+        method: &str,
+        url: &url::Url,
+        depth: &str,
+        body: String,
+    ) -> Result<Vec<caldav::Response>, Error> {
+        let Source::CalDav {
+            username, password, ..
+        } = &self.source
+        else {
+            anyhow::bail!("not_a_caldav_source");
+        };
+
+        let text = self
+            .client
+            .request(Method::from_bytes(method.as_bytes())?, url.clone())
+            .basic_auth(username, Some(password))
+            .header("Depth", depth)
+            .header(header::CONTENT_TYPE, "application/xml; charset=utf-8")
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        Ok(caldav::parse_multistatus(&text)?)
+    }
+
+    // Follows `current-user-principal` and `calendar-home-set`, so that users can paste the server root.
+    // Servers that do not support discovery are expected to be given the calendar home directly.
+    async fn calendar_home(&self, url: &url::Url) -> Result<url::Url, Error> {
+        let principal = self
+            .dav("PROPFIND", url, "0", caldav::PROPFIND_PRINCIPAL.to_string())
+            .await?
+            .into_iter()
+            .find_map(|r| r.current_user_principal)
+            .map(|href| url.join(&href))
+            .transpose()?
+            .unwrap_or_else(|| url.clone());
+
+        let home = self
+            .dav(
+                "PROPFIND",
+                &principal,
+                "0",
+                caldav::PROPFIND_CALENDAR_HOME.to_string(),
+            )
+            .await?
+            .into_iter()
+            .find_map(|r| r.calendar_home_set)
+            .map(|href| principal.join(&href))
+            .transpose()?
+            .unwrap_or(principal);
+
+        Ok(home)
+    }
+}
+
+impl CalendarSource for Handle {
+    async fn list_calendars(&self) -> Result<Vec<Calendar>, Error> {
+        match &self.source {
+            Source::Ics { url } => {
+                let calendars = ics::parse(&self.fetch_ics(url).await?)?;
+
+                Ok(vec![Calendar {
+                    id: url.clone(),
+                    platform: Platform::CalDav,
+                    name: ics::calendar_name(&calendars).unwrap_or_else(|| url.clone()),
+                    source: None,
+                }])
+            }
+            Source::CalDav { url, .. } => {
+                let url = url::Url::parse(url)?;
+                let home = self.calendar_home(&url).await?;
+
+                let calendars = self
+                    .dav(
+                        "PROPFIND",
+                        &home,
+                        "1",
+                        caldav::PROPFIND_CALENDARS.to_string(),
+                    )
+                    .await?
+                    .into_iter()
+                    .filter(|r| r.is_calendar)
+                    .map(|r| -> Result<Calendar, Error> {
+                        let id = home.join(&r.href)?;
+                        let name = r
+                            .display_name
+                            .filter(|n| !n.is_empty())
+                            .unwrap_or_else(|| r.href.trim_end_matches('/').to_string());
+
+                        Ok(Calendar {
+                            id: id.to_string(),
+                            platform: Platform::CalDav,
+                            name,
+                            source: url.host_str().map(|h| h.to_string()),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                Ok(calendars)
+            }
+        }
+    }
+
+    async fn list_events(&self, filter: EventFilter) -> Result<Vec<Event>, Error> {
+        let documents = match &self.source {
+            Source::Ics { .. } => vec![self.fetch_ics(&filter.calendar_tracking_id).await?],
+            Source::CalDav { .. } => self
+                .dav(
+                    "REPORT",
+                    &url::Url::parse(&filter.calendar_tracking_id)?,
+                    "1",
+                    caldav::calendar_query(filter.from, filter.to),
+                )
+                .await?
+                .into_iter()
+                .filter_map(|r| r.calendar_data)
+                .collect(),
+        };
+
+        let mut events = Vec::new();
+        for document in documents {
+            for calendar in ics::parse(&document)? {
+                events.extend(calendar.events);
+            }
+        }
+
+        Ok(ics::expand(
+            &filter.calendar_tracking_id,
+            &events,
+            filter.from,
+            filter.to,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{
+        http::{HeaderMap, Method, StatusCode, Uri},
+        Router,
+    };
+    use chrono::{DateTime, Utc};
+
+    // base64("user:pass")
+    const AUTHORIZATION: &str = "Basic dXNlcjpwYXNz";
+
+    const WORK_ICS: &str = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:planning@example.com
+DTSTART:20250107T090000Z
+DTEND:20250107T100000Z
+SUMMARY:Planning
+RRULE:FREQ=WEEKLY;COUNT=3
+EXDATE:20250114T090000Z
+ATTENDEE;CN=Jane Doe:mailto:jane@example.com
+END:VEVENT
+END:VCALENDAR
+";
+
+    const ONE_OFF_ICS: &str = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:kickoff@example.com
+DTSTART:20250110T150000Z
+DTEND:20250110T160000Z
+SUMMARY:Kickoff
+END:VEVENT
+END:VCALENDAR
+";
+
+    fn multistatus(responses: &[String]) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">{}</d:multistatus>"#,
+            responses.join("")
+        )
+    }
+
+    fn response(href: &str, props: &str) -> String {
+        format!(
+            "<d:response><d:href>{}</d:href><d:propstat><d:prop>{}</d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>",
+            href, props
+        )
+    }
+
+    // Stand-in for a CalDAV server such as Fastmail or Nextcloud.
+    async fn dav(method: Method, uri: Uri, headers: HeaderMap) -> (StatusCode, String) {
+        if uri.path() == "/feed.ics" {
+            return (StatusCode::OK, WORK_ICS.to_string());
+        }
+
+        if headers.get("authorization").and_then(|v| v.to_str().ok()) != Some(AUTHORIZATION) {
+            return (StatusCode::UNAUTHORIZED, String::new());
+        }
+
+        let body = match (method.as_str(), uri.path()) {
+            ("PROPFIND", "/") => multistatus(&[response(
+                "/",
+                "<d:current-user-principal><d:href>/principals/user/</d:href></d:current-user-principal>",
+            )]),
+            ("PROPFIND", "/principals/user/") => multistatus(&[response(
+                "/principals/user/",
+                "<cal:calendar-home-set><d:href>/calendars/user/</d:href></cal:calendar-home-set>",
+            )]),
+            ("PROPFIND", "/calendars/user/") => multistatus(&[
+                response(
+                    "/calendars/user/",
+                    "<d:resourcetype><d:collection/></d:resourcetype>",
+                ),
+                response(
+                    "/calendars/user/work/",
+                    "<d:resourcetype><d:collection/><cal:calendar/></d:resourcetype><d:displayname>Work</d:displayname>",
+                ),
+                response(
+                    "/calendars/user/inbox/",
+                    "<d:resourcetype><d:collection/><cal:schedule-inbox/></d:resourcetype>",
+                ),
+            ]),
+            ("REPORT", "/calendars/user/work/") => multistatus(&[
+                response(
+                    "/calendars/user/work/planning.ics",
+                    &format!("<cal:calendar-data>{}</cal:calendar-data>", WORK_ICS),
+                ),
+                response(
+                    "/calendars/user/work/kickoff.ics",
+                    &format!("<cal:calendar-data>{}</cal:calendar-data>", ONE_OFF_ICS),
+                ),
+            ]),
+            _ => return (StatusCode::NOT_FOUND, String::new()),
+        };
+
+        (StatusCode::MULTI_STATUS, body)
+    }
+
+    async fn serve() -> String {
+        let app = Router::new().fallback(dav);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        format!("http://{}", addr)
+    }
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn january(calendar_tracking_id: String) -> EventFilter {
+        EventFilter {
+            calendar_tracking_id,
+            from: utc("2025-01-01T00:00:00Z"),
+            to: utc("2025-02-01T00:00:00Z"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_caldav() {
+        let base = serve().await;
+        let handle = Handle::caldav(format!("{}/", base), "user", "pass");
+
+        let calendars = handle.list_calendars().await.unwrap();
+        assert_eq!(calendars.len(), 1);
+        assert_eq!(calendars[0].id, format!("{}/calendars/user/work/", base));
+        assert_eq!(calendars[0].name, "Work");
+        assert_eq!(calendars[0].platform, Platform::CalDav);
+
+        let events = handle
+            .list_events(january(calendars[0].id.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|e| (e.name.as_str(), e.start_date, e.is_recurring))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Planning", utc("2025-01-07T09:00:00Z"), true),
+                ("Kickoff", utc("2025-01-10T15:00:00Z"), false),
+                ("Planning", utc("2025-01-21T09:00:00Z"), true),
+            ]
+        );
+        assert_eq!(events[0].participants[0].name, "Jane Doe");
+    }
+
+    #[tokio::test]
+    async fn test_caldav_unauthorized() {
+        let base = serve().await;
+        let handle = Handle::caldav(format!("{}/", base), "user", "wrong");
+
+        assert!(handle.list_calendars().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ics_url() {
+        let base = serve().await;
+        let url = format!("{}/feed.ics", base);
+        let handle = Handle::ics(&url);
+
+        let calendars = handle.list_calendars().await.unwrap();
+        assert_eq!(calendars.len(), 1);
+        assert_eq!(calendars[0].id, url);
+
+        let events = handle.list_events(january(url)).await.unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|e| e.name == "Planning"));
+    }
+
+    #[tokio::test]
+    async fn test_ics_file() {
+        let path = std::env::temp_dir().join(format!("hypr-{}.ics", std::process::id()));
+        std::fs::write(&path, ONE_OFF_ICS).unwrap();
+
+        let id = path.to_string_lossy().to_string();
+        let handle = Handle::ics(&id);
+
+        let events = handle.list_events(january(id)).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "Kickoff");
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -105,6 +105,11 @@ impl CalendarSource for Handle {
                     end_date: end,
                     google_event_url: Some(event.html_link.clone()),
                     is_recurring: false,
+                    location: Some(event.location.clone()).filter(|l| !l.is_empty()),
+                    meeting_hints: Some(event.hangout_link.clone())
+                        .filter(|l| !l.is_empty())
+                        .into_iter()
+                        .collect(),
                 }
             })
             .collect();
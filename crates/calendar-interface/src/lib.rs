@@ -14,6 +14,7 @@ pub enum Platform {
     Apple,
     Google,
     Outlook,
+    CalDav,
 }
 
 impl std::fmt::Display for Platform {
@@ -22,6 +23,7 @@ impl std::fmt::Display for Platform {
             Platform::Apple => write!(f, "Apple"),
             Platform::Google => write!(f, "Google"),
             Platform::Outlook => write!(f, "Outlook"),
+            Platform::CalDav => write!(f, "CalDav"),
         }
     }
 }
@@ -47,6 +49,11 @@ pub struct Event {
     pub google_event_url: Option<String>,
     #[serde(default)]
     pub is_recurring: bool,
+    #[serde(default)]
+    pub location: Option<String>,
+    // Other fields that usually carry the conference link, e.g. Google's `hangoutLink` or ICS `X-` properties.
+    #[serde(default)]
+    pub meeting_hints: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -91,6 +98,9 @@ impl Event {
             Platform::Outlook => {
                 anyhow::bail!("Outlook is not supported yet");
             }
+            Platform::CalDav => {
+                anyhow::bail!("CalDAV events can not be opened");
+            }
         }
     }
 }
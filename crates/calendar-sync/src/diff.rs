@@ -0,0 +1,705 @@
+use std::collections::{HashMap, HashSet};
+
+use hypr_db_user::{Calendar, Event, Session};
+
+#[derive(Debug, Default)]
+pub struct CalendarSyncState {
+    pub to_delete: Vec<Calendar>,
+    pub to_upsert: Vec<Calendar>,
+}
+
+#[derive(Debug, Default)]
+pub struct EventSyncState {
+    pub to_delete: Vec<Event>,
+    pub to_upsert: Vec<Event>,
+    pub to_update: Vec<Event>,
+    // Existing events moved in place to their new slot, so that linked sessions follow them.
+    pub to_reschedule: Vec<Event>,
+    pub session_transfers: Vec<(String, String)>, // (session_id, new_event_id)
+}
+
+// Recurring occurrences share the same source id, so we suffix it with the start date.
+pub fn composite_tracking_id(event: &hypr_calendar_interface::Event) -> String {
+    if event.is_recurring {
+        format!(
+            "{}__HYPR__{}",
+            event.id,
+            event.start_date.format("%Y%m%d%H%M%S")
+        )
+    } else {
+        event.id.clone()
+    }
+}
+
+// `db_calendars` must only contain calendars from the same platform as `source_calendars`.
+pub fn diff_calendars(
+    user_id: &str,
+    db_calendars: &[Calendar],
+    source_calendars: &[hypr_calendar_interface::Calendar],
+) -> CalendarSyncState {
+    let to_delete = db_calendars
+        .iter()
+        .filter(|db_c| !source_calendars.iter().any(|c| c.id == db_c.tracking_id))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let to_upsert = source_calendars
+        .iter()
+        .map(|c| {
+            let existing = db_calendars.iter().find(|db_c| db_c.tracking_id == c.id);
+
+            Calendar {
+                id: existing.map_or(uuid::Uuid::new_v4().to_string(), |c| c.id.clone()),
+                tracking_id: c.id.clone(),
+                user_id: user_id.to_string(),
+                name: c.name.clone(),
+                platform: c.platform.clone().into(),
+                selected: existing.is_some_and(|c| c.selected),
+                source: c.source.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    tracing::info!(
+        to_delete = to_delete.len(),
+        to_upsert = to_upsert.len(),
+        "diff_calendars"
+    );
+
+    CalendarSyncState {
+        to_delete,
+        to_upsert,
+    }
+}
+
+// `source_events_per_calendar` is keyed by the database id of each selected calendar.
+pub fn diff_events(
+    user_id: &str,
+    db_events_with_session: &[(Event, Option<Session>)],
+    db_selected_calendars: &[Calendar],
+    source_events_per_calendar: &HashMap<String, Vec<hypr_calendar_interface::Event>>,
+) -> EventSyncState {
+    let mut state = EventSyncState::default();
+
+    let mut handled_tracking_ids = HashSet::<String>::new();
+    let mut unmatched = Vec::<&Event>::new();
+
+    for (db_event, session) in db_events_with_session {
+        let is_selected_cal = db_selected_calendars
+            .iter()
+            .any(|c| Some(&c.id) == db_event.calendar_id.as_ref());
+
+        // if the event is not from a selected calendar and has no session, delete it
+        // applies to both recurring and non-recurring events
+        if !is_selected_cal && session.as_ref().is_none_or(|s| s.is_empty()) {
+            state.to_delete.push(db_event.clone());
+            continue;
+        }
+
+        let matching_event = db_event.calendar_id.as_ref().and_then(|calendar_id| {
+            source_events_per_calendar
+                .get(calendar_id)?
+                .iter()
+                .find(|e| composite_tracking_id(e) == db_event.tracking_id)
+        });
+
+        match matching_event {
+            Some(matching_event) => {
+                state.to_update.push(Event {
+                    id: db_event.id.clone(),
+                    tracking_id: db_event.tracking_id.clone(),
+                    user_id: user_id.to_string(),
+                    calendar_id: db_event.calendar_id.clone(),
+                    name: matching_event.name.clone(),
+                    note: matching_event.note.clone(),
+                    start_date: matching_event.start_date,
+                    end_date: matching_event.end_date,
+                    google_event_url: matching_event
+                        .google_event_url
+                        .clone()
+                        .or(db_event.google_event_url.clone()),
+                    participants: Some(participants_json(matching_event)),
+                    is_recurring: matching_event.is_recurring,
+                    meeting_link: meeting_link_json(matching_event),
+                });
+                handled_tracking_ids.insert(db_event.tracking_id.clone());
+            }
+            None => unmatched.push(db_event),
+        }
+    }
+
+    let existing_tracking_ids = db_events_with_session
+        .iter()
+        .map(|(e, _)| e.tracking_id.as_str())
+        .collect::<HashSet<_>>();
+
+    let candidates = db_selected_calendars
+        .iter()
+        .filter_map(|c| Some((c.id.as_str(), source_events_per_calendar.get(&c.id)?)))
+        .flat_map(|(calendar_id, events)| events.iter().map(move |e| (calendar_id, e)))
+        .filter(|(_, e)| !existing_tracking_ids.contains(composite_tracking_id(e).as_str()))
+        .collect::<Vec<_>>();
+
+    let mut rescheduled = HashSet::new();
+
+    for (i, j) in crate::reschedule::match_rescheduled(&unmatched, &candidates) {
+        let db_event = unmatched[i];
+        let source_event = candidates[j].1;
+        let tracking_id = composite_tracking_id(source_event);
+
+        tracing::info!(
+            event_id = %db_event.id,
+            from = %db_event.start_date,
+            to = %source_event.start_date,
+            "rescheduled_event_detected"
+        );
+
+        state.to_reschedule.push(Event {
+            id: db_event.id.clone(),
+            tracking_id: tracking_id.clone(),
+            user_id: user_id.to_string(),
+            calendar_id: db_event.calendar_id.clone(),
+            name: source_event.name.clone(),
+            note: source_event.note.clone(),
+            start_date: source_event.start_date,
+            end_date: source_event.end_date,
+            google_event_url: source_event
+                .google_event_url
+                .clone()
+                .or(db_event.google_event_url.clone()),
+            participants: Some(participants_json(source_event)),
+            is_recurring: source_event.is_recurring,
+            meeting_link: meeting_link_json(source_event),
+        });
+
+        handled_tracking_ids.insert(tracking_id);
+        rescheduled.insert(i);
+    }
+
+    for (i, db_event) in unmatched.into_iter().enumerate() {
+        if !rescheduled.contains(&i) {
+            state.to_delete.push(db_event.clone());
+        }
+    }
+
+    for db_calendar in db_selected_calendars {
+        let Some(source_events) = source_events_per_calendar.get(&db_calendar.id) else {
+            continue;
+        };
+
+        for source_event in source_events {
+            let tracking_id = composite_tracking_id(source_event);
+
+            if handled_tracking_ids.contains(&tracking_id) {
+                continue;
+            }
+
+            // Skip if this event already exists in the database with the same tracking_id
+            if db_events_with_session
+                .iter()
+                .any(|(db_event, _)| db_event.tracking_id == tracking_id)
+            {
+                continue;
+            }
+
+            let new_event = Event {
+                id: uuid::Uuid::new_v4().to_string(),
+                tracking_id: tracking_id.clone(),
+                user_id: user_id.to_string(),
+                calendar_id: Some(db_calendar.id.clone()),
+                name: source_event.name.clone(),
+                note: source_event.note.clone(),
+                start_date: source_event.start_date,
+                end_date: source_event.end_date,
+                google_event_url: source_event.google_event_url.clone(),
+                participants: Some(participants_json(source_event)),
+                is_recurring: source_event.is_recurring,
+                meeting_link: meeting_link_json(source_event),
+            };
+
+            // Backward compatibility: recurring events used to be stored with their base id only.
+            if source_event.is_recurring {
+                let legacy_session = db_events_with_session
+                    .iter()
+                    .find(|(db_event, session)| {
+                        db_event.tracking_id == source_event.id
+                            && db_event.start_date == source_event.start_date
+                            && db_event.name == source_event.name
+                            && !db_event.is_recurring
+                            && session.is_some()
+                    })
+                    .and_then(|(_, session)| session.as_ref());
+
+                if let Some(session) = legacy_session {
+                    state
+                        .session_transfers
+                        .push((session.id.clone(), new_event.id.clone()));
+                }
+            }
+
+            handled_tracking_ids.insert(tracking_id);
+            state.to_upsert.push(new_event);
+        }
+    }
+
+    tracing::info!(
+        to_delete = state.to_delete.len(),
+        to_upsert = state.to_upsert.len(),
+        to_update = state.to_update.len(),
+        to_reschedule = state.to_reschedule.len(),
+        session_transfers = state.session_transfers.len(),
+        "diff_events"
+    );
+
+    state
+}
+
+fn participants_json(event: &hypr_calendar_interface::Event) -> String {
+    serde_json::to_string(&event.participants).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn meeting_link_json(event: &hypr_calendar_interface::Event) -> Option<String> {
+    let sources = event
+        .meeting_hints
+        .iter()
+        .map(String::as_str)
+        .chain(event.location.as_deref())
+        .chain([event.note.as_str()]);
+
+    hypr_meeting_link::parse_all(sources).and_then(|link| serde_json::to_string(&link).ok())
+}
+
+impl CalendarSyncState {
+    pub async fn execute(self, db: &hypr_db_user::UserDatabase) {
+        for calendar in self.to_delete {
+            if let Err(e) = db.delete_calendar(&calendar.id).await {
+                tracing::error!("delete_calendar_error: {}", e);
+            }
+        }
+
+        for calendar in self.to_upsert {
+            if let Err(e) = db.upsert_calendar(calendar).await {
+                tracing::error!("upsert_calendar_error: {}", e);
+            }
+        }
+    }
+}
+
+impl EventSyncState {
+    pub async fn execute(self, db: &hypr_db_user::UserDatabase) {
+        for event in self.to_upsert {
+            if let Err(e) = db.upsert_event(event).await {
+                tracing::error!("upsert_event_error: {}", e);
+            }
+        }
+
+        for event in self.to_update {
+            if let Err(e) = db.update_event(event).await {
+                tracing::error!("update_event_error: {}", e);
+            }
+        }
+
+        // A single UPDATE per event, which keeps `sessions.calendar_event_id` pointing at it.
+        for event in self.to_reschedule {
+            if let Err(e) = db.update_event(event).await {
+                tracing::error!("reschedule_event_error: {}", e);
+            }
+        }
+
+        for (session_id, new_event_id) in self.session_transfers {
+            if let Err(e) = db.session_set_event(session_id, Some(new_event_id)).await {
+                tracing::error!("session_transfer_error: {}", e);
+            }
+        }
+
+        for event in self.to_delete {
+            if let Err(e) = db.delete_event(&event.id).await {
+                tracing::error!("delete_event_error: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Duration, Utc};
+    use hypr_calendar_interface::{Participant, Platform};
+
+    fn t0() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-03-03T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn participants(emails: &[&str]) -> Vec<Participant> {
+        emails
+            .iter()
+            .map(|e| Participant {
+                name: e.to_string(),
+                email: Some(e.to_string()),
+            })
+            .collect()
+    }
+
+    struct Source {
+        id: &'static str,
+        calendar_id: &'static str,
+        name: &'static str,
+        start: DateTime<Utc>,
+        emails: &'static [&'static str],
+        is_recurring: bool,
+    }
+
+    impl Source {
+        fn to_event(&self) -> hypr_calendar_interface::Event {
+            hypr_calendar_interface::Event {
+                id: self.id.to_string(),
+                calendar_id: format!("{}-tracking", self.calendar_id),
+                platform: Platform::Apple,
+                name: self.name.to_string(),
+                note: String::new(),
+                participants: participants(self.emails),
+                start_date: self.start,
+                end_date: self.start + Duration::minutes(30),
+                google_event_url: None,
+                is_recurring: self.is_recurring,
+                location: None,
+                meeting_hints: vec![],
+            }
+        }
+    }
+
+    struct Db {
+        id: &'static str,
+        calendar_id: &'static str,
+        name: &'static str,
+        start: DateTime<Utc>,
+        emails: &'static [&'static str],
+        is_recurring: bool,
+    }
+
+    impl Db {
+        fn to_event(&self, source: &[Source]) -> Event {
+            // Tracking ids of database events are derived the same way the sync would have stored them.
+            let tracking_id = source
+                .iter()
+                .find(|s| s.id == self.id.split("__").next().unwrap())
+                .map(|s| {
+                    composite_tracking_id(&hypr_calendar_interface::Event {
+                        start_date: self.start,
+                        ..s.to_event()
+                    })
+                })
+                .unwrap_or_else(|| self.id.to_string());
+
+            Event {
+                id: format!("db-{}", self.id),
+                user_id: "TEST".to_string(),
+                tracking_id,
+                calendar_id: Some(self.calendar_id.to_string()),
+                name: self.name.to_string(),
+                note: String::new(),
+                start_date: self.start,
+                end_date: self.start + Duration::minutes(30),
+                google_event_url: None,
+                participants: Some(serde_json::to_string(&participants(self.emails)).unwrap()),
+                is_recurring: self.is_recurring,
+                meeting_link: None,
+            }
+        }
+    }
+
+    struct Case {
+        name: &'static str,
+        db: Vec<Db>,
+        source: Vec<Source>,
+        // (db event id, new start)
+        rescheduled: Vec<(&'static str, DateTime<Utc>)>,
+        deleted: Vec<&'static str>,
+        upserted: Vec<&'static str>,
+    }
+
+    fn calendar(id: &str) -> Calendar {
+        Calendar {
+            id: id.to_string(),
+            tracking_id: format!("{}-tracking", id),
+            user_id: "TEST".to_string(),
+            platform: hypr_db_user::Platform::Apple,
+            name: id.to_string(),
+            selected: true,
+            source: None,
+        }
+    }
+
+    const TEAM: &[&str] = &["a@example.com", "b@example.com", "c@example.com"];
+
+    #[test]
+    fn test_diff_events_rescheduled() {
+        let cases = vec![
+            Case {
+                name: "moved with a new id",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "work",
+                    name: "Design Review",
+                    start: t0(),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                source: vec![Source {
+                    id: "new",
+                    calendar_id: "work",
+                    name: "Design Review",
+                    start: t0() + Duration::hours(3),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                rescheduled: vec![("db-old", t0() + Duration::hours(3))],
+                deleted: vec![],
+                upserted: vec![],
+            },
+            Case {
+                name: "renamed and moved with the same attendees",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "work",
+                    name: "Weekly sync",
+                    start: t0(),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                source: vec![Source {
+                    id: "new",
+                    calendar_id: "work",
+                    name: "Weekly sync (moved)",
+                    start: t0() + Duration::days(2),
+                    emails: &["a@example.com", "b@example.com"],
+                    is_recurring: false,
+                }],
+                rescheduled: vec![("db-old", t0() + Duration::days(2))],
+                deleted: vec![],
+                upserted: vec![],
+            },
+            Case {
+                name: "renamed without attendees",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "work",
+                    name: "Weekly sync",
+                    start: t0(),
+                    emails: &[],
+                    is_recurring: false,
+                }],
+                source: vec![Source {
+                    id: "new",
+                    calendar_id: "work",
+                    name: "Weekly sync (moved)",
+                    start: t0() + Duration::days(2),
+                    emails: &[],
+                    is_recurring: false,
+                }],
+                rescheduled: vec![],
+                deleted: vec!["db-old"],
+                upserted: vec!["new"],
+            },
+            Case {
+                name: "same name with different attendees",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "work",
+                    name: "1:1",
+                    start: t0(),
+                    emails: &["a@example.com", "b@example.com"],
+                    is_recurring: false,
+                }],
+                source: vec![Source {
+                    id: "new",
+                    calendar_id: "work",
+                    name: "1:1",
+                    start: t0() + Duration::days(1),
+                    emails: &["a@example.com", "c@example.com"],
+                    is_recurring: false,
+                }],
+                rescheduled: vec![],
+                deleted: vec!["db-old"],
+                upserted: vec!["new"],
+            },
+            Case {
+                name: "moved too far",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "work",
+                    name: "Offsite",
+                    start: t0(),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                source: vec![Source {
+                    id: "new",
+                    calendar_id: "work",
+                    name: "Offsite",
+                    start: t0() + Duration::days(45),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                rescheduled: vec![],
+                deleted: vec!["db-old"],
+                upserted: vec!["new"],
+            },
+            Case {
+                name: "moved to another calendar",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "personal",
+                    name: "Design Review",
+                    start: t0(),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                source: vec![Source {
+                    id: "new",
+                    calendar_id: "work",
+                    name: "Design Review",
+                    start: t0(),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                rescheduled: vec![],
+                deleted: vec!["db-old"],
+                upserted: vec!["new"],
+            },
+            Case {
+                name: "closest candidate wins",
+                db: vec![Db {
+                    id: "old",
+                    calendar_id: "work",
+                    name: "Sync",
+                    start: t0(),
+                    emails: TEAM,
+                    is_recurring: false,
+                }],
+                source: vec![
+                    Source {
+                        id: "far",
+                        calendar_id: "work",
+                        name: "Sync",
+                        start: t0() + Duration::days(5),
+                        emails: TEAM,
+                        is_recurring: false,
+                    },
+                    Source {
+                        id: "near",
+                        calendar_id: "work",
+                        name: "Sync",
+                        start: t0() + Duration::days(1),
+                        emails: TEAM,
+                        is_recurring: false,
+                    },
+                ],
+                rescheduled: vec![("db-old", t0() + Duration::days(1))],
+                deleted: vec![],
+                upserted: vec!["far"],
+            },
+            Case {
+                name: "recurring occurrence moved",
+                db: vec![
+                    Db {
+                        id: "series__1",
+                        calendar_id: "work",
+                        name: "Standup",
+                        start: t0(),
+                        emails: &[],
+                        is_recurring: true,
+                    },
+                    Db {
+                        id: "series__2",
+                        calendar_id: "work",
+                        name: "Standup",
+                        start: t0() + Duration::days(7),
+                        emails: &[],
+                        is_recurring: true,
+                    },
+                ],
+                source: vec![
+                    Source {
+                        id: "series",
+                        calendar_id: "work",
+                        name: "Standup (Tuesday)",
+                        start: t0() + Duration::days(1),
+                        emails: &[],
+                        is_recurring: true,
+                    },
+                    Source {
+                        id: "series",
+                        calendar_id: "work",
+                        name: "Standup",
+                        start: t0() + Duration::days(7),
+                        emails: &[],
+                        is_recurring: true,
+                    },
+                ],
+                rescheduled: vec![("db-series__1", t0() + Duration::days(1))],
+                deleted: vec![],
+                upserted: vec![],
+            },
+        ];
+
+        let calendars = vec![calendar("work"), calendar("personal")];
+
+        for case in cases {
+            let db_events = case
+                .db
+                .iter()
+                .map(|e| (e.to_event(&case.source), None))
+                .collect::<Vec<_>>();
+
+            let mut source_events = HashMap::<String, Vec<_>>::new();
+            for s in &case.source {
+                source_events
+                    .entry(s.calendar_id.to_string())
+                    .or_default()
+                    .push(s.to_event());
+            }
+
+            let state = diff_events("TEST", &db_events, &calendars, &source_events);
+
+            let rescheduled = state
+                .to_reschedule
+                .iter()
+                .map(|e| (e.id.as_str(), e.start_date))
+                .collect::<Vec<_>>();
+            assert_eq!(rescheduled, case.rescheduled, "{}", case.name);
+
+            for event in &state.to_reschedule {
+                let source = case
+                    .source
+                    .iter()
+                    .map(|s| s.to_event())
+                    .find(|s| s.start_date == event.start_date)
+                    .unwrap();
+                assert_eq!(
+                    event.tracking_id,
+                    composite_tracking_id(&source),
+                    "{}",
+                    case.name
+                );
+            }
+
+            let deleted = state
+                .to_delete
+                .iter()
+                .map(|e| e.id.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(deleted, case.deleted, "{}", case.name);
+
+            let upserted = state
+                .to_upsert
+                .iter()
+                .map(|e| e.tracking_id.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(upserted, case.upserted, "{}", case.name);
+        }
+    }
+}
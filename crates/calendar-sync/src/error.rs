@@ -0,0 +1,14 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("database error: {0}")]
+    DatabaseError(#[from] hypr_db_user::Error),
+    // `hypr_calendar_interface::Error` is an `anyhow::Error`, which does not implement `std::error::Error`.
+    #[error("calendar source error: {0}")]
+    SourceError(String),
+}
+
+impl From<hypr_calendar_interface::Error> for Error {
+    fn from(error: hypr_calendar_interface::Error) -> Self {
+        Error::SourceError(error.to_string())
+    }
+}
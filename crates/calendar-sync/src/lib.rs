@@ -0,0 +1,594 @@
+mod diff;
+mod error;
+mod reschedule;
+
+pub use diff::*;
+pub use error::*;
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use hypr_calendar_interface::{CalendarSource, EventFilter, Platform};
+use hypr_db_user::{
+    Calendar, Event, GetSessionFilter, ListEventFilter, ListEventFilterCommon,
+    ListEventFilterSpecific, Session, UserDatabase,
+};
+
+const SYNC_WINDOW_DAYS: i64 = 100;
+const SYNC_EVENTS_LIMIT: u32 = 700;
+
+pub async fn sync_calendars<S: CalendarSource>(
+    db: &UserDatabase,
+    user_id: impl Into<String>,
+    platform: Platform,
+    source: &S,
+) -> Result<(), Error> {
+    let user_id = user_id.into();
+
+    // Fail before touching the database, so an unavailable source does not wipe every calendar.
+    let source_calendars = source.list_calendars().await?;
+    let db_calendars = list_db_calendars(db, &user_id, &platform).await?;
+
+    diff_calendars(&user_id, &db_calendars, &source_calendars)
+        .execute(db)
+        .await;
+
+    Ok(())
+}
+
+pub async fn sync_events<S: CalendarSource>(
+    db: &UserDatabase,
+    user_id: impl Into<String>,
+    platform: Platform,
+    source: &S,
+) -> Result<(), Error> {
+    let user_id = user_id.into();
+
+    let db_calendars = list_db_calendars(db, &user_id, &platform).await?;
+    let db_selected_calendars = db_calendars
+        .iter()
+        .filter(|c| c.selected)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let now = Utc::now();
+    let mut source_events_per_calendar = HashMap::new();
+
+    for db_calendar in &db_selected_calendars {
+        let events = source
+            .list_events(EventFilter {
+                calendar_tracking_id: db_calendar.tracking_id.clone(),
+                from: now,
+                to: now + chrono::Duration::days(SYNC_WINDOW_DAYS),
+            })
+            .await?;
+
+        source_events_per_calendar.insert(db_calendar.id.clone(), events);
+    }
+
+    let db_events_with_session =
+        list_db_events_with_session(db, &user_id, &platform, &db_calendars).await?;
+
+    diff_events(
+        &user_id,
+        &db_events_with_session,
+        &db_selected_calendars,
+        &source_events_per_calendar,
+    )
+    .execute(db)
+    .await;
+
+    Ok(())
+}
+
+async fn list_db_calendars(
+    db: &UserDatabase,
+    user_id: &str,
+    platform: &Platform,
+) -> Result<Vec<Calendar>, Error> {
+    let platform: hypr_db_user::Platform = platform.clone().into();
+
+    let calendars = db
+        .list_calendars(user_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.platform == platform)
+        .collect();
+
+    Ok(calendars)
+}
+
+// Events of other platforms are left alone. Events without a (known) calendar are
+// claimed by every platform, so that they get cleaned up by whichever sync runs first.
+async fn list_db_events_with_session(
+    db: &UserDatabase,
+    user_id: &str,
+    platform: &Platform,
+    db_platform_calendars: &[Calendar],
+) -> Result<Vec<(Event, Option<Session>)>, Error> {
+    let all_calendar_ids = db
+        .list_calendars(user_id)
+        .await?
+        .into_iter()
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
+
+    let now = Utc::now();
+    let events = db
+        .list_events(Some(ListEventFilter {
+            common: ListEventFilterCommon {
+                user_id: user_id.to_string(),
+                limit: Some(SYNC_EVENTS_LIMIT),
+            },
+            specific: ListEventFilterSpecific::DateRange {
+                start: now,
+                end: now + chrono::Duration::days(SYNC_WINDOW_DAYS),
+            },
+        }))
+        .await?;
+
+    let mut events_with_session = Vec::new();
+
+    for event in events {
+        let owned = match &event.calendar_id {
+            Some(calendar_id) => {
+                db_platform_calendars.iter().any(|c| &c.id == calendar_id)
+                    || !all_calendar_ids.contains(calendar_id)
+            }
+            None => true,
+        };
+
+        if !owned {
+            continue;
+        }
+
+        let session = db
+            .get_session(GetSessionFilter::CalendarEventId(event.id.clone()))
+            .await?;
+
+        events_with_session.push((event, session));
+    }
+
+    tracing::info!(
+        platform = %platform,
+        events = events_with_session.len(),
+        "list_db_events_with_session"
+    );
+
+    Ok(events_with_session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Duration, Timelike};
+    use hypr_calendar_interface::Participant;
+    use hypr_db_user::Human;
+
+    #[derive(Default)]
+    struct FakeSource {
+        calendars: Vec<hypr_calendar_interface::Calendar>,
+        events: HashMap<String, Vec<hypr_calendar_interface::Event>>,
+        fail: bool,
+    }
+
+    impl CalendarSource for FakeSource {
+        async fn list_calendars(
+            &self,
+        ) -> Result<Vec<hypr_calendar_interface::Calendar>, hypr_calendar_interface::Error>
+        {
+            if self.fail {
+                return Err(hypr_calendar_interface::Error::msg("unavailable"));
+            }
+
+            Ok(self.calendars.clone())
+        }
+
+        async fn list_events(
+            &self,
+            filter: EventFilter,
+        ) -> Result<Vec<hypr_calendar_interface::Event>, hypr_calendar_interface::Error> {
+            if self.fail {
+                return Err(hypr_calendar_interface::Error::msg("unavailable"));
+            }
+
+            Ok(self
+                .events
+                .get(&filter.calendar_tracking_id)
+                .cloned()
+                .unwrap_or_default())
+        }
+    }
+
+    async fn setup_db() -> (UserDatabase, String) {
+        let base_db = hypr_db_core::DatabaseBuilder::default()
+            .memory()
+            .build()
+            .await
+            .unwrap();
+        let db = UserDatabase::from(base_db);
+        hypr_db_user::migrate(&db).await.unwrap();
+
+        let human = db
+            .upsert_human(Human {
+                full_name: Some("test".to_string()),
+                is_user: true,
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+
+        (db, human.id)
+    }
+
+    fn calendar(id: &str, platform: Platform) -> hypr_calendar_interface::Calendar {
+        hypr_calendar_interface::Calendar {
+            id: id.to_string(),
+            platform,
+            name: id.to_string(),
+            source: None,
+        }
+    }
+
+    fn event(
+        id: &str,
+        calendar_id: &str,
+        start_date: DateTime<Utc>,
+    ) -> hypr_calendar_interface::Event {
+        hypr_calendar_interface::Event {
+            id: id.to_string(),
+            calendar_id: calendar_id.to_string(),
+            platform: Platform::Apple,
+            name: id.to_string(),
+            note: String::new(),
+            participants: vec![Participant {
+                name: "john".to_string(),
+                email: None,
+            }],
+            start_date,
+            end_date: start_date + Duration::hours(1),
+            google_event_url: None,
+            is_recurring: false,
+            location: None,
+            meeting_hints: vec![],
+        }
+    }
+
+    async fn select_all(db: &UserDatabase, user_id: &str) {
+        for calendar in db.list_calendars(user_id).await.unwrap() {
+            if !calendar.selected {
+                db.toggle_calendar_selected(&calendar.tracking_id)
+                    .await
+                    .unwrap();
+            }
+        }
+    }
+
+    fn tomorrow() -> DateTime<Utc> {
+        (Utc::now() + Duration::days(1)).with_nanosecond(0).unwrap()
+    }
+
+    #[test]
+    fn test_diff_empty() {
+        let state = diff_calendars("TEST", &[], &[]);
+        assert!(state.to_delete.is_empty());
+        assert!(state.to_upsert.is_empty());
+
+        let state = diff_events("TEST", &[], &[], &HashMap::new());
+        assert!(state.to_delete.is_empty());
+        assert!(state.to_upsert.is_empty());
+        assert!(state.to_update.is_empty());
+    }
+
+    #[test]
+    fn test_composite_tracking_id() {
+        let start_date = DateTime::parse_from_rfc3339("2025-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut e = event("abc", "cal", start_date);
+        assert_eq!(composite_tracking_id(&e), "abc");
+
+        e.is_recurring = true;
+        assert_eq!(composite_tracking_id(&e), "abc__HYPR__20250102030405");
+    }
+
+    #[tokio::test]
+    async fn test_sync_calendars_per_platform() {
+        let (db, user_id) = setup_db().await;
+
+        let google = FakeSource {
+            calendars: vec![calendar("g1", Platform::Google)],
+            ..Default::default()
+        };
+        sync_calendars(&db, &user_id, Platform::Google, &google)
+            .await
+            .unwrap();
+
+        let apple = FakeSource {
+            calendars: vec![
+                calendar("a1", Platform::Apple),
+                calendar("a2", Platform::Apple),
+            ],
+            ..Default::default()
+        };
+        sync_calendars(&db, &user_id, Platform::Apple, &apple)
+            .await
+            .unwrap();
+        assert_eq!(db.list_calendars(&user_id).await.unwrap().len(), 3);
+
+        let apple = FakeSource {
+            calendars: vec![calendar("a2", Platform::Apple)],
+            ..Default::default()
+        };
+        sync_calendars(&db, &user_id, Platform::Apple, &apple)
+            .await
+            .unwrap();
+
+        let mut tracking_ids = db
+            .list_calendars(&user_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.tracking_id)
+            .collect::<Vec<_>>();
+        tracking_ids.sort();
+        assert_eq!(tracking_ids, vec!["a2", "g1"]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_calendars_source_error() {
+        let (db, user_id) = setup_db().await;
+
+        let apple = FakeSource {
+            calendars: vec![calendar("a1", Platform::Apple)],
+            ..Default::default()
+        };
+        sync_calendars(&db, &user_id, Platform::Apple, &apple)
+            .await
+            .unwrap();
+
+        let broken = FakeSource {
+            fail: true,
+            ..Default::default()
+        };
+        assert!(sync_calendars(&db, &user_id, Platform::Apple, &broken)
+            .await
+            .is_err());
+        assert_eq!(db.list_calendars(&user_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_events() {
+        let (db, user_id) = setup_db().await;
+        let start_date = tomorrow();
+
+        let mut source = FakeSource {
+            calendars: vec![calendar("a1", Platform::Apple)],
+            events: HashMap::from([(
+                "a1".to_string(),
+                vec![event("e1", "a1", start_date), event("e2", "a1", start_date)],
+            )]),
+            ..Default::default()
+        };
+
+        sync_calendars(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+
+        // Nothing is selected yet.
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+        assert!(db.list_events(None).await.unwrap().is_empty());
+
+        select_all(&db, &user_id).await;
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+        assert_eq!(db.list_events(None).await.unwrap().len(), 2);
+
+        let events = source.events.get_mut("a1").unwrap();
+        events.retain(|e| e.id == "e1");
+        events[0].name = "renamed".to_string();
+
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+
+        let events = db.list_events(None).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].tracking_id, "e1");
+        assert_eq!(events[0].name, "renamed");
+    }
+
+    #[tokio::test]
+    async fn test_sync_events_meeting_link() {
+        let (db, user_id) = setup_db().await;
+        let start_date = tomorrow();
+
+        let source = FakeSource {
+            calendars: vec![calendar("a1", Platform::Apple)],
+            events: HashMap::from([(
+                "a1".to_string(),
+                vec![
+                    hypr_calendar_interface::Event {
+                        note: "Meeting ID: 867 4631 3244\nPasscode: 291681".to_string(),
+                        location: Some("https://hyprnote.zoom.us/j/86746313244".to_string()),
+                        ..event("e1", "a1", start_date)
+                    },
+                    event("e2", "a1", start_date),
+                ],
+            )]),
+            ..Default::default()
+        };
+
+        sync_calendars(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+        select_all(&db, &user_id).await;
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+
+        let events = db.list_events(None).await.unwrap();
+        let link = |tracking_id: &str| {
+            events
+                .iter()
+                .find(|e| e.tracking_id == tracking_id)
+                .unwrap()
+                .meeting_link
+                .as_deref()
+                .map(|json| serde_json::from_str::<hypr_meeting_link::MeetingLink>(json).unwrap())
+        };
+
+        let zoom = link("e1").unwrap();
+        assert_eq!(zoom.platform, hypr_meeting_link::MeetingPlatform::Zoom);
+        assert_eq!(zoom.meeting_id.as_deref(), Some("86746313244"));
+        assert_eq!(zoom.passcode.as_deref(), Some("291681"));
+        assert_eq!(link("e2"), None);
+    }
+
+    #[tokio::test]
+    async fn test_sync_events_rescheduled_keeps_session() {
+        let (db, user_id) = setup_db().await;
+        let start_date = tomorrow();
+
+        let mut source = FakeSource {
+            calendars: vec![calendar("a1", Platform::Apple)],
+            events: HashMap::from([("a1".to_string(), vec![event("e1", "a1", start_date)])]),
+            ..Default::default()
+        };
+
+        sync_calendars(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+        select_all(&db, &user_id).await;
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+
+        let original = db.list_events(None).await.unwrap().remove(0);
+        let session = db
+            .upsert_session(Session {
+                id: uuid::Uuid::new_v4().to_string(),
+                created_at: Utc::now(),
+                visited_at: Utc::now(),
+                user_id: user_id.clone(),
+                calendar_event_id: Some(original.id.clone()),
+                title: "e1".to_string(),
+                raw_memo_html: "<p>agenda</p>".to_string(),
+                enhanced_memo_html: None,
+                conversations: vec![],
+                words: vec![],
+                record_start: None,
+                record_end: None,
+                pre_meeting_memo_html: None,
+            })
+            .await
+            .unwrap();
+
+        let moved = hypr_calendar_interface::Event {
+            name: "e1".to_string(),
+            ..event("e1-moved", "a1", start_date + Duration::hours(2))
+        };
+        source.events.insert("a1".to_string(), vec![moved.clone()]);
+
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+
+        let events = db.list_events(None).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, original.id);
+        assert_eq!(events[0].tracking_id, "e1-moved");
+        assert_eq!(events[0].start_date, moved.start_date);
+
+        let linked = db
+            .get_session(GetSessionFilter::CalendarEventId(original.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(linked.id, session.id);
+    }
+
+    #[tokio::test]
+    async fn test_sync_events_recurring() {
+        let (db, user_id) = setup_db().await;
+        let start_date = tomorrow();
+
+        let mut first = event("r1", "a1", start_date);
+        first.is_recurring = true;
+        let mut second = event("r1", "a1", start_date + Duration::days(7));
+        second.is_recurring = true;
+
+        let source = FakeSource {
+            calendars: vec![calendar("a1", Platform::Apple)],
+            events: HashMap::from([("a1".to_string(), vec![first.clone(), second.clone()])]),
+            ..Default::default()
+        };
+
+        sync_calendars(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+        select_all(&db, &user_id).await;
+
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+        sync_events(&db, &user_id, Platform::Apple, &source)
+            .await
+            .unwrap();
+
+        let mut tracking_ids = db
+            .list_events(None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.tracking_id)
+            .collect::<Vec<_>>();
+        tracking_ids.sort();
+        assert_eq!(
+            tracking_ids,
+            vec![
+                composite_tracking_id(&first),
+                composite_tracking_id(&second)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_events_other_platform_untouched() {
+        let (db, user_id) = setup_db().await;
+        let start_date = tomorrow();
+
+        let google = FakeSource {
+            calendars: vec![calendar("g1", Platform::Google)],
+            events: HashMap::from([("g1".to_string(), vec![event("ge1", "g1", start_date)])]),
+            ..Default::default()
+        };
+        sync_calendars(&db, &user_id, Platform::Google, &google)
+            .await
+            .unwrap();
+        select_all(&db, &user_id).await;
+        sync_events(&db, &user_id, Platform::Google, &google)
+            .await
+            .unwrap();
+
+        let apple = FakeSource {
+            calendars: vec![calendar("a1", Platform::Apple)],
+            ..Default::default()
+        };
+        sync_calendars(&db, &user_id, Platform::Apple, &apple)
+            .await
+            .unwrap();
+        sync_events(&db, &user_id, Platform::Apple, &apple)
+            .await
+            .unwrap();
+
+        let events = db.list_events(None).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].tracking_id, "ge1");
+    }
+}
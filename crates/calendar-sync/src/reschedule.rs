@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use chrono::Duration;
+use hypr_db_user::Event;
+
+// A moved meeting usually stays within a few weeks of its original slot.
+const MAX_SHIFT_DAYS: i64 = 30;
+// Occurrences of the same series are only matched to nearby slots, to not confuse them with the next one.
+const MAX_OCCURRENCE_SHIFT_DAYS: i64 = 7;
+
+const MIN_NAME_SIMILARITY: f64 = 0.6;
+// Without attendees on both sides, the name is the only signal we have.
+const CONFIDENT_NAME_SIMILARITY: f64 = 0.9;
+const MIN_ATTENDEE_OVERLAP: f64 = 0.5;
+
+const SAME_SERIES_SCORE: f64 = 3.0;
+
+// Pairs up database events that lost their source event with source events that are not in the database yet.
+// Returns `(db_index, candidate_index)` pairs, best matches first, each side used at most once.
+pub fn match_rescheduled(
+    db_events: &[&Event],
+    candidates: &[(&str, &hypr_calendar_interface::Event)],
+) -> Vec<(usize, usize)> {
+    let mut scored = Vec::new();
+
+    for (i, db_event) in db_events.iter().enumerate() {
+        for (j, (calendar_id, candidate)) in candidates.iter().enumerate() {
+            if db_event.calendar_id.as_deref() != Some(*calendar_id) {
+                continue;
+            }
+
+            if let Some(score) = score(db_event, candidate) {
+                let shift = (candidate.start_date - db_event.start_date).abs();
+                scored.push((i, j, score, shift));
+            }
+        }
+    }
+
+    scored.sort_by(|a, b| b.2.total_cmp(&a.2).then(a.3.cmp(&b.3)));
+
+    let mut used_db = HashSet::new();
+    let mut used_candidates = HashSet::new();
+    let mut matches = Vec::new();
+
+    for (i, j, _, _) in scored {
+        if used_db.contains(&i) || used_candidates.contains(&j) {
+            continue;
+        }
+
+        used_db.insert(i);
+        used_candidates.insert(j);
+        matches.push((i, j));
+    }
+
+    matches
+}
+
+fn score(db_event: &Event, candidate: &hypr_calendar_interface::Event) -> Option<f64> {
+    let shift = (candidate.start_date - db_event.start_date).abs();
+
+    let same_series = db_event.is_recurring
+        && candidate.is_recurring
+        && base_tracking_id(&db_event.tracking_id) == candidate.id;
+
+    if same_series {
+        return (shift <= Duration::days(MAX_OCCURRENCE_SHIFT_DAYS)).then_some(SAME_SERIES_SCORE);
+    }
+
+    if shift > Duration::days(MAX_SHIFT_DAYS) {
+        return None;
+    }
+
+    let name = name_similarity(&db_event.name, &candidate.name);
+    if name < MIN_NAME_SIMILARITY {
+        return None;
+    }
+
+    let db_emails = db_event
+        .participants
+        .as_deref()
+        .and_then(|p| serde_json::from_str::<Vec<hypr_calendar_interface::Participant>>(p).ok())
+        .map(|p| emails(&p))
+        .unwrap_or_default();
+    let candidate_emails = emails(&candidate.participants);
+
+    if db_emails.is_empty() || candidate_emails.is_empty() {
+        return (name >= CONFIDENT_NAME_SIMILARITY).then_some(name);
+    }
+
+    let overlap = db_emails.intersection(&candidate_emails).count() as f64
+        / db_emails.union(&candidate_emails).count() as f64;
+
+    (overlap >= MIN_ATTENDEE_OVERLAP).then_some(name + overlap)
+}
+
+fn base_tracking_id(tracking_id: &str) -> &str {
+    tracking_id
+        .split_once("__HYPR__")
+        .map_or(tracking_id, |(base, _)| base)
+}
+
+fn emails(participants: &[hypr_calendar_interface::Participant]) -> HashSet<String> {
+    participants
+        .iter()
+        .filter_map(|p| p.email.as_ref())
+        .map(|e| e.trim().to_lowercase())
+        .collect()
+}
+
+// Sørensen–Dice coefficient over character bigrams, tolerant to small edits like "(moved)" or casing.
+fn name_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (normalize(a), normalize(b));
+
+    if a == b {
+        return 1.0;
+    }
+
+    let (a, b) = (bigrams(&a), bigrams(&b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let mut remaining = b.clone();
+    let mut common = 0;
+
+    for bigram in &a {
+        if let Some(pos) = remaining.iter().position(|x| x == bigram) {
+            remaining.swap_remove(pos);
+            common += 1;
+        }
+    }
+
+    (2 * common) as f64 / (a.len() + b.len()) as f64
+}
+
+fn normalize(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bigrams(s: &str) -> Vec<(char, char)> {
+    let chars = s.chars().collect::<Vec<_>>();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("Design Review", "design review!"), 1.0);
+        assert!(name_similarity("Weekly sync", "Weekly sync (moved)") >= MIN_NAME_SIMILARITY);
+        assert!(name_similarity("1:1 Alice", "1:1 Bob") < MIN_NAME_SIMILARITY);
+        assert_eq!(name_similarity("", "Standup"), 0.0);
+    }
+
+    #[test]
+    fn test_base_tracking_id() {
+        assert_eq!(base_tracking_id("abc__HYPR__20250101090000"), "abc");
+        assert_eq!(base_tracking_id("abc"), "abc");
+    }
+}
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS action_items (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL,
+  owner_id TEXT DEFAULT NULL,
+  text TEXT NOT NULL,
+  due_date TEXT DEFAULT NULL,
+  source_quote TEXT DEFAULT NULL,
+  start_ms INTEGER DEFAULT NULL,
+  end_ms INTEGER DEFAULT NULL,
+  done BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE,
+  FOREIGN KEY (owner_id) REFERENCES humans(id) ON DELETE
+  SET
+    NULL
+);
@@ -0,0 +1,508 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    upsert_decision_on, ActionItem, Decision, DecisionKind, Human, ListActionItemFilter,
+    SessionExtraction, UserDatabase,
+};
+
+impl UserDatabase {
+    pub async fn upsert_action_item(&self, item: ActionItem) -> Result<ActionItem, crate::Error> {
+        let conn = self.conn()?;
+        upsert_action_item_on(&conn, item).await
+    }
+
+    pub async fn list_action_items(
+        &self,
+        filter: ListActionItemFilter,
+    ) -> Result<Vec<ActionItem>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = match filter {
+            ListActionItemFilter::Session { session_id } => {
+                conn.query(
+                    "SELECT * FROM action_items
+                    WHERE session_id = ?
+                    ORDER BY start_ms IS NULL, start_ms ASC, created_at ASC",
+                    vec![session_id],
+                )
+                .await?
+            }
+            ListActionItemFilter::Open { user_id, limit } => {
+                conn.query(
+                    "SELECT a.* FROM action_items a
+                    JOIN sessions s ON a.session_id = s.id
+                    WHERE s.user_id = ? AND a.done = FALSE
+                    ORDER BY a.due_date IS NULL, a.due_date ASC, a.created_at DESC
+                    LIMIT ?",
+                    vec![user_id, limit.unwrap_or(100).to_string()],
+                )
+                .await?
+            }
+        };
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: ActionItem = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn set_action_item_done(
+        &self,
+        id: impl Into<String>,
+        done: bool,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "UPDATE action_items SET done = ? WHERE id = ?",
+            (done, id.into()),
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_action_item(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute("DELETE FROM action_items WHERE id = ?", vec![id.into()])
+            .await?;
+        Ok(())
+    }
+
+    // Replaces the previous extraction for the session. Items already marked as done are kept,
+    // and not re-created when the model extracts them again.
+    pub async fn session_apply_extraction(
+        &self,
+        session_id: impl Into<String>,
+        extraction: SessionExtraction,
+    ) -> Result<(), crate::Error> {
+        let session_id = session_id.into();
+        let conn = self.conn()?;
+
+        let words = self.get_words(&session_id).await?;
+        let participants = self.session_list_participants(&session_id).await?;
+
+        let done_texts = self
+            .list_action_items(ListActionItemFilter::Session {
+                session_id: session_id.clone(),
+            })
+            .await?
+            .into_iter()
+            .filter(|item| item.done)
+            .map(|item| normalize(&item.text).join(" "))
+            .collect::<HashSet<_>>();
+
+        let now = chrono::Utc::now();
+
+        let items = extraction
+            .action_items
+            .into_iter()
+            .filter(|item| !done_texts.contains(&normalize(&item.task).join(" ")))
+            .map(|item| {
+                let (start_ms, end_ms) = locate_quote_ms(&words, &item.quote);
+
+                ActionItem {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    session_id: session_id.clone(),
+                    owner_id: item
+                        .owner
+                        .as_deref()
+                        .and_then(|owner| resolve_owner(&participants, owner)),
+                    text: item.task,
+                    due_date: item.due.as_deref().and_then(parse_due_date),
+                    source_quote: Some(item.quote),
+                    start_ms,
+                    end_ms,
+                    done: false,
+                    created_at: now,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let decisions = extraction
+            .decisions
+            .into_iter()
+            .map(|s| (DecisionKind::Decision, s))
+            .chain(
+                extraction
+                    .open_questions
+                    .into_iter()
+                    .map(|s| (DecisionKind::OpenQuestion, s)),
+            )
+            .map(|(kind, statement)| {
+                let (start_ms, end_ms) = locate_quote_ms(&words, &statement.quote);
+
+                Decision {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    session_id: session_id.clone(),
+                    kind,
+                    text: statement.text,
+                    source_quote: Some(statement.quote),
+                    start_ms,
+                    end_ms,
+                    created_at: now,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Either the whole previous extraction is replaced, or none of it.
+        let tx = conn.transaction().await?;
+
+        tx.execute(
+            "DELETE FROM action_items WHERE session_id = ? AND done = FALSE",
+            vec![session_id.clone()],
+        )
+        .await?;
+        tx.execute(
+            "DELETE FROM decisions WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
+        for item in items {
+            upsert_action_item_on(&tx, item).await?;
+        }
+        for decision in decisions {
+            upsert_decision_on(&tx, decision).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+pub(crate) async fn upsert_action_item_on(
+    conn: &libsql::Connection,
+    item: ActionItem,
+) -> Result<ActionItem, crate::Error> {
+    let mut rows = conn
+        .query(
+            "INSERT INTO action_items (
+                id,
+                session_id,
+                owner_id,
+                text,
+                due_date,
+                source_quote,
+                start_ms,
+                end_ms,
+                done,
+                created_at
+            ) VALUES (
+                :id,
+                :session_id,
+                :owner_id,
+                :text,
+                :due_date,
+                :source_quote,
+                :start_ms,
+                :end_ms,
+                :done,
+                :created_at
+            ) ON CONFLICT(id) DO UPDATE SET
+                owner_id = :owner_id,
+                text = :text,
+                due_date = :due_date,
+                source_quote = :source_quote,
+                start_ms = :start_ms,
+                end_ms = :end_ms,
+                done = :done
+            RETURNING *",
+            libsql::named_params! {
+                ":id": item.id,
+                ":session_id": item.session_id,
+                ":owner_id": item.owner_id,
+                ":text": item.text,
+                ":due_date": item.due_date.map(|dt| dt.to_rfc3339()),
+                ":source_quote": item.source_quote,
+                ":start_ms": item.start_ms.map(|v| v as i64),
+                ":end_ms": item.end_ms.map(|v| v as i64),
+                ":done": item.done,
+                ":created_at": item.created_at.to_rfc3339(),
+            },
+        )
+        .await?;
+
+    let row = rows.next().await?.unwrap();
+    let item: ActionItem = libsql::de::from_row(&row)?;
+    Ok(item)
+}
+
+fn normalize(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|token| {
+            token
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(|c| c.to_lowercase())
+                .collect::<String>()
+        })
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+fn parse_due_date(due: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::NaiveDate::parse_from_str(due.trim(), "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+fn resolve_owner(participants: &[Human], owner: &str) -> Option<String> {
+    let owner = normalize(owner);
+    if owner.is_empty() {
+        return None;
+    }
+
+    let names = participants
+        .iter()
+        .filter_map(|h| h.full_name.as_deref().map(|name| (h, normalize(name))))
+        .collect::<Vec<_>>();
+
+    if let Some((human, _)) = names.iter().find(|(_, name)| *name == owner) {
+        return Some(human.id.clone());
+    }
+
+    // "John" should resolve to "John Jeong", but only when it is unambiguous.
+    let by_first_name = names
+        .iter()
+        .filter(|(_, name)| name.first() == owner.first())
+        .collect::<Vec<_>>();
+
+    match by_first_name.as_slice() {
+        [(human, _)] => Some(human.id.clone()),
+        _ => None,
+    }
+}
+
+// Finds the words that the quote was taken from. The model is asked to copy the quote verbatim,
+// but STT output and model output rarely agree on punctuation or casing, so we match on
+// normalized tokens and accept windows where most of the quote is present.
+fn locate_quote(words: &[owhisper_interface::Word2], quote: &str) -> Option<(usize, usize)> {
+    let quote = normalize(quote);
+    if quote.is_empty() {
+        return None;
+    }
+
+    let tokens = words
+        .iter()
+        .enumerate()
+        .flat_map(|(i, word)| normalize(&word.text).into_iter().map(move |t| (i, t)))
+        .collect::<Vec<_>>();
+
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let window = quote.len().min(tokens.len());
+
+    let mut best: Option<(usize, usize)> = None;
+    for start in 0..=(tokens.len() - window) {
+        let mut remaining = HashMap::<&str, usize>::new();
+        for token in &quote {
+            *remaining.entry(token.as_str()).or_default() += 1;
+        }
+
+        let mut score = 0;
+        for (_, token) in &tokens[start..start + window] {
+            if let Some(count) = remaining.get_mut(token.as_str()) {
+                if *count > 0 {
+                    *count -= 1;
+                    score += 1;
+                }
+            }
+        }
+
+        if best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((start, score));
+            if score == quote.len() {
+                break;
+            }
+        }
+    }
+
+    let (start, score) = best?;
+    if score * 10 < quote.len() * 6 {
+        return None;
+    }
+
+    Some((tokens[start].0, tokens[start + window - 1].0))
+}
+
+fn locate_quote_ms(words: &[owhisper_interface::Word2], quote: &str) -> (Option<u64>, Option<u64>) {
+    match locate_quote(words, quote) {
+        Some((first, last)) => (words[first].start_ms, words[last].end_ms),
+        None => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::setup_db, ExtractedActionItem, ExtractedStatement, Human, Session, SessionExtraction,
+    };
+
+    fn words(text: &str) -> Vec<owhisper_interface::Word2> {
+        text.split_whitespace()
+            .enumerate()
+            .map(|(i, t)| owhisper_interface::Word2 {
+                text: t.to_string(),
+                start_ms: Some(i as u64 * 1000),
+                end_ms: Some(i as u64 * 1000 + 500),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_locate_quote() {
+        let words =
+            words("okay so -John, you will send the deck by Friday. Then we ship on monday");
+
+        assert_eq!(
+            locate_quote(&words, "send the deck by friday"),
+            Some((5, 9))
+        );
+        assert_eq!(locate_quote(&words, "John you will send"), Some((2, 5)));
+        assert_eq!(
+            locate_quote(&words, "we will ship on monday"),
+            Some((10, 14))
+        );
+        assert_eq!(locate_quote(&words, "completely unrelated sentence"), None);
+        assert_eq!(locate_quote(&words, ""), None);
+        assert_eq!(locate_quote(&[], "send the deck"), None);
+    }
+
+    #[test]
+    fn test_resolve_owner() {
+        let john = Human {
+            full_name: Some("John Jeong".to_string()),
+            ..Human::default()
+        };
+        let yujong = Human {
+            full_name: Some("Yujong Lee".to_string()),
+            ..Human::default()
+        };
+        let john_2 = Human {
+            full_name: Some("John Doe".to_string()),
+            ..Human::default()
+        };
+
+        let participants = vec![john.clone(), yujong.clone()];
+        assert_eq!(resolve_owner(&participants, "john"), Some(john.id.clone()));
+        assert_eq!(
+            resolve_owner(&participants, "Yujong Lee"),
+            Some(yujong.id.clone())
+        );
+        assert_eq!(resolve_owner(&participants, "Sung"), None);
+
+        let participants = vec![john.clone(), john_2.clone()];
+        assert_eq!(resolve_owner(&participants, "John"), None);
+        assert_eq!(resolve_owner(&participants, "John Doe"), Some(john_2.id));
+    }
+
+    #[tokio::test]
+    async fn test_session_apply_extraction() {
+        let db = setup_db().await;
+
+        let user = db
+            .upsert_human(Human {
+                full_name: Some("John Jeong".to_string()),
+                is_user: true,
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+
+        let session = db
+            .upsert_session(Session {
+                id: uuid::Uuid::new_v4().to_string(),
+                user_id: user.id.clone(),
+                created_at: chrono::Utc::now(),
+                visited_at: chrono::Utc::now(),
+                calendar_event_id: None,
+                title: "Test Session".to_string(),
+                raw_memo_html: "".to_string(),
+                enhanced_memo_html: None,
+                conversations: vec![],
+                words: words("i will send the deck by friday and we decided to ship on monday"),
+                record_start: None,
+                record_end: None,
+                pre_meeting_memo_html: None,
+            })
+            .await
+            .unwrap();
+
+        db.session_add_participant(&session.id, &user.id)
+            .await
+            .unwrap();
+
+        let extraction = SessionExtraction {
+            action_items: vec![ExtractedActionItem {
+                owner: Some("John".to_string()),
+                task: "Send the deck".to_string(),
+                due: Some("2025-01-10".to_string()),
+                quote: "send the deck by friday".to_string(),
+            }],
+            decisions: vec![ExtractedStatement {
+                text: "Ship on Monday".to_string(),
+                quote: "ship on monday".to_string(),
+            }],
+            open_questions: vec![],
+        };
+
+        db.session_apply_extraction(&session.id, extraction.clone())
+            .await
+            .unwrap();
+
+        let items = db
+            .list_action_items(ListActionItemFilter::Open {
+                user_id: user.id.clone(),
+                limit: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].owner_id, Some(user.id.clone()));
+        assert_eq!(items[0].start_ms, Some(2000));
+        assert_eq!(items[0].end_ms, Some(6500));
+        assert_eq!(
+            items[0].due_date.map(|d| d.date_naive().to_string()),
+            Some("2025-01-10".to_string())
+        );
+
+        let decisions = db.list_decisions(&session.id).await.unwrap();
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].kind, DecisionKind::Decision);
+        assert_eq!(decisions[0].start_ms, Some(11000));
+
+        db.set_action_item_done(&items[0].id, true).await.unwrap();
+        assert!(db
+            .list_action_items(ListActionItemFilter::Open {
+                user_id: user.id.clone(),
+                limit: None,
+            })
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Re-running the extraction must neither duplicate nor resurrect done items.
+        db.session_apply_extraction(&session.id, extraction)
+            .await
+            .unwrap();
+
+        let items = db
+            .list_action_items(ListActionItemFilter::Session {
+                session_id: session.id.clone(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(items[0].done);
+        assert_eq!(db.list_decisions(&session.id).await.unwrap().len(), 1);
+    }
+}
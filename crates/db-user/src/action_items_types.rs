@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[sql_table("action_items")]
+    pub struct ActionItem {
+        pub id: String,
+        pub session_id: String,
+        pub owner_id: Option<String>,
+        pub text: String,
+        pub due_date: Option<DateTime<Utc>>,
+        pub source_quote: Option<String>,
+        pub start_ms: Option<u64>,
+        pub end_ms: Option<u64>,
+        pub done: bool,
+        pub created_at: DateTime<Utc>,
+    }
+}
+
+user_common_derives! {
+    #[serde(tag = "type")]
+    pub enum ListActionItemFilter {
+        #[serde(rename = "session")]
+        Session { session_id: String },
+        #[serde(rename = "open")]
+        Open { user_id: String, limit: Option<u32> },
+    }
+}
+
+// Output of `extract_action_items.user` rendered with `hypr_gbnf::Grammar::ActionItems`.
+user_common_derives! {
+    #[derive(Default)]
+    pub struct SessionExtraction {
+        pub action_items: Vec<ExtractedActionItem>,
+        pub decisions: Vec<ExtractedStatement>,
+        pub open_questions: Vec<ExtractedStatement>,
+    }
+}
+
+user_common_derives! {
+    pub struct ExtractedActionItem {
+        pub owner: Option<String>,
+        pub task: String,
+        pub due: Option<String>,
+        pub quote: String,
+    }
+}
+
+user_common_derives! {
+    pub struct ExtractedStatement {
+        pub text: String,
+        pub quote: String,
+    }
+}
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS bookmarks (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL,
+  label TEXT DEFAULT NULL,
+  offset_ms INTEGER NOT NULL,
+  created_at TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
@@ -0,0 +1,70 @@
+use super::{Bookmark, UserDatabase};
+
+impl UserDatabase {
+    pub async fn upsert_bookmark(&self, bookmark: Bookmark) -> Result<Bookmark, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "INSERT INTO bookmarks (
+                    id,
+                    session_id,
+                    label,
+                    offset_ms,
+                    created_at
+                ) VALUES (
+                    :id,
+                    :session_id,
+                    :label,
+                    :offset_ms,
+                    :created_at
+                ) ON CONFLICT(id) DO UPDATE SET
+                    label = :label,
+                    offset_ms = :offset_ms
+                RETURNING *",
+                libsql::named_params! {
+                    ":id": bookmark.id,
+                    ":session_id": bookmark.session_id,
+                    ":label": bookmark.label,
+                    ":offset_ms": bookmark.offset_ms as i64,
+                    ":created_at": bookmark.created_at.to_rfc3339(),
+                },
+            )
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let bookmark: Bookmark = libsql::de::from_row(&row)?;
+        Ok(bookmark)
+    }
+
+    pub async fn list_bookmarks(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<Bookmark>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM bookmarks
+                WHERE session_id = ?
+                ORDER BY offset_ms ASC, created_at ASC",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: Bookmark = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn delete_bookmark(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute("DELETE FROM bookmarks WHERE id = ?", vec![id.into()])
+            .await?;
+        Ok(())
+    }
+}
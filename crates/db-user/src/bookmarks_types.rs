@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[sql_table("bookmarks")]
+    pub struct Bookmark {
+        pub id: String,
+        pub session_id: String,
+        pub label: Option<String>,
+        // Position in the session's recording, on the same timeline as its words.
+        pub offset_ms: u64,
+        pub created_at: DateTime<Utc>,
+    }
+}
+
+impl Bookmark {
+    // `mm:ss`, or `h:mm:ss` for recordings over an hour.
+    pub fn timestamp(&self) -> String {
+        let secs = self.offset_ms / 1000;
+        let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+        if h > 0 {
+            format!("{}:{:02}:{:02}", h, m, s)
+        } else {
+            format!("{:02}:{:02}", m, s)
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        match self.label.as_deref().map(str::trim) {
+            Some(label) if !label.is_empty() => label,
+            _ => "Bookmark",
+        }
+    }
+}
@@ -21,6 +21,8 @@ user_common_derives! {
         Google,
         #[strum(serialize = "Outlook")]
         Outlook,
+        #[strum(serialize = "CalDav")]
+        CalDav,
     }
 }
 
@@ -30,6 +32,7 @@ impl From<hypr_calendar_interface::Platform> for Platform {
             hypr_calendar_interface::Platform::Apple => Platform::Apple,
             hypr_calendar_interface::Platform::Google => Platform::Google,
             hypr_calendar_interface::Platform::Outlook => Platform::Outlook,
+            hypr_calendar_interface::Platform::CalDav => Platform::CalDav,
         }
     }
 }
@@ -40,6 +43,7 @@ impl From<Platform> for hypr_calendar_interface::Platform {
             Platform::Apple => hypr_calendar_interface::Platform::Apple,
             Platform::Google => hypr_calendar_interface::Platform::Google,
             Platform::Outlook => hypr_calendar_interface::Platform::Outlook,
+            Platform::CalDav => hypr_calendar_interface::Platform::CalDav,
         }
     }
 }
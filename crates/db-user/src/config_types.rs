@@ -49,9 +49,73 @@ user_common_derives! {
         #[schemars(with = "String", regex(pattern = "^[a-zA-Z]{2}$"))]
         #[serde(default)]
         pub summary_language: hypr_language::Language,
+        #[serde(default)]
+        pub recording_format: RecordingFormat,
+        #[serde(default)]
+        pub recording_tracks: RecordingTracks,
+        // Only used for Opus.
+        #[serde(default = "default_recording_bitrate_kbps")]
+        pub recording_bitrate_kbps: u32,
+        // Read when a session starts, so a change applies from the next one on. A session can
+        // override it when it is started.
+        #[serde(default)]
+        pub noise_suppression: NoiseSuppression,
+    }
+}
+
+user_common_derives! {
+    #[derive(Default)]
+    #[serde(rename_all = "lowercase")]
+    pub enum RecordingFormat {
+        #[default]
+        Wav,
+        Flac,
+        Opus,
+    }
+}
+
+user_common_derives! {
+    #[derive(Default)]
+    #[serde(rename_all = "lowercase")]
+    pub enum RecordingTracks {
+        // Mic and speaker summed into one channel.
+        #[default]
+        Mixed,
+        // Mic on the left channel, speaker on the right.
+        Stereo,
+        // The mixed recording, plus one file per source.
+        Separate,
+    }
+}
+
+user_common_derives! {
+    #[derive(Default)]
+    #[serde(rename_all = "lowercase")]
+    pub enum NoiseSuppression {
+        Off,
+        Low,
+        #[default]
+        Medium,
+        High,
+    }
+}
+
+impl NoiseSuppression {
+    // How much of the denoised signal replaces the original, or `None` to skip denoising.
+    pub fn strength(&self) -> Option<f32> {
+        match self {
+            NoiseSuppression::Off => None,
+            NoiseSuppression::Low => Some(0.5),
+            NoiseSuppression::Medium => Some(0.8),
+            NoiseSuppression::High => Some(1.0),
+        }
     }
 }
 
+fn default_recording_bitrate_kbps() -> u32 {
+    32
+}
+
 impl Default for ConfigGeneral {
     fn default() -> Self {
         Self {
@@ -63,6 +127,10 @@ impl Default for ConfigGeneral {
             save_recordings: Some(false),
             selected_template_id: None,
             summary_language: hypr_language::ISO639::En.into(),
+            recording_format: RecordingFormat::default(),
+            recording_tracks: RecordingTracks::default(),
+            recording_bitrate_kbps: default_recording_bitrate_kbps(),
+            noise_suppression: NoiseSuppression::default(),
         }
     }
 }
@@ -73,15 +141,27 @@ user_common_derives! {
         pub auto: bool,
         #[serde(rename = "ignoredPlatforms")]
         pub ignored_platforms: Option<Vec<String>>,
+        // Minutes before the start of an event to remind at. One reminder per entry.
+        #[serde(default = "default_lead_minutes")]
+        pub lead_minutes: Vec<u32>,
+        // Start listening when a meeting from a selected calendar begins.
+        #[serde(default)]
+        pub auto_start: bool,
     }
 }
 
+fn default_lead_minutes() -> Vec<u32> {
+    vec![5]
+}
+
 impl Default for ConfigNotification {
     fn default() -> Self {
         Self {
             before: true,
             auto: true,
             ignored_platforms: None,
+            lead_minutes: default_lead_minutes(),
+            auto_start: false,
         }
     }
 }
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS decisions (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL,
+  kind TEXT NOT NULL,
+  text TEXT NOT NULL,
+  source_quote TEXT DEFAULT NULL,
+  start_ms INTEGER DEFAULT NULL,
+  end_ms INTEGER DEFAULT NULL,
+  created_at TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
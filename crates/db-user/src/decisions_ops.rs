@@ -0,0 +1,88 @@
+use super::{Decision, UserDatabase};
+
+impl UserDatabase {
+    pub async fn upsert_decision(&self, decision: Decision) -> Result<Decision, crate::Error> {
+        let conn = self.conn()?;
+        upsert_decision_on(&conn, decision).await
+    }
+
+    pub async fn list_decisions(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<Decision>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM decisions
+                WHERE session_id = ?
+                ORDER BY start_ms IS NULL, start_ms ASC, created_at ASC",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: Decision = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn delete_decision(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute("DELETE FROM decisions WHERE id = ?", vec![id.into()])
+            .await?;
+        Ok(())
+    }
+}
+
+pub(crate) async fn upsert_decision_on(
+    conn: &libsql::Connection,
+    decision: Decision,
+) -> Result<Decision, crate::Error> {
+    let mut rows = conn
+        .query(
+            "INSERT INTO decisions (
+                id,
+                session_id,
+                kind,
+                text,
+                source_quote,
+                start_ms,
+                end_ms,
+                created_at
+            ) VALUES (
+                :id,
+                :session_id,
+                :kind,
+                :text,
+                :source_quote,
+                :start_ms,
+                :end_ms,
+                :created_at
+            ) ON CONFLICT(id) DO UPDATE SET
+                kind = :kind,
+                text = :text,
+                source_quote = :source_quote,
+                start_ms = :start_ms,
+                end_ms = :end_ms
+            RETURNING *",
+            libsql::named_params! {
+                ":id": decision.id,
+                ":session_id": decision.session_id,
+                ":kind": decision.kind.to_string(),
+                ":text": decision.text,
+                ":source_quote": decision.source_quote,
+                ":start_ms": decision.start_ms.map(|v| v as i64),
+                ":end_ms": decision.end_ms.map(|v| v as i64),
+                ":created_at": decision.created_at.to_rfc3339(),
+            },
+        )
+        .await?;
+
+    let row = rows.next().await?.unwrap();
+    let decision: Decision = libsql::de::from_row(&row)?;
+    Ok(decision)
+}
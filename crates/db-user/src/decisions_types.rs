@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display)]
+    pub enum DecisionKind {
+        #[serde(rename = "decision")]
+        #[strum(serialize = "decision")]
+        Decision,
+        #[serde(rename = "open-question")]
+        #[strum(serialize = "open-question")]
+        OpenQuestion,
+    }
+}
+
+user_common_derives! {
+    #[sql_table("decisions")]
+    pub struct Decision {
+        pub id: String,
+        pub session_id: String,
+        pub kind: DecisionKind,
+        pub text: String,
+        pub source_quote: Option<String>,
+        pub start_ms: Option<u64>,
+        pub end_ms: Option<u64>,
+        pub created_at: DateTime<Utc>,
+    }
+}
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS event_notifications (
+  event_id TEXT NOT NULL,
+  kind TEXT NOT NULL,
+  start_date TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  PRIMARY KEY (event_id, kind, start_date),
+  FOREIGN KEY (event_id) REFERENCES events(id) ON DELETE CASCADE
+);
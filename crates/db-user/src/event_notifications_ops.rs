@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+
+use super::{EventNotificationKind, UserDatabase};
+
+impl UserDatabase {
+    // Returns `false` if the notification was already sent for this occurrence of the event.
+    // Keyed on `start_date`, so that a rescheduled event is notified again.
+    pub async fn claim_event_notification(
+        &self,
+        event_id: impl Into<String>,
+        kind: EventNotificationKind,
+        start_date: DateTime<Utc>,
+    ) -> Result<bool, crate::Error> {
+        let conn = self.conn()?;
+
+        let inserted = conn
+            .execute(
+                "INSERT OR IGNORE INTO event_notifications (
+                    event_id,
+                    kind,
+                    start_date,
+                    created_at
+                ) VALUES (?, ?, ?, ?)",
+                vec![
+                    event_id.into(),
+                    kind.to_string(),
+                    start_date.to_rfc3339(),
+                    Utc::now().to_rfc3339(),
+                ],
+            )
+            .await?;
+
+        Ok(inserted > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::setup_db, Event, Human};
+
+    #[tokio::test]
+    async fn test_claim_event_notification() {
+        let db = setup_db().await;
+
+        let human = db.upsert_human(Human::default()).await.unwrap();
+
+        let start_date = Utc::now();
+        let event = db
+            .upsert_event(Event {
+                id: uuid::Uuid::new_v4().to_string(),
+                user_id: human.id,
+                tracking_id: "event_test".to_string(),
+                calendar_id: None,
+                name: "test".to_string(),
+                note: "".to_string(),
+                start_date,
+                end_date: start_date,
+                google_event_url: None,
+                participants: None,
+                is_recurring: false,
+                meeting_link: None,
+            })
+            .await
+            .unwrap();
+
+        let claim = |kind, start_date| db.claim_event_notification(&event.id, kind, start_date);
+
+        assert!(claim(EventNotificationKind::Reminder(10), start_date)
+            .await
+            .unwrap());
+        assert!(!claim(EventNotificationKind::Reminder(10), start_date)
+            .await
+            .unwrap());
+        assert!(claim(EventNotificationKind::Reminder(5), start_date)
+            .await
+            .unwrap());
+        assert!(claim(EventNotificationKind::AutoStart, start_date)
+            .await
+            .unwrap());
+
+        let rescheduled = start_date + chrono::Duration::hours(1);
+        assert!(claim(EventNotificationKind::Reminder(10), rescheduled)
+            .await
+            .unwrap());
+    }
+}
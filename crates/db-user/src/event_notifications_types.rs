@@ -0,0 +1,12 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(strum::Display)]
+    pub enum EventNotificationKind {
+        // One per configured lead time, e.g. `reminder_5`.
+        #[strum(serialize = "reminder_{0}")]
+        Reminder(u32),
+        #[strum(serialize = "auto_start")]
+        AutoStart,
+    }
+}
@@ -15,6 +15,8 @@ user_common_derives! {
         pub google_event_url: Option<String>,
         pub participants: Option<String>,
         pub is_recurring: bool,
+        // JSON of `hypr_meeting_link::MeetingLink`.
+        pub meeting_link: Option<String>,
     }
 }
 
@@ -79,6 +79,7 @@ pub async fn onboarding(db: &UserDatabase, user_id: impl Into<String>) -> Result
         google_event_url: None,
         participants: None,
         is_recurring: false,
+        meeting_link: None,
     };
 
     let onboarding_session_id = db.onboarding_session_id();
@@ -242,6 +243,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 2: Tomorrow - with session (top 3 test)
         Event {
@@ -256,6 +258,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 3: In 2 days - without session (top 3 test)
         Event {
@@ -270,6 +273,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 4: In 3 days - with session (top 3 test)
         Event {
@@ -284,6 +288,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 5: In 7 days - with session (should NOT be in top 3, tests append logic when active)
         Event {
@@ -298,6 +303,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 6: In 14 days - without session (should NOT be in top 3)
         Event {
@@ -312,6 +318,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 7: In 4 hours - Video Call with session
         Event {
@@ -338,6 +345,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: Some("https://zoom.us/j/123456789?pwd=abc123".to_string()),
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 8: In 6 hours - Quick sync without session
         Event {
@@ -352,6 +360,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 9: Later today - Team retro with session
         Event {
@@ -376,6 +385,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: Some("https://teams.microsoft.com/l/meetup-join/meeting123".to_string()),
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 10: In 5 days - Client demo with session  
         Event {
@@ -402,6 +412,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: Some("https://meet.google.com/abc-defg-hij".to_string()),
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 11: In 6 days - Workshop without session
         Event {
@@ -416,6 +427,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 12: In 8 days - Interview with session
         Event {
@@ -443,6 +455,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: Some("https://zoom.us/j/987654321?pwd=interview123".to_string()),
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 13: In 10 days - Board meeting without session
         Event {
@@ -457,6 +470,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 14: In 12 days - Training session with session
         Event {
@@ -483,6 +497,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: Some("https://company.webex.com/meet/security.training".to_string()),
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 15: In 3 weeks - Conference without session
         Event {
@@ -497,6 +512,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // === PAST EVENTS (for NotesList section testing) ===
         // Event 16: Yesterday - with session (should appear in notes)
@@ -512,6 +528,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 17: Last week - with session (should appear in notes)
         Event {
@@ -526,6 +543,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 18: 10 days ago - with session (should appear in notes)
         Event {
@@ -540,6 +558,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
         // Event 19: 2 weeks ago - without session (should not appear anywhere)
         Event {
@@ -554,6 +573,7 @@ pub async fn seed(db: &UserDatabase, user_id: impl Into<String>) -> Result<(), c
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         },
     ];
 
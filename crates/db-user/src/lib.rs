@@ -1,3 +1,7 @@
+mod action_items_ops;
+mod action_items_types;
+mod bookmarks_ops;
+mod bookmarks_types;
 mod calendars_ops;
 mod calendars_types;
 mod chat_groups_ops;
@@ -6,6 +10,10 @@ mod chat_messages_ops;
 mod chat_messages_types;
 mod config_ops;
 mod config_types;
+mod decisions_ops;
+mod decisions_types;
+mod event_notifications_ops;
+mod event_notifications_types;
 mod events_ops;
 mod events_types;
 mod extensions_ops;
@@ -18,9 +26,19 @@ mod sessions_ops;
 mod sessions_types;
 mod tags_ops;
 mod tags_types;
+mod template_overrides_ops;
+mod template_overrides_types;
 mod templates_ops;
 mod templates_types;
 
+#[allow(unused)]
+pub use action_items_ops::*;
+#[allow(unused)]
+pub use action_items_types::*;
+#[allow(unused)]
+pub use bookmarks_ops::*;
+#[allow(unused)]
+pub use bookmarks_types::*;
 #[allow(unused)]
 pub use calendars_ops::*;
 #[allow(unused)]
@@ -38,6 +56,14 @@ pub use config_ops::*;
 #[allow(unused)]
 pub use config_types::*;
 #[allow(unused)]
+pub use decisions_ops::*;
+#[allow(unused)]
+pub use decisions_types::*;
+#[allow(unused)]
+pub use event_notifications_ops::*;
+#[allow(unused)]
+pub use event_notifications_types::*;
+#[allow(unused)]
 pub use events_ops::*;
 #[allow(unused)]
 pub use events_types::*;
@@ -62,6 +88,10 @@ pub use tags_ops::*;
 #[allow(unused)]
 pub use tags_types::*;
 #[allow(unused)]
+pub use template_overrides_ops::*;
+#[allow(unused)]
+pub use template_overrides_types::*;
+#[allow(unused)]
 pub use templates_ops::*;
 #[allow(unused)]
 pub use templates_types::*;
@@ -129,7 +159,7 @@ impl std::ops::Deref for UserDatabase {
 }
 
 // Append only. Do not reorder.
-const MIGRATIONS: [&str; 23] = [
+const MIGRATIONS: [&str; 29] = [
     include_str!("./calendars_migration.sql"),
     include_str!("./configs_migration.sql"),
     include_str!("./events_migration.sql"),
@@ -153,6 +183,12 @@ const MIGRATIONS: [&str; 23] = [
     include_str!("./session_participants_migration_1.sql"),
     include_str!("./events_migration_2.sql"),
     include_str!("./chat_messages_migration_1.sql"),
+    include_str!("./action_items_migration.sql"),
+    include_str!("./decisions_migration.sql"),
+    include_str!("./template_overrides_migration.sql"),
+    include_str!("./event_notifications_migration.sql"),
+    include_str!("./events_migration_3.sql"),
+    include_str!("./bookmarks_migration.sql"),
 ];
 
 pub async fn migrate(db: &UserDatabase) -> Result<(), crate::Error> {
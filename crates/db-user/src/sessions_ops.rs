@@ -132,6 +132,24 @@ impl UserDatabase {
         )
         .await?;
 
+        conn.execute(
+            "DELETE FROM action_items WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
+        conn.execute(
+            "DELETE FROM decisions WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
+        conn.execute(
+            "DELETE FROM bookmarks WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
         conn.execute("DELETE FROM sessions WHERE id = ?", vec![session_id])
             .await?;
 
@@ -286,8 +304,35 @@ impl UserDatabase {
     }
 
     pub async fn upsert_session(&self, session: Session) -> Result<Session, crate::Error> {
+        let (session, _) = self.upsert_session_inserted(session).await?;
+        Ok(session)
+    }
+
+    // Same as `upsert_session`, but also tells whether the session is new, without reading it first.
+    pub async fn upsert_session_inserted(
+        &self,
+        session: Session,
+    ) -> Result<(Session, bool), crate::Error> {
         let conn = self.conn()?;
 
+        let params = || {
+            libsql::named_params! {
+                ":id": session.id.clone(),
+                ":created_at": session.created_at.to_rfc3339(),
+                ":visited_at": session.visited_at.to_rfc3339(),
+                ":user_id": session.user_id.clone(),
+                ":calendar_event_id": session.calendar_event_id.clone(),
+                ":title": session.title.clone(),
+                ":raw_memo_html": session.raw_memo_html.clone(),
+                ":enhanced_memo_html": session.enhanced_memo_html.clone(),
+                ":conversations": "[]",
+                ":words": serde_json::to_string(&session.words).unwrap(),
+                ":record_start": session.record_start.map(|dt| dt.to_rfc3339()),
+                ":record_end": session.record_end.map(|dt| dt.to_rfc3339()),
+                ":pre_meeting_memo_html": session.pre_meeting_memo_html.clone(),
+            }
+        };
+
         let mut rows = conn
             .query(
                 "INSERT INTO sessions (
@@ -319,7 +364,19 @@ impl UserDatabase {
                     :record_end,
                     :pre_meeting_memo_html
                 )
-                ON CONFLICT(id) DO UPDATE SET
+                ON CONFLICT(id) DO NOTHING
+                RETURNING *",
+                params(),
+            )
+            .await?;
+
+        if let Some(row) = rows.next().await? {
+            return Ok((Session::from_row(&row)?, true));
+        }
+
+        let mut rows = conn
+            .query(
+                "UPDATE sessions SET
                     created_at = :created_at,
                     visited_at = :visited_at,
                     user_id = :user_id,
@@ -332,28 +389,14 @@ impl UserDatabase {
                     record_start = :record_start,
                     record_end = :record_end,
                     pre_meeting_memo_html = :pre_meeting_memo_html
+                WHERE id = :id
                 RETURNING *",
-                libsql::named_params! {
-                    ":id": session.id.clone(),
-                    ":created_at": session.created_at.to_rfc3339(),
-                    ":visited_at": session.visited_at.to_rfc3339(),
-                    ":user_id": session.user_id.clone(),
-                    ":calendar_event_id": session.calendar_event_id.clone(),
-                    ":title": session.title.clone(),
-                    ":raw_memo_html": session.raw_memo_html.clone(),
-                    ":enhanced_memo_html": session.enhanced_memo_html.clone(),
-                    ":conversations": "[]",
-                    ":words": serde_json::to_string(&session.words).unwrap(),
-                    ":record_start": session.record_start.map(|dt| dt.to_rfc3339()),
-                    ":record_end": session.record_end.map(|dt| dt.to_rfc3339()),
-                    ":pre_meeting_memo_html": session.pre_meeting_memo_html.clone(),
-                },
+                params(),
             )
             .await?;
 
         let row = rows.next().await?.unwrap();
-        let session = Session::from_row(&row)?;
-        Ok(session)
+        Ok((Session::from_row(&row)?, false))
     }
 
     pub async fn session_set_event(
@@ -495,7 +538,8 @@ mod tests {
             pre_meeting_memo_html: Some("pre_meeting_memo_html_1".to_string()),
         };
 
-        let mut session = db.upsert_session(session).await.unwrap();
+        let (mut session, inserted) = db.upsert_session_inserted(session).await.unwrap();
+        assert!(inserted);
         assert_eq!(session.raw_memo_html, "raw_memo_html_1");
         assert_eq!(session.enhanced_memo_html, None);
         assert_eq!(session.title, "test");
@@ -509,7 +553,8 @@ mod tests {
         assert_eq!(sessions.len(), 1);
 
         session.raw_memo_html = "raw_memo_html_2".to_string();
-        let session = db.upsert_session(session).await.unwrap();
+        let (session, inserted) = db.upsert_session_inserted(session).await.unwrap();
+        assert!(!inserted);
         assert_eq!(session.raw_memo_html, "raw_memo_html_2");
 
         let sessions = db.list_sessions(None).await.unwrap();
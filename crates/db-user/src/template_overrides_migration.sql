@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS template_overrides (
+  id TEXT PRIMARY KEY,
+  name TEXT NOT NULL,
+  version INTEGER NOT NULL,
+  content TEXT DEFAULT NULL,
+  created_at TEXT NOT NULL,
+  UNIQUE (name, version)
+);
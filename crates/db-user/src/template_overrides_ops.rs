@@ -0,0 +1,162 @@
+use super::{TemplateOverride, UserDatabase};
+
+impl UserDatabase {
+    // Saving the same content as the latest version returns that version instead of a new one.
+    pub async fn add_template_override(
+        &self,
+        name: impl Into<String>,
+        content: Option<String>,
+    ) -> Result<TemplateOverride, crate::Error> {
+        let name = name.into();
+        if let Some(latest) = self
+            .list_template_overrides(&name)
+            .await?
+            .into_iter()
+            .next()
+        {
+            if latest.content == content {
+                return Ok(latest);
+            }
+        }
+
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "INSERT INTO template_overrides (
+                    id,
+                    name,
+                    version,
+                    content,
+                    created_at
+                ) VALUES (
+                    :id,
+                    :name,
+                    (SELECT COALESCE(MAX(version), 0) + 1 FROM template_overrides WHERE name = :name),
+                    :content,
+                    :created_at
+                )
+                RETURNING *",
+                libsql::named_params! {
+                    ":id": uuid::Uuid::new_v4().to_string(),
+                    ":name": name,
+                    ":content": content,
+                    ":created_at": chrono::Utc::now().to_rfc3339(),
+                },
+            )
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let item: TemplateOverride = libsql::de::from_row(&row)?;
+        Ok(item)
+    }
+
+    pub async fn list_template_overrides(
+        &self,
+        name: impl Into<String>,
+    ) -> Result<Vec<TemplateOverride>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM template_overrides WHERE name = ? ORDER BY version DESC",
+                vec![name.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: TemplateOverride = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    // Latest version of every overridden template, skipping the ones that were reset to default.
+    pub async fn list_active_template_overrides(
+        &self,
+    ) -> Result<Vec<TemplateOverride>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT t.* FROM template_overrides t
+                JOIN (
+                    SELECT name, MAX(version) AS version FROM template_overrides GROUP BY name
+                ) latest ON t.name = latest.name AND t.version = latest.version
+                WHERE t.content IS NOT NULL",
+                (),
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: TemplateOverride = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::setup_db;
+
+    #[tokio::test]
+    async fn test_template_overrides() {
+        let db = setup_db().await;
+
+        assert!(db
+            .list_active_template_overrides()
+            .await
+            .unwrap()
+            .is_empty());
+
+        let v1 = db
+            .add_template_override("enhance.system", Some("v1".to_string()))
+            .await
+            .unwrap();
+        let v2 = db
+            .add_template_override("enhance.system", Some("v2".to_string()))
+            .await
+            .unwrap();
+        db.add_template_override("create_title.user", Some("title".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!((v1.version, v2.version), (1, 2));
+
+        let unchanged = db
+            .add_template_override("enhance.system", Some("v2".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(unchanged.id, v2.id);
+
+        let active = db.list_active_template_overrides().await.unwrap();
+        assert_eq!(active.len(), 2);
+        assert!(active
+            .iter()
+            .any(|t| t.name == "enhance.system" && t.content.as_deref() == Some("v2")));
+
+        let reset = db
+            .add_template_override("enhance.system", None)
+            .await
+            .unwrap();
+        assert_eq!(reset.version, 3);
+        let reset_again = db
+            .add_template_override("enhance.system", None)
+            .await
+            .unwrap();
+        assert_eq!(reset_again.version, 3);
+
+        let active = db.list_active_template_overrides().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].name, "create_title.user");
+
+        let history = db.list_template_overrides("enhance.system").await.unwrap();
+        assert_eq!(
+            history.iter().map(|t| t.version).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+// `content` is `None` for versions created by "reset to default".
+user_common_derives! {
+    #[sql_table("template_overrides")]
+    pub struct TemplateOverride {
+        pub id: String,
+        pub name: String,
+        pub version: u32,
+        pub content: Option<String>,
+        pub created_at: DateTime<Utc>,
+    }
+}
@@ -0,0 +1,267 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use realfft::{num_complex::Complex, ComplexToReal, RealFftPlanner, RealToComplex};
+
+use hypr_onnx::{
+    ndarray::{Array1, Array3, Array4},
+    ort::{session::Session, value::TensorRef},
+};
+
+mod error;
+pub use error::*;
+
+mod model;
+pub use model::{BLOCK_SHIFT, BLOCK_SIZE};
+
+// The output lags the input by this many samples, since every block is overlap-added.
+pub const LATENCY: usize = BLOCK_SIZE - BLOCK_SHIFT;
+
+// DTLN noise suppression (https://arxiv.org/abs/2005.07551) on 16kHz mono audio.
+pub struct Denoiser {
+    session_1: Session,
+    session_2: Session,
+    fft: Arc<dyn RealToComplex<f32>>,
+    ifft: Arc<dyn ComplexToReal<f32>>,
+    // 0.0 passes the input through untouched, 1.0 is the model's output alone.
+    strength: f32,
+    states_1: Array4<f32>,
+    states_2: Array4<f32>,
+    in_buffer: Vec<f32>,
+    out_buffer: Vec<f32>,
+    spectrum: Vec<Complex<f32>>,
+    scratch: Vec<Complex<f32>>,
+    // The input, delayed to line up with the output it is mixed with.
+    dry: VecDeque<f32>,
+    pending: Vec<f32>,
+    ready: VecDeque<f32>,
+}
+
+impl Denoiser {
+    pub fn new(strength: f32) -> Result<Self, crate::Error> {
+        let mut fft_planner = RealFftPlanner::<f32>::new();
+        let fft = fft_planner.plan_fft_forward(BLOCK_SIZE);
+        let ifft = fft_planner.plan_fft_inverse(BLOCK_SIZE);
+        let scratch_len = fft.get_scratch_len().max(ifft.get_scratch_len());
+
+        Ok(Self {
+            session_1: hypr_onnx::load_model_from_bytes(model::BYTES_1)?,
+            session_2: hypr_onnx::load_model_from_bytes(model::BYTES_2)?,
+            fft,
+            ifft,
+            strength: strength.clamp(0.0, 1.0),
+            states_1: Array4::<f32>::zeros((1, 2, model::STATE_SIZE, 2)),
+            states_2: Array4::<f32>::zeros((1, 2, model::STATE_SIZE, 2)),
+            in_buffer: vec![0.0; BLOCK_SIZE],
+            out_buffer: vec![0.0; BLOCK_SIZE],
+            spectrum: vec![Complex::new(0.0, 0.0); BLOCK_SIZE / 2 + 1],
+            scratch: vec![Complex::new(0.0, 0.0); scratch_len],
+            dry: VecDeque::from(vec![0.0; LATENCY]),
+            pending: Vec::with_capacity(BLOCK_SHIFT),
+            ready: VecDeque::new(),
+        })
+    }
+
+    pub fn reset(&mut self) {
+        self.states_1.fill(0.0);
+        self.states_2.fill(0.0);
+        self.in_buffer.fill(0.0);
+        self.out_buffer.fill(0.0);
+        self.dry = VecDeque::from(vec![0.0; LATENCY]);
+        self.pending.clear();
+        self.ready.clear();
+    }
+
+    // Always returns as many samples as it is given, `LATENCY` behind the input when chunks are a
+    // multiple of `BLOCK_SHIFT`, and up to one `BLOCK_SHIFT` more otherwise.
+    pub fn process_streaming(&mut self, input: &[f32]) -> Result<Vec<f32>, crate::Error> {
+        self.pending.extend_from_slice(input);
+
+        let blocks = self.pending.len() / BLOCK_SHIFT;
+        for i in 0..blocks {
+            let block = self.pending[i * BLOCK_SHIFT..(i + 1) * BLOCK_SHIFT].to_vec();
+            self.process_block(&block)?;
+        }
+        self.pending.drain(..blocks * BLOCK_SHIFT);
+
+        let available = self.ready.len().min(input.len());
+        let mut output = vec![0.0; input.len() - available];
+        output.extend(self.ready.drain(..available));
+        Ok(output)
+    }
+
+    // Denoises a whole recording, without the streaming latency.
+    pub fn process(&mut self, input: &[f32]) -> Result<Vec<f32>, crate::Error> {
+        self.reset();
+
+        let padded_len = (input.len() + LATENCY).div_ceil(BLOCK_SHIFT) * BLOCK_SHIFT;
+        let mut padded = input.to_vec();
+        padded.resize(padded_len, 0.0);
+
+        let output = self.process_streaming(&padded)?;
+        Ok(output[LATENCY..LATENCY + input.len()].to_vec())
+    }
+
+    fn process_block(&mut self, block: &[f32]) -> Result<(), crate::Error> {
+        self.in_buffer.rotate_left(BLOCK_SHIFT);
+        self.in_buffer[BLOCK_SIZE - BLOCK_SHIFT..].copy_from_slice(block);
+
+        let mut fft_input = self.in_buffer.clone();
+        self.fft
+            .process_with_scratch(&mut fft_input, &mut self.spectrum, &mut self.scratch)?;
+
+        let magnitude = Array3::from_shape_fn((1, 1, BLOCK_SIZE / 2 + 1), |(_, _, i)| {
+            self.spectrum[i].norm()
+        });
+        let mask = self.run_model_1(&magnitude)?;
+        for (c, m) in self.spectrum.iter_mut().zip(mask.iter()) {
+            *c *= *m;
+        }
+
+        let mut estimated = vec![0.0f32; BLOCK_SIZE];
+        self.ifft
+            .process_with_scratch(&mut self.spectrum, &mut estimated, &mut self.scratch)?;
+        let estimated = Array3::from_shape_fn((1, 1, BLOCK_SIZE), |(_, _, i)| {
+            estimated[i] / BLOCK_SIZE as f32
+        });
+
+        let out_block = self.run_model_2(&estimated)?;
+
+        self.out_buffer.rotate_left(BLOCK_SHIFT);
+        self.out_buffer[BLOCK_SIZE - BLOCK_SHIFT..].fill(0.0);
+        for (o, b) in self.out_buffer.iter_mut().zip(out_block.iter()) {
+            *o += *b;
+        }
+
+        self.dry.extend(block);
+        for i in 0..BLOCK_SHIFT {
+            let dry = self.dry.pop_front().unwrap_or(0.0);
+            let wet = self.out_buffer[i];
+            self.ready
+                .push_back(self.strength * wet + (1.0 - self.strength) * dry);
+        }
+
+        Ok(())
+    }
+
+    fn run_model_1(&mut self, magnitude: &Array3<f32>) -> Result<Array1<f32>, crate::Error> {
+        let outputs = self.session_1.run(hypr_onnx::ort::inputs![
+            TensorRef::from_array_view(magnitude.view())?,
+            TensorRef::from_array_view(self.states_1.view())?
+        ])?;
+
+        let mask = outputs[0]
+            .try_extract_array::<f32>()?
+            .to_owned()
+            .into_shape_with_order((BLOCK_SIZE / 2 + 1,))?;
+
+        self.states_1 = outputs[1]
+            .try_extract_array::<f32>()?
+            .to_owned()
+            .into_shape_with_order((1, 2, model::STATE_SIZE, 2))?;
+
+        Ok(mask)
+    }
+
+    fn run_model_2(&mut self, estimated: &Array3<f32>) -> Result<Array1<f32>, crate::Error> {
+        let outputs = self.session_2.run(hypr_onnx::ort::inputs![
+            TensorRef::from_array_view(estimated.view())?,
+            TensorRef::from_array_view(self.states_2.view())?
+        ])?;
+
+        let block = outputs[0]
+            .try_extract_array::<f32>()?
+            .to_owned()
+            .into_shape_with_order((BLOCK_SIZE,))?;
+
+        self.states_2 = outputs[1]
+            .try_extract_array::<f32>()?
+            .to_owned()
+            .into_shape_with_order((1, 2, model::STATE_SIZE, 2))?;
+
+        Ok(block)
+    }
+}
+
+// cargo test -p denoise -- --nocapture
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::Source;
+
+    fn clean() -> Vec<f32> {
+        rodio::Decoder::new(std::io::BufReader::new(
+            std::fs::File::open(hypr_data::english_2::AUDIO_PATH).unwrap(),
+        ))
+        .unwrap()
+        .convert_samples::<f32>()
+        .collect()
+    }
+
+    // Fan-like hiss plus keyboard-like clicks, deterministic so the numbers are comparable.
+    fn add_noise(clean: &[f32]) -> Vec<f32> {
+        let mut seed: u32 = 42;
+        let mut white = move || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0
+        };
+
+        clean
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let hiss = 0.03 * white();
+                let click = if i % 2400 < 40 { 0.2 * white() } else { 0.0 };
+                s + hiss + click
+            })
+            .collect()
+    }
+
+    fn snr_db(clean: &[f32], other: &[f32]) -> f32 {
+        let signal: f32 = clean.iter().map(|s| s * s).sum();
+        let noise: f32 = clean.iter().zip(other).map(|(c, o)| (c - o).powi(2)).sum();
+        10.0 * (signal / noise).log10()
+    }
+
+    #[test]
+    fn test_denoise_improves_snr() {
+        let clean = clean();
+        let noisy = add_noise(&clean);
+
+        let denoised = Denoiser::new(1.0).unwrap().process(&noisy).unwrap();
+        assert_eq!(denoised.len(), noisy.len());
+        assert!(denoised.iter().all(|x| x.is_finite()));
+
+        let before = snr_db(&clean, &noisy);
+        let after = snr_db(&clean, &denoised);
+        assert!(
+            after > before + 3.0,
+            "snr: {:.2}dB -> {:.2}dB",
+            before,
+            after
+        );
+    }
+
+    #[test]
+    fn test_zero_strength_passes_through() {
+        let noisy = add_noise(&clean()[..16000]);
+
+        let output = Denoiser::new(0.0).unwrap().process(&noisy).unwrap();
+        assert_eq!(output, noisy);
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let noisy = add_noise(&clean()[..32000]);
+
+        let batch = Denoiser::new(0.8).unwrap().process(&noisy).unwrap();
+
+        let mut denoiser = Denoiser::new(0.8).unwrap();
+        let mut streaming = vec![];
+        for chunk in noisy.chunks(BLOCK_SIZE) {
+            streaming.extend(denoiser.process_streaming(chunk).unwrap());
+        }
+
+        assert_eq!(streaming.len(), noisy.len());
+        assert_eq!(&streaming[LATENCY..], &batch[..batch.len() - LATENCY],);
+    }
+}
@@ -0,0 +1,8 @@
+// https://github.com/breizhn/DTLN/tree/master/pretrained_model
+pub const BYTES_1: &[u8] = include_bytes!("../data/model_1.onnx");
+pub const BYTES_2: &[u8] = include_bytes!("../data/model_2.onnx");
+pub const STATE_SIZE: usize = 128;
+
+// model already trained with these numbers.
+pub const BLOCK_SIZE: usize = 512;
+pub const BLOCK_SHIFT: usize = 128;
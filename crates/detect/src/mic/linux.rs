@@ -0,0 +1,190 @@
+use std::path::Path;
+use std::process::Command;
+
+use tokio::time::{interval, Duration};
+
+use crate::BackgroundTask;
+
+const PROC_ASOUND: &str = "/proc/asound";
+
+#[derive(Default)]
+pub struct Detector {
+    background: BackgroundTask,
+}
+
+impl crate::Observer for Detector {
+    fn start(&mut self, f: crate::DetectCallback) {
+        self.background.start(|running, mut rx| async move {
+            let mut interval_timer = interval(Duration::from_secs(1));
+            let mut was_in_use = false;
+
+            loop {
+                tokio::select! {
+                    _ = &mut rx => {
+                        break;
+                    }
+                    _ = interval_timer.tick() => {
+                        if !running.load(std::sync::atomic::Ordering::SeqCst) {
+                            break;
+                        }
+
+                        let in_use = tokio::task::spawn_blocking(is_mic_in_use)
+                            .await
+                            .unwrap_or(false);
+
+                        if in_use && !was_in_use {
+                            f("microphone_in_use".to_string());
+                        }
+                        was_in_use = in_use;
+                    }
+                }
+            }
+        });
+    }
+
+    fn stop(&mut self) {
+        self.background.stop();
+    }
+}
+
+// PulseAudio (and PipeWire's pulse server) keep ALSA devices open while idle, so ask it first.
+// Plain ALSA setups only expose the state through `/proc/asound`.
+fn is_mic_in_use() -> bool {
+    match pulse_recording_clients() {
+        Some(clients) => clients > 0,
+        None => alsa_capture_running(Path::new(PROC_ASOUND)),
+    }
+}
+
+fn pulse_recording_clients() -> Option<usize> {
+    let sources = pactl(&["list", "short", "sources"])?;
+    let outputs = pactl(&["list", "source-outputs"])?;
+
+    Some(count_recording_clients(
+        &sources,
+        &outputs,
+        std::process::id(),
+    ))
+}
+
+fn pactl(args: &[&str]) -> Option<String> {
+    let output = Command::new("pactl")
+        .args(args)
+        .env("LC_ALL", "C")
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// `/proc/asound/card*/pcm*c/sub*/status` reads `state: RUNNING` while a capture stream is active, `closed` otherwise.
+fn alsa_capture_running(root: &Path) -> bool {
+    let Ok(cards) = std::fs::read_dir(root) else {
+        return false;
+    };
+
+    cards
+        .flatten()
+        .filter(|card| card.file_name().to_string_lossy().starts_with("card"))
+        .filter_map(|card| std::fs::read_dir(card.path()).ok())
+        .flatten()
+        .flatten()
+        .filter(|pcm| {
+            let name = pcm.file_name().to_string_lossy().into_owned();
+            name.starts_with("pcm") && name.ends_with('c')
+        })
+        .filter_map(|pcm| std::fs::read_dir(pcm.path()).ok())
+        .flatten()
+        .flatten()
+        .filter(|sub| sub.file_name().to_string_lossy().starts_with("sub"))
+        .filter_map(|sub| std::fs::read_to_string(sub.path().join("status")).ok())
+        .any(|status| status.lines().any(|line| line.trim() == "state: RUNNING"))
+}
+
+// Counts uncorked source outputs recording from a real input, skipping monitors of sinks and our own process.
+fn count_recording_clients(sources: &str, source_outputs: &str, own_pid: u32) -> usize {
+    let monitors = sources
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split('\t');
+            let id = columns.next()?.trim();
+            let name = columns.next()?.trim();
+            name.ends_with(".monitor").then_some(id)
+        })
+        .collect::<Vec<_>>();
+
+    source_outputs
+        .split("Source Output #")
+        .skip(1)
+        .filter(|block| {
+            let source = field(block, "Source:");
+            let corked = field(block, "Corked:") == Some("yes");
+            let pid = property(block, "application.process.id");
+
+            !corked
+                && source.is_some_and(|s| !monitors.contains(&s))
+                && pid != Some(own_pid.to_string().as_str())
+        })
+        .count()
+}
+
+fn field<'a>(block: &'a str, key: &str) -> Option<&'a str> {
+    block
+        .lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix(key))
+        .map(str::trim)
+}
+
+fn property<'a>(block: &'a str, key: &str) -> Option<&'a str> {
+    block
+        .lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix(key)?.trim().strip_prefix('='))
+        .map(|value| value.trim().trim_matches('"'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCES: &str = include_str!("../../data/linux/pactl_sources.txt");
+    const SOURCE_OUTPUTS: &str = include_str!("../../data/linux/pactl_source_outputs.txt");
+
+    #[test]
+    fn test_count_recording_clients() {
+        // Firefox records from the microphone, OBS from a monitor, and the corked client is paused.
+        assert_eq!(count_recording_clients(SOURCES, SOURCE_OUTPUTS, 1), 1);
+        // Our own recording does not count.
+        assert_eq!(count_recording_clients(SOURCES, SOURCE_OUTPUTS, 4242), 0);
+        assert_eq!(count_recording_clients(SOURCES, "", 1), 0);
+    }
+
+    #[test]
+    fn test_alsa_capture_running() {
+        let root = std::env::temp_dir().join(format!("hypr-asound-{}", std::process::id()));
+
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+
+        write("card0/pcm0p/sub0/status", "state: RUNNING\n");
+        write("card0/pcm0c/sub0/status", "closed\n");
+        write("card1/pcm0c/sub0/status", "closed\n");
+        assert!(!alsa_capture_running(&root));
+
+        write(
+            "card1/pcm0c/sub0/status",
+            include_str!("../../data/linux/asound_capture_running.txt"),
+        );
+        assert!(alsa_capture_running(&root));
+
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(!alsa_capture_running(&root));
+    }
+}
@@ -7,6 +7,8 @@ pub enum Grammar {
     Title,
     #[serde(rename = "tags")]
     Tags,
+    #[serde(rename = "action-items")]
+    ActionItems,
 }
 
 impl Grammar {
@@ -15,6 +17,7 @@ impl Grammar {
             Grammar::Enhance { sections } => build_enhance_other_grammar(sections),
             Grammar::Title => build_title_grammar(),
             Grammar::Tags => build_tags_grammar(),
+            Grammar::ActionItems => build_action_items_grammar(),
         }
     }
 }
@@ -98,6 +101,22 @@ fn build_tags_grammar() -> String {
     .join("\n")
 }
 
+fn build_action_items_grammar() -> String {
+    vec![
+        r##"root ::= "{" ws "\"action_items\":" ws items "," ws "\"decisions\":" ws statements "," ws "\"open_questions\":" ws statements ws "}""##,
+        r##"items ::= "[" ws "]" | "[" ws item ("," ws item)* ws "]""##,
+        r##"item ::= "{" ws "\"owner\":" ws nstring "," ws "\"task\":" ws string "," ws "\"due\":" ws (date | "null") "," ws "\"quote\":" ws string ws "}""##,
+        r##"statements ::= "[" ws "]" | "[" ws statement ("," ws statement)* ws "]""##,
+        r##"statement ::= "{" ws "\"text\":" ws string "," ws "\"quote\":" ws string ws "}""##,
+        r##"date ::= "\"" [0-9]{4} "-" [0-9]{2} "-" [0-9]{2} "\"""##,
+        r##"nstring ::= string | "null""##,
+        r##"string ::= "\"" char+ "\"""##,
+        r##"char ::= [^"\\\x7F\x00-\x1F] | [\\] (["\\bfnrt] | "u" [0-9a-fA-F]{4})"##,
+        r##"ws ::= [ \t\n\r]*"##,
+    ]
+    .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +154,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_action_items_grammar() {
+        let gbnf = gbnf_validator::Validator::new().unwrap();
+
+        for (input, expected) in vec![
+            (
+                r#"{"action_items": [], "decisions": [], "open_questions": []}"#,
+                true,
+            ),
+            (
+                indoc! {r#"
+                {
+                  "action_items": [
+                    {"owner": "John", "task": "Send the deck", "due": "2025-01-10", "quote": "i will send the deck by friday"},
+                    {"owner": null, "task": "Book a room", "due": null, "quote": "someone should book a room"}
+                  ],
+                  "decisions": [{"text": "Ship on Monday", "quote": "let's ship it on monday"}],
+                  "open_questions": [{"text": "Who owns billing?", "quote": "who owns billing"}]
+                }"#},
+                true,
+            ),
+            (
+                r#"{"action_items": [{"owner": "John", "task": "Send the deck", "due": "friday", "quote": "i will"}], "decisions": [], "open_questions": []}"#,
+                false,
+            ),
+            (r#"{"action_items": [], "decisions": []}"#, false),
+        ] {
+            let result = gbnf.validate(&build_action_items_grammar(), input).unwrap();
+            assert_eq!(result, expected, "failed: {}", input);
+        }
+    }
+
     #[test]
     fn test_enhance_grammar() {
         let input_1 = "<headers>\n- Objective\n- Key Takeaways\n- Importance of Complementary Skills\n- Benefits of Using Online Resources\n- Advice for Undergrad Students\n</headers># Objective\n\n- **Search is the Best Way to Find Answers**: The speaker emphasizes the importance of utilizing online resources like Google to find answers to questions.\n- **Value in Complementary Skills**: The speaker highlights the need to acquire complementary skills to traditional research methods.\n\n# Key Takeaways\n\n- **Complementary skills include both traditional research and online resource utilization**: The speaker suggests that skills like using a blank sheet of paper with no Internet and effective Google searching are essential.\n- **Online resources can help find pre-solved problems**: The speaker advises investing time in finding existing resources and communities that have already solved problems.\n\n# Importance of Complementary Skills\n\n- **Traditional research is just the starting point**: The speaker suggests that traditional research methods are just the beginning and should be complemented with other skills.\n- **Effective use of online resources can save time and effort**: The speaker highlights the benefits of utilizing online resources in research and problem-solving.\n\n# Benefits of Using Online Resources\n\n- **Access to knowledge from experts and communities**: The speaker suggests that online resources provide access to knowledge and expertise from experienced individuals.\n- **Time-saving and efficient**: The speaker emphasizes the benefits of finding pre-solved problems through online resources.\n\n# Advice for Undergrad Students\n\n- **Start by searching online**: The speaker advises undergrad students to start by searching online for answers to questions and exploring different resources.\n- **Be open to finding existing solutions**: The speaker emphasizes the importance of being open to finding pre-solved problems and leveraging existing resources.\n\n";
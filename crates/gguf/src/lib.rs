@@ -1,5 +1,5 @@
 use std::fs::File;
-use std::io::{Cursor, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::path::Path;
 
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
@@ -17,8 +17,17 @@ pub use value::*;
 mod utils;
 pub use utils::*;
 
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GgufMetadata {
+    pub architecture: Option<String>,
+    pub name: Option<String>,
+    pub context_length: Option<u64>,
+    pub size_bytes: u64,
+}
+
 pub trait GgufExt {
     fn gguf_chat_format(&self) -> Result<Option<ChatTemplate>>;
+    fn gguf_metadata(&self) -> Result<GgufMetadata>;
 }
 
 impl<T: AsRef<Path>> GgufExt for T {
@@ -130,6 +139,88 @@ impl<T: AsRef<Path>> GgufExt for T {
             Ok(None)
         }
     }
+
+    fn gguf_metadata(&self) -> Result<GgufMetadata> {
+        let file = File::open(self.as_ref())?;
+        let size_bytes = file.metadata()?.len();
+        let map = unsafe { Mmap::map(&file)? };
+        let mut reader = Cursor::new(&map[..]);
+
+        let (version, is_little_endian) = read_header(&mut reader)?;
+
+        let _tensor_count = read_versioned_size(&mut reader, version, is_little_endian)?;
+        let metadata_kv_count = read_versioned_size(&mut reader, version, is_little_endian)?;
+
+        let mut metadata = GgufMetadata {
+            size_bytes,
+            ..Default::default()
+        };
+        // `{arch}.context_length` can appear before `general.architecture`, so keep all of them.
+        let mut context_lengths = Vec::new();
+
+        for _ in 0..metadata_kv_count {
+            let key = read_string(&mut reader, version, is_little_endian)?;
+
+            let value_type_raw = if is_little_endian {
+                reader.read_u32::<LittleEndian>()?
+            } else {
+                reader.read_u32::<BigEndian>()?
+            };
+            let value_type = GGUFMetadataValueType::try_from(value_type_raw)?;
+
+            match (key.as_str(), value_type) {
+                ("general.architecture", GGUFMetadataValueType::String) => {
+                    metadata.architecture =
+                        Some(read_string(&mut reader, version, is_little_endian)?);
+                }
+                ("general.name", GGUFMetadataValueType::String) => {
+                    metadata.name = Some(read_string(&mut reader, version, is_little_endian)?);
+                }
+                (k, _) if k.ends_with(".context_length") => {
+                    match read_integer(&mut reader, value_type, is_little_endian)? {
+                        Some(v) => context_lengths
+                            .push((k.trim_end_matches(".context_length").to_string(), v)),
+                        None => skip_value(&mut reader, value_type, version, is_little_endian)?,
+                    }
+                }
+                _ => skip_value(&mut reader, value_type, version, is_little_endian)?,
+            }
+        }
+
+        metadata.context_length = context_lengths
+            .iter()
+            .find(|(arch, _)| Some(arch) == metadata.architecture.as_ref())
+            .or(context_lengths.first())
+            .map(|(_, v)| *v);
+
+        Ok(metadata)
+    }
+}
+
+fn read_header<R: Read + Seek>(reader: &mut R) -> Result<(u32, bool)> {
+    let magic = reader.read_u32::<LittleEndian>()?;
+    if magic != GGUF_MAGIC {
+        return Err(Error::InvalidMagic);
+    }
+
+    let (version, is_little_endian) = {
+        let version_le = reader.read_u32::<LittleEndian>()?;
+
+        if version_le & 65535 != 0 {
+            (version_le, true)
+        } else {
+            reader.seek(SeekFrom::Start(4))?;
+            let version_be = reader.read_u32::<BigEndian>()?;
+            (version_be, false)
+        }
+    };
+
+    if version > 3 {
+        return Err(Error::UnsupportedVersion(version));
+    }
+
+    reader.seek(SeekFrom::Start(8))?;
+    Ok((version, is_little_endian))
 }
 
 #[cfg(test)]
@@ -146,4 +237,50 @@ mod tests {
         assert!(test_path.exists());
         test_path.gguf_chat_format().unwrap().unwrap();
     }
+
+    fn write_string(buf: &mut Vec<u8>, s: &str) {
+        buf.extend_from_slice(&(s.len() as u64).to_le_bytes());
+        buf.extend_from_slice(s.as_bytes());
+    }
+
+    #[test]
+    fn test_gguf_metadata() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&super::GGUF_MAGIC.to_le_bytes());
+        buf.extend_from_slice(&3u32.to_le_bytes());
+        buf.extend_from_slice(&0u64.to_le_bytes());
+        buf.extend_from_slice(&4u64.to_le_bytes());
+
+        write_string(&mut buf, "qwen3.context_length");
+        buf.extend_from_slice(&4u32.to_le_bytes());
+        buf.extend_from_slice(&40960u32.to_le_bytes());
+
+        write_string(&mut buf, "general.architecture");
+        buf.extend_from_slice(&8u32.to_le_bytes());
+        write_string(&mut buf, "qwen3");
+
+        write_string(&mut buf, "general.file_type");
+        buf.extend_from_slice(&4u32.to_le_bytes());
+        buf.extend_from_slice(&15u32.to_le_bytes());
+
+        write_string(&mut buf, "general.name");
+        buf.extend_from_slice(&8u32.to_le_bytes());
+        write_string(&mut buf, "Qwen3 1.7B");
+
+        let path = std::env::temp_dir().join("gguf_metadata_test.gguf");
+        std::fs::write(&path, &buf).unwrap();
+
+        let metadata = path.gguf_metadata().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            metadata,
+            super::GgufMetadata {
+                architecture: Some("qwen3".to_string()),
+                name: Some("Qwen3 1.7B".to_string()),
+                context_length: Some(40960),
+                size_bytes: buf.len() as u64,
+            }
+        );
+    }
 }
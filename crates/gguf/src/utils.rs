@@ -32,6 +32,30 @@ pub fn read_string<R: Read + Seek>(
     String::from_utf8(buf).map_err(|_| Error::InvalidUtf8)
 }
 
+// Returns `None` for non-integer types, leaving the reader untouched.
+pub fn read_integer<R: Read + Seek>(
+    reader: &mut R,
+    value_type: GGUFMetadataValueType,
+    is_little_endian: bool,
+) -> Result<Option<u64>, Error> {
+    let value = match (value_type, is_little_endian) {
+        (GGUFMetadataValueType::Uint8, _) => reader.read_u8()? as u64,
+        (GGUFMetadataValueType::Uint16, true) => reader.read_u16::<LittleEndian>()? as u64,
+        (GGUFMetadataValueType::Uint16, false) => reader.read_u16::<BigEndian>()? as u64,
+        (GGUFMetadataValueType::Uint32, true) => reader.read_u32::<LittleEndian>()? as u64,
+        (GGUFMetadataValueType::Uint32, false) => reader.read_u32::<BigEndian>()? as u64,
+        (GGUFMetadataValueType::Uint64, true) => reader.read_u64::<LittleEndian>()?,
+        (GGUFMetadataValueType::Uint64, false) => reader.read_u64::<BigEndian>()?,
+        (GGUFMetadataValueType::Int32, true) => reader.read_i32::<LittleEndian>()?.max(0) as u64,
+        (GGUFMetadataValueType::Int32, false) => reader.read_i32::<BigEndian>()?.max(0) as u64,
+        (GGUFMetadataValueType::Int64, true) => reader.read_i64::<LittleEndian>()?.max(0) as u64,
+        (GGUFMetadataValueType::Int64, false) => reader.read_i64::<BigEndian>()?.max(0) as u64,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(value))
+}
+
 pub fn skip_value<R: Read + Seek>(
     reader: &mut R,
     value_type: GGUFMetadataValueType,
@@ -1,6 +1,6 @@
 use mac_address2::get_mac_address;
 use std::hash::{DefaultHasher, Hash, Hasher};
-use sysinfo::System;
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
 
 pub fn cpu_arch() -> String {
     System::cpu_arch()
@@ -10,6 +10,13 @@ pub fn long_os_version() -> String {
     System::long_os_version().unwrap_or("Unknown".to_string())
 }
 
+pub fn total_memory() -> u64 {
+    let sys = System::new_with_specifics(
+        RefreshKind::nothing().with_memory(MemoryRefreshKind::nothing().with_ram()),
+    );
+    sys.total_memory()
+}
+
 pub fn fingerprint() -> String {
     let mac_address = get_mac_address()
         .ok()
@@ -53,6 +60,11 @@ mod tests {
         assert_eq!(a, c);
     }
 
+    #[test]
+    fn test_total_memory() {
+        assert!(total_memory() > 0);
+    }
+
     #[test]
     fn test_fingerprint() {
         let a = fingerprint();
@@ -7,7 +7,9 @@ use llama_cpp_2::{
     llama_batch::LlamaBatch,
     model::{params::LlamaModelParams, AddBos, LlamaModel, Special},
     sampling::LlamaSampler,
-    send_logs_to_tracing, LogOptions,
+    send_logs_to_tracing,
+    token::{logit_bias::LlamaLogitBias, LlamaToken},
+    LogOptions,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tokio_util::sync::CancellationToken;
@@ -15,9 +17,11 @@ use tokio_util::sync::CancellationToken;
 use hypr_gguf::GgufExt;
 
 mod error;
+mod sampling;
 mod types;
 
 pub use error::*;
+pub use sampling::*;
 pub use types::*;
 
 const DEFAULT_MAX_INPUT_TOKENS: u32 = 1024 * 16;
@@ -81,7 +85,11 @@ impl Llama {
         }
     }
 
-    fn get_sampler(model: &LlamaModel, grammar: Option<&str>) -> LlamaSampler {
+    fn get_sampler(
+        model: &LlamaModel,
+        grammar: Option<&str>,
+        params: &SamplingParams,
+    ) -> LlamaSampler {
         let mut samplers = Vec::new();
 
         if let Some(grammar) = grammar {
@@ -94,25 +102,63 @@ impl Llama {
             }
         }
 
-        {
-            // https://huggingface.co/Qwen/Qwen3-1.7B-GGUF
-            samplers.push(LlamaSampler::temp(0.6));
-            samplers.push(LlamaSampler::top_k(20));
-            samplers.push(LlamaSampler::top_p(0.95, 10));
-            samplers.push(LlamaSampler::min_p(0.0, 10));
+        if !params.logit_bias.is_empty() {
+            let biases = params
+                .logit_bias
+                .iter()
+                .map(|(token, bias)| LlamaLogitBias::new(LlamaToken::new(*token), *bias))
+                .collect::<Vec<_>>();
+            samplers.push(LlamaSampler::logit_bias(model.n_vocab(), &biases));
+        }
 
-            samplers.push(LlamaSampler::penalties(0, 1.5, 0.2, 0.2));
-            samplers.push(LlamaSampler::dist(1234));
+        let temperature = params.temperature.unwrap_or(0.6);
+        let penalties = LlamaSampler::penalties(
+            params.penalty_last_n.unwrap_or(0),
+            params.repeat_penalty.unwrap_or(1.0),
+            params.frequency_penalty.unwrap_or(0.0),
+            params.presence_penalty.unwrap_or(0.0),
+        );
+
+        if temperature <= 0.0 {
+            samplers.push(penalties);
+            samplers.push(LlamaSampler::greedy());
+        } else {
+            samplers.push(LlamaSampler::temp(temperature));
+            samplers.push(LlamaSampler::top_k(params.top_k.unwrap_or(20)));
+            samplers.push(LlamaSampler::top_p(params.top_p.unwrap_or(0.95), 10));
+            samplers.push(LlamaSampler::min_p(params.min_p.unwrap_or(0.0), 10));
+
+            samplers.push(penalties);
+            samplers.push(LlamaSampler::dist(params.seed.unwrap_or(1234)));
         }
 
         LlamaSampler::chain_simple(samplers)
     }
 
+    // Recommended sampling settings that some GGUF files ship with.
+    // https://github.com/ggml-org/llama.cpp/pull/12359
+    fn get_model_defaults(model: &LlamaModel) -> SamplingParams {
+        fn meta<T: std::str::FromStr>(model: &LlamaModel, key: &str) -> Option<T> {
+            model.meta_val_str(key).ok()?.trim().parse().ok()
+        }
+
+        SamplingParams {
+            temperature: meta(model, "general.sampling.temp"),
+            top_k: meta(model, "general.sampling.top_k"),
+            top_p: meta(model, "general.sampling.top_p"),
+            min_p: meta(model, "general.sampling.min_p"),
+            penalty_last_n: meta(model, "general.sampling.penalty_last_n"),
+            repeat_penalty: meta(model, "general.sampling.penalty_repeat"),
+            ..Default::default()
+        }
+    }
+
     fn process_prefill<'a>(
         model: &'a LlamaModel,
         backend: &LlamaBackend,
         template: &str,
         request: &LlamaRequest,
+        max_output_tokens: u32,
         callback: Box<dyn FnMut(f64) + Send + 'static>,
         cancellation_token: CancellationToken,
     ) -> Result<
@@ -204,7 +250,8 @@ impl Llama {
                 backend,
                 LlamaContextParams::default()
                     .with_n_ctx(std::num::NonZeroU32::new(
-                        input_tokens_len + DEFAULT_MAX_OUTPUT_TOKENS,
+                        (input_tokens_len + max_output_tokens)
+                            .min(model.n_ctx_train().max(input_tokens_len)),
                     ))
                     .with_n_batch(input_tokens_len)
                     .with_embeddings(false)
@@ -246,15 +293,18 @@ impl Llama {
         mut batch: LlamaBatch,
         last_index: i32,
         request: &LlamaRequest,
+        params: &SamplingParams,
         response_sender: tokio::sync::mpsc::UnboundedSender<String>,
         progress_data_ptr: *mut std::ffi::c_void,
         cancellation_token: CancellationToken,
     ) {
         let mut n_cur = batch.n_tokens();
         let mut decoder = encoding_rs::UTF_8.new_decoder();
-        let mut sampler = Self::get_sampler(model, request.grammar.as_deref());
+        let mut sampler = Self::get_sampler(model, request.grammar.as_deref(), params);
+        let mut stop_matcher = StopMatcher::new(params.stop.clone());
+        let max_output_tokens = params.max_tokens.unwrap_or(DEFAULT_MAX_OUTPUT_TOKENS);
 
-        while n_cur <= last_index + DEFAULT_MAX_OUTPUT_TOKENS as i32 {
+        while n_cur <= last_index + max_output_tokens as i32 && n_cur < ctx.n_ctx() as i32 {
             if cancellation_token.is_cancelled() {
                 break;
             }
@@ -275,7 +325,13 @@ impl Llama {
                 io::stdout().flush().unwrap();
             }
 
-            if response_sender.send(output_string).is_err() {
+            let (output_string, stopped) = stop_matcher.push(&output_string);
+
+            if !output_string.is_empty() && response_sender.send(output_string).is_err() {
+                break;
+            }
+
+            if stopped {
                 break;
             }
 
@@ -286,6 +342,11 @@ impl Llama {
             ctx.decode(&mut batch).unwrap();
         }
 
+        let rest = stop_matcher.finish();
+        if !rest.is_empty() {
+            let _ = response_sender.send(rest);
+        }
+
         drop(response_sender);
 
         unsafe {
@@ -304,6 +365,7 @@ impl Llama {
 
         let backend = Self::get_backend();
         let model = Self::load_model(model_path)?;
+        let model_defaults = Self::get_model_defaults(&model);
         let name = match model.meta_val_str("general.name") {
             Ok(name) if name == "hypr-llm" => ModelName::HyprLLM,
             Ok(name) => ModelName::Other(Some(name.to_string())),
@@ -322,11 +384,25 @@ impl Llama {
                             callback,
                             cancellation_token,
                         } => {
+                            let mut params = request
+                                .sampling
+                                .clone()
+                                .or(&model_defaults)
+                                .or(&SamplingParams::fallback());
+                            // Requests can ask for far more than the model was trained on.
+                            params.max_tokens = Some(
+                                params
+                                    .max_tokens
+                                    .unwrap_or(DEFAULT_MAX_OUTPUT_TOKENS)
+                                    .min(model.n_ctx_train()),
+                            );
+
                             match Self::process_prefill(
                                 &model,
                                 &backend,
                                 template.as_ref(),
                                 &request,
+                                params.max_tokens.unwrap_or(DEFAULT_MAX_OUTPUT_TOKENS),
                                 callback,
                                 cancellation_token.clone(),
                             ) {
@@ -337,6 +413,7 @@ impl Llama {
                                         batch,
                                         last_index,
                                         &request,
+                                        &params,
                                         response_sender,
                                         progress_data_ptr,
                                         cancellation_token,
@@ -439,6 +516,7 @@ mod tests {
                 },
             ],
             tools: None,
+            ..Default::default()
         }
     }
 
@@ -460,6 +538,7 @@ mod tests {
                 },
             ],
             tools: None,
+            ..Default::default()
         };
 
         run(&llama, request).await;
@@ -475,6 +554,23 @@ mod tests {
         run(&llama, request).await;
     }
 
+    // cargo test test_deterministic -p llama -- --nocapture --ignored
+    #[ignore]
+    #[tokio::test]
+    async fn test_deterministic() {
+        let llama = get_model();
+        let request = || LlamaRequest {
+            sampling: SamplingParams {
+                seed: Some(42),
+                max_tokens: Some(64),
+                ..Default::default()
+            },
+            ..get_request()
+        };
+
+        assert_eq!(run(&llama, request()).await, run(&llama, request()).await);
+    }
+
     // cargo test test_cancel_generation -p llama -- --nocapture --ignored
     #[ignore]
     #[tokio::test]
@@ -0,0 +1,203 @@
+use async_openai::types::{CreateChatCompletionRequest, Stop};
+
+// Every field is optional so request values, model defaults and our fallback can be layered.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SamplingParams {
+    pub temperature: Option<f32>,
+    pub top_k: Option<i32>,
+    pub top_p: Option<f32>,
+    pub min_p: Option<f32>,
+    pub penalty_last_n: Option<i32>,
+    pub repeat_penalty: Option<f32>,
+    pub frequency_penalty: Option<f32>,
+    pub presence_penalty: Option<f32>,
+    pub seed: Option<u32>,
+    pub max_tokens: Option<u32>,
+    pub stop: Vec<String>,
+    pub logit_bias: Vec<(i32, f32)>,
+}
+
+impl SamplingParams {
+    // https://huggingface.co/Qwen/Qwen3-1.7B-GGUF
+    pub fn fallback() -> Self {
+        Self {
+            temperature: Some(0.6),
+            top_k: Some(20),
+            top_p: Some(0.95),
+            min_p: Some(0.0),
+            penalty_last_n: Some(0),
+            repeat_penalty: Some(1.5),
+            frequency_penalty: Some(0.2),
+            presence_penalty: Some(0.2),
+            seed: Some(1234),
+            max_tokens: Some(crate::DEFAULT_MAX_OUTPUT_TOKENS),
+            stop: vec![],
+            logit_bias: vec![],
+        }
+    }
+
+    pub fn or(self, defaults: &SamplingParams) -> Self {
+        Self {
+            temperature: self.temperature.or(defaults.temperature),
+            top_k: self.top_k.or(defaults.top_k),
+            top_p: self.top_p.or(defaults.top_p),
+            min_p: self.min_p.or(defaults.min_p),
+            penalty_last_n: self.penalty_last_n.or(defaults.penalty_last_n),
+            repeat_penalty: self.repeat_penalty.or(defaults.repeat_penalty),
+            frequency_penalty: self.frequency_penalty.or(defaults.frequency_penalty),
+            presence_penalty: self.presence_penalty.or(defaults.presence_penalty),
+            seed: self.seed.or(defaults.seed),
+            max_tokens: self.max_tokens.or(defaults.max_tokens),
+            stop: if self.stop.is_empty() {
+                defaults.stop.clone()
+            } else {
+                self.stop
+            },
+            logit_bias: if self.logit_bias.is_empty() {
+                defaults.logit_bias.clone()
+            } else {
+                self.logit_bias
+            },
+        }
+    }
+}
+
+impl From<&CreateChatCompletionRequest> for SamplingParams {
+    #[allow(deprecated)]
+    fn from(request: &CreateChatCompletionRequest) -> Self {
+        let has_penalty = request.frequency_penalty.is_some() || request.presence_penalty.is_some();
+
+        Self {
+            temperature: request.temperature,
+            top_p: request.top_p,
+            // llama.cpp skips penalties entirely when the window is 0, which is our fallback.
+            // OpenAI has no repeat penalty, so keep it neutral once penalties are turned on.
+            penalty_last_n: has_penalty.then_some(64),
+            repeat_penalty: has_penalty.then_some(1.0),
+            frequency_penalty: request.frequency_penalty,
+            presence_penalty: request.presence_penalty,
+            seed: request.seed.map(|s| s as u32),
+            max_tokens: request.max_completion_tokens.or(request.max_tokens),
+            stop: match &request.stop {
+                Some(Stop::String(s)) => vec![s.clone()],
+                Some(Stop::StringArray(v)) => v.clone(),
+                None => vec![],
+            },
+            logit_bias: request
+                .logit_bias
+                .as_ref()
+                .map(|biases| {
+                    biases
+                        .iter()
+                        .filter_map(|(token, bias)| {
+                            Some((token.parse::<i32>().ok()?, bias.as_f64()? as f32))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+// Holds back text that might be the beginning of a stop sequence, so it never leaks into the stream.
+pub struct StopMatcher {
+    stop: Vec<String>,
+    pending: String,
+}
+
+impl StopMatcher {
+    pub fn new(stop: Vec<String>) -> Self {
+        Self {
+            stop: stop.into_iter().filter(|s| !s.is_empty()).collect(),
+            pending: String::new(),
+        }
+    }
+
+    // Returns the text that is safe to emit, and whether a stop sequence was hit.
+    pub fn push(&mut self, chunk: &str) -> (String, bool) {
+        self.pending.push_str(chunk);
+
+        if let Some(idx) = self
+            .stop
+            .iter()
+            .filter_map(|s| self.pending.find(s.as_str()))
+            .min()
+        {
+            let emit = self.pending[..idx].to_string();
+            self.pending.clear();
+            return (emit, true);
+        }
+
+        let hold = self
+            .stop
+            .iter()
+            .map(|s| partial_match_len(&self.pending, s))
+            .max()
+            .unwrap_or(0);
+
+        let emit = self
+            .pending
+            .drain(..self.pending.len() - hold)
+            .collect::<String>();
+        (emit, false)
+    }
+
+    pub fn finish(&mut self) -> String {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+fn partial_match_len(text: &str, stop: &str) -> usize {
+    (1..stop.len())
+        .rev()
+        .find(|&len| stop.is_char_boundary(len) && text.ends_with(&stop[..len]))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampling_params_or() {
+        let request = SamplingParams {
+            temperature: Some(0.0),
+            stop: vec!["</note>".into()],
+            ..Default::default()
+        };
+        let model = SamplingParams {
+            temperature: Some(0.7),
+            top_k: Some(40),
+            ..Default::default()
+        };
+
+        let params = request.or(&model).or(&SamplingParams::fallback());
+        assert_eq!(params.temperature, Some(0.0));
+        assert_eq!(params.top_k, Some(40));
+        assert_eq!(params.top_p, Some(0.95));
+        assert_eq!(params.seed, Some(1234));
+        assert_eq!(params.stop, vec!["</note>".to_string()]);
+    }
+
+    #[test]
+    fn test_stop_matcher() {
+        let mut matcher = StopMatcher::new(vec!["</end>".into()]);
+
+        assert_eq!(matcher.push("hello "), ("hello ".to_string(), false));
+        assert_eq!(matcher.push("world</"), ("world".to_string(), false));
+        assert_eq!(matcher.push("en"), ("".to_string(), false));
+        assert_eq!(matcher.push("d> ignored"), ("".to_string(), true));
+        assert_eq!(matcher.finish(), "");
+    }
+
+    #[test]
+    fn test_stop_matcher_false_start() {
+        let mut matcher = StopMatcher::new(vec!["STOP".into()]);
+
+        assert_eq!(matcher.push("ST"), ("".to_string(), false));
+        assert_eq!(matcher.push("ay"), ("STay".to_string(), false));
+        assert_eq!(matcher.push("S"), ("".to_string(), false));
+        assert_eq!(matcher.finish(), "S");
+    }
+}
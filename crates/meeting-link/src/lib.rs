@@ -0,0 +1,355 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub enum MeetingPlatform {
+    Zoom,
+    GoogleMeet,
+    Teams,
+    Webex,
+    Chime,
+    Jitsi,
+    Whereby,
+    SlackHuddle,
+    CalCom,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct MeetingLink {
+    pub platform: MeetingPlatform,
+    pub url: String,
+    pub meeting_id: Option<String>,
+    pub passcode: Option<String>,
+    pub dial_in: Vec<String>,
+}
+
+lazy_static::lazy_static! {
+    // `id` is captured when the URL itself carries the meeting id or room name.
+    static ref PLATFORMS: Vec<(MeetingPlatform, Regex)> = vec![
+        (
+            MeetingPlatform::Zoom,
+            Regex::new(r#"(?i)https://(?:[a-z0-9-]+\.)*zoom(?:gov)?\.(?:us|com)/(?:j|w|s|my)/(?P<id>[a-z0-9.]+)(?:\?[^\s"'<>]*)?"#).unwrap(),
+        ),
+        (
+            MeetingPlatform::GoogleMeet,
+            Regex::new(r"https://meet\.google\.com/(?P<id>[a-z0-9]{3,4}-[a-z0-9]{3,4}-[a-z0-9]{3,4})").unwrap(),
+        ),
+        (
+            MeetingPlatform::Teams,
+            Regex::new(r#"https://teams\.(?:microsoft|live)\.com/(?:l/meetup-join/[^\s"'<>]+|meet/(?P<id>\d+)(?:\?[^\s"'<>]*)?)"#).unwrap(),
+        ),
+        (
+            MeetingPlatform::Webex,
+            Regex::new(r#"(?i)https://[a-z0-9-]+\.webex\.com/(?:[a-z0-9-]+/)?(?:j\.php\?MTID=[a-z0-9]+|meet/(?P<id>[a-z0-9._-]+)|join/[^\s"'<>]+)"#).unwrap(),
+        ),
+        (
+            MeetingPlatform::Chime,
+            Regex::new(r"https://chime\.aws/(?P<id>\d{10})").unwrap(),
+        ),
+        (
+            MeetingPlatform::Jitsi,
+            Regex::new(r#"https://(?:meet\.jit\.si|8x8\.vc)/(?P<id>[^\s"'<>?#]+)"#).unwrap(),
+        ),
+        (
+            MeetingPlatform::Whereby,
+            Regex::new(r"https://whereby\.com/(?P<id>[a-zA-Z0-9_-]+)").unwrap(),
+        ),
+        (
+            MeetingPlatform::SlackHuddle,
+            Regex::new(r"https://app\.slack\.com/huddle/[A-Z0-9]+/(?P<id>[A-Z0-9]+)").unwrap(),
+        ),
+        (
+            MeetingPlatform::CalCom,
+            Regex::new(r"https://app\.cal\.com/video/(?P<id>[a-zA-Z0-9]+)").unwrap(),
+        ),
+    ];
+
+    static ref MEETING_ID: Regex = Regex::new(
+        r"(?i)\b(?:meeting id|meeting number|conference id|webinar id|access code)\s*(?:\(access code\))?\s*[:#]?\s*(\d[\d ]{4,}\d)"
+    )
+    .unwrap();
+
+    static ref PASSCODE: Regex =
+        Regex::new(r"(?i)\b(?:passcode|password|pin)\s*:\s*([a-z0-9]+)").unwrap();
+
+    static ref DIAL_IN: Regex = Regex::new(r"\+\d[\d \-().]{6,}\d").unwrap();
+}
+
+pub fn parse(text: &str) -> Option<MeetingLink> {
+    parse_all([text])
+}
+
+// Sources are checked in order for a conference URL, e.g. `[conference url, location, description]`.
+// Meeting id, passcode and dial-in numbers are read from the source of the URL and from sources
+// without a link of their own, so that a description of another meeting does not leak into it.
+pub fn parse_all<'a>(sources: impl IntoIterator<Item = &'a str>) -> Option<MeetingLink> {
+    let sources = sources
+        .into_iter()
+        .map(|s| s.replace("&amp;", "&").replace("&nbsp;", " "))
+        .map(|s| {
+            let found = find_url(&s);
+            (s, found)
+        })
+        .collect::<Vec<_>>();
+
+    let (platform, url, id) = sources.iter().find_map(|(_, found)| found.clone())?;
+
+    let text = sources
+        .iter()
+        .filter(|(_, found)| {
+            found
+                .as_ref()
+                .is_none_or(|(p, u, i)| *u == url || (*p == platform && i.is_some() && *i == id))
+        })
+        .map(|(s, _)| s.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let meeting_id = id.or_else(|| {
+        MEETING_ID
+            .captures(&text)
+            .map(|c| c[1].split_whitespace().collect::<String>())
+    });
+    let passcode = PASSCODE.captures(&text).map(|c| c[1].to_string());
+
+    Some(MeetingLink {
+        platform,
+        url,
+        meeting_id,
+        passcode,
+        dial_in: dial_in_numbers(&text),
+    })
+}
+
+fn find_url(text: &str) -> Option<(MeetingPlatform, String, Option<String>)> {
+    PLATFORMS
+        .iter()
+        .filter_map(|(platform, regex)| regex.captures(text).map(|c| (platform, c)))
+        .min_by_key(|(_, c)| c.get(0).unwrap().start())
+        .map(|(platform, c)| {
+            let url = trim_punctuation(&c[0]).to_string();
+            let id = c
+                .name("id")
+                .map(|id| trim_punctuation(id.as_str()).to_string());
+            (platform.clone(), url, id)
+        })
+}
+
+// One-tap numbers like `+16694449171,,86746313244#` are the same line as `+1 669 444 9171`, so dedupe on digits.
+fn dial_in_numbers(text: &str) -> Vec<String> {
+    let text = text.replace("%20", " ");
+
+    let mut seen = std::collections::HashSet::new();
+    DIAL_IN
+        .find_iter(&text)
+        .map(|m| m.as_str().to_string())
+        .filter(|number| {
+            seen.insert(
+                number
+                    .chars()
+                    .filter(char::is_ascii_digit)
+                    .collect::<String>(),
+            )
+        })
+        .collect()
+}
+
+// Links are often followed by a period or wrapped in parentheses.
+fn trim_punctuation(s: &str) -> &str {
+    s.trim_end_matches(['.', ',', ';', ':', ')', ']', '\''])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platforms() {
+        let test_cases = [
+            (
+                "https://us05web.zoom.us/j/87636383039?pwd=NOWbxkY9GNblR0yaLKaIzcy76IWRoj.1",
+                MeetingPlatform::Zoom,
+                Some("87636383039"),
+            ),
+            (
+                "https://acme.zoomgov.com/j/1612345678",
+                MeetingPlatform::Zoom,
+                Some("1612345678"),
+            ),
+            (
+                "https://meet.google.com/tjw-fcje-ewx",
+                MeetingPlatform::GoogleMeet,
+                Some("tjw-fcje-ewx"),
+            ),
+            (
+                "https://teams.microsoft.com/l/meetup-join/19%3ameeting_NzQ1MDk2%40thread.v2/0?context=%7b%22Tid%22%3a%22a1%22%7d",
+                MeetingPlatform::Teams,
+                None,
+            ),
+            (
+                "https://teams.live.com/meet/9384726150123?p=aBcD1234",
+                MeetingPlatform::Teams,
+                Some("9384726150123"),
+            ),
+            (
+                "https://acme.webex.com/acme/j.php?MTID=m0a1b2c3d4e5f",
+                MeetingPlatform::Webex,
+                None,
+            ),
+            (
+                "https://acme.webex.com/meet/jane.doe",
+                MeetingPlatform::Webex,
+                Some("jane.doe"),
+            ),
+            (
+                "https://chime.aws/1234567890",
+                MeetingPlatform::Chime,
+                Some("1234567890"),
+            ),
+            (
+                "https://meet.jit.si/HyprnoteWeeklySync",
+                MeetingPlatform::Jitsi,
+                Some("HyprnoteWeeklySync"),
+            ),
+            (
+                "https://whereby.com/hyprnote-standup",
+                MeetingPlatform::Whereby,
+                Some("hyprnote-standup"),
+            ),
+            (
+                "https://app.slack.com/huddle/T01ABCDEF/C02GHIJKL",
+                MeetingPlatform::SlackHuddle,
+                Some("C02GHIJKL"),
+            ),
+            (
+                "https://app.cal.com/video/d713v9w1d2krBptPtwUAnJ",
+                MeetingPlatform::CalCom,
+                Some("d713v9w1d2krBptPtwUAnJ"),
+            ),
+        ];
+
+        for (url, platform, meeting_id) in test_cases {
+            let link = parse(&format!("Join: {}.", url)).unwrap();
+
+            assert_eq!(link.platform, platform, "{}", url);
+            assert_eq!(link.url, url);
+            assert_eq!(link.meeting_id.as_deref(), meeting_id, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_not_a_meeting() {
+        for text in [
+            "https://meet.google.com/landing",
+            "https://zoom.us/not-a-meeting",
+            "https://app.cal.com/booking/12345",
+            "https://example.com/j/123",
+            "No link here",
+        ] {
+            assert_eq!(parse(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_zoom_details() {
+        let text = indoc::indoc! {r#"
+            <p>Join Zoom Meeting<br/>https://hyprnote.zoom.us/j/86746313244?pwd=zFIICnVHzPim44QcYGbLCAAqtBrGzx.1<br/><br/>
+            Meeting ID: 867 4631 3244<br/>Passcode: 291681</p>
+
+            One tap mobile
+            +16694449171,,86746313244#,,,,*291681# US
+            +16699006833,,86746313244#,,,,*291681# US (San Jose)
+
+            Dial by your location
+            • +1 669 444 9171 US
+            • +1 669 900 6833 US (San Jose)
+            • +1 253 205 0468 US
+        "#};
+
+        assert_eq!(
+            parse(text).unwrap(),
+            MeetingLink {
+                platform: MeetingPlatform::Zoom,
+                url: "https://hyprnote.zoom.us/j/86746313244?pwd=zFIICnVHzPim44QcYGbLCAAqtBrGzx.1"
+                    .to_string(),
+                meeting_id: Some("86746313244".to_string()),
+                passcode: Some("291681".to_string()),
+                dial_in: vec![
+                    "+16694449171".to_string(),
+                    "+16699006833".to_string(),
+                    "+1 253 205 0468".to_string(),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_teams_details() {
+        let text = indoc::indoc! {r#"
+            Microsoft Teams meeting
+            Join on your computer, mobile app or room device
+            Click here to join the meeting<https://teams.microsoft.com/l/meetup-join/19%3ameeting_NzQ1MDk2%40thread.v2/0?context=%7b%22Tid%22%3a%22a1%22%7d&amp;btype=a>
+            Meeting ID: 235 461 895 112
+            Passcode: 4JkSRP
+            Or call in (audio only)
+            +1 323-849-4874,,518417412#   United States, Los Angeles
+        "#};
+
+        let link = parse(text).unwrap();
+        assert_eq!(link.platform, MeetingPlatform::Teams);
+        assert!(link.url.ends_with("%7d&btype=a"));
+        assert_eq!(link.meeting_id.as_deref(), Some("235461895112"));
+        assert_eq!(link.passcode.as_deref(), Some("4JkSRP"));
+        assert_eq!(link.dial_in, vec!["+1 323-849-4874".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let description = indoc::indoc! {r#"
+            Google Meet으로 참석: https://meet.google.com/xkf-xcmo-rwh
+            또는 다음 전화번호로 전화 걸기: (US) +1 402-732-7278 PIN: 765104423#
+            tel:+1%20650-817-8427;205595809%23
+        "#};
+
+        // Location wins over the description, whose details belong to the Meet link.
+        let link = parse_all([
+            "https://us02web.zoom.us/j/81234567890",
+            "Conference room 3",
+            description,
+        ])
+        .unwrap();
+        assert_eq!(link.platform, MeetingPlatform::Zoom);
+        assert_eq!(link.meeting_id.as_deref(), Some("81234567890"));
+        assert_eq!(link.passcode, None);
+        assert!(link.dial_in.is_empty());
+
+        let link = parse_all(["Conference room 3", description]).unwrap();
+        assert_eq!(link.platform, MeetingPlatform::GoogleMeet);
+        assert_eq!(link.passcode.as_deref(), Some("765104423"));
+        assert_eq!(
+            link.dial_in,
+            vec!["+1 402-732-7278".to_string(), "+1 650-817-8427".to_string()]
+        );
+
+        // Details next to no link at all are picked up.
+        let link = parse_all([
+            "https://hyprnote.zoom.us/j/86746313244",
+            "Meeting ID: 867 4631 3244\nPasscode: 291681",
+        ])
+        .unwrap();
+        assert_eq!(link.passcode.as_deref(), Some("291681"));
+
+        assert_eq!(parse_all(["", "Conference room 3"]), None);
+    }
+
+    #[test]
+    fn test_serde() {
+        let link = parse("https://chime.aws/1234567890").unwrap();
+        let json = serde_json::to_string(&link).unwrap();
+        assert_eq!(
+            json,
+            r#"{"platform":"Chime","url":"https://chime.aws/1234567890","meeting_id":"1234567890","passcode":null,"dial_in":[]}"#
+        );
+        assert_eq!(serde_json::from_str::<MeetingLink>(&json).unwrap(), link);
+    }
+}
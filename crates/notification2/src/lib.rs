@@ -3,6 +3,9 @@ pub use wezterm::ToastNotification as Notification;
 #[cfg(target_os = "macos")]
 mod macos;
 
+#[cfg(target_os = "linux")]
+mod linux;
+
 pub fn show(notif: Notification) {
     if cfg!(debug_assertions) {
         return;
@@ -12,6 +15,10 @@ pub fn show(notif: Notification) {
     #[cfg(target_os = "macos")]
     wezterm::macos_initialize();
 
+    #[cfg(target_os = "linux")]
+    linux::show(notif);
+
+    #[cfg(not(target_os = "linux"))]
     wezterm::show(notif);
 }
 
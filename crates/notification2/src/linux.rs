@@ -0,0 +1,268 @@
+// https://specifications.freedesktop.org/notification-spec/latest/protocol.html
+
+use std::collections::HashMap;
+
+use zbus::{blocking::Connection, zvariant::Value};
+
+use crate::Notification;
+
+const SERVICE: &str = "org.freedesktop.Notifications";
+const PATH: &str = "/org/freedesktop/Notifications";
+const APP_NAME: &str = "Hyprnote";
+
+// Invoked when the notification body is clicked. Servers that support actions may render it as a button too.
+const DEFAULT_ACTION: &str = "default";
+const OPEN_ACTION: &str = "open";
+
+pub fn show(notif: Notification) {
+    std::thread::spawn(move || {
+        let result = Connection::session().and_then(|connection| show_with(&connection, &notif));
+
+        match result {
+            Ok(Some(action)) if action == DEFAULT_ACTION || action == OPEN_ACTION => {
+                if let Some(url) = &notif.url {
+                    let _ = std::process::Command::new("xdg-open").arg(url).spawn();
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("show_notification_error: {:?}", e),
+        }
+    });
+}
+
+// Blocks until the notification is either clicked or closed, and returns the invoked action.
+pub fn show_with(connection: &Connection, notif: &Notification) -> zbus::Result<Option<String>> {
+    // Subscribe before sending, so that a fast click is not missed.
+    let rule = zbus::MatchRule::builder()
+        .msg_type(zbus::message::Type::Signal)
+        .interface(SERVICE)?
+        .path(PATH)?
+        .build();
+    let signals = zbus::blocking::MessageIterator::for_match_rule(rule, connection, None)?;
+
+    let actions = match notif.url {
+        Some(_) => vec![DEFAULT_ACTION, "Open", OPEN_ACTION, "Open"],
+        None => vec![],
+    };
+
+    let mut hints: HashMap<&str, Value<'_>> = HashMap::new();
+    hints.insert("urgency", Value::U8(1));
+    hints.insert("desktop-entry", Value::from("hyprnote"));
+
+    let expire_timeout = notif
+        .timeout
+        .map(|t| t.as_millis().min(i32::MAX as u128) as i32)
+        .unwrap_or(-1);
+
+    let reply = connection.call_method(
+        Some(SERVICE),
+        PATH,
+        Some(SERVICE),
+        "Notify",
+        &(
+            APP_NAME,
+            0u32,
+            "",
+            notif.title.as_str(),
+            notif.message.as_str(),
+            actions,
+            hints,
+            expire_timeout,
+        ),
+    )?;
+    let id: u32 = reply.body().deserialize()?;
+
+    for message in signals {
+        let message = message?;
+        let header = message.header();
+
+        match header.member().map(|m| m.as_str()) {
+            Some("ActionInvoked") => {
+                let (signal_id, action): (u32, String) = message.body().deserialize()?;
+                if signal_id == id {
+                    return Ok(Some(action));
+                }
+            }
+            Some("NotificationClosed") => {
+                let (signal_id, _reason): (u32, u32) = message.body().deserialize()?;
+                if signal_id == id {
+                    return Ok(None);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::sync::mpsc;
+
+    use zbus::object_server::SignalEmitter;
+
+    struct Bus(Child);
+
+    impl Drop for Bus {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+        }
+    }
+
+    // Private session bus, so that tests do not pop up real notifications.
+    fn session_bus() -> Option<(Bus, String)> {
+        let mut child = Command::new("dbus-daemon")
+            .args(["--session", "--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .ok()?;
+
+        let mut address = String::new();
+        BufReader::new(child.stdout.take()?)
+            .read_line(&mut address)
+            .ok()?;
+
+        Some((Bus(child), address.trim().to_string()))
+    }
+
+    struct Server {
+        tx: mpsc::Sender<(String, String, Vec<String>)>,
+        action: Option<&'static str>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.Notifications")]
+    impl Server {
+        #[allow(clippy::too_many_arguments)]
+        async fn notify(
+            &self,
+            _app_name: String,
+            _replaces_id: u32,
+            _app_icon: String,
+            summary: String,
+            body: String,
+            actions: Vec<String>,
+            _hints: HashMap<String, zbus::zvariant::OwnedValue>,
+            _expire_timeout: i32,
+            #[zbus(signal_emitter)] emitter: SignalEmitter<'_>,
+        ) -> u32 {
+            let id = 7;
+            self.tx.send((summary, body, actions)).unwrap();
+
+            // Another notification's signal must be ignored.
+            Self::notification_closed(&emitter, id + 1, 2)
+                .await
+                .unwrap();
+
+            match self.action {
+                Some(action) => Self::action_invoked(&emitter, id, action).await.unwrap(),
+                None => Self::notification_closed(&emitter, id, 1).await.unwrap(),
+            }
+
+            id
+        }
+
+        #[zbus(signal)]
+        async fn action_invoked(
+            emitter: &SignalEmitter<'_>,
+            id: u32,
+            action_key: &str,
+        ) -> zbus::Result<()>;
+
+        #[zbus(signal)]
+        async fn notification_closed(
+            emitter: &SignalEmitter<'_>,
+            id: u32,
+            reason: u32,
+        ) -> zbus::Result<()>;
+    }
+
+    fn serve(
+        address: &str,
+        action: Option<&'static str>,
+    ) -> (Connection, mpsc::Receiver<(String, String, Vec<String>)>) {
+        let (tx, rx) = mpsc::channel();
+
+        let server = zbus::blocking::connection::Builder::address(address)
+            .unwrap()
+            .name(SERVICE)
+            .unwrap()
+            .serve_at(PATH, Server { tx, action })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        (server, rx)
+    }
+
+    fn client(address: &str) -> Connection {
+        zbus::blocking::connection::Builder::address(address)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_show_with_action() {
+        let Some((_bus, address)) = session_bus() else {
+            eprintln!("dbus-daemon not found, skipping");
+            return;
+        };
+
+        let (_server, rx) = serve(&address, Some(OPEN_ACTION));
+
+        let action = show_with(
+            &client(&address),
+            &Notification {
+                title: "Meeting starting in 5 minutes".to_string(),
+                message: "Weekly sync".to_string(),
+                url: Some("hypr://hyprnote.com/notification".to_string()),
+                timeout: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(action.as_deref(), Some(OPEN_ACTION));
+        assert_eq!(
+            rx.recv().unwrap(),
+            (
+                "Meeting starting in 5 minutes".to_string(),
+                "Weekly sync".to_string(),
+                vec![
+                    DEFAULT_ACTION.to_string(),
+                    "Open".to_string(),
+                    OPEN_ACTION.to_string(),
+                    "Open".to_string()
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn test_show_with_closed() {
+        let Some((_bus, address)) = session_bus() else {
+            eprintln!("dbus-daemon not found, skipping");
+            return;
+        };
+
+        let (_server, rx) = serve(&address, None);
+
+        let action = show_with(
+            &client(&address),
+            &Notification {
+                title: "Meeting detected".to_string(),
+                message: "".to_string(),
+                url: None,
+                timeout: Some(std::time::Duration::from_secs(10)),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(action, None);
+        assert!(rx.recv().unwrap().2.is_empty());
+    }
+}
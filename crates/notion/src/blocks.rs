@@ -0,0 +1,802 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+// https://developers.notion.com/reference/request-limits#limits-for-property-values
+const MAX_TEXT_LENGTH: usize = 2000;
+// A single request can only create two levels of nested blocks.
+const MAX_DEPTH: usize = 2;
+
+// https://developers.notion.com/reference/block
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Block {
+    #[serde(rename = "paragraph")]
+    Paragraph { paragraph: TextBlock },
+    #[serde(rename = "heading_1")]
+    Heading1 { heading_1: TextBlock },
+    #[serde(rename = "heading_2")]
+    Heading2 { heading_2: TextBlock },
+    #[serde(rename = "heading_3")]
+    Heading3 { heading_3: TextBlock },
+    #[serde(rename = "bulleted_list_item")]
+    BulletedListItem { bulleted_list_item: TextBlock },
+    #[serde(rename = "numbered_list_item")]
+    NumberedListItem { numbered_list_item: TextBlock },
+    #[serde(rename = "to_do")]
+    ToDo { to_do: ToDoBlock },
+    #[serde(rename = "quote")]
+    Quote { quote: TextBlock },
+    #[serde(rename = "code")]
+    Code { code: CodeBlock },
+    #[serde(rename = "divider")]
+    Divider { divider: EmptyBlock },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TextBlock {
+    pub rich_text: Vec<RichText>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Block>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ToDoBlock {
+    pub rich_text: Vec<RichText>,
+    pub checked: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Block>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CodeBlock {
+    pub rich_text: Vec<RichText>,
+    pub language: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EmptyBlock {}
+
+// https://developers.notion.com/reference/rich-text
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichText {
+    Text {
+        text: Text,
+        annotations: Annotations,
+    },
+    Mention {
+        mention: Mention,
+        annotations: Annotations,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Text {
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<Link>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Link {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Mention {
+    Page { page: PageReference },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PageReference {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Annotations {
+    pub bold: bool,
+    pub italic: bool,
+    pub strikethrough: bool,
+    pub underline: bool,
+    pub code: bool,
+}
+
+impl RichText {
+    pub fn text(content: impl Into<String>) -> Self {
+        Self::Text {
+            text: Text {
+                content: content.into(),
+                link: None,
+            },
+            annotations: Annotations::default(),
+        }
+    }
+
+    pub fn link(content: impl Into<String>, url: impl Into<String>) -> Self {
+        Self::Text {
+            text: Text {
+                content: content.into(),
+                link: Some(Link { url: url.into() }),
+            },
+            annotations: Annotations::default(),
+        }
+    }
+}
+
+impl Block {
+    pub fn paragraph(rich_text: Vec<RichText>) -> Self {
+        Self::Paragraph {
+            paragraph: TextBlock {
+                rich_text,
+                children: vec![],
+            },
+        }
+    }
+}
+
+// Converts the editor's HTML (`enhanced_memo_html`, `raw_memo_html`) into Notion blocks.
+// `pages` maps session ids to exported Notion pages, so that note mentions become page mentions.
+pub fn html_to_blocks(html: &str, pages: &HashMap<String, String>) -> Vec<Block> {
+    let Ok(dom) = tl::parse(html, tl::ParserOptions::default()) else {
+        return vec![];
+    };
+
+    Converter {
+        parser: dom.parser(),
+        pages,
+    }
+    .blocks(dom.children(), 0)
+}
+
+struct Converter<'p, 'a> {
+    parser: &'p tl::Parser<'a>,
+    pages: &'p HashMap<String, String>,
+}
+
+impl Converter<'_, '_> {
+    fn blocks(&self, handles: &[tl::NodeHandle], depth: usize) -> Vec<Block> {
+        let mut blocks = vec![];
+        // Inline content outside of any block element, e.g. `<strong>` directly in the root.
+        let mut pending = vec![];
+
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tag = match node {
+                tl::Node::Tag(tag) => tag,
+                tl::Node::Raw(_) => {
+                    pending.extend(self.inline(&[*handle], Annotations::default(), None));
+                    continue;
+                }
+                tl::Node::Comment(_) => continue,
+            };
+
+            let name = tag.name().as_utf8_str().to_ascii_lowercase();
+            let children = tag.children();
+            let children = children.top();
+
+            let block = match name.as_str() {
+                "h1" => Some(Block::Heading1 {
+                    heading_1: self.text_block(children),
+                }),
+                "h2" => Some(Block::Heading2 {
+                    heading_2: self.text_block(children),
+                }),
+                "h3" | "h4" | "h5" | "h6" => Some(Block::Heading3 {
+                    heading_3: self.text_block(children),
+                }),
+                "p" => {
+                    let rich_text = self.paragraphs(children);
+                    (!rich_text.is_empty()).then(|| Block::paragraph(rich_text))
+                }
+                "blockquote" => Some(Block::Quote {
+                    quote: TextBlock {
+                        rich_text: self.paragraphs(children),
+                        children: vec![],
+                    },
+                }),
+                "pre" => Some(Block::Code {
+                    code: self.code_block(tag),
+                }),
+                "hr" => Some(Block::Divider {
+                    divider: EmptyBlock {},
+                }),
+                "ul" | "ol" => {
+                    flush(&mut pending, &mut blocks);
+                    blocks.extend(self.list(tag, depth));
+                    continue;
+                }
+                "div" | "section" | "article" | "main" | "body" | "html" => {
+                    flush(&mut pending, &mut blocks);
+                    blocks.extend(self.blocks(children, depth));
+                    continue;
+                }
+                "head" | "script" | "style" => continue,
+                _ => {
+                    pending.extend(self.inline(&[*handle], Annotations::default(), None));
+                    continue;
+                }
+            };
+
+            flush(&mut pending, &mut blocks);
+            blocks.extend(block);
+        }
+
+        flush(&mut pending, &mut blocks);
+        blocks
+    }
+
+    fn list(&self, list: &tl::HTMLTag, depth: usize) -> Vec<Block> {
+        let ordered = list.name().as_utf8_str().eq_ignore_ascii_case("ol");
+        let task_list = attribute(list, "data-type").as_deref() == Some("taskList");
+
+        let mut blocks = vec![];
+
+        for handle in list.children().top().iter() {
+            let Some(tl::Node::Tag(item)) = handle.get(self.parser) else {
+                continue;
+            };
+            if !item.name().as_utf8_str().eq_ignore_ascii_case("li") {
+                continue;
+            }
+
+            let mut rich_text = vec![];
+            let mut nested = vec![];
+            self.list_item(item.children().top(), depth, &mut rich_text, &mut nested);
+            let rich_text = normalize(rich_text);
+
+            // Lists nested deeper than a request allows are flattened into their parent.
+            let (children, siblings) = if depth + 1 < MAX_DEPTH {
+                (nested, vec![])
+            } else {
+                (vec![], nested)
+            };
+
+            let block = if task_list || attribute(item, "data-checked").is_some() {
+                Block::ToDo {
+                    to_do: ToDoBlock {
+                        rich_text,
+                        checked: attribute(item, "data-checked").as_deref() == Some("true"),
+                        children,
+                    },
+                }
+            } else if ordered {
+                Block::NumberedListItem {
+                    numbered_list_item: TextBlock {
+                        rich_text,
+                        children,
+                    },
+                }
+            } else {
+                Block::BulletedListItem {
+                    bulleted_list_item: TextBlock {
+                        rich_text,
+                        children,
+                    },
+                }
+            };
+
+            blocks.push(block);
+            blocks.extend(siblings);
+        }
+
+        blocks
+    }
+
+    // Task items wrap their content in `<label><input/></label><div><p>..</p></div>`.
+    fn list_item(
+        &self,
+        handles: &[tl::NodeHandle],
+        depth: usize,
+        rich_text: &mut Vec<RichText>,
+        nested: &mut Vec<Block>,
+    ) {
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tl::Node::Tag(tag) = node else {
+                rich_text.extend(self.inline(&[*handle], Annotations::default(), None));
+                continue;
+            };
+
+            match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                "ul" | "ol" => nested.extend(self.list(tag, depth + 1)),
+                "div" | "label" => self.list_item(tag.children().top(), depth, rich_text, nested),
+                "input" => {}
+                "p" => {
+                    if !rich_text.is_empty() {
+                        rich_text.push(RichText::text("\n"));
+                    }
+                    rich_text.extend(self.inline(
+                        tag.children().top(),
+                        Annotations::default(),
+                        None,
+                    ));
+                }
+                _ => rich_text.extend(self.inline(&[*handle], Annotations::default(), None)),
+            }
+        }
+    }
+
+    fn text_block(&self, handles: &[tl::NodeHandle]) -> TextBlock {
+        TextBlock {
+            rich_text: normalize(self.inline(handles, Annotations::default(), None)),
+            children: vec![],
+        }
+    }
+
+    // Content of `<p>` and `<blockquote>`, where nested paragraphs are kept as line breaks.
+    fn paragraphs(&self, handles: &[tl::NodeHandle]) -> Vec<RichText> {
+        let mut rich_text = vec![];
+
+        for handle in handles {
+            match handle.get(self.parser) {
+                Some(tl::Node::Tag(tag)) if tag.name().as_utf8_str().eq_ignore_ascii_case("p") => {
+                    if !rich_text.is_empty() {
+                        rich_text.push(RichText::text("\n"));
+                    }
+                    rich_text.extend(self.inline(
+                        tag.children().top(),
+                        Annotations::default(),
+                        None,
+                    ));
+                }
+                _ => rich_text.extend(self.inline(&[*handle], Annotations::default(), None)),
+            }
+        }
+
+        normalize(rich_text)
+    }
+
+    fn code_block(&self, pre: &tl::HTMLTag) -> CodeBlock {
+        let language = pre
+            .children()
+            .top()
+            .iter()
+            .filter_map(|handle| match handle.get(self.parser) {
+                Some(tl::Node::Tag(tag)) => attribute(tag, "class"),
+                _ => None,
+            })
+            .find_map(|class| {
+                class
+                    .split_whitespace()
+                    .find_map(|c| c.strip_prefix("language-").map(str::to_string))
+            });
+
+        let content = decode_entities(&pre.inner_text(self.parser));
+        let content = content.strip_suffix('\n').unwrap_or(&content);
+
+        CodeBlock {
+            rich_text: split_long(RichText::text(content)),
+            language: code_language(language.as_deref()).to_string(),
+        }
+    }
+
+    fn inline(
+        &self,
+        handles: &[tl::NodeHandle],
+        annotations: Annotations,
+        link: Option<&str>,
+    ) -> Vec<RichText> {
+        let mut rich_text = vec![];
+
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tag = match node {
+                tl::Node::Tag(tag) => tag,
+                tl::Node::Raw(raw) => {
+                    let content = collapse_whitespace(&decode_entities(&raw.as_utf8_str()));
+                    if !content.is_empty() {
+                        rich_text.push(RichText::Text {
+                            text: Text {
+                                content,
+                                link: link.map(|url| Link {
+                                    url: url.to_string(),
+                                }),
+                            },
+                            annotations,
+                        });
+                    }
+                    continue;
+                }
+                tl::Node::Comment(_) => continue,
+            };
+
+            let children = tag.children();
+            let children = children.top();
+            let mut annotations = annotations;
+
+            match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                "br" => {
+                    rich_text.push(RichText::text("\n"));
+                    continue;
+                }
+                "img" | "input" | "script" | "style" => continue,
+                "a" if attribute(tag, "data-mention").as_deref() == Some("true") => {
+                    rich_text.push(self.mention(tag, annotations));
+                    continue;
+                }
+                "a" => {
+                    let href = attribute(tag, "href").filter(|href| is_supported_url(href));
+                    rich_text.extend(self.inline(children, annotations, href.as_deref().or(link)));
+                    continue;
+                }
+                "strong" | "b" => annotations.bold = true,
+                "em" | "i" => annotations.italic = true,
+                "s" | "del" | "strike" => annotations.strikethrough = true,
+                "u" => annotations.underline = true,
+                "code" => annotations.code = true,
+                _ => {}
+            }
+
+            rich_text.extend(self.inline(children, annotations, link));
+        }
+
+        rich_text
+    }
+
+    fn mention(&self, tag: &tl::HTMLTag, annotations: Annotations) -> RichText {
+        let page = match (attribute(tag, "data-type"), attribute(tag, "data-id")) {
+            (Some(kind), Some(id)) if kind == "note" => self.pages.get(&id),
+            _ => None,
+        };
+
+        match page {
+            Some(page_id) => RichText::Mention {
+                mention: Mention::Page {
+                    page: PageReference {
+                        id: page_id.clone(),
+                    },
+                },
+                annotations,
+            },
+            None => {
+                let label = attribute(tag, "data-label")
+                    .map(|label| format!("@{}", label))
+                    .unwrap_or_else(|| decode_entities(&tag.inner_text(self.parser)));
+
+                RichText::Text {
+                    text: Text {
+                        content: label,
+                        link: None,
+                    },
+                    annotations,
+                }
+            }
+        }
+    }
+}
+
+fn flush(pending: &mut Vec<RichText>, blocks: &mut Vec<Block>) {
+    let rich_text = normalize(std::mem::take(pending));
+    if !rich_text.is_empty() {
+        blocks.push(Block::paragraph(rich_text));
+    }
+}
+
+fn attribute(tag: &tl::HTMLTag, key: &str) -> Option<String> {
+    tag.attributes()
+        .get(key)
+        .flatten()
+        .map(|value| decode_entities(&value.as_utf8_str()))
+}
+
+// Notion rejects links that are not absolute, e.g. the `javascript:` ones used for mentions.
+fn is_supported_url(url: &str) -> bool {
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+// Trims the edges of the block, merges runs with the same style, and splits overly long ones.
+fn normalize(rich_text: Vec<RichText>) -> Vec<RichText> {
+    let mut merged: Vec<RichText> = vec![];
+
+    for item in rich_text {
+        match (merged.last_mut(), item) {
+            (
+                Some(RichText::Text {
+                    text: prev,
+                    annotations: prev_annotations,
+                }),
+                RichText::Text { text, annotations },
+            ) if prev.link == text.link && *prev_annotations == annotations => {
+                if prev.content.ends_with(char::is_whitespace) {
+                    prev.content.push_str(text.content.trim_start_matches(' '));
+                } else {
+                    prev.content.push_str(&text.content);
+                }
+            }
+            (_, item) => merged.push(item),
+        }
+    }
+
+    if let Some(RichText::Text { text, .. }) = merged.first_mut() {
+        text.content = text.content.trim_start().to_string();
+    }
+    if let Some(RichText::Text { text, .. }) = merged.last_mut() {
+        text.content = text.content.trim_end().to_string();
+    }
+
+    merged
+        .into_iter()
+        .filter(|item| !matches!(item, RichText::Text { text, .. } if text.content.is_empty()))
+        .flat_map(split_long)
+        .collect()
+}
+
+fn split_long(item: RichText) -> Vec<RichText> {
+    let RichText::Text { text, annotations } = item else {
+        return vec![item];
+    };
+
+    if text.content.chars().count() <= MAX_TEXT_LENGTH {
+        return vec![RichText::Text { text, annotations }];
+    }
+
+    text.content
+        .chars()
+        .collect::<Vec<_>>()
+        .chunks(MAX_TEXT_LENGTH)
+        .map(|chunk| RichText::Text {
+            text: Text {
+                content: chunk.iter().collect(),
+                link: text.link.clone(),
+            },
+            annotations,
+        })
+        .collect()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_space = false;
+
+    for c in text.chars() {
+        // `&nbsp;` is decoded to U+00A0 and should survive.
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !last_was_space {
+                collapsed.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            collapsed.push(c);
+            last_was_space = false;
+        }
+    }
+
+    collapsed
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => entity.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+// https://developers.notion.com/reference/block#code
+// Notion rejects languages outside of its list, so anything else is exported as plain text.
+fn code_language(language: Option<&str>) -> &'static str {
+    match language.map(|l| l.to_ascii_lowercase()).as_deref() {
+        Some("bash" | "sh" | "shell" | "zsh") => "shell",
+        Some("c") => "c",
+        Some("cpp" | "c++") => "c++",
+        Some("cs" | "csharp" | "c#") => "c#",
+        Some("css") => "css",
+        Some("diff") => "diff",
+        Some("go" | "golang") => "go",
+        Some("html") => "html",
+        Some("java") => "java",
+        Some("js" | "javascript") => "javascript",
+        Some("json") => "json",
+        Some("kotlin" | "kt") => "kotlin",
+        Some("markdown" | "md") => "markdown",
+        Some("python" | "py") => "python",
+        Some("ruby" | "rb") => "ruby",
+        Some("rust" | "rs") => "rust",
+        Some("sql") => "sql",
+        Some("swift") => "swift",
+        Some("ts" | "typescript") => "typescript",
+        Some("yaml" | "yml") => "yaml",
+        _ => "plain text",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn blocks(html: &str) -> serde_json::Value {
+        serde_json::to_value(html_to_blocks(html, &HashMap::new())).unwrap()
+    }
+
+    fn text(content: &str) -> serde_json::Value {
+        json!({
+            "type": "text",
+            "text": { "content": content },
+            "annotations": {
+                "bold": false,
+                "italic": false,
+                "strikethrough": false,
+                "underline": false,
+                "code": false
+            }
+        })
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        assert_eq!(
+            blocks("<h1>Summary</h1>\n<p>We   agreed\n on <em>scope</em>.</p><h4>Details</h4><p></p><hr>"),
+            json!([
+                { "type": "heading_1", "heading_1": { "rich_text": [text("Summary")] } },
+                {
+                    "type": "paragraph",
+                    "paragraph": {
+                        "rich_text": [
+                            text("We agreed on "),
+                            {
+                                "type": "text",
+                                "text": { "content": "scope" },
+                                "annotations": {
+                                    "bold": false,
+                                    "italic": true,
+                                    "strikethrough": false,
+                                    "underline": false,
+                                    "code": false
+                                }
+                            },
+                            text(".")
+                        ]
+                    }
+                },
+                { "type": "heading_3", "heading_3": { "rich_text": [text("Details")] } },
+                { "type": "divider", "divider": {} }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let html = "<ul><li><p>One</p><ul><li><p>Nested</p><ul><li><p>Deep</p></li></ul></li></ul></li></ul><ol><li>First</li></ol>";
+
+        assert_eq!(
+            blocks(html),
+            json!([
+                {
+                    "type": "bulleted_list_item",
+                    "bulleted_list_item": {
+                        "rich_text": [text("One")],
+                        "children": [
+                            { "type": "bulleted_list_item", "bulleted_list_item": { "rich_text": [text("Nested")] } },
+                            { "type": "bulleted_list_item", "bulleted_list_item": { "rich_text": [text("Deep")] } }
+                        ]
+                    }
+                },
+                { "type": "numbered_list_item", "numbered_list_item": { "rich_text": [text("First")] } }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_todos() {
+        let html = r#"<ul data-type="taskList"><li data-checked="true" data-type="taskItem"><label><input type="checkbox" checked="checked"><span></span></label><div><p>Send deck</p></div></li><li data-checked="false" data-type="taskItem"><label><input type="checkbox"><span></span></label><div><p>Book room</p></div></li></ul>"#;
+
+        assert_eq!(
+            blocks(html),
+            json!([
+                { "type": "to_do", "to_do": { "rich_text": [text("Send deck")], "checked": true } },
+                { "type": "to_do", "to_do": { "rich_text": [text("Book room")], "checked": false } }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_links_and_mentions() {
+        let pages = HashMap::from([("s1".to_string(), "page-1".to_string())]);
+        let html = r#"<p>See <a href="https://example.com?a=1&amp;b=2">docs</a>, <a class="mention" data-mention="true" data-id="s1" data-type="note" data-label="Kickoff" href="javascript:void(0)">@Kickoff</a> and <a class="mention" data-mention="true" data-id="s2" data-type="note" data-label="Retro" href="javascript:void(0)">@Retro</a></p>"#;
+
+        assert_eq!(
+            serde_json::to_value(html_to_blocks(html, &pages)).unwrap(),
+            json!([{
+                "type": "paragraph",
+                "paragraph": {
+                    "rich_text": [
+                        text("See "),
+                        {
+                            "type": "text",
+                            "text": { "content": "docs", "link": { "url": "https://example.com?a=1&b=2" } },
+                            "annotations": text("")["annotations"]
+                        },
+                        text(", "),
+                        {
+                            "type": "mention",
+                            "mention": { "type": "page", "page": { "id": "page-1" } },
+                            "annotations": text("")["annotations"]
+                        },
+                        text(" and @Retro")
+                    ]
+                }
+            }])
+        );
+    }
+
+    #[test]
+    fn test_code_and_quote() {
+        let html = "<blockquote><p>Ship it</p><p>&lt;soon&gt;</p></blockquote><pre><code class=\"language-rs\">fn main() {}\n</code></pre>";
+
+        assert_eq!(
+            blocks(html),
+            json!([
+                { "type": "quote", "quote": { "rich_text": [text("Ship it\n<soon>")] } },
+                { "type": "code", "code": { "rich_text": [text("fn main() {}")], "language": "rust" } }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_long_text_is_split() {
+        let html = format!("<p>{}</p>", "a".repeat(MAX_TEXT_LENGTH + 1));
+        let blocks = html_to_blocks(&html, &HashMap::new());
+
+        let Block::Paragraph { paragraph } = &blocks[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(paragraph.rich_text.len(), 2);
+    }
+}
@@ -0,0 +1,508 @@
+use std::collections::HashMap;
+
+use hypr_db_user::{
+    Bookmark, Event, ExtensionMapping, GetSessionFilter, Human, Session, Tag, UserDatabase,
+};
+use serde_json::{json, Value};
+
+use crate::{
+    html_to_blocks, Block, NotionClient, Page, RichText, SessionDatabasePropertyKey, TextBlock,
+};
+
+pub const EXTENSION_ID: &str = "notion";
+
+// Stored as the `config` of the user's Notion `ExtensionMapping`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NotionConfig {
+    // Page the database is created under, if there is none yet.
+    #[serde(default)]
+    pub parent_page_id: Option<String>,
+    #[serde(default)]
+    pub database_id: Option<String>,
+    // Session id to the id of the page it was exported to.
+    #[serde(default)]
+    pub pages: HashMap<String, String>,
+}
+
+pub struct SessionExport {
+    pub session: Session,
+    pub event: Option<Event>,
+    pub participants: Vec<Human>,
+    pub tags: Vec<Tag>,
+    pub bookmarks: Vec<Bookmark>,
+}
+
+impl SessionExport {
+    pub async fn load(db: &UserDatabase, session_id: impl Into<String>) -> anyhow::Result<Self> {
+        let session_id = session_id.into();
+
+        let session = db
+            .get_session(GetSessionFilter::Id(session_id.clone()))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("session not found: {}", session_id))?;
+
+        Ok(Self {
+            event: db.session_get_event(&session_id).await?,
+            participants: db.session_list_participants(&session_id).await?,
+            tags: db.list_session_tags(&session_id).await?,
+            bookmarks: db.list_bookmarks(&session_id).await?,
+            session,
+        })
+    }
+
+    // https://developers.notion.com/reference/page-property-values
+    pub fn properties(&self, title_property: &str) -> serde_json::Map<String, Value> {
+        let title = match self.session.title.trim() {
+            "" => "Untitled",
+            title => title,
+        };
+
+        let (start, end) = match &self.event {
+            Some(event) => (event.start_date, Some(event.end_date)),
+            None => (
+                self.session.record_start.unwrap_or(self.session.created_at),
+                self.session.record_end,
+            ),
+        };
+
+        let participants = self
+            .participants
+            .iter()
+            .filter_map(|human| human.full_name.as_ref().or(human.email.as_ref()))
+            .map(String::as_str);
+
+        let event = match &self.event {
+            Some(event) => match event
+                .google_event_url
+                .as_ref()
+                .filter(|url| url.starts_with("https://"))
+            {
+                Some(url) => vec![RichText::link(&event.name, url)],
+                None => vec![RichText::text(&event.name)],
+            },
+            None => vec![],
+        };
+
+        serde_json::Map::from_iter([
+            (
+                title_property.to_string(),
+                json!({ "title": [RichText::text(title)] }),
+            ),
+            (
+                SessionDatabasePropertyKey::Id.to_string(),
+                json!({ "rich_text": [RichText::text(&self.session.id)] }),
+            ),
+            (
+                SessionDatabasePropertyKey::Date.to_string(),
+                json!({
+                    "date": {
+                        "start": start.to_rfc3339(),
+                        "end": end.filter(|end| *end > start).map(|end| end.to_rfc3339()),
+                    }
+                }),
+            ),
+            (
+                SessionDatabasePropertyKey::Participants.to_string(),
+                json!({ "multi_select": select_options(participants) }),
+            ),
+            (
+                SessionDatabasePropertyKey::Tags.to_string(),
+                json!({ "multi_select": select_options(self.tags.iter().map(|tag| tag.name.as_str())) }),
+            ),
+            (
+                SessionDatabasePropertyKey::Event.to_string(),
+                json!({ "rich_text": event }),
+            ),
+        ])
+    }
+
+    pub fn blocks(&self, pages: &HashMap<String, String>) -> Vec<Block> {
+        let html = match &self.session.enhanced_memo_html {
+            Some(html) if !html.trim().is_empty() => html,
+            _ => &self.session.raw_memo_html,
+        };
+
+        let mut blocks = html_to_blocks(html, pages);
+
+        // Notion has no media chapters, so bookmarks are listed under their own heading.
+        if !self.bookmarks.is_empty() {
+            blocks.push(Block::Heading2 {
+                heading_2: TextBlock {
+                    rich_text: vec![RichText::text("Chapters")],
+                    children: vec![],
+                },
+            });
+            blocks.extend(
+                self.bookmarks
+                    .iter()
+                    .map(|bookmark| Block::BulletedListItem {
+                        bulleted_list_item: TextBlock {
+                            rich_text: vec![RichText::text(format!(
+                                "[{}] {}",
+                                bookmark.timestamp(),
+                                bookmark.title()
+                            ))],
+                            children: vec![],
+                        },
+                    }),
+            );
+        }
+
+        blocks
+    }
+}
+
+// Exports the session to the user's Notion database.
+// A session that was exported before has its page updated in place, instead of getting a new one.
+pub async fn export_session(
+    client: &NotionClient,
+    db: &UserDatabase,
+    user_id: impl Into<String>,
+    session_id: impl Into<String>,
+) -> anyhow::Result<Page> {
+    let user_id = user_id.into();
+    let session_id = session_id.into();
+
+    let mut mapping = db
+        .get_extension_mapping(&user_id, EXTENSION_ID)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("notion is not connected"))?;
+    let mut config: NotionConfig = serde_json::from_value(mapping.config.clone())?;
+
+    let database = match (&config.database_id, &config.parent_page_id) {
+        (Some(database_id), _) => client.ensure_db_schema(database_id).await?,
+        (None, Some(parent_page_id)) => client.create_db(parent_page_id).await?,
+        (None, None) => anyhow::bail!("no notion database or parent page configured"),
+    };
+
+    let export = SessionExport::load(db, &session_id).await?;
+    let properties = export.properties(&database.title_property());
+    let children = export.blocks(&config.pages);
+
+    let existing = match config.pages.get(&session_id) {
+        Some(page_id) => client
+            .find_page(page_id)
+            .await?
+            .filter(|page| !page.archived),
+        None => None,
+    };
+
+    let page = match existing {
+        Some(page) => {
+            let page = client.update_page(&page.id, properties).await?;
+            client.replace_children(&page.id, children).await?;
+            page
+        }
+        None => {
+            client
+                .create_page(&database.id, properties, children)
+                .await?
+        }
+    };
+
+    config.database_id = Some(database.id);
+    config.pages.insert(session_id, page.id.clone());
+
+    db.upsert_extension_mapping(ExtensionMapping {
+        config: serde_json::to_value(&config)?,
+        ..mapping
+    })
+    .await?;
+
+    Ok(page)
+}
+
+// Select option names can not contain commas, and are limited to 100 characters.
+fn select_options<'a>(names: impl Iterator<Item = &'a str>) -> Vec<Value> {
+    let mut options: Vec<String> = vec![];
+
+    for name in names {
+        let name = name
+            .replace(',', " ")
+            .trim()
+            .chars()
+            .take(100)
+            .collect::<String>();
+        if !name.is_empty() && !options.contains(&name) {
+            options.push(name);
+        }
+    }
+
+    options
+        .into_iter()
+        .map(|name| json!({ "name": name }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Duration, Utc};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup_db() -> (UserDatabase, String) {
+        let base_db = hypr_db_core::DatabaseBuilder::default()
+            .memory()
+            .build()
+            .await
+            .unwrap();
+        let db = UserDatabase::from(base_db);
+        hypr_db_user::migrate(&db).await.unwrap();
+
+        let human = db
+            .upsert_human(Human {
+                full_name: Some("test".to_string()),
+                is_user: true,
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+
+        (db, human.id)
+    }
+
+    fn start() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-02T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    async fn seed(db: &UserDatabase, user_id: &str) -> Session {
+        let event = db
+            .upsert_event(Event {
+                id: "event-1".to_string(),
+                user_id: user_id.to_string(),
+                tracking_id: "event-1".to_string(),
+                calendar_id: None,
+                name: "Weekly sync".to_string(),
+                note: "".to_string(),
+                start_date: start(),
+                end_date: start() + Duration::hours(1),
+                google_event_url: Some("https://calendar.google.com/event?eid=1".to_string()),
+                participants: None,
+                is_recurring: false,
+                meeting_link: None,
+            })
+            .await
+            .unwrap();
+
+        let session = db
+            .upsert_session(Session {
+                id: "session-1".to_string(),
+                created_at: start(),
+                visited_at: start(),
+                user_id: user_id.to_string(),
+                calendar_event_id: Some(event.id),
+                title: "Weekly sync".to_string(),
+                raw_memo_html: "<p>raw</p>".to_string(),
+                enhanced_memo_html: Some(
+                    "<h1>Decisions</h1><ul><li><p>Ship on Friday</p></li></ul>".to_string(),
+                ),
+                conversations: vec![],
+                words: vec![],
+                record_start: None,
+                record_end: None,
+                pre_meeting_memo_html: None,
+            })
+            .await
+            .unwrap();
+
+        let john = db
+            .upsert_human(Human {
+                full_name: Some("John, Jr.".to_string()),
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+        db.session_add_participant(&session.id, &john.id)
+            .await
+            .unwrap();
+
+        let tag = db
+            .upsert_tag(Tag {
+                id: "tag-1".to_string(),
+                name: "sales".to_string(),
+            })
+            .await
+            .unwrap();
+        db.assign_tag_to_session(&tag.id, &session.id)
+            .await
+            .unwrap();
+
+        session
+    }
+
+    #[tokio::test]
+    async fn test_properties() {
+        let (db, user_id) = setup_db().await;
+        let session = seed(&db, &user_id).await;
+
+        let export = SessionExport::load(&db, &session.id).await.unwrap();
+        let properties = Value::Object(export.properties("Name"));
+
+        assert_eq!(
+            properties["Name"]["title"][0]["text"]["content"],
+            "Weekly sync"
+        );
+        assert_eq!(
+            properties["Date"]["date"],
+            json!({
+                "start": "2025-01-02T09:00:00+00:00",
+                "end": "2025-01-02T10:00:00+00:00"
+            })
+        );
+        assert_eq!(
+            properties["Participants"]["multi_select"],
+            json!([{ "name": "John  Jr." }])
+        );
+        assert_eq!(
+            properties["Tags"]["multi_select"],
+            json!([{ "name": "sales" }])
+        );
+        assert_eq!(
+            properties["Event"]["rich_text"][0]["text"]["link"]["url"],
+            "https://calendar.google.com/event?eid=1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blocks_with_bookmarks() {
+        let (db, user_id) = setup_db().await;
+        let session = seed(&db, &user_id).await;
+
+        db.upsert_bookmark(Bookmark {
+            id: "bookmark-1".to_string(),
+            session_id: session.id.clone(),
+            label: Some("Launch date".to_string()),
+            offset_ms: 754_000,
+            created_at: start(),
+        })
+        .await
+        .unwrap();
+
+        let export = SessionExport::load(&db, &session.id).await.unwrap();
+        let blocks = serde_json::to_value(export.blocks(&HashMap::new())).unwrap();
+
+        assert_eq!(blocks[2]["type"], "heading_2");
+        assert_eq!(
+            blocks[3]["bulleted_list_item"]["rich_text"][0]["text"]["content"],
+            "[12:34] Launch date"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_session_twice() {
+        let (db, user_id) = setup_db().await;
+        let session = seed(&db, &user_id).await;
+
+        db.upsert_extension_mapping(ExtensionMapping {
+            id: "mapping-1".to_string(),
+            user_id: user_id.clone(),
+            extension_id: EXTENSION_ID.to_string(),
+            config: json!({ "parent_page_id": "parent-1" }),
+            widgets: vec![],
+        })
+        .await
+        .unwrap();
+
+        let server = MockServer::start().await;
+        let database = json!({
+            "id": "db-1",
+            "properties": {
+                "Title": { "type": "title" },
+                "Id": { "type": "rich_text" },
+                "Date": { "type": "date" },
+                "Participants": { "type": "multi_select" },
+                "Tags": { "type": "multi_select" },
+                "Event": { "type": "rich_text" }
+            }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/databases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&database))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/databases/db-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&database))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/pages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "page-1" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/pages/page-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "id": "page-1", "archived": false })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/pages/page-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "page-1" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/blocks/page-1/children"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{ "id": "b1" }, { "id": "b2" }],
+                "has_more": false,
+                "next_cursor": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "b1" })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/blocks/page-1/children"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "results": [] })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NotionClient::with_base_url("token", server.uri());
+
+        let page = export_session(&client, &db, &user_id, &session.id)
+            .await
+            .unwrap();
+        assert_eq!(page.id, "page-1");
+
+        let requests = server.received_requests().await.unwrap();
+        let create: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(create["parent"]["database_id"], "db-1");
+        assert_eq!(create["children"][0]["type"], "heading_1");
+        assert_eq!(create["children"][1]["type"], "bulleted_list_item");
+
+        let page = export_session(&client, &db, &user_id, &session.id)
+            .await
+            .unwrap();
+        assert_eq!(page.id, "page-1");
+
+        let mapping = db
+            .get_extension_mapping(&user_id, EXTENSION_ID)
+            .await
+            .unwrap()
+            .unwrap();
+        let config: NotionConfig = serde_json::from_value(mapping.config).unwrap();
+        assert_eq!(config.database_id.as_deref(), Some("db-1"));
+        assert_eq!(
+            config.pages.get("session-1").map(String::as_str),
+            Some("page-1")
+        );
+    }
+}
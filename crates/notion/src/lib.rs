@@ -1,151 +1,457 @@
-use std::collections::BTreeMap;
-
-use notion_client::{
-    endpoints::{
-        databases::create::request::CreateADatabaseRequestBuilder,
-        pages::create::request::CreateAPageRequestBuilder, Client,
-    },
-    objects::{
-        block::{Block, BlockType, ParagraphValue},
-        database::Database,
-        page::{Page, PageProperty},
-        parent::Parent,
-        rich_text::{RichText, Text},
-    },
-};
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use strum::IntoEnumIterator;
+
+mod blocks;
+mod export;
+
+pub use blocks::*;
+pub use export::*;
+
+const NOTION_API: &str = "https://api.notion.com/v1";
+// https://developers.notion.com/reference/versioning
+const NOTION_VERSION: &str = "2022-06-28";
+// https://developers.notion.com/reference/request-limits#limits-for-property-values
+const MAX_CHILDREN: usize = 100;
 
 pub struct NotionClient {
-    client: Client,
+    client: reqwest::Client,
+    base_url: String,
+    token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Database {
+    pub id: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, Value>,
 }
 
-#[derive(PartialOrd, Ord, Eq, PartialEq, strum::Display)]
-enum SessionDatabasePropertyKey {
+impl Database {
+    // Databases created in Notion name it "Name", ours name it "Title".
+    pub fn title_property(&self) -> String {
+        self.properties
+            .iter()
+            .find(|(_, property)| property["type"] == "title")
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| SessionDatabasePropertyKey::Title.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Page {
+    pub id: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockList {
+    results: Vec<BlockId>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockId {
+    id: String,
+}
+
+// https://developers.notion.com/reference/status-codes
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    code: String,
+    message: String,
+}
+
+#[derive(PartialOrd, Ord, Eq, PartialEq, strum::Display, strum::EnumIter)]
+pub(crate) enum SessionDatabasePropertyKey {
     #[strum(serialize = "Id")]
     Id,
     #[strum(serialize = "Title")]
     Title,
+    #[strum(serialize = "Date")]
+    Date,
+    #[strum(serialize = "Participants")]
+    Participants,
+    #[strum(serialize = "Tags")]
+    Tags,
+    #[strum(serialize = "Event")]
+    Event,
 }
 
-type SessionDatabaseProperty = BTreeMap<SessionDatabasePropertyKey, PageProperty>;
+impl SessionDatabasePropertyKey {
+    // https://developers.notion.com/reference/property-schema-object
+    fn schema(&self) -> Value {
+        match self {
+            Self::Title => json!({ "title": {} }),
+            Self::Id | Self::Event => json!({ "rich_text": {} }),
+            Self::Date => json!({ "date": {} }),
+            Self::Participants | Self::Tags => json!({ "multi_select": {} }),
+        }
+    }
+}
 
-// https://github.com/takassh/notion-client/tree/main/examples
 impl NotionClient {
     pub fn new(token: impl Into<String>) -> Self {
-        let client = Client::new(token.into(), None).unwrap();
-        Self { client }
+        Self::with_base_url(token, NOTION_API)
     }
 
-    pub async fn find_db(&self, db_id: impl AsRef<str>) -> anyhow::Result<Database> {
-        let response = self
-            .client
-            .databases
-            .retrieve_a_database(db_id.as_ref())
-            .await?;
+    pub fn with_base_url(token: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+        }
+    }
 
-        Ok(response)
+    // https://developers.notion.com/reference/retrieve-a-database
+    pub async fn find_db(&self, db_id: impl AsRef<str>) -> anyhow::Result<Database> {
+        self.send(
+            reqwest::Method::GET,
+            &format!("/databases/{}", db_id.as_ref()),
+            None,
+        )
+        .await
     }
 
     // https://developers.notion.com/reference/create-a-database
     pub async fn create_db(&self, page_id: impl Into<String>) -> anyhow::Result<Database> {
-        let request = CreateADatabaseRequestBuilder::default()
-            .title(vec![RichText::Text {
-                text: Text {
-                    content: "Hyprnote Meetings".to_string(),
-                    link: None,
-                },
-                plain_text: None,
-                annotations: None,
-                href: None,
-            }])
-            .parent(Parent::PageId {
-                page_id: page_id.into(),
-            })
-            .build()?;
-
-        let response = self.client.databases.create_a_database(request).await?;
-        Ok(response)
+        let properties = SessionDatabasePropertyKey::iter()
+            .map(|key| (key.to_string(), key.schema()))
+            .collect::<serde_json::Map<_, _>>();
+
+        self.send(
+            reqwest::Method::POST,
+            "/databases",
+            Some(json!({
+                "parent": { "type": "page_id", "page_id": page_id.into() },
+                "title": [RichText::text("Hyprnote Meetings")],
+                "properties": properties,
+            })),
+        )
+        .await
+    }
+
+    // Adds the properties that databases created by older versions are missing.
+    // https://developers.notion.com/reference/update-a-database
+    pub async fn ensure_db_schema(&self, db_id: impl AsRef<str>) -> anyhow::Result<Database> {
+        let db = self.find_db(db_id.as_ref()).await?;
+
+        let missing = SessionDatabasePropertyKey::iter()
+            .filter(|key| *key != SessionDatabasePropertyKey::Title)
+            .filter(|key| !db.properties.contains_key(&key.to_string()))
+            .map(|key| (key.to_string(), key.schema()))
+            .collect::<serde_json::Map<_, _>>();
+
+        if missing.is_empty() {
+            return Ok(db);
+        }
+
+        self.send(
+            reqwest::Method::PATCH,
+            &format!("/databases/{}", db.id),
+            Some(json!({ "properties": missing })),
+        )
+        .await
+    }
+
+    // Returns `None` if the page does not exist or was shared with the integration no longer.
+    // https://developers.notion.com/reference/retrieve-a-page
+    pub async fn find_page(&self, page_id: impl AsRef<str>) -> anyhow::Result<Option<Page>> {
+        self.try_send(
+            reqwest::Method::GET,
+            &format!("/pages/{}", page_id.as_ref()),
+            None,
+        )
+        .await
     }
 
     // https://developers.notion.com/reference/post-page
-    pub async fn append_to_db(
+    pub async fn create_page(
         &self,
         db_id: impl Into<String>,
-        session: hypr_db_user::Session,
+        properties: serde_json::Map<String, Value>,
+        mut children: Vec<Block>,
     ) -> anyhow::Result<Page> {
-        let properties: SessionDatabaseProperty = BTreeMap::from([
-            (
-                SessionDatabasePropertyKey::Id,
-                PageProperty::RichText {
-                    id: None,
-                    rich_text: vec![RichText::Text {
-                        text: Text {
-                            content: session.id.to_string(),
-                            link: None,
-                        },
-                        plain_text: None,
-                        annotations: None,
-                        href: None,
-                    }],
-                },
-            ),
-            (
-                SessionDatabasePropertyKey::Title,
-                PageProperty::Title {
-                    id: None,
-                    title: vec![RichText::Text {
-                        text: Text {
-                            content: session.title.to_string(),
-                            link: None,
-                        },
-                        plain_text: None,
-                        annotations: None,
-                        href: None,
-                    }],
-                },
-            ),
-        ]);
-
-        let children = vec![Block {
-            object: None,
-            id: None,
-            parent: None,
-            block_type: BlockType::Paragraph {
-                paragraph: ParagraphValue {
-                    rich_text: vec![RichText::Text {
-                        text: Text {
-                            content: session.title.to_string(),
-                            link: None,
-                        },
-                        plain_text: None,
-                        annotations: None,
-                        href: None,
-                    }],
-                    color: None,
-                    children: None,
-                },
-            },
-            created_time: None,
-            created_by: None,
-            last_edited_time: None,
-            last_edited_by: None,
-            archived: None,
-            has_children: None,
-        }];
-
-        let request = CreateAPageRequestBuilder::default()
-            .parent(Parent::DatabaseId {
-                database_id: db_id.into(),
-            })
-            .properties(
-                properties
-                    .into_iter()
-                    .map(|(k, v)| (k.to_string(), v))
-                    .collect(),
+        let rest = children.split_off(children.len().min(MAX_CHILDREN));
+
+        let page: Page = self
+            .send(
+                reqwest::Method::POST,
+                "/pages",
+                Some(json!({
+                    "parent": { "database_id": db_id.into() },
+                    "properties": properties,
+                    "children": children,
+                })),
             )
-            .children(children)
-            .build()?;
+            .await?;
+
+        self.append_children(&page.id, rest).await?;
+        Ok(page)
+    }
+
+    // https://developers.notion.com/reference/patch-page
+    pub async fn update_page(
+        &self,
+        page_id: impl AsRef<str>,
+        properties: serde_json::Map<String, Value>,
+    ) -> anyhow::Result<Page> {
+        self.send(
+            reqwest::Method::PATCH,
+            &format!("/pages/{}", page_id.as_ref()),
+            Some(json!({ "properties": properties })),
+        )
+        .await
+    }
+
+    // Notion has no way to replace the content of a page, so existing blocks are deleted one by one.
+    pub async fn replace_children(
+        &self,
+        block_id: impl AsRef<str>,
+        children: Vec<Block>,
+    ) -> anyhow::Result<()> {
+        let block_id = block_id.as_ref();
+
+        for child in self.list_children(block_id).await? {
+            let _: BlockId = self
+                .send(reqwest::Method::DELETE, &format!("/blocks/{}", child), None)
+                .await?;
+        }
+
+        self.append_children(block_id, children).await
+    }
+
+    // https://developers.notion.com/reference/patch-block-children
+    pub async fn append_children(
+        &self,
+        block_id: impl AsRef<str>,
+        children: Vec<Block>,
+    ) -> anyhow::Result<()> {
+        for chunk in children.chunks(MAX_CHILDREN) {
+            let _: Value = self
+                .send(
+                    reqwest::Method::PATCH,
+                    &format!("/blocks/{}/children", block_id.as_ref()),
+                    Some(json!({ "children": chunk })),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // https://developers.notion.com/reference/get-block-children
+    async fn list_children(&self, block_id: &str) -> anyhow::Result<Vec<String>> {
+        let mut ids = vec![];
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let path = match &cursor {
+                Some(cursor) => format!(
+                    "/blocks/{}/children?page_size=100&start_cursor={}",
+                    block_id, cursor
+                ),
+                None => format!("/blocks/{}/children?page_size=100", block_id),
+            };
+
+            let list: BlockList = self.send(reqwest::Method::GET, &path, None).await?;
+            ids.extend(list.results.into_iter().map(|block| block.id));
+
+            match list.next_cursor {
+                Some(next) if list.has_more => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(ids)
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> anyhow::Result<T> {
+        self.try_send(method, path, body)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("notion object not found: {}", path))
+    }
+
+    async fn try_send<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> anyhow::Result<Option<T>> {
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.token)
+            .header("Notion-Version", NOTION_VERSION);
+
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !status.is_success() {
+            let message = match response.json::<ApiError>().await {
+                Ok(error) => format!("{}: {}", error.code, error.message),
+                Err(_) => status.to_string(),
+            };
+            anyhow::bail!("notion request failed ({}): {}", status, message);
+        }
+
+        Ok(Some(response.json().await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_ensure_db_schema() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/databases/db-1"))
+            .and(header("Notion-Version", NOTION_VERSION))
+            .and(header("Authorization", "Bearer token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "db-1",
+                "properties": {
+                    "Name": { "type": "title" },
+                    "Id": { "type": "rich_text" }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/databases/db-1"))
+            .and(body_partial_json(json!({
+                "properties": {
+                    "Date": { "date": {} },
+                    "Participants": { "multi_select": {} },
+                    "Tags": { "multi_select": {} },
+                    "Event": { "rich_text": {} }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "db-1" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NotionClient::with_base_url("token", server.uri());
+        client.ensure_db_schema("db-1").await.unwrap();
+
+        let request = &server.received_requests().await.unwrap()[1];
+        let body: Value = serde_json::from_slice(&request.body).unwrap();
+        assert!(body["properties"].get("Title").is_none());
+        assert!(body["properties"].get("Id").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_replace_children() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/blocks/page-1/children"))
+            .and(query_param("start_cursor", "cursor-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{ "id": "b2" }],
+                "has_more": false,
+                "next_cursor": null
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/blocks/page-1/children"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{ "id": "b1" }],
+                "has_more": true,
+                "next_cursor": "cursor-1"
+            })))
+            .mount(&server)
+            .await;
+
+        for id in ["b1", "b2"] {
+            Mock::given(method("DELETE"))
+                .and(path(format!("/blocks/{}", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": id })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        Mock::given(method("PATCH"))
+            .and(path("/blocks/page-1/children"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "results": [] })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = NotionClient::with_base_url("token", server.uri());
+        let children = (0..MAX_CHILDREN + 1)
+            .map(|i| Block::paragraph(vec![RichText::text(i.to_string())]))
+            .collect();
+
+        client.replace_children("page-1", children).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/databases/db-1"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "object": "error",
+                "status": 401,
+                "code": "unauthorized",
+                "message": "API token is invalid."
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/pages/page-1"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "object": "error",
+                "status": 404,
+                "code": "object_not_found",
+                "message": "Could not find page."
+            })))
+            .mount(&server)
+            .await;
+
+        let client = NotionClient::with_base_url("token", server.uri());
+
+        let error = client.find_db("db-1").await.unwrap_err();
+        assert!(error.to_string().contains("API token is invalid."));
 
-        let response = self.client.pages.create_a_page(request).await?;
-        Ok(response)
+        assert!(client.find_page("page-1").await.unwrap().is_none());
     }
 }
@@ -0,0 +1,15 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    UrlError(#[from] url::ParseError),
+    #[error("invalid base url")]
+    InvalidBaseUrl,
+    #[error("invalid path: {0}")]
+    InvalidPath(String),
+    #[error("obsidian error ({status}): {message}")]
+    ApiError { status: u16, message: String },
+}
@@ -0,0 +1,387 @@
+mod error;
+mod markdown;
+mod note;
+
+pub use error::*;
+pub use markdown::*;
+pub use note::*;
+
+use std::path::{Component, Path, PathBuf};
+
+pub enum Vault {
+    // https://github.com/coddingtonbear/obsidian-local-rest-api
+    Rest {
+        client: reqwest::Client,
+        base_url: url::Url,
+        api_key: String,
+    },
+    // Writing to the vault folder directly works without Obsidian running.
+    Filesystem {
+        root: PathBuf,
+    },
+}
+
+impl Vault {
+    pub fn rest(base_url: impl AsRef<str>, api_key: impl Into<String>) -> Result<Self, Error> {
+        let base_url = url::Url::parse(base_url.as_ref())?;
+        if base_url.cannot_be_a_base() {
+            return Err(Error::InvalidBaseUrl);
+        }
+
+        Ok(Self::Rest {
+            client: reqwest::Client::new(),
+            base_url,
+            api_key: api_key.into(),
+        })
+    }
+
+    pub fn filesystem(root: impl Into<PathBuf>) -> Self {
+        Self::Filesystem { root: root.into() }
+    }
+
+    // `path` is relative to the vault root, with `/` as separator.
+    pub async fn read(&self, path: &str) -> Result<Option<String>, Error> {
+        match self {
+            Self::Rest {
+                client,
+                base_url,
+                api_key,
+            } => {
+                let res = client
+                    .get(vault_url(base_url, path)?)
+                    .bearer_auth(api_key)
+                    .header(reqwest::header::ACCEPT, "text/markdown")
+                    .send()
+                    .await?;
+
+                if res.status() == reqwest::StatusCode::NOT_FOUND {
+                    return Ok(None);
+                }
+
+                Ok(Some(check(res).await?.text().await?))
+            }
+            Self::Filesystem { root } => {
+                match tokio::fs::read_to_string(vault_path(root, path)?).await {
+                    Ok(content) => Ok(Some(content)),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                    Err(e) => Err(e.into()),
+                }
+            }
+        }
+    }
+
+    pub async fn write(&self, path: &str, content: &str) -> Result<(), Error> {
+        match self {
+            Self::Rest {
+                client,
+                base_url,
+                api_key,
+            } => {
+                let res = client
+                    .put(vault_url(base_url, path)?)
+                    .bearer_auth(api_key)
+                    .header(reqwest::header::CONTENT_TYPE, "text/markdown")
+                    .body(content.to_string())
+                    .send()
+                    .await?;
+
+                check(res).await?;
+                Ok(())
+            }
+            Self::Filesystem { root } => {
+                let path = vault_path(root, path)?;
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                tokio::fs::write(path, content).await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+fn vault_url(base_url: &url::Url, path: &str) -> Result<url::Url, Error> {
+    let mut url = base_url.clone();
+    url.path_segments_mut()
+        .map_err(|_| Error::InvalidBaseUrl)?
+        .pop_if_empty()
+        .push("vault")
+        .extend(path.split('/'));
+    Ok(url)
+}
+
+fn vault_path(root: &Path, path: &str) -> Result<PathBuf, Error> {
+    let relative = Path::new(path);
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(Error::InvalidPath(path.to_string()));
+    }
+
+    Ok(root.join(relative))
+}
+
+async fn check(res: reqwest::Response) -> Result<reqwest::Response, Error> {
+    let status = res.status();
+    if status.is_success() {
+        return Ok(res);
+    }
+
+    Err(Error::ApiError {
+        status: status.as_u16(),
+        message: res.text().await.unwrap_or_default(),
+    })
+}
+
+// What was last written for a session, kept by the caller between syncs.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SyncRecord {
+    pub path: String,
+    pub fingerprint: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncStatus {
+    Created,
+    Updated,
+    Unchanged,
+    // The note was edited in Obsidian since the last sync, and was left as-is.
+    Conflict,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncOutcome {
+    pub status: SyncStatus,
+    pub record: SyncRecord,
+}
+
+// A session keeps the path it was first written to, so syncing again updates the same note.
+// Unless `force` is set, a note that changed since the last sync is never overwritten.
+pub async fn sync_note(
+    vault: &Vault,
+    note: &Note,
+    folder: Option<&str>,
+    previous: Option<&SyncRecord>,
+    force: bool,
+) -> Result<SyncOutcome, Error> {
+    let content = note.render();
+    let hash = fingerprint(&content);
+
+    let Some(previous) = previous else {
+        let path = available_path(vault, folder, &note.filename()).await?;
+        vault.write(&path, &content).await?;
+
+        return Ok(SyncOutcome {
+            status: SyncStatus::Created,
+            record: SyncRecord {
+                path,
+                fingerprint: hash,
+            },
+        });
+    };
+
+    let status = match vault.read(&previous.path).await? {
+        // Deleted in Obsidian, or moved outside of it.
+        None => SyncStatus::Created,
+        Some(existing) if fingerprint(&existing) != previous.fingerprint && !force => {
+            return Ok(SyncOutcome {
+                status: SyncStatus::Conflict,
+                record: previous.clone(),
+            });
+        }
+        Some(existing) if fingerprint(&existing) == hash => SyncStatus::Unchanged,
+        Some(_) => SyncStatus::Updated,
+    };
+
+    if status != SyncStatus::Unchanged {
+        vault.write(&previous.path, &content).await?;
+    }
+
+    Ok(SyncOutcome {
+        status,
+        record: SyncRecord {
+            path: previous.path.clone(),
+            fingerprint: hash,
+        },
+    })
+}
+
+// Never takes over a note that was not written by us, e.g. another meeting with the same title.
+async fn available_path(
+    vault: &Vault,
+    folder: Option<&str>,
+    filename: &str,
+) -> Result<String, Error> {
+    let folder = folder
+        .map(|folder| folder.trim_matches('/'))
+        .filter(|folder| !folder.is_empty());
+
+    for i in 1.. {
+        let name = match i {
+            1 => format!("{}.md", filename),
+            i => format!("{} {}.md", filename, i),
+        };
+        let path = match folder {
+            Some(folder) => format!("{}/{}", folder, name),
+            None => name,
+        };
+
+        if vault.read(&path).await?.is_none() {
+            return Ok(path);
+        }
+    }
+
+    unreachable!()
+}
+
+// Stable across releases, unlike `DefaultHasher`, since it is persisted.
+// Line endings and trailing whitespace are ignored, as editors tend to normalize them.
+fn fingerprint(content: &str) -> String {
+    let normalized = content.replace("\r\n", "\n");
+
+    // https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+    let hash = normalized
+        .trim_end()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Utc};
+    use hypr_db_user::Session;
+
+    fn note(title: &str, enhanced_memo_html: &str) -> Note {
+        let date = DateTime::parse_from_rfc3339("2025-01-02T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        Note {
+            session: Session {
+                id: "session-1".to_string(),
+                created_at: date,
+                visited_at: date,
+                user_id: "user-1".to_string(),
+                calendar_event_id: None,
+                title: title.to_string(),
+                raw_memo_html: "".to_string(),
+                enhanced_memo_html: Some(enhanced_memo_html.to_string()),
+                conversations: vec![],
+                words: vec![],
+                record_start: None,
+                record_end: None,
+                pre_meeting_memo_html: None,
+            },
+            participants: vec![],
+            tags: vec![],
+            event: None,
+            bookmarks: vec![],
+            include_transcript: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_note() {
+        let dir = tempfile::tempdir().unwrap();
+        let vault = Vault::filesystem(dir.path());
+
+        // Someone else's note with the same name is left alone.
+        vault
+            .write("Meetings/Weekly sync.md", "my own note")
+            .await
+            .unwrap();
+
+        let first = note("Weekly sync", "<p>Draft</p>");
+        let created = sync_note(&vault, &first, Some("/Meetings/"), None, false)
+            .await
+            .unwrap();
+        assert_eq!(created.status, SyncStatus::Created);
+        assert_eq!(created.record.path, "Meetings/Weekly sync 2.md");
+        assert_eq!(
+            vault.read("Meetings/Weekly sync.md").await.unwrap(),
+            Some("my own note".to_string())
+        );
+
+        let unchanged = sync_note(&vault, &first, None, Some(&created.record), false)
+            .await
+            .unwrap();
+        assert_eq!(unchanged.status, SyncStatus::Unchanged);
+        assert_eq!(unchanged.record, created.record);
+
+        // Renaming the session keeps writing to the same note.
+        let second = note("Weekly sync (renamed)", "<p>Final</p>");
+        let updated = sync_note(&vault, &second, None, Some(&created.record), false)
+            .await
+            .unwrap();
+        assert_eq!(updated.status, SyncStatus::Updated);
+        assert_eq!(updated.record.path, created.record.path);
+        assert_eq!(
+            vault.read(&updated.record.path).await.unwrap(),
+            Some(second.render())
+        );
+
+        let edited = format!("{}\nAdded in Obsidian\n", second.render());
+        vault.write(&updated.record.path, &edited).await.unwrap();
+
+        let third = note("Weekly sync (renamed)", "<p>Final, again</p>");
+        let conflict = sync_note(&vault, &third, None, Some(&updated.record), false)
+            .await
+            .unwrap();
+        assert_eq!(conflict.status, SyncStatus::Conflict);
+        assert_eq!(conflict.record, updated.record);
+        assert_eq!(
+            vault.read(&updated.record.path).await.unwrap(),
+            Some(edited)
+        );
+
+        let forced = sync_note(&vault, &third, None, Some(&updated.record), true)
+            .await
+            .unwrap();
+        assert_eq!(forced.status, SyncStatus::Updated);
+        assert_eq!(
+            vault.read(&updated.record.path).await.unwrap(),
+            Some(third.render())
+        );
+
+        tokio::fs::remove_file(dir.path().join(&forced.record.path))
+            .await
+            .unwrap();
+        let recreated = sync_note(&vault, &third, None, Some(&forced.record), false)
+            .await
+            .unwrap();
+        assert_eq!(recreated.status, SyncStatus::Created);
+        assert_eq!(recreated.record, forced.record);
+    }
+
+    #[tokio::test]
+    async fn test_vault_path() {
+        let vault = Vault::filesystem("/tmp/vault");
+        assert!(vault.read("../secrets.md").await.is_err());
+        assert!(vault.read("/etc/hosts").await.is_err());
+    }
+
+    #[test]
+    fn test_vault_url() {
+        let base_url = url::Url::parse("http://127.0.0.1:27123").unwrap();
+        assert_eq!(
+            vault_url(&base_url, "Meetings/Weekly sync?.md")
+                .unwrap()
+                .as_str(),
+            "http://127.0.0.1:27123/vault/Meetings/Weekly%20sync%3F.md"
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(fingerprint("a\r\nb\n"), fingerprint("a\nb"));
+        assert_ne!(fingerprint("a\nb"), fingerprint("a\nc"));
+    }
+}
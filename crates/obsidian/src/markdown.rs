@@ -0,0 +1,432 @@
+// https://help.obsidian.md/Editing+and+formatting/Basic+formatting+syntax
+
+// Converts the editor's HTML into Obsidian-flavored markdown, one paragraph per block-level node.
+// Mentions become wikilinks, so people and notes show up in Obsidian's graph and backlinks.
+pub fn html_to_markdown(html: &str) -> String {
+    let Ok(dom) = tl::parse(html, tl::ParserOptions::default()) else {
+        return String::new();
+    };
+
+    Converter {
+        parser: dom.parser(),
+    }
+    .blocks(dom.children())
+    .join("\n\n")
+}
+
+struct Converter<'p, 'a> {
+    parser: &'p tl::Parser<'a>,
+}
+
+impl Converter<'_, '_> {
+    fn blocks(&self, handles: &[tl::NodeHandle]) -> Vec<String> {
+        let mut blocks = vec![];
+        // Inline content outside of any block element, e.g. `<strong>` directly in the root.
+        let mut pending = String::new();
+
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tl::Node::Tag(tag) = node else {
+                pending.push_str(&self.inline(&[*handle]));
+                continue;
+            };
+
+            let children = tag.children();
+            let children = children.top();
+
+            let name = tag.name().as_utf8_str().to_ascii_lowercase();
+            let block = match name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    let level = name[1..].parse::<usize>().unwrap_or(1);
+                    let text = single_line(&self.inline(children));
+                    (!text.is_empty()).then(|| format!("{} {}", "#".repeat(level), text))
+                }
+                "p" => Some(self.inline(children).trim().to_string()),
+                "ul" | "ol" => {
+                    let mut lines = vec![];
+                    self.list(tag, 0, &mut lines);
+                    Some(lines.join("\n"))
+                }
+                "blockquote" => Some(
+                    self.blocks(children)
+                        .join("\n\n")
+                        .lines()
+                        .map(|line| format!("> {}", line).trim_end().to_string())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+                "pre" => {
+                    let code = decode_entities(&tag.inner_text(self.parser));
+                    let fence = match code.contains("```") {
+                        true => "~~~",
+                        false => "```",
+                    };
+                    Some(format!(
+                        "{}{}\n{}\n{}",
+                        fence,
+                        self.code_language(tag).unwrap_or_default(),
+                        code.trim_end_matches('\n'),
+                        fence
+                    ))
+                }
+                "hr" => Some("---".to_string()),
+                "div" | "section" | "article" | "main" | "body" | "html" => {
+                    flush(&mut pending, &mut blocks);
+                    blocks.extend(self.blocks(children));
+                    continue;
+                }
+                "head" | "script" | "style" => continue,
+                _ => {
+                    pending.push_str(&self.inline(&[*handle]));
+                    continue;
+                }
+            };
+
+            flush(&mut pending, &mut blocks);
+            blocks.extend(block.filter(|block| !block.is_empty()));
+        }
+
+        flush(&mut pending, &mut blocks);
+        blocks
+    }
+
+    fn list(&self, list: &tl::HTMLTag, depth: usize, lines: &mut Vec<String>) {
+        let ordered = list.name().as_utf8_str().eq_ignore_ascii_case("ol");
+        let task_list = attribute(list, "data-type").as_deref() == Some("taskList");
+
+        let children = list.children();
+        let items = children
+            .top()
+            .iter()
+            .filter_map(|handle| match handle.get(self.parser) {
+                Some(tl::Node::Tag(tag)) if tag.name().as_utf8_str().eq_ignore_ascii_case("li") => {
+                    Some(tag)
+                }
+                _ => None,
+            });
+
+        for (i, item) in items.enumerate() {
+            let mut text = String::new();
+            let mut nested = vec![];
+            self.list_item(item.children().top(), depth, &mut text, &mut nested);
+
+            let marker = match attribute(item, "data-checked") {
+                Some(checked) if checked == "true" => "- [x]".to_string(),
+                Some(_) => "- [ ]".to_string(),
+                None if task_list => "- [ ]".to_string(),
+                None if ordered => format!("{}.", i + 1),
+                None => "-".to_string(),
+            };
+
+            lines.push(format!(
+                "{}{} {}",
+                "    ".repeat(depth),
+                marker,
+                single_line(&text)
+            ));
+            lines.extend(nested);
+        }
+    }
+
+    // Task items wrap their content in `<label><input/></label><div><p>..</p></div>`.
+    fn list_item(
+        &self,
+        handles: &[tl::NodeHandle],
+        depth: usize,
+        text: &mut String,
+        nested: &mut Vec<String>,
+    ) {
+        for handle in handles {
+            match handle.get(self.parser) {
+                Some(tl::Node::Tag(tag)) => {
+                    match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                        "ul" | "ol" => self.list(tag, depth + 1, nested),
+                        "div" | "label" => {
+                            self.list_item(tag.children().top(), depth, text, nested)
+                        }
+                        "input" => {}
+                        "p" => {
+                            if !text.trim().is_empty() {
+                                text.push(' ');
+                            }
+                            text.push_str(&self.inline(tag.children().top()));
+                        }
+                        _ => text.push_str(&self.inline(&[*handle])),
+                    }
+                }
+                Some(_) => text.push_str(&self.inline(&[*handle])),
+                None => {}
+            }
+        }
+    }
+
+    fn inline(&self, handles: &[tl::NodeHandle]) -> String {
+        let mut text = String::new();
+
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tag = match node {
+                tl::Node::Tag(tag) => tag,
+                tl::Node::Raw(raw) => {
+                    text.push_str(&escape(&collapse_whitespace(&decode_entities(
+                        &raw.as_utf8_str(),
+                    ))));
+                    continue;
+                }
+                tl::Node::Comment(_) => continue,
+            };
+
+            let children = tag.children();
+            let children = children.top();
+
+            match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                "br" => text.push_str("  \n"),
+                "img" | "input" | "script" | "style" => {}
+                "strong" | "b" => text.push_str(&wrap("**", &self.inline(children))),
+                "em" | "i" => text.push_str(&wrap("*", &self.inline(children))),
+                "s" | "del" | "strike" => text.push_str(&wrap("~~", &self.inline(children))),
+                "mark" => text.push_str(&wrap("==", &self.inline(children))),
+                "code" => {
+                    let code = decode_entities(&tag.inner_text(self.parser));
+                    let tick = match code.contains('`') {
+                        true => "``",
+                        false => "`",
+                    };
+                    text.push_str(&format!("{}{}{}", tick, code, tick));
+                }
+                "a" if attribute(tag, "data-mention").as_deref() == Some("true") => {
+                    let label = attribute(tag, "data-label")
+                        .unwrap_or_else(|| decode_entities(&tag.inner_text(self.parser)));
+                    text.push_str(&wikilink(label.trim_start_matches('@')));
+                }
+                "a" => {
+                    let label = self.inline(children);
+                    match attribute(tag, "href").filter(|href| is_supported_url(href)) {
+                        Some(href) if !label.trim().is_empty() => text.push_str(&format!(
+                            "[{}]({})",
+                            label.trim(),
+                            href.replace(' ', "%20").replace(')', "%29")
+                        )),
+                        Some(href) => text.push_str(&format!("<{}>", href)),
+                        None => text.push_str(&label),
+                    }
+                }
+                _ => text.push_str(&self.inline(children)),
+            }
+        }
+
+        text
+    }
+
+    // Tiptap puts the language on the inner `<code class="language-rust">`.
+    fn code_language(&self, pre: &tl::HTMLTag) -> Option<String> {
+        pre.children().top().iter().find_map(|handle| {
+            let Some(tl::Node::Tag(tag)) = handle.get(self.parser) else {
+                return None;
+            };
+
+            attribute(tag, "class")?
+                .split_whitespace()
+                .find_map(|class| class.strip_prefix("language-"))
+                .map(|language| language.to_string())
+        })
+    }
+}
+
+// Characters that Obsidian does not allow in a link target are dropped, the rest is kept as-is.
+pub fn wikilink(name: &str) -> String {
+    let target = name
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | '|' | '#' | '^'))
+        .collect::<String>();
+
+    format!("[[{}]]", target.trim())
+}
+
+fn flush(pending: &mut String, blocks: &mut Vec<String>) {
+    let text = std::mem::take(pending);
+    let text = text.trim();
+    if !text.is_empty() {
+        blocks.push(text.to_string());
+    }
+}
+
+fn attribute(tag: &tl::HTMLTag, key: &str) -> Option<String> {
+    tag.attributes()
+        .get(key)
+        .flatten()
+        .map(|value| decode_entities(&value.as_utf8_str()))
+}
+
+fn is_supported_url(url: &str) -> bool {
+    ["http://", "https://", "mailto:", "obsidian://"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+// Formatting markers only apply when they hug the text, e.g. `**bold**` but not `** bold **`.
+fn wrap(marker: &str, text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+
+    format!("{}{}{}{}{}", leading, marker, trimmed, marker, trailing)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '=' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn single_line(text: &str) -> String {
+    text.replace("  \n", " ")
+        .replace('\n', " ")
+        .trim()
+        .to_string()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_space = false;
+
+    for c in text.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !last_was_space {
+                collapsed.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            collapsed.push(c);
+            last_was_space = false;
+        }
+    }
+
+    collapsed
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => entity.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_markdown() {
+        let html = indoc::indoc! {r#"
+            <h1>Summary &amp; next steps</h1>
+            <p>We <strong>agreed </strong>on <em>scope</em> for v2_final, see <a href="https://example.com">the doc</a> and <a class="mention" data-mention="true" data-id="1" data-type="human" data-label="John" href="javascript:void(0)">@John</a>.</p>
+            <ul><li><p>One</p><ul><li><p>Nested</p></li></ul></li><li><p>Two &lt;3</p></li></ul>
+            <ol><li>First</li><li>Second</li></ol>
+            <ul data-type="taskList"><li data-checked="true" data-type="taskItem"><label><input type="checkbox" checked="checked"><span></span></label><div><p>Send deck</p></div></li><li data-checked="false" data-type="taskItem"><label><input type="checkbox"><span></span></label><div><p>Book room</p></div></li></ul>
+            <hr>
+            <blockquote><p>Ship it</p><p>Today</p></blockquote>
+            <pre><code class="language-rust">let a = 1 &lt; 2;
+            </code></pre>
+        "#};
+
+        assert_eq!(
+            html_to_markdown(html),
+            indoc::indoc! {r#"
+                # Summary & next steps
+
+                We **agreed** on *scope* for v2\_final, see [the doc](https://example.com) and [[John]].
+
+                - One
+                    - Nested
+                - Two \<3
+
+                1. First
+                2. Second
+
+                - [x] Send deck
+                - [ ] Book room
+
+                ---
+
+                > Ship it
+                >
+                > Today
+
+                ```rust
+                let a = 1 < 2;
+                ```"#}
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(html_to_markdown(""), "");
+        assert_eq!(html_to_markdown("<p></p>\n<p>  </p>"), "");
+    }
+
+    #[test]
+    fn test_wikilink() {
+        assert_eq!(wikilink("John Doe"), "[[John Doe]]");
+        assert_eq!(wikilink("Q3 [draft] #1"), "[[Q3 draft 1]]");
+    }
+}
@@ -0,0 +1,312 @@
+use hypr_db_user::{Bookmark, Event, Human, Session, Tag};
+use owhisper_interface::{SpeakerIdentity, Word2};
+
+use crate::markdown::{html_to_markdown, wikilink};
+
+pub struct Note {
+    pub session: Session,
+    pub participants: Vec<Human>,
+    pub tags: Vec<Tag>,
+    pub event: Option<Event>,
+    pub bookmarks: Vec<Bookmark>,
+    pub include_transcript: bool,
+}
+
+impl Note {
+    pub fn title(&self) -> String {
+        match self.session.title.trim() {
+            "" => "Untitled".to_string(),
+            title => title.to_string(),
+        }
+    }
+
+    // Used for new notes only, so renaming a session never leaves a stale copy behind.
+    pub fn filename(&self) -> String {
+        let name = self
+            .title()
+            .chars()
+            .filter(|c| !matches!(c, '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+            .filter(|c| !matches!(c, '#' | '^' | '[' | ']'))
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        match name.trim_start_matches('.') {
+            "" => "Untitled".to_string(),
+            name => name.to_string(),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut content = self.frontmatter();
+
+        let html = match &self.session.enhanced_memo_html {
+            Some(html) if !html.trim().is_empty() => html,
+            _ => &self.session.raw_memo_html,
+        };
+
+        let body = html_to_markdown(html);
+        if !body.is_empty() {
+            content.push('\n');
+            content.push_str(&body);
+            content.push('\n');
+        }
+
+        if !self.bookmarks.is_empty() {
+            content.push_str("\n## Chapters\n\n");
+            for bookmark in &self.bookmarks {
+                content.push_str(&format!(
+                    "- [{}] {}\n",
+                    bookmark.timestamp(),
+                    bookmark.title()
+                ));
+            }
+        }
+
+        if self.include_transcript {
+            let transcript = transcript(&self.session.words);
+            if !transcript.is_empty() {
+                content.push_str("\n## Transcript\n\n");
+                content.push_str(&transcript);
+                content.push('\n');
+            }
+        }
+
+        content
+    }
+
+    // https://help.obsidian.md/Editing+and+formatting/Properties
+    fn frontmatter(&self) -> String {
+        let date = self.session.record_start.unwrap_or(self.session.created_at);
+
+        let mut lines = vec![
+            "---".to_string(),
+            format!("hyprnote_id: {}", yaml_string(&self.session.id)),
+            format!("title: {}", yaml_string(&self.title())),
+            format!("date: {}", date.format("%Y-%m-%dT%H:%M:%SZ")),
+        ];
+
+        let attendees = self
+            .participants
+            .iter()
+            .filter_map(|human| human.full_name.as_ref().or(human.email.as_ref()))
+            .filter(|name| !name.trim().is_empty())
+            .map(|name| wikilink(name))
+            .collect::<Vec<_>>();
+        lines.extend(yaml_list("attendees", &attendees));
+
+        // Obsidian tags can not contain spaces.
+        let tags = self
+            .tags
+            .iter()
+            .map(|tag| tag.name.split_whitespace().collect::<Vec<_>>().join("-"))
+            .filter(|tag| !tag.is_empty())
+            .collect::<Vec<_>>();
+        lines.extend(yaml_list("tags", &tags));
+
+        if let Some(event) = &self.event {
+            lines.push(format!("event: {}", yaml_string(&event.name)));
+        }
+
+        lines.push("---".to_string());
+        lines.join("\n") + "\n"
+    }
+}
+
+fn yaml_list(key: &str, values: &[String]) -> Vec<String> {
+    if values.is_empty() {
+        return vec![];
+    }
+
+    std::iter::once(format!("{}:", key))
+        .chain(
+            values
+                .iter()
+                .map(|value| format!("  - {}", yaml_string(value))),
+        )
+        .collect()
+}
+
+// Always double-quoted, so values like `[[John]]`, `yes` or `12:30` keep their meaning.
+fn yaml_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn transcript(words: &[Word2]) -> String {
+    let mut paragraphs: Vec<(Option<&SpeakerIdentity>, Vec<&str>)> = vec![];
+
+    for word in words {
+        match paragraphs.last_mut() {
+            Some((speaker, texts)) if *speaker == word.speaker.as_ref() => {
+                texts.push(word.text.trim())
+            }
+            _ => paragraphs.push((word.speaker.as_ref(), vec![word.text.trim()])),
+        }
+    }
+
+    paragraphs
+        .into_iter()
+        .map(|(speaker, texts)| (speaker_label(speaker), texts.join(" ")))
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(speaker, text)| format!("**{}**: {}", speaker, text.trim()))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn speaker_label(speaker: Option<&SpeakerIdentity>) -> String {
+    match speaker {
+        Some(SpeakerIdentity::Assigned { label, .. }) => label.clone(),
+        Some(SpeakerIdentity::Unassigned { index: 0 }) => "You".to_string(),
+        Some(SpeakerIdentity::Unassigned { index }) => format!("Speaker {}", index),
+        None => "Speaker".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Utc};
+
+    fn date() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-02T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn note(title: &str, enhanced_memo_html: &str) -> Note {
+        Note {
+            session: Session {
+                id: "session-1".to_string(),
+                created_at: date(),
+                visited_at: date(),
+                user_id: "user-1".to_string(),
+                calendar_event_id: None,
+                title: title.to_string(),
+                raw_memo_html: "".to_string(),
+                enhanced_memo_html: Some(enhanced_memo_html.to_string()),
+                conversations: vec![],
+                words: vec![],
+                record_start: None,
+                record_end: None,
+                pre_meeting_memo_html: None,
+            },
+            participants: vec![],
+            tags: vec![],
+            event: None,
+            bookmarks: vec![],
+            include_transcript: false,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let mut note = note("Weekly \"sync\"", "<p>Ship on <strong>Friday</strong></p>");
+        note.participants = vec![
+            Human {
+                full_name: Some("John Doe".to_string()),
+                ..Human::default()
+            },
+            Human {
+                email: Some("jane@example.com".to_string()),
+                ..Human::default()
+            },
+        ];
+        note.tags = vec![Tag {
+            id: "t1".to_string(),
+            name: "product review".to_string(),
+        }];
+        note.bookmarks = vec![
+            Bookmark {
+                id: "b1".to_string(),
+                session_id: "session-1".to_string(),
+                label: Some("Pricing".to_string()),
+                offset_ms: 83_500,
+                created_at: date(),
+            },
+            Bookmark {
+                id: "b2".to_string(),
+                session_id: "session-1".to_string(),
+                label: None,
+                offset_ms: 3_723_000,
+                created_at: date(),
+            },
+        ];
+        note.include_transcript = true;
+        note.session.words = vec![
+            Word2 {
+                text: "Hello".to_string(),
+                speaker: Some(SpeakerIdentity::Unassigned { index: 0 }),
+                confidence: None,
+                start_ms: None,
+                end_ms: None,
+            },
+            Word2 {
+                text: "there".to_string(),
+                speaker: Some(SpeakerIdentity::Unassigned { index: 0 }),
+                confidence: None,
+                start_ms: None,
+                end_ms: None,
+            },
+            Word2 {
+                text: "Hi".to_string(),
+                speaker: Some(SpeakerIdentity::Assigned {
+                    id: "h1".to_string(),
+                    label: "John Doe".to_string(),
+                }),
+                confidence: None,
+                start_ms: None,
+                end_ms: None,
+            },
+        ];
+
+        assert_eq!(
+            note.render(),
+            indoc::indoc! {r#"
+                ---
+                hyprnote_id: "session-1"
+                title: "Weekly \"sync\""
+                date: 2025-01-02T09:00:00Z
+                attendees:
+                  - "[[John Doe]]"
+                  - "[[jane@example.com]]"
+                tags:
+                  - "product-review"
+                ---
+
+                Ship on **Friday**
+
+                ## Chapters
+
+                - [01:23] Pricing
+                - [1:02:03] Bookmark
+
+                ## Transcript
+
+                **You**: Hello there
+
+                **John Doe**: Hi
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_filename() {
+        assert_eq!(note("Q3: plan / review?", "").filename(), "Q3 plan review");
+        assert_eq!(note("  ", "").filename(), "Untitled");
+        assert_eq!(note("...", "").filename(), "Untitled");
+    }
+}
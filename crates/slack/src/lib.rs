@@ -1,40 +1,27 @@
 pub use slack_morphism;
 use slack_morphism::prelude::*;
 
+mod mrkdwn;
+mod render;
+
+pub use render::*;
+
 pub struct Client {
     client: SlackClient<SlackClientHyperHttpsConnector>,
     api_token: SlackApiToken,
 }
 
-pub struct SessionMessage {
-    session: hypr_db_user::Session,
+#[derive(Debug, Clone, PartialEq)]
+pub struct Channel {
+    pub id: String,
+    pub name: String,
 }
 
-impl SlackMessageTemplate for SessionMessage {
-    fn render_template(&self) -> SlackMessageContent {
-        // https://slack-rust.abdolence.dev/block-kit-support.html
-        SlackMessageContent::new()
-            .with_text(format!("Session ID: {}", self.session.id))
-            .with_blocks(slack_blocks![
-                some_into(SlackSectionBlock::new().with_text(md!("Hey {}", "123"))),
-                some_into(SlackDividerBlock::new()),
-                some_into(SlackImageBlock::new(
-                    SlackImageUrlOrFile::ImageUrl {
-                        image_url: "https://www.gstatic.com/webp/gallery3/2_webp_ll.png"
-                            .parse::<url::Url>()
-                            .unwrap(),
-                    },
-                    "Test Image".into()
-                )),
-                some_into(SlackHeaderBlock::new(pt!("Simple header"))),
-                some_into(SlackActionsBlock::new(slack_blocks![some_into(
-                    SlackBlockButtonElement::new(
-                        "simple-message-button".into(),
-                        pt!("Simple button text")
-                    )
-                )]))
-            ])
-    }
+// Where an earlier `send_session` stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resume {
+    pub thread_ts: SlackTs,
+    pub pages_posted: usize,
 }
 
 impl Client {
@@ -49,21 +36,103 @@ impl Client {
         }
     }
 
+    // https://api.slack.com/methods/conversations.list
+    pub async fn list_channels(&self) -> anyhow::Result<Vec<Channel>> {
+        let slack_session = self.client.open_session(&self.api_token);
+
+        let mut channels = vec![];
+        let mut cursor: Option<SlackCursorId> = None;
+
+        loop {
+            let mut req = SlackApiConversationsListRequest::new()
+                .with_exclude_archived(true)
+                .with_limit(200)
+                .with_types(vec![
+                    SlackConversationType::Public,
+                    SlackConversationType::Private,
+                ]);
+            if let Some(cursor) = cursor.take() {
+                req = req.with_cursor(cursor);
+            }
+
+            let res = slack_session.conversations_list(&req).await?;
+
+            channels.extend(res.channels.into_iter().map(|channel| Channel {
+                name: channel.name.unwrap_or_else(|| channel.id.to_string()),
+                id: channel.id.to_string(),
+            }));
+
+            cursor = res
+                .response_metadata
+                .and_then(|metadata| metadata.next_cursor)
+                .filter(|next| !next.to_string().is_empty());
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        channels.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(channels)
+    }
+
+    // Posts the first page to the channel, and the rest as replies in its thread.
+    // `on_posted` is called with the thread, the number of pages posted so far and the total
+    // after every page, so that a post that fails half-way can be resumed with `resume`.
     // https://github.com/abdolence/slack-morphism-rust/blob/master/examples/client.rs
     pub async fn send_session(
         &self,
         channel: impl Into<SlackChannelId>,
-        hypr_session: hypr_db_user::Session,
-    ) -> anyhow::Result<SlackApiChatPostMessageResponse> {
+        session_message: SessionMessage,
+        resume: Option<Resume>,
+        mut on_posted: impl FnMut(&SlackTs, usize, usize) -> anyhow::Result<()>,
+    ) -> anyhow::Result<SlackTs> {
         let slack_session = self.client.open_session(&self.api_token);
-        let session_message = SessionMessage {
-            session: hypr_session,
+        let channel = channel.into();
+        let title = session_message.title();
+
+        let pages = session_message.pages();
+        let total = pages.len();
+
+        let (mut thread_ts, skip) = match resume {
+            Some(resume) => (Some(resume.thread_ts), resume.pages_posted),
+            None => (None, 0),
         };
 
-        let req =
-            SlackApiChatPostMessageRequest::new(channel.into(), session_message.render_template());
+        for (i, page) in pages.into_iter().enumerate().skip(skip) {
+            // Shown in notifications, and by clients that can not render blocks.
+            let text = match i {
+                0 => title.clone(),
+                _ => format!("{} (continued)", title),
+            };
+
+            let content = SlackMessageContent::new()
+                .with_text(text)
+                .with_blocks(page.iter().map(to_slack_block).collect());
+
+            let mut req = SlackApiChatPostMessageRequest::new(channel.clone(), content);
+            if let Some(ts) = &thread_ts {
+                req = req.with_thread_ts(ts.clone());
+            }
+
+            let res = slack_session.chat_post_message(&req).await?;
+            let ts = thread_ts.get_or_insert(res.ts);
+            on_posted(ts, i + 1, total)?;
+        }
+
+        thread_ts.ok_or_else(|| anyhow::anyhow!("nothing to post"))
+    }
+}
 
-        let res = slack_session.chat_post_message(&req).await?;
-        Ok(res)
+// https://slack-rust.abdolence.dev/block-kit-support.html
+fn to_slack_block(block: &Block) -> SlackBlock {
+    match block {
+        Block::Header(text) => SlackHeaderBlock::new(pt!("{}", text)).into(),
+        Block::Context(text) => SlackContextBlock::new(vec![SlackContextBlockElement::MarkDown(
+            SlackBlockMarkDownText::new(text.clone()),
+        )])
+        .into(),
+        Block::Section(text) => SlackSectionBlock::new().with_text(md!("{}", text)).into(),
+        Block::Divider => SlackDividerBlock::new().into(),
     }
 }
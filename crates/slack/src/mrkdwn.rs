@@ -0,0 +1,385 @@
+// https://api.slack.com/reference/surfaces/formatting#basic-formatting
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element {
+    Heading(String),
+    Text(String),
+    Divider,
+}
+
+// Converts the editor's HTML into Slack `mrkdwn`, one element per block-level node.
+pub fn html_to_elements(html: &str) -> Vec<Element> {
+    let Ok(dom) = tl::parse(html, tl::ParserOptions::default()) else {
+        return vec![];
+    };
+
+    Converter {
+        parser: dom.parser(),
+    }
+    .elements(dom.children())
+}
+
+struct Converter<'p, 'a> {
+    parser: &'p tl::Parser<'a>,
+}
+
+impl Converter<'_, '_> {
+    fn elements(&self, handles: &[tl::NodeHandle]) -> Vec<Element> {
+        let mut elements = vec![];
+        // Inline content outside of any block element, e.g. `<strong>` directly in the root.
+        let mut pending = String::new();
+
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tl::Node::Tag(tag) = node else {
+                pending.push_str(&self.inline(&[*handle]));
+                continue;
+            };
+
+            let children = tag.children();
+            let children = children.top();
+
+            let element = match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    let text = plain(&decode_entities(&tag.inner_text(self.parser)));
+                    (!text.is_empty()).then(|| Element::Heading(escape(&text)))
+                }
+                "p" => Some(Element::Text(self.inline(children).trim().to_string())),
+                "ul" | "ol" => {
+                    let mut lines = vec![];
+                    self.list(tag, 0, &mut lines);
+                    Some(Element::Text(lines.join("\n")))
+                }
+                "blockquote" => {
+                    let quote = self
+                        .elements(children)
+                        .into_iter()
+                        .filter_map(|element| match element {
+                            Element::Heading(text) | Element::Text(text) => Some(text),
+                            Element::Divider => None,
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    Some(Element::Text(
+                        quote
+                            .lines()
+                            .map(|line| format!("> {}", line))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    ))
+                }
+                "pre" => {
+                    let code = decode_entities(&tag.inner_text(self.parser));
+                    let code = code.trim_end_matches('\n');
+                    Some(Element::Text(format!("```\n{}\n```", escape(code))))
+                }
+                "hr" => Some(Element::Divider),
+                "div" | "section" | "article" | "main" | "body" | "html" => {
+                    flush(&mut pending, &mut elements);
+                    elements.extend(self.elements(children));
+                    continue;
+                }
+                "head" | "script" | "style" => continue,
+                _ => {
+                    pending.push_str(&self.inline(&[*handle]));
+                    continue;
+                }
+            };
+
+            flush(&mut pending, &mut elements);
+            elements.extend(element.filter(|element| match element {
+                Element::Text(text) => !text.is_empty(),
+                _ => true,
+            }));
+        }
+
+        flush(&mut pending, &mut elements);
+        elements
+    }
+
+    // Slack has no nested lists, so nesting is rendered with indentation.
+    fn list(&self, list: &tl::HTMLTag, depth: usize, lines: &mut Vec<String>) {
+        let ordered = list.name().as_utf8_str().eq_ignore_ascii_case("ol");
+        let task_list = attribute(list, "data-type").as_deref() == Some("taskList");
+
+        let children = list.children();
+        let items = children
+            .top()
+            .iter()
+            .filter_map(|handle| match handle.get(self.parser) {
+                Some(tl::Node::Tag(tag)) if tag.name().as_utf8_str().eq_ignore_ascii_case("li") => {
+                    Some(tag)
+                }
+                _ => None,
+            });
+
+        for (i, item) in items.enumerate() {
+            let mut text = String::new();
+            let mut nested = vec![];
+            self.list_item(item.children().top(), depth, &mut text, &mut nested);
+
+            let marker = match attribute(item, "data-checked") {
+                Some(checked) if checked == "true" => "☑".to_string(),
+                Some(_) => "☐".to_string(),
+                None if task_list => "☐".to_string(),
+                None if ordered => format!("{}.", i + 1),
+                None => "•".to_string(),
+            };
+
+            lines.push(format!(
+                "{}{} {}",
+                "    ".repeat(depth),
+                marker,
+                text.trim()
+            ));
+            lines.extend(nested);
+        }
+    }
+
+    // Task items wrap their content in `<label><input/></label><div><p>..</p></div>`.
+    fn list_item(
+        &self,
+        handles: &[tl::NodeHandle],
+        depth: usize,
+        text: &mut String,
+        nested: &mut Vec<String>,
+    ) {
+        for handle in handles {
+            match handle.get(self.parser) {
+                Some(tl::Node::Tag(tag)) => {
+                    match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                        "ul" | "ol" => self.list(tag, depth + 1, nested),
+                        "div" | "label" => {
+                            self.list_item(tag.children().top(), depth, text, nested)
+                        }
+                        "input" => {}
+                        "p" => {
+                            if !text.trim().is_empty() {
+                                text.push(' ');
+                            }
+                            text.push_str(&self.inline(tag.children().top()));
+                        }
+                        _ => text.push_str(&self.inline(&[*handle])),
+                    }
+                }
+                Some(_) => text.push_str(&self.inline(&[*handle])),
+                None => {}
+            }
+        }
+    }
+
+    fn inline(&self, handles: &[tl::NodeHandle]) -> String {
+        let mut text = String::new();
+
+        for handle in handles {
+            let Some(node) = handle.get(self.parser) else {
+                continue;
+            };
+
+            let tag = match node {
+                tl::Node::Tag(tag) => tag,
+                tl::Node::Raw(raw) => {
+                    text.push_str(&escape(&collapse_whitespace(&decode_entities(
+                        &raw.as_utf8_str(),
+                    ))));
+                    continue;
+                }
+                tl::Node::Comment(_) => continue,
+            };
+
+            let children = tag.children();
+            let children = children.top();
+
+            match tag.name().as_utf8_str().to_ascii_lowercase().as_str() {
+                "br" => text.push('\n'),
+                "img" | "input" | "script" | "style" => {}
+                "strong" | "b" => text.push_str(&wrap("*", &self.inline(children))),
+                "em" | "i" => text.push_str(&wrap("_", &self.inline(children))),
+                "s" | "del" | "strike" => text.push_str(&wrap("~", &self.inline(children))),
+                "code" => text.push_str(&wrap("`", &self.inline(children))),
+                "a" if attribute(tag, "data-mention").as_deref() == Some("true") => {
+                    let label = attribute(tag, "data-label")
+                        .map(|label| format!("@{}", label))
+                        .unwrap_or_else(|| decode_entities(&tag.inner_text(self.parser)));
+                    text.push_str(&escape(&label));
+                }
+                "a" => {
+                    let label = self.inline(children);
+                    match attribute(tag, "href").filter(|href| is_supported_url(href)) {
+                        Some(href) if !label.trim().is_empty() => text.push_str(&format!(
+                            "<{}|{}>",
+                            escape(&href),
+                            label.trim().replace('|', "/")
+                        )),
+                        Some(href) => text.push_str(&format!("<{}>", escape(&href))),
+                        None => text.push_str(&label),
+                    }
+                }
+                _ => text.push_str(&self.inline(children)),
+            }
+        }
+
+        text
+    }
+}
+
+fn flush(pending: &mut String, elements: &mut Vec<Element>) {
+    let text = std::mem::take(pending);
+    let text = text.trim();
+    if !text.is_empty() {
+        elements.push(Element::Text(text.to_string()));
+    }
+}
+
+fn attribute(tag: &tl::HTMLTag, key: &str) -> Option<String> {
+    tag.attributes()
+        .get(key)
+        .flatten()
+        .map(|value| decode_entities(&value.as_utf8_str()))
+}
+
+fn is_supported_url(url: &str) -> bool {
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+// Formatting markers only apply when they hug the text, e.g. `*bold*` but not `* bold *`.
+fn wrap(marker: &str, text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+
+    format!("{}{}{}{}{}", leading, marker, trimmed, marker, trailing)
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn plain(text: &str) -> String {
+    collapse_whitespace(text).trim().to_string()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_space = false;
+
+    for c in text.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !last_was_space {
+                collapsed.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            collapsed.push(c);
+            last_was_space = false;
+        }
+    }
+
+    collapsed
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => entity.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_elements() {
+        let html = indoc::indoc! {r#"
+            <h1>Summary &amp; next steps</h1>
+            <p>We <strong>agreed </strong>on <em>scope</em>, see <a href="https://example.com">the doc</a> and <a class="mention" data-mention="true" data-id="1" data-type="human" data-label="John" href="javascript:void(0)">@John</a>.</p>
+            <ul><li><p>One</p><ul><li><p>Nested</p></li></ul></li><li><p>Two &lt;3</p></li></ul>
+            <ol><li>First</li><li>Second</li></ol>
+            <ul data-type="taskList"><li data-checked="true" data-type="taskItem"><label><input type="checkbox" checked="checked"><span></span></label><div><p>Send deck</p></div></li></ul>
+            <hr>
+            <blockquote><p>Ship it</p></blockquote>
+            <pre><code>let a = 1 &lt; 2;
+            </code></pre>
+        "#};
+
+        assert_eq!(
+            html_to_elements(html),
+            vec![
+                Element::Heading("Summary &amp; next steps".to_string()),
+                Element::Text(
+                    "We *agreed* on _scope_, see <https://example.com|the doc> and @John."
+                        .to_string()
+                ),
+                Element::Text("• One\n    • Nested\n• Two &lt;3".to_string()),
+                Element::Text("1. First\n2. Second".to_string()),
+                Element::Text("☑ Send deck".to_string()),
+                Element::Divider,
+                Element::Text("> Ship it".to_string()),
+                Element::Text("```\nlet a = 1 &lt; 2;\n```".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(html_to_elements("").is_empty());
+        assert!(html_to_elements("<p></p>\n<p>  </p>").is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+use hypr_db_user::{ActionItem, Human, Session};
+
+use crate::mrkdwn::{escape, html_to_elements, Element};
+
+// https://api.slack.com/reference/block-kit/blocks
+const MAX_BLOCKS: usize = 50;
+const MAX_SECTION_TEXT: usize = 3000;
+const MAX_HEADER_TEXT: usize = 150;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Header(String),
+    Context(String),
+    Section(String),
+    Divider,
+}
+
+pub struct SessionMessage {
+    pub session: Session,
+    pub participants: Vec<Human>,
+    pub action_items: Vec<ActionItem>,
+}
+
+impl SessionMessage {
+    pub fn title(&self) -> String {
+        match self.session.title.trim() {
+            "" => "Untitled".to_string(),
+            title => title.to_string(),
+        }
+    }
+
+    // Each page is posted as its own message, the ones after the first as replies in its thread.
+    pub fn pages(&self) -> Vec<Vec<Block>> {
+        let mut blocks = vec![Block::Header(truncate(&self.title(), MAX_HEADER_TEXT))];
+        blocks.push(Block::Context(truncate(&self.context(), MAX_SECTION_TEXT)));
+
+        let html = match &self.session.enhanced_memo_html {
+            Some(html) if !html.trim().is_empty() => html,
+            _ => &self.session.raw_memo_html,
+        };
+
+        let notes = html_to_elements(html);
+        if !notes.is_empty() {
+            blocks.push(Block::Divider);
+            blocks.extend(sections(notes));
+        }
+
+        if !self.action_items.is_empty() {
+            blocks.push(Block::Divider);
+            blocks.extend(sections(vec![
+                Element::Heading("Action items".to_string()),
+                Element::Text(
+                    self.action_items
+                        .iter()
+                        .map(|item| action_item(item, &self.participants))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+            ]));
+        }
+
+        blocks
+            .chunks(MAX_BLOCKS)
+            .map(|page| page.to_vec())
+            .collect()
+    }
+
+    fn context(&self) -> String {
+        let date = self.session.record_start.unwrap_or(self.session.created_at);
+        // https://api.slack.com/reference/surfaces/formatting#date-formatting
+        let mut context = format!(
+            "<!date^{}^{{date_short_pretty}} at {{time}}|{}>",
+            date.timestamp(),
+            date.format("%Y-%m-%d %H:%M UTC")
+        );
+
+        let participants = self
+            .participants
+            .iter()
+            .filter_map(|human| human.full_name.as_ref().or(human.email.as_ref()))
+            .map(|name| escape(name))
+            .collect::<Vec<_>>();
+
+        if !participants.is_empty() {
+            context.push_str(&format!(" · {}", participants.join(", ")));
+        }
+
+        context
+    }
+}
+
+fn action_item(item: &ActionItem, participants: &[Human]) -> String {
+    let text = escape(item.text.trim());
+    let mut line = match item.done {
+        true => format!("☑ ~{}~", text),
+        false => format!("☐ {}", text),
+    };
+
+    let owner = item
+        .owner_id
+        .as_ref()
+        .and_then(|id| participants.iter().find(|human| &human.id == id))
+        .and_then(|human| human.full_name.as_ref().or(human.email.as_ref()));
+    if let Some(owner) = owner {
+        line.push_str(&format!(" — {}", escape(owner)));
+    }
+
+    if let Some(due) = item.due_date {
+        line.push_str(&format!(
+            " (due <!date^{}^{{date_short}}|{}>)",
+            due.timestamp(),
+            due.format("%Y-%m-%d")
+        ));
+    }
+
+    line
+}
+
+// Packs elements into as few sections as possible, starting a new one at each heading.
+fn sections(elements: Vec<Element>) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut current = String::new();
+    // A heading is kept on the line right above its content.
+    let mut after_heading = false;
+
+    let flush = |current: &mut String, blocks: &mut Vec<Block>| {
+        if !current.trim().is_empty() {
+            blocks.push(Block::Section(std::mem::take(current)));
+        }
+        current.clear();
+    };
+
+    for element in elements {
+        match element {
+            Element::Divider => {
+                flush(&mut current, &mut blocks);
+                blocks.push(Block::Divider);
+                after_heading = false;
+            }
+            Element::Heading(heading) => {
+                flush(&mut current, &mut blocks);
+                current = format!("*{}*", truncate(&heading, MAX_SECTION_TEXT - 2));
+                after_heading = true;
+            }
+            Element::Text(text) => {
+                for piece in split(&text, MAX_SECTION_TEXT) {
+                    let separator = match (current.is_empty(), after_heading) {
+                        (true, _) => "",
+                        (false, true) => "\n",
+                        (false, false) => "\n\n",
+                    };
+                    after_heading = false;
+
+                    if len(&current) + len(separator) + len(&piece) > MAX_SECTION_TEXT {
+                        flush(&mut current, &mut blocks);
+                        current = piece;
+                    } else {
+                        current.push_str(separator);
+                        current.push_str(&piece);
+                    }
+                }
+            }
+        }
+    }
+
+    flush(&mut current, &mut blocks);
+    blocks
+}
+
+// Splits at line breaks where possible, so that formatting and list items stay intact.
+fn split(text: &str, max: usize) -> Vec<String> {
+    let mut pieces: Vec<String> = vec![];
+    let mut current = String::new();
+
+    for line in text.lines() {
+        let line_pieces = match len(line) > max {
+            true => line
+                .chars()
+                .collect::<Vec<_>>()
+                .chunks(max)
+                .map(|chunk| chunk.iter().collect())
+                .collect(),
+            false => vec![line.to_string()],
+        };
+
+        for piece in line_pieces {
+            if !current.is_empty() && len(&current) + 1 + len(&piece) > max {
+                pieces.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push('\n');
+            }
+            current.push_str(&piece);
+        }
+    }
+
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    match len(text) > max {
+        true => format!("{}…", text.chars().take(max - 1).collect::<String>()),
+        false => text.to_string(),
+    }
+}
+
+fn len(text: &str) -> usize {
+    text.chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{DateTime, Utc};
+
+    fn date() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-02T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn session(title: &str, enhanced_memo_html: &str) -> Session {
+        Session {
+            id: "session-1".to_string(),
+            created_at: date(),
+            visited_at: date(),
+            user_id: "user-1".to_string(),
+            calendar_event_id: None,
+            title: title.to_string(),
+            raw_memo_html: "".to_string(),
+            enhanced_memo_html: Some(enhanced_memo_html.to_string()),
+            conversations: vec![],
+            words: vec![],
+            record_start: None,
+            record_end: None,
+            pre_meeting_memo_html: None,
+        }
+    }
+
+    fn human(id: &str, name: &str) -> Human {
+        Human {
+            id: id.to_string(),
+            full_name: Some(name.to_string()),
+            ..Human::default()
+        }
+    }
+
+    #[test]
+    fn test_pages() {
+        let message = SessionMessage {
+            session: session(
+                "Weekly sync",
+                "<h2>Decisions</h2><p>Ship on Friday</p><p>Hire <strong>two</strong></p>",
+            ),
+            participants: vec![human("h1", "John"), human("h2", "Jane <PM>")],
+            action_items: vec![ActionItem {
+                id: "a1".to_string(),
+                session_id: "session-1".to_string(),
+                owner_id: Some("h2".to_string()),
+                text: "Send deck".to_string(),
+                due_date: Some(date()),
+                source_quote: None,
+                start_ms: None,
+                end_ms: None,
+                done: false,
+                created_at: date(),
+            }],
+        };
+
+        assert_eq!(
+            message.pages(),
+            vec![vec![
+                Block::Header("Weekly sync".to_string()),
+                Block::Context(
+                    "<!date^1735808400^{date_short_pretty} at {time}|2025-01-02 09:00 UTC> · John, Jane &lt;PM&gt;"
+                        .to_string()
+                ),
+                Block::Divider,
+                Block::Section("*Decisions*\nShip on Friday\n\nHire *two*".to_string()),
+                Block::Divider,
+                Block::Section(
+                    "*Action items*\n☐ Send deck — Jane &lt;PM&gt; (due <!date^1735808400^{date_short}|2025-01-02>)"
+                        .to_string()
+                ),
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_pages_respect_limits() {
+        let paragraphs = (0..200)
+            .map(|i| format!("<h3>Topic {}</h3><p>{}</p>", i, "a".repeat(100)))
+            .collect::<String>();
+        let long = format!("<p>{}</p>", "b".repeat(MAX_SECTION_TEXT * 2 + 10));
+
+        let message = SessionMessage {
+            session: session(&"t".repeat(200), &(paragraphs + &long)),
+            participants: vec![],
+            action_items: vec![],
+        };
+
+        let pages = message.pages();
+        assert_eq!(pages.len(), 5);
+        assert!(pages.iter().all(|page| page.len() <= MAX_BLOCKS));
+
+        let blocks = pages.concat();
+        assert_eq!(blocks.len(), 3 + 200 + 3);
+        assert!(matches!(&blocks[0], Block::Header(header) if len(header) == MAX_HEADER_TEXT));
+        assert!(blocks.iter().all(|block| match block {
+            Block::Section(text) => len(text) <= MAX_SECTION_TEXT,
+            _ => true,
+        }));
+    }
+}
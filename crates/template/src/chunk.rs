@@ -0,0 +1,134 @@
+use owhisper_interface::Word2;
+
+// Rough estimate that holds for the tokenizers we ship with; we only need to stay under the budget.
+const CHARS_PER_TOKEN: usize = 4;
+const SILENCE_GAP_MS: u64 = 2000;
+
+pub fn estimate_tokens(words: &[Word2]) -> usize {
+    words.iter().map(word_tokens).sum()
+}
+
+// Splits a transcript into chunks that each fit within `max_tokens`.
+// Chunks end at the last speaker change or silence before the budget runs out,
+// and only fall back to splitting mid-turn when a single turn is larger than the budget.
+pub fn chunk_words(words: &[Word2], max_tokens: usize) -> Vec<Vec<Word2>> {
+    let mut chunks = Vec::new();
+
+    let mut current: Vec<Word2> = Vec::new();
+    let mut current_tokens = 0;
+    let mut last_boundary: Option<usize> = None;
+
+    for word in words {
+        if current.last().is_some_and(|prev| is_boundary(prev, word)) {
+            last_boundary = Some(current.len());
+        }
+
+        let cost = word_tokens(word);
+
+        while !current.is_empty() && current_tokens + cost > max_tokens {
+            let split_at = last_boundary.take().unwrap_or(current.len());
+            let rest = current.split_off(split_at);
+
+            chunks.push(std::mem::replace(&mut current, rest));
+            current_tokens = estimate_tokens(&current);
+        }
+
+        current.push(word.clone());
+        current_tokens += cost;
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+fn word_tokens(word: &Word2) -> usize {
+    word.text.chars().count().div_ceil(CHARS_PER_TOKEN) + 1
+}
+
+fn is_boundary(prev: &Word2, next: &Word2) -> bool {
+    if prev.speaker != next.speaker {
+        return true;
+    }
+
+    match (prev.end_ms, next.start_ms) {
+        (Some(end), Some(start)) => start.saturating_sub(end) >= SILENCE_GAP_MS,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use owhisper_interface::SpeakerIdentity;
+
+    fn word(text: &str, speaker: u8, start_ms: u64) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: Some(SpeakerIdentity::Unassigned { index: speaker }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(start_ms + 300),
+        }
+    }
+
+    fn texts(chunks: &[Vec<Word2>]) -> Vec<Vec<&str>> {
+        chunks
+            .iter()
+            .map(|c| c.iter().map(|w| w.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_chunk_words_fits() {
+        let words = vec![word("hi", 0, 0), word("there", 0, 400)];
+        assert_eq!(texts(&chunk_words(&words, 100)), vec![vec!["hi", "there"]]);
+        assert!(chunk_words(&[], 100).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_words_speaker_boundary() {
+        // each word costs 2 tokens
+        let words = vec![
+            word("a", 0, 0),
+            word("b", 0, 400),
+            word("c", 1, 800),
+            word("d", 1, 1200),
+            word("e", 1, 1600),
+        ];
+
+        assert_eq!(
+            texts(&chunk_words(&words, 6)),
+            vec![vec!["a", "b"], vec!["c", "d", "e"]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_words_silence_boundary() {
+        let words = vec![
+            word("a", 0, 0),
+            word("b", 0, 400),
+            word("c", 0, 5000),
+            word("d", 0, 5400),
+        ];
+
+        assert_eq!(
+            texts(&chunk_words(&words, 6)),
+            vec![vec!["a", "b"], vec!["c", "d"]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_words_hard_split() {
+        let words = (0..5).map(|i| word("x", 0, i * 400)).collect::<Vec<_>>();
+
+        let chunks = chunk_words(&words, 4);
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert!(chunks.iter().all(|c| estimate_tokens(c) <= 4));
+    }
+}
@@ -34,6 +34,17 @@ pub fn timeline(words: String) -> String {
         .join("\n\n")
 }
 
+pub fn timestamp(offset_ms: u64) -> String {
+    let secs = offset_ms / 1000;
+    let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,6 +55,13 @@ mod tests {
         assert_eq!(language("ko".to_string()), "Korean");
     }
 
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(timestamp(0), "00:00");
+        assert_eq!(timestamp(83_500), "01:23");
+        assert_eq!(timestamp(3_723_000), "1:02:03");
+    }
+
     #[test]
     fn test_timeline() {
         insta::assert_snapshot!(timeline(hypr_data::english_3::WORDS_JSON.to_string()), @r###"
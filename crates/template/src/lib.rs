@@ -1,8 +1,12 @@
 use codes_iso_639::part_1::LanguageCode;
+use strum::IntoEnumIterator;
 
 mod filters;
 mod testers;
 
+mod chunk;
+pub use chunk::*;
+
 mod error;
 pub use error::*;
 
@@ -28,12 +32,25 @@ impl From<Template> for String {
     }
 }
 
-#[derive(Debug, strum::AsRefStr, strum::Display)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
 pub enum PredefinedTemplate {
     #[strum(serialize = "enhance.system")]
     EnhanceSystem,
     #[strum(serialize = "enhance.user")]
     EnhanceUser,
+    #[strum(serialize = "enhance_chunk.system")]
+    EnhanceChunkSystem,
+    #[strum(serialize = "enhance_chunk.user")]
+    EnhanceChunkUser,
     #[strum(serialize = "create_title.system")]
     CreateTitleSystem,
     #[strum(serialize = "create_title.user")]
@@ -48,40 +65,61 @@ pub enum PredefinedTemplate {
     AutoGenerateTagsSystem,
     #[strum(serialize = "auto_generate_tags.user")]
     AutoGenerateTagsUser,
+    #[strum(serialize = "extract_action_items.system")]
+    ExtractActionItemsSystem,
+    #[strum(serialize = "extract_action_items.user")]
+    ExtractActionItemsUser,
+    #[strum(serialize = "postprocess_enhance.system")]
+    PostprocessEnhanceSystem,
+    #[strum(serialize = "postprocess_enhance.user")]
+    PostprocessEnhanceUser,
+    #[strum(serialize = "show_annotation.system")]
+    ShowAnnotationSystem,
+    #[strum(serialize = "show_annotation.user")]
+    ShowAnnotationUser,
+    #[strum(serialize = "ai_chat_2.system")]
+    AiChat2System,
+}
+
+impl PredefinedTemplate {
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::iter()
+    }
+
+    pub fn source(&self) -> &'static str {
+        match self {
+            PredefinedTemplate::EnhanceSystem => ENHANCE_SYSTEM_TPL,
+            PredefinedTemplate::EnhanceUser => ENHANCE_USER_TPL,
+            PredefinedTemplate::EnhanceChunkSystem => ENHANCE_CHUNK_SYSTEM_TPL,
+            PredefinedTemplate::EnhanceChunkUser => ENHANCE_CHUNK_USER_TPL,
+            PredefinedTemplate::CreateTitleSystem => CREATE_TITLE_SYSTEM_TPL,
+            PredefinedTemplate::CreateTitleUser => CREATE_TITLE_USER_TPL,
+            PredefinedTemplate::SuggestTagsSystem => SUGGEST_TAGS_SYSTEM_TPL,
+            PredefinedTemplate::SuggestTagsUser => SUGGEST_TAGS_USER_TPL,
+            PredefinedTemplate::AiChatSystem => AI_CHAT_SYSTEM_TPL,
+            PredefinedTemplate::AutoGenerateTagsSystem => AUTO_GENERATE_TAGS_SYSTEM_TPL,
+            PredefinedTemplate::AutoGenerateTagsUser => AUTO_GENERATE_TAGS_USER_TPL,
+            PredefinedTemplate::ExtractActionItemsSystem => EXTRACT_ACTION_ITEMS_SYSTEM_TPL,
+            PredefinedTemplate::ExtractActionItemsUser => EXTRACT_ACTION_ITEMS_USER_TPL,
+            PredefinedTemplate::PostprocessEnhanceSystem => POSTPROCESS_ENHANCE_SYSTEM_TPL,
+            PredefinedTemplate::PostprocessEnhanceUser => POSTPROCESS_ENHANCE_USER_TPL,
+            PredefinedTemplate::ShowAnnotationSystem => SHOW_ANNOTATION_SYSTEM_TPL,
+            PredefinedTemplate::ShowAnnotationUser => SHOW_ANNOTATION_USER_TPL,
+            PredefinedTemplate::AiChat2System => AI_CHAT_2_SYSTEM_TPL,
+        }
+    }
 }
 
 impl From<PredefinedTemplate> for Template {
     fn from(value: PredefinedTemplate) -> Self {
-        match value {
-            PredefinedTemplate::EnhanceSystem => {
-                Template::Static(PredefinedTemplate::EnhanceSystem)
-            }
-            PredefinedTemplate::EnhanceUser => Template::Static(PredefinedTemplate::EnhanceUser),
-            PredefinedTemplate::CreateTitleSystem => {
-                Template::Static(PredefinedTemplate::CreateTitleSystem)
-            }
-            PredefinedTemplate::CreateTitleUser => {
-                Template::Static(PredefinedTemplate::CreateTitleUser)
-            }
-            PredefinedTemplate::SuggestTagsSystem => {
-                Template::Static(PredefinedTemplate::SuggestTagsSystem)
-            }
-            PredefinedTemplate::SuggestTagsUser => {
-                Template::Static(PredefinedTemplate::SuggestTagsUser)
-            }
-            PredefinedTemplate::AiChatSystem => Template::Static(PredefinedTemplate::AiChatSystem),
-            PredefinedTemplate::AutoGenerateTagsSystem => {
-                Template::Static(PredefinedTemplate::AutoGenerateTagsSystem)
-            }
-            PredefinedTemplate::AutoGenerateTagsUser => {
-                Template::Static(PredefinedTemplate::AutoGenerateTagsUser)
-            }
-        }
+        Template::Static(value)
     }
 }
 
 pub const ENHANCE_SYSTEM_TPL: &str = include_str!("../assets/enhance.system.jinja");
 pub const ENHANCE_USER_TPL: &str = include_str!("../assets/enhance.user.jinja");
+pub const ENHANCE_CHUNK_SYSTEM_TPL: &str = include_str!("../assets/enhance_chunk.system.jinja");
+pub const ENHANCE_CHUNK_USER_TPL: &str = include_str!("../assets/enhance_chunk.user.jinja");
 pub const CREATE_TITLE_SYSTEM_TPL: &str = include_str!("../assets/create_title.system.jinja");
 pub const CREATE_TITLE_USER_TPL: &str = include_str!("../assets/create_title.user.jinja");
 pub const SUGGEST_TAGS_SYSTEM_TPL: &str = include_str!("../assets/suggest_tags.system.jinja");
@@ -91,54 +129,29 @@ pub const AUTO_GENERATE_TAGS_SYSTEM_TPL: &str =
     include_str!("../assets/auto_generate_tags.system.jinja");
 pub const AUTO_GENERATE_TAGS_USER_TPL: &str =
     include_str!("../assets/auto_generate_tags.user.jinja");
+pub const EXTRACT_ACTION_ITEMS_SYSTEM_TPL: &str =
+    include_str!("../assets/extract_action_items.system.jinja");
+pub const EXTRACT_ACTION_ITEMS_USER_TPL: &str =
+    include_str!("../assets/extract_action_items.user.jinja");
+pub const POSTPROCESS_ENHANCE_SYSTEM_TPL: &str =
+    include_str!("../assets/postprocess_enhance.system.jinja");
+pub const POSTPROCESS_ENHANCE_USER_TPL: &str =
+    include_str!("../assets/postprocess_enhance.user.jinja");
+pub const SHOW_ANNOTATION_SYSTEM_TPL: &str = include_str!("../assets/show_annotation.system.jinja");
+pub const SHOW_ANNOTATION_USER_TPL: &str = include_str!("../assets/show_annotation.user.jinja");
+pub const AI_CHAT_2_SYSTEM_TPL: &str = include_str!("../assets/ai_chat_2.system.jinja");
 
 pub fn init(env: &mut minijinja::Environment) {
     env.set_unknown_method_callback(minijinja_contrib::pycompat::unknown_method_callback);
 
-    env.add_template(
-        PredefinedTemplate::EnhanceSystem.as_ref(),
-        ENHANCE_SYSTEM_TPL,
-    )
-    .unwrap();
-    env.add_template(PredefinedTemplate::EnhanceUser.as_ref(), ENHANCE_USER_TPL)
-        .unwrap();
-    env.add_template(
-        PredefinedTemplate::CreateTitleSystem.as_ref(),
-        CREATE_TITLE_SYSTEM_TPL,
-    )
-    .unwrap();
-    env.add_template(
-        PredefinedTemplate::CreateTitleUser.as_ref(),
-        CREATE_TITLE_USER_TPL,
-    )
-    .unwrap();
-    env.add_template(
-        PredefinedTemplate::SuggestTagsSystem.as_ref(),
-        SUGGEST_TAGS_SYSTEM_TPL,
-    )
-    .unwrap();
-    env.add_template(
-        PredefinedTemplate::SuggestTagsUser.as_ref(),
-        SUGGEST_TAGS_USER_TPL,
-    )
-    .unwrap();
-    env.add_template(
-        PredefinedTemplate::AiChatSystem.as_ref(),
-        AI_CHAT_SYSTEM_TPL,
-    )
-    .unwrap();
-    env.add_template(
-        PredefinedTemplate::AutoGenerateTagsSystem.as_ref(),
-        AUTO_GENERATE_TAGS_SYSTEM_TPL,
-    )
-    .unwrap();
-    env.add_template(
-        PredefinedTemplate::AutoGenerateTagsUser.as_ref(),
-        AUTO_GENERATE_TAGS_USER_TPL,
-    )
-    .unwrap();
+    for template in PredefinedTemplate::iter() {
+        env.add_template(template.as_ref(), template.source())
+            .unwrap();
+    }
+
     env.add_filter("timeline", filters::timeline);
     env.add_filter("language", filters::language);
+    env.add_filter("timestamp", filters::timestamp);
 
     [LanguageCode::En, LanguageCode::Ko]
         .iter()
@@ -29,6 +29,8 @@ use aws_sdk_transcribestreaming::types::{
 };
 use aws_sdk_transcribestreaming::{config::Region, Client};
 
+use aws_sdk_transcribe::types::VocabularyState;
+
 use owhisper_interface::{ListenInputChunk, ListenOutputChunk, ListenParams, Word2};
 
 mod error;
@@ -37,6 +39,7 @@ pub use error::*;
 #[derive(Clone)]
 pub struct TranscribeService {
     client: Arc<Client>,
+    vocabulary_client: Arc<aws_sdk_transcribe::Client>,
 }
 
 impl TranscribeService {
@@ -50,9 +53,11 @@ impl TranscribeService {
             .load()
             .await;
         let client = Client::new(&shared_config);
+        let vocabulary_client = aws_sdk_transcribe::Client::new(&shared_config);
 
         Ok(Self {
             client: Arc::new(client),
+            vocabulary_client: Arc::new(vocabulary_client),
         })
     }
 
@@ -68,7 +73,7 @@ impl TranscribeService {
     async fn handle_socket(self, socket: WebSocket, params: Option<ListenParams>) {
         let (sender, mut receiver) = socket.split();
 
-        let _params = params.unwrap_or_default();
+        let params = params.unwrap_or_default();
 
         let (audio_tx, audio_rx) = mpsc::channel::<Bytes>(100);
 
@@ -105,8 +110,13 @@ impl TranscribeService {
             }
         });
 
+        let vocabulary_name = self.vocabulary_name(&params.keywords).await;
+
         // Start transcription
-        if let Err(e) = self.start_transcription(audio_rx, sender).await {
+        if let Err(e) = self
+            .start_transcription(audio_rx, sender, vocabulary_name)
+            .await
+        {
             error!("Transcription error: {}", e);
         }
 
@@ -117,6 +127,7 @@ impl TranscribeService {
         &self,
         mut audio_rx: mpsc::Receiver<Bytes>,
         mut sender: futures_util::stream::SplitSink<WebSocket, Message>,
+        vocabulary_name: Option<String>,
     ) -> Result<(), crate::Error> {
         // Create audio stream for AWS Transcribe
         let input_stream = stream! {
@@ -136,6 +147,7 @@ impl TranscribeService {
             .language_code(LanguageCode::EnUs) // TODO: make configurable
             .media_sample_rate_hertz(16000)
             .media_encoding(MediaEncoding::Pcm)
+            .set_vocabulary_name(vocabulary_name)
             .audio_stream(input_stream.into())
             .send()
             .await?;
@@ -194,6 +206,79 @@ impl TranscribeService {
         let _ = sender.close().await;
         Ok(())
     }
+
+    // https://docs.aws.amazon.com/transcribe/latest/dg/custom-vocabulary.html
+    // Vocabularies are named after their content, so the same keywords reuse the same one.
+    async fn vocabulary_name(&self, keywords: &[String]) -> Option<String> {
+        let phrases = vocabulary_phrases(keywords);
+        if phrases.is_empty() {
+            return None;
+        }
+
+        let name = vocabulary_name(&phrases);
+
+        match self
+            .vocabulary_client
+            .get_vocabulary()
+            .vocabulary_name(&name)
+            .send()
+            .await
+        {
+            Ok(output) => match output.vocabulary_state() {
+                Some(VocabularyState::Ready) => Some(name),
+                state => {
+                    tracing::info!(vocabulary = %name, state = ?state, "aws_vocabulary_not_ready");
+                    None
+                }
+            },
+            // Building a vocabulary takes minutes, so it is only used from the next session on.
+            Err(_) => {
+                if let Err(e) = self
+                    .vocabulary_client
+                    .create_vocabulary()
+                    .vocabulary_name(&name)
+                    .language_code(aws_sdk_transcribe::types::LanguageCode::EnUs)
+                    .set_phrases(Some(phrases))
+                    .send()
+                    .await
+                {
+                    error!("aws_vocabulary_create_failed: {}", e);
+                }
+                None
+            }
+        }
+    }
+}
+
+// Phrases can not contain spaces, so words are joined with hyphens. Terms with digits or symbols
+// are left out, since they would have to be spelled out to be accepted.
+fn vocabulary_phrases(keywords: &[String]) -> Vec<String> {
+    let mut phrases = keywords
+        .iter()
+        .filter(|keyword| {
+            keyword
+                .chars()
+                .all(|c| c.is_alphabetic() || c.is_whitespace() || matches!(c, '\'' | '-' | '.'))
+        })
+        .map(|keyword| keyword.split_whitespace().collect::<Vec<_>>().join("-"))
+        .filter(|phrase| !phrase.is_empty() && phrase.len() <= 256)
+        .collect::<Vec<_>>();
+
+    phrases.sort();
+    phrases.dedup();
+    phrases
+}
+
+fn vocabulary_name(phrases: &[String]) -> String {
+    // https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+    let hash = phrases
+        .join("\n")
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+    format!("hyprnote-{:016x}", hash)
 }
 
 impl Service<Request<Body>> for TranscribeService {
@@ -210,11 +295,22 @@ impl Service<Request<Body>> for TranscribeService {
 
         Box::pin(async move {
             if req.headers().get("upgrade").and_then(|v| v.to_str().ok()) == Some("websocket") {
+                let params =
+                    match serde_qs::from_str::<ListenParams>(req.uri().query().unwrap_or("")) {
+                        Ok(params) => params,
+                        Err(e) => {
+                            return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(e.to_string()))
+                                .unwrap());
+                        }
+                    };
+
                 let (parts, body) = req.into_parts();
                 let axum_req = axum::extract::Request::from_parts(parts, body);
 
                 match WebSocketUpgrade::from_request(axum_req, &()).await {
-                    Ok(ws) => Ok(service.handle_websocket(ws, None).await),
+                    Ok(ws) => Ok(service.handle_websocket(ws, Some(params)).await),
                     Err(_) => Ok(Response::builder()
                         .status(StatusCode::BAD_REQUEST)
                         .body(Body::from("Invalid WebSocket upgrade request"))
@@ -258,3 +354,28 @@ fn mix_audio(mic: Vec<u8>, speaker: Vec<u8>) -> Vec<u8> {
 
     mixed
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vocabulary_phrases() {
+        let keywords = vec![
+            "Hyprnote".to_string(),
+            "Los Angeles".to_string(),
+            "GPT-4o".to_string(),
+            "Hyprnote".to_string(),
+            "42".to_string(),
+        ];
+
+        assert_eq!(
+            vocabulary_phrases(&keywords),
+            vec!["Hyprnote", "Los-Angeles"]
+        );
+        assert_eq!(
+            vocabulary_name(&vocabulary_phrases(&keywords)),
+            vocabulary_name(&vocabulary_phrases(&keywords[..3]))
+        );
+    }
+}
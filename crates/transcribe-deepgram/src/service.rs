@@ -53,7 +53,7 @@ impl TranscribeService {
     async fn handle_socket(self, socket: WebSocket, params: Option<ListenParams>) {
         let (mut sender, mut receiver) = socket.split();
 
-        let _params = params.unwrap_or_default();
+        let params = params.unwrap_or_default();
 
         let (audio_tx, audio_rx) = mpsc::channel::<Result<bytes::Bytes, std::io::Error>>(100);
 
@@ -96,6 +96,7 @@ impl TranscribeService {
             .smart_format(true)
             .language(Language::en)
             .encoding(Encoding::Linear16)
+            .keywords(params.keywords.iter().map(String::as_str))
             .build();
 
         match self
@@ -181,11 +182,22 @@ impl Service<Request<Body>> for TranscribeService {
 
         Box::pin(async move {
             if req.headers().get("upgrade").and_then(|v| v.to_str().ok()) == Some("websocket") {
+                let params =
+                    match serde_qs::from_str::<ListenParams>(req.uri().query().unwrap_or("")) {
+                        Ok(params) => params,
+                        Err(e) => {
+                            return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(e.to_string()))
+                                .unwrap());
+                        }
+                    };
+
                 let (parts, body) = req.into_parts();
                 let axum_req = axum::extract::Request::from_parts(parts, body);
 
                 match WebSocketUpgrade::from_request(axum_req, &()).await {
-                    Ok(ws) => Ok(service.handle_websocket(ws, None).await),
+                    Ok(ws) => Ok(service.handle_websocket(ws, Some(params)).await),
                     Err(_) => Ok(Response::builder()
                         .status(StatusCode::BAD_REQUEST)
                         .body(Body::from("Invalid WebSocket upgrade request"))
@@ -1,2 +1,15 @@
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    AudioError(#[from] hypr_audio_utils::Error),
+    #[error(transparent)]
+    AudioCodecError(#[from] hypr_audio_codec::Error),
+    #[error(transparent)]
+    SegmentationError(#[from] hypr_pyannote_local::Error),
+    #[error(transparent)]
+    WhisperError(#[from] hypr_whisper_local::Error),
+    #[error("audio file not found")]
+    AudioNotFound,
+    #[error("cancelled")]
+    Cancelled,
+}
@@ -0,0 +1,86 @@
+// LocalAgreement-2 from https://arxiv.org/abs/2307.14743: a word is shown once two consecutive
+// hypotheses of the same growing utterance agree on it and on everything before it.
+#[derive(Debug, Default)]
+pub(crate) struct LocalAgreement {
+    previous: Vec<String>,
+    confirmed: Vec<String>,
+}
+
+impl LocalAgreement {
+    // Returns the confirmed words whenever more of them were confirmed.
+    pub fn insert(&mut self, hypothesis: Vec<String>) -> Option<&[String]> {
+        let agreed = self
+            .previous
+            .iter()
+            .zip(&hypothesis)
+            .take_while(|(a, b)| normalize(a) == normalize(b))
+            .count();
+
+        // Confirmed words never change until the final result replaces them.
+        let extends = agreed > self.confirmed.len()
+            && self
+                .confirmed
+                .iter()
+                .zip(&hypothesis)
+                .all(|(a, b)| normalize(a) == normalize(b));
+
+        if extends {
+            self.confirmed = hypothesis[..agreed].to_vec();
+        }
+        self.previous = hypothesis;
+
+        extends.then_some(self.confirmed.as_slice())
+    }
+
+    pub fn reset(&mut self) {
+        self.previous.clear();
+        self.confirmed.clear();
+    }
+}
+
+// Hypotheses often only differ in punctuation and casing at their end.
+fn normalize(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric() || *c == '\'')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_local_agreement() {
+        let mut agreement = LocalAgreement::default();
+
+        assert_eq!(agreement.insert(words("so the")), None);
+        assert_eq!(
+            agreement.insert(words("So the plan.")),
+            Some(words("So the").as_slice())
+        );
+        assert_eq!(
+            agreement.insert(words("So the plan is")),
+            Some(words("So the plan").as_slice())
+        );
+        assert_eq!(
+            agreement.insert(words("So the plan is to ship")),
+            Some(words("So the plan is").as_slice())
+        );
+
+        // A hypothesis that disagrees with what was confirmed does not take it back.
+        assert_eq!(agreement.insert(words("Though the plan is to ship")), None);
+        assert_eq!(agreement.insert(words("Though the plan is to ship")), None);
+
+        agreement.reset();
+        assert_eq!(agreement.insert(words("next")), None);
+        assert_eq!(
+            agreement.insert(words("next one")),
+            Some(words("next").as_slice())
+        );
+    }
+}
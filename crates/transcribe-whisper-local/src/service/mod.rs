@@ -1,3 +1,5 @@
+mod agreement;
+
 mod streaming;
 pub use streaming::*;
 
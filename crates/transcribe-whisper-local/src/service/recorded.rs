@@ -1,58 +1,111 @@
-use owhisper_interface::Word2;
+use owhisper_interface::{ListenParams, Word2};
 
+const SAMPLE_RATE: u32 = 16000;
+// Whisper only looks at 30 seconds at a time.
+const MAX_CHUNK_SAMPLES: usize = SAMPLE_RATE as usize * 28;
+
+// `on_progress` is called with the number of processed and total speech segments,
+// and returning `false` from it stops the transcription.
 pub fn process_recorded(
     model_path: impl AsRef<std::path::Path>,
     audio_path: impl AsRef<std::path::Path>,
+    params: &ListenParams,
+    mut on_progress: impl FnMut(usize, usize) -> bool,
 ) -> Result<Vec<Word2>, crate::Error> {
-    let samples = {
-        use rodio::Source;
-
-        let source = hypr_audio_utils::source_from_path(audio_path.as_ref()).unwrap();
-        let original_sample_rate = source.sample_rate();
-
-        let resampled_samples = if original_sample_rate != 16000 {
-            hypr_audio_utils::resample_audio(source, 16000).unwrap()
-        } else {
-            source.convert_samples().collect()
-        };
+    if !audio_path.as_ref().exists() {
+        return Err(crate::Error::AudioNotFound);
+    }
 
-        hypr_audio_utils::f32_to_i16_samples(&resampled_samples)
-    };
+    // Stereo recordings keep the mic and speaker on separate channels, so both are mixed back in.
+    let samples = hypr_audio_utils::f32_to_i16_samples(&hypr_audio_codec::decode_mono(
+        audio_path.as_ref(),
+        SAMPLE_RATE,
+    )?);
 
     let mut model = hypr_whisper_local::Whisper::builder()
         .model_path(model_path.as_ref().to_str().unwrap())
-        .languages(vec![])
-        .build()
-        .unwrap();
+        .languages(
+            params
+                .languages
+                .iter()
+                .filter_map(|lang| lang.clone().try_into().ok())
+                .collect::<Vec<hypr_whisper::Language>>(),
+        )
+        .vocabulary(params.keywords.clone())
+        .build()?;
 
-    let mut segmenter = hypr_pyannote_local::segmentation::Segmenter::new(16000).unwrap();
-    let segments = segmenter.process(&samples, 16000).unwrap();
+    let mut segmenter = hypr_pyannote_local::segmentation::Segmenter::new(SAMPLE_RATE)?;
+    let segments = segmenter.process(&samples, SAMPLE_RATE)?;
 
     let mut words = Vec::new();
 
-    for segment in segments {
-        let audio_f32 = hypr_audio_utils::i16_to_f32_samples(&segment.samples);
-
-        let whisper_segments = model.transcribe(&audio_f32).unwrap();
-
-        for whisper_segment in whisper_segments {
-            let start_sec: f64 = segment.start + (whisper_segment.start() as f64);
-            let end_sec: f64 = segment.start + (whisper_segment.end() as f64);
-            let start_ms = (start_sec * 1000.0) as u64;
-            let end_ms = (end_sec * 1000.0) as u64;
+    for (i, segment) in segments.iter().enumerate() {
+        if !on_progress(i, segments.len()) {
+            return Err(crate::Error::Cancelled);
+        }
 
-            let word = Word2 {
-                text: whisper_segment.text().to_string(),
-                speaker: None,
-                confidence: Some(whisper_segment.confidence()),
-                start_ms: Some(start_ms),
-                end_ms: Some(end_ms),
-            };
+        for (j, chunk) in segment.samples.chunks(MAX_CHUNK_SAMPLES).enumerate() {
+            let offset = segment.start + (j * MAX_CHUNK_SAMPLES) as f64 / SAMPLE_RATE as f64;
+            let audio_f32 = hypr_audio_utils::i16_to_f32_samples(chunk);
 
-            // TODO
-            words.push(word.clone());
+            for whisper_segment in model.transcribe(&audio_f32)? {
+                words.extend(split_words(
+                    whisper_segment.text(),
+                    offset + whisper_segment.start(),
+                    offset + whisper_segment.end(),
+                    whisper_segment.confidence(),
+                ));
+            }
         }
     }
 
+    on_progress(segments.len(), segments.len());
     Ok(words)
 }
+
+// Whisper only times whole segments, so words get a share of it proportional to their length.
+fn split_words(text: &str, start_sec: f64, end_sec: f64, confidence: f32) -> Vec<Word2> {
+    let texts = text.split_whitespace().collect::<Vec<_>>();
+    let total_chars = texts
+        .iter()
+        .map(|t| t.chars().count())
+        .sum::<usize>()
+        .max(1);
+    let duration_sec = (end_sec - start_sec).max(0.0);
+
+    let mut chars_before = 0;
+    texts
+        .into_iter()
+        .map(|text| {
+            let chars = text.chars().count();
+            let start = start_sec + duration_sec * chars_before as f64 / total_chars as f64;
+            chars_before += chars;
+            let end = start_sec + duration_sec * chars_before as f64 / total_chars as f64;
+
+            Word2 {
+                text: text.to_string(),
+                speaker: None,
+                confidence: Some(confidence),
+                start_ms: Some((start * 1000.0).round() as u64),
+                end_ms: Some((end * 1000.0).round() as u64),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        let words = split_words(" Hi there ", 1.0, 2.4, 0.9);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "Hi");
+        assert_eq!(words[0].start_ms, Some(1000));
+        assert_eq!(words[0].end_ms, Some(1400));
+        assert_eq!(words[1].text, "there");
+        assert_eq!(words[1].start_ms, Some(1400));
+        assert_eq!(words[1].end_ms, Some(2400));
+    }
+}
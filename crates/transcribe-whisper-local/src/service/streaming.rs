@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     future::Future,
     path::PathBuf,
     pin::Pin,
@@ -14,13 +15,20 @@ use axum::{
     http::{Request, StatusCode},
     response::{IntoResponse, Response},
 };
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{FutureExt, SinkExt, StreamExt};
 use tower::Service;
 
 use hypr_vad::VadExt;
 use hypr_ws_utils::{ConnectionGuard, ConnectionManager};
 use owhisper_interface::{Alternatives, Channel, ListenParams, Metadata, StreamResponse, Word};
 
+use super::agreement::LocalAgreement;
+
+// How often the speech heard so far is transcribed again, while someone is still talking.
+const PARTIAL_INTERVAL: Duration = Duration::from_secs(1);
+// Whisper only looks at the first 30 seconds, so longer partials are not worth decoding.
+const MAX_PARTIAL_SAMPLES: usize = 30 * 16 * 1000;
+
 #[derive(Clone)]
 pub struct TranscribeService {
     model_path: PathBuf,
@@ -99,6 +107,7 @@ where
                         .filter_map(|lang| lang.clone().try_into().ok())
                         .collect::<Vec<hypr_whisper::Language>>(),
                 )
+                .vocabulary(params.keywords.clone())
                 .build()
             {
                 Ok(model) => model,
@@ -154,7 +163,7 @@ async fn handle_single_channel(
     redemption_time: Duration,
 ) {
     let audio_source = hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000);
-    let vad_chunks = audio_source.speech_chunks(redemption_time);
+    let vad_chunks = audio_source.speech_updates(redemption_time, PARTIAL_INTERVAL);
 
     let chunked = hypr_whisper_local::AudioChunkStream(process_vad_stream(vad_chunks, "mixed"));
 
@@ -173,12 +182,12 @@ async fn handle_dual_channel(
         hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000);
 
     let mic_chunked = {
-        let mic_vad_chunks = mic_source.speech_chunks(redemption_time);
+        let mic_vad_chunks = mic_source.speech_updates(redemption_time, PARTIAL_INTERVAL);
         hypr_whisper_local::AudioChunkStream(process_vad_stream(mic_vad_chunks, "mic"))
     };
 
     let speaker_chunked = {
-        let speaker_vad_chunks = speaker_source.speech_chunks(redemption_time);
+        let speaker_vad_chunks = speaker_source.speech_updates(redemption_time, PARTIAL_INTERVAL);
         hypr_whisper_local::AudioChunkStream(process_vad_stream(speaker_vad_chunks, "speaker"))
     };
 
@@ -193,78 +202,65 @@ async fn handle_dual_channel(
     process_transcription_stream(ws_sender, stream, guard, 2).await;
 }
 
-async fn process_transcription_stream(
-    mut ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
+async fn process_transcription_stream<S>(
+    mut ws_sender: S,
     mut stream: impl futures_util::Stream<Item = hypr_whisper_local::Segment> + Unpin,
     guard: ConnectionGuard,
     channels: i32,
-) {
+) where
+    S: futures_util::Sink<Message> + Unpin,
+    S::Error: std::fmt::Display,
+{
+    let mut agreements: HashMap<Option<String>, LocalAgreement> = HashMap::new();
+    // Segments of the chunk being received, sent as one response once the chunk is complete.
+    let mut pending: Vec<hypr_whisper_local::Segment> = Vec::new();
+
     loop {
         tokio::select! {
             _ = guard.cancelled() => {
                 tracing::info!("websocket_cancelled_by_new_connection");
                 break;
             }
-            chunk_opt = stream.next() => {
-                let Some(chunk) = chunk_opt else { break };
-
-                let meta = chunk.meta();
-                let text = chunk.text().to_string();
-                let language = chunk.language().map(|s| s.to_string()).map(|s| vec![s]).unwrap_or_default();
-                let start_f64 = chunk.start() as f64;
-                let duration_f64 = chunk.duration() as f64;
-                let confidence = chunk.confidence() as f64;
-
-                let source = meta.and_then(|meta|
-                    meta.get("source")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string())
-                );
-
-                let (speaker, channel_index) = match source.as_deref() {
-                    Some("mic") => (Some(0), vec![0, channels]),
-                    Some("speaker") => (Some(1), vec![1, channels]),
-                    _ => (None, vec![0, 1]),
-                };
-
-                let words: Vec<Word> = text
-                    .split_whitespace()
-                    .filter(|w| !w.is_empty())
-                    .map(|w| Word {
-                        word: w.trim().to_string(),
-                        start: start_f64,
-                        end: start_f64 + duration_f64,
-                        confidence,
-                        speaker: speaker.clone(),
-                        punctuated_word: None,
-                        language: None,
-                    })
-                    .collect();
-
-                let response = StreamResponse::TranscriptResponse {
-                    type_field: "Results".to_string(),
-                    start: start_f64,
-                    duration: duration_f64,
-                    is_final: true,
-                    speech_final: true,
-                    from_finalize: false,
-                    channel: Channel{
-                        alternatives: vec![Alternatives{
-                            transcript: text.clone(),
-                            languages: language.clone(),
-                            words,
-                            confidence,
-                        }],
-                    },
-                    metadata: Metadata::default(),
-                    channel_index,
-                };
-
-                let msg = Message::Text(serde_json::to_string(&response).unwrap().into());
-                if let Err(e) = ws_sender.send(msg).await {
+            segment_opt = stream.next() => {
+                let Some(segment) = segment_opt else { break };
+
+                let mut chunks = Vec::new();
+                let mut next = Some(segment);
+                let mut ended = false;
+
+                // A chunk is complete when the chunk id changes. All of its segments are ready as
+                // soon as it is transcribed, so it is also complete when nothing more is ready.
+                while let Some(segment) = next.take() {
+                    if pending
+                        .first()
+                        .is_some_and(|first| chunk_key(first) != chunk_key(&segment))
+                    {
+                        chunks.push(std::mem::take(&mut pending));
+                    }
+                    pending.push(segment);
+
+                    match stream.next().now_or_never() {
+                        Some(Some(segment)) => next = Some(segment),
+                        Some(None) => ended = true,
+                        None => {}
+                    }
+                }
+                chunks.push(std::mem::take(&mut pending));
+
+                let messages = chunks
+                    .iter()
+                    .filter_map(|chunk| transcript_response(chunk, &mut agreements, channels))
+                    .map(|response| Ok(Message::Text(serde_json::to_string(&response).unwrap().into())))
+                    .collect::<Vec<_>>();
+
+                if let Err(e) = ws_sender.send_all(&mut futures_util::stream::iter(messages)).await {
                     tracing::warn!("websocket_send_error: {}", e);
                     break;
                 }
+
+                if ended {
+                    break;
+                }
             }
         }
     }
@@ -272,15 +268,111 @@ async fn process_transcription_stream(
     let _ = ws_sender.close().await;
 }
 
+fn chunk_key(segment: &hypr_whisper_local::Segment) -> Option<(String, u64)> {
+    let meta = segment.meta.as_ref()?;
+    Some((
+        meta.get("source")?.as_str()?.to_string(),
+        meta.get("chunk_id")?.as_u64()?,
+    ))
+}
+
+// One response per chunk. A partial chunk is one hypothesis for the agreement, and only the words
+// it confirms are sent.
+fn transcript_response(
+    segments: &[hypr_whisper_local::Segment],
+    agreements: &mut HashMap<Option<String>, LocalAgreement>,
+    channels: i32,
+) -> Option<StreamResponse> {
+    let first = segments.first()?;
+    let meta = first.meta();
+    let partial = first.partial();
+    let language = first
+        .language()
+        .map(|s| vec![s.to_string()])
+        .unwrap_or_default();
+    let confidence = first.confidence() as f64;
+
+    let source = meta.as_ref().and_then(|meta| {
+        meta.get("source")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    });
+
+    // Whisper's own timestamps count from the start of the chunk, not of the stream.
+    let offset = meta
+        .as_ref()
+        .and_then(|meta| meta.get("start_ms")?.as_f64())
+        .map_or(0.0, |start_ms| start_ms / 1000.0);
+
+    let (speaker, channel_index) = match source.as_deref() {
+        Some("mic") => (Some(0), vec![0, channels]),
+        Some("speaker") => (Some(1), vec![1, channels]),
+        _ => (None, vec![0, 1]),
+    };
+
+    let mut words: Vec<Word> = segments
+        .iter()
+        .flat_map(|segment| {
+            let (start, end) = (offset + segment.start(), offset + segment.end());
+            segment.text().split_whitespace().map(move |w| Word {
+                word: w.to_string(),
+                start,
+                end,
+                confidence,
+                speaker,
+                punctuated_word: None,
+                language: None,
+            })
+        })
+        .collect();
+
+    let agreement = agreements.entry(source).or_default();
+    if partial {
+        let hypothesis = words.iter().map(|w| w.word.clone()).collect();
+        let confirmed = agreement.insert(hypothesis)?.len();
+        words.truncate(confirmed);
+    } else {
+        agreement.reset();
+    }
+
+    let transcript = words
+        .iter()
+        .map(|w| w.word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let start = words.first().map_or(offset, |w| w.start);
+    let end = words.last().map_or(offset, |w| w.end);
+
+    Some(StreamResponse::TranscriptResponse {
+        type_field: "Results".to_string(),
+        start,
+        duration: end - start,
+        is_final: !partial,
+        speech_final: !partial,
+        from_finalize: false,
+        channel: Channel {
+            alternatives: vec![Alternatives {
+                transcript,
+                languages: language,
+                words,
+                confidence,
+            }],
+        },
+        metadata: Metadata::default(),
+        channel_index,
+    })
+}
+
 fn process_vad_stream<S, E>(
     stream: S,
     source_name: &str,
 ) -> impl futures_util::Stream<Item = hypr_whisper_local::SimpleAudioChunk>
 where
-    S: futures_util::Stream<Item = Result<hypr_vad::AudioChunk, E>>,
+    S: futures_util::Stream<Item = Result<hypr_vad::SpeechUpdate, E>>,
     E: std::fmt::Display,
 {
     let source_name = source_name.to_string();
+    let mut chunk_id = 0u64;
 
     stream
         .take_while(move |chunk_result| {
@@ -295,10 +387,174 @@ where
         .filter_map(move |chunk_result| {
             futures_util::future::ready(match chunk_result {
                 Err(_) => None,
-                Ok(chunk) => Some(hypr_whisper_local::SimpleAudioChunk {
-                    samples: chunk.samples,
-                    meta: Some(serde_json::json!({ "source": source_name })),
-                }),
+                Ok(update) => {
+                    let (chunk, partial) = match update {
+                        hypr_vad::SpeechUpdate::Partial(chunk) => (chunk, true),
+                        hypr_vad::SpeechUpdate::Final(chunk) => (chunk, false),
+                    };
+
+                    chunk_id += 1;
+
+                    if partial && chunk.samples.len() > MAX_PARTIAL_SAMPLES {
+                        None
+                    } else {
+                        Some(hypr_whisper_local::SimpleAudioChunk {
+                            samples: chunk.samples,
+                            partial,
+                            meta: Some(serde_json::json!({
+                                "source": source_name,
+                                "chunk_id": chunk_id,
+                                "start_ms": chunk.start_timestamp_ms,
+                                "end_ms": chunk.end_timestamp_ms,
+                            })),
+                        })
+                    }
+                }
             })
         })
+        // Decoding can fall behind the audio, and then only the latest partial is worth decoding.
+        .ready_chunks(32)
+        .flat_map(|chunks| futures_util::stream::iter(skip_stale_partials(chunks)))
+}
+
+fn skip_stale_partials(
+    chunks: Vec<hypr_whisper_local::SimpleAudioChunk>,
+) -> Vec<hypr_whisper_local::SimpleAudioChunk> {
+    let last = chunks.len().saturating_sub(1);
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .filter(|(i, chunk)| !chunk.partial || *i == last)
+        .map(|(_, chunk)| chunk)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(
+        text: &str,
+        (start, end): (f64, f64),
+        chunk_id: u64,
+        partial: bool,
+    ) -> hypr_whisper_local::Segment {
+        hypr_whisper_local::Segment {
+            text: text.to_string(),
+            start,
+            end,
+            confidence: 1.0,
+            partial,
+            meta: Some(serde_json::json!({
+                "source": "mic",
+                "chunk_id": chunk_id,
+                "start_ms": 2000,
+            })),
+            ..Default::default()
+        }
+    }
+
+    // (is_final, [(word, start_ms, end_ms)])
+    fn summarize(message: &Message) -> (bool, Vec<(String, u64, u64)>) {
+        let Message::Text(text) = message else {
+            panic!("unexpected message: {:?}", message);
+        };
+        let StreamResponse::TranscriptResponse {
+            is_final, channel, ..
+        } = serde_json::from_str(text).unwrap()
+        else {
+            panic!("unexpected response: {}", text);
+        };
+
+        let words = channel.alternatives[0]
+            .words
+            .iter()
+            .map(|w| {
+                let ms = |t: f64| (t * 1000.0).round() as u64;
+                (w.word.clone(), ms(w.start), ms(w.end))
+            })
+            .collect();
+        (is_final, words)
+    }
+
+    #[tokio::test]
+    async fn test_process_transcription_stream() {
+        let segments = vec![
+            segment("So the", (0.0, 0.8), 1, true),
+            segment("plan.", (0.8, 1.2), 1, true),
+            segment("So the", (0.0, 0.8), 2, true),
+            segment("plan is", (0.8, 1.6), 2, true),
+            segment("to ship", (1.6, 2.2), 2, true),
+            segment("So the plan is", (0.0, 1.6), 3, false),
+            segment("to ship it.", (1.6, 2.5), 3, false),
+        ];
+
+        let mut messages = Vec::new();
+        process_transcription_stream(
+            &mut messages,
+            futures_util::stream::iter(segments),
+            ConnectionManager::default().acquire_connection(),
+            2,
+        )
+        .await;
+
+        let word = |w: &str, start, end| (w.to_string(), start, end);
+        assert_eq!(
+            messages.iter().map(summarize).collect::<Vec<_>>(),
+            vec![
+                (
+                    false,
+                    vec![
+                        word("So", 2000, 2800),
+                        word("the", 2000, 2800),
+                        word("plan", 2800, 3600),
+                    ]
+                ),
+                (
+                    true,
+                    vec![
+                        word("So", 2000, 3600),
+                        word("the", 2000, 3600),
+                        word("plan", 2000, 3600),
+                        word("is", 2000, 3600),
+                        word("to", 3600, 4500),
+                        word("ship", 3600, 4500),
+                        word("it.", 3600, 4500),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunk_sent_before_next_arrives() {
+        let segments = vec![
+            segment("So the", (0.0, 0.8), 1, false),
+            segment("plan.", (0.8, 1.2), 1, false),
+        ];
+
+        let mut messages = Vec::new();
+        let pending = process_transcription_stream(
+            &mut messages,
+            futures_util::stream::iter(segments).chain(futures_util::stream::pending()),
+            ConnectionManager::default().acquire_connection(),
+            2,
+        )
+        .now_or_never();
+        assert!(pending.is_none());
+
+        let word = |w: &str, start, end| (w.to_string(), start, end);
+        assert_eq!(
+            messages.iter().map(summarize).collect::<Vec<_>>(),
+            vec![(
+                true,
+                vec![
+                    word("So", 2000, 2800),
+                    word("the", 2000, 2800),
+                    word("plan.", 2800, 3200),
+                ]
+            )]
+        );
+    }
 }
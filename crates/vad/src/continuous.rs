@@ -29,6 +29,14 @@ pub struct AudioChunk {
     pub end_timestamp_ms: usize,
 }
 
+#[derive(Debug, Clone)]
+pub enum SpeechUpdate {
+    // Everything said so far in an utterance that is still going.
+    Partial(AudioChunk),
+    // The whole utterance, once it ended.
+    Final(AudioChunk),
+}
+
 pub struct ContinuousVadStream<S: AsyncSource> {
     source: S,
     vad_session: VadSession,
@@ -173,30 +181,148 @@ pub trait VadExt: AsyncSource + Sized {
     where
         Self: Unpin + 'static,
     {
-        let config = VadConfig {
+        self.with_vad(speech_config(redemption_time))
+            .filter_map(|item| {
+                future::ready(match item {
+                    Ok(VadStreamItem::SpeechEnd {
+                        samples,
+                        start_timestamp_ms,
+                        end_timestamp_ms,
+                    }) => Some(Ok(AudioChunk {
+                        samples,
+                        start_timestamp_ms,
+                        end_timestamp_ms,
+                    })),
+                    Ok(_) => None,
+                    Err(e) => Some(Err(e)),
+                })
+            })
+    }
+
+    // Like `speech_chunks`, but while an utterance is going on, the audio heard so far is also
+    // yielded every `partial_interval`.
+    fn speech_updates(
+        self,
+        redemption_time: Duration,
+        partial_interval: Duration,
+    ) -> impl Stream<Item = Result<SpeechUpdate, crate::Error>>
+    where
+        Self: Unpin + 'static,
+    {
+        let mut tracker = SpeechTracker::new(
+            self.sample_rate() as usize,
             redemption_time,
-            pre_speech_pad: redemption_time,
-            post_speech_pad: Duration::from_millis(0),
-            min_speech_time: Duration::from_millis(50),
-            ..Default::default()
-        };
-
-        self.with_vad(config).filter_map(|item| {
-            future::ready(match item {
-                Ok(VadStreamItem::SpeechEnd {
-                    samples,
-                    start_timestamp_ms,
-                    end_timestamp_ms,
-                }) => Some(Ok(AudioChunk {
-                    samples,
-                    start_timestamp_ms,
-                    end_timestamp_ms,
-                })),
-                Ok(_) => None,
-                Err(e) => Some(Err(e)),
+            partial_interval,
+        );
+
+        self.with_vad(speech_config(redemption_time))
+            .filter_map(move |item| {
+                future::ready(match item {
+                    Ok(item) => tracker.push(item).map(Ok),
+                    Err(e) => Some(Err(e)),
+                })
             })
-        })
     }
 }
 
 impl<T: AsyncSource> VadExt for T {}
+
+fn speech_config(redemption_time: Duration) -> VadConfig {
+    VadConfig {
+        redemption_time,
+        pre_speech_pad: redemption_time,
+        post_speech_pad: Duration::from_millis(0),
+        min_speech_time: Duration::from_millis(50),
+        ..Default::default()
+    }
+}
+
+struct SpeechTracker {
+    sample_rate: usize,
+    partial_interval: usize,
+    // `SpeechStart` points back into audio that was already seen, so some is kept between utterances.
+    keep: usize,
+    history: Vec<f32>,
+    history_start: usize,
+    total: usize,
+    speech_start_ms: Option<usize>,
+    last_partial_at: usize,
+    last_partial_end_ms: usize,
+}
+
+impl SpeechTracker {
+    fn new(sample_rate: usize, redemption_time: Duration, partial_interval: Duration) -> Self {
+        let to_samples = |d: Duration| (d.as_secs_f64() * sample_rate as f64) as usize;
+
+        Self {
+            sample_rate,
+            partial_interval: to_samples(partial_interval),
+            keep: to_samples(redemption_time + Duration::from_secs(2)),
+            history: Vec::new(),
+            history_start: 0,
+            total: 0,
+            speech_start_ms: None,
+            last_partial_at: 0,
+            last_partial_end_ms: 0,
+        }
+    }
+
+    fn push(&mut self, item: VadStreamItem) -> Option<SpeechUpdate> {
+        match item {
+            VadStreamItem::AudioSamples(samples) => {
+                self.total += samples.len();
+                self.history.extend(samples);
+
+                let Some(start_ms) = self.speech_start_ms else {
+                    self.trim();
+                    return None;
+                };
+
+                if self.total - self.last_partial_at < self.partial_interval {
+                    return None;
+                }
+                self.last_partial_at = self.total;
+
+                let from = (start_ms * self.sample_rate / 1000)
+                    .saturating_sub(self.history_start)
+                    .min(self.history.len());
+                self.last_partial_end_ms = self.total * 1000 / self.sample_rate;
+
+                Some(SpeechUpdate::Partial(AudioChunk {
+                    samples: self.history[from..].to_vec(),
+                    start_timestamp_ms: start_ms,
+                    end_timestamp_ms: self.last_partial_end_ms,
+                }))
+            }
+            VadStreamItem::SpeechStart { timestamp_ms } => {
+                self.speech_start_ms = Some(timestamp_ms);
+                self.last_partial_at = self.total;
+                self.last_partial_end_ms = 0;
+                None
+            }
+            VadStreamItem::SpeechEnd {
+                start_timestamp_ms,
+                end_timestamp_ms,
+                samples,
+            } => {
+                self.speech_start_ms = None;
+                self.trim();
+
+                // Partials can run into the redemption time, and the final has to cover them to replace them.
+                Some(SpeechUpdate::Final(AudioChunk {
+                    samples,
+                    start_timestamp_ms,
+                    end_timestamp_ms: end_timestamp_ms.max(self.last_partial_end_ms),
+                }))
+            }
+        }
+    }
+
+    fn trim(&mut self) {
+        if self.history.len() > self.keep {
+            let excess = self.history.len() - self.keep;
+            self.history.drain(..excess);
+            self.history_start += excess;
+        }
+    }
+}
@@ -78,4 +78,40 @@ pub mod tests {
             writer.write_sample(sample).unwrap();
         }
     }
+
+    #[tokio::test]
+    async fn test_partials_are_covered_by_finals() {
+        let updates = rodio::Decoder::new(std::io::BufReader::new(
+            std::fs::File::open(hypr_data::english_1::AUDIO_PATH).unwrap(),
+        ))
+        .unwrap()
+        .speech_updates(
+            std::time::Duration::from_millis(400),
+            std::time::Duration::from_secs(1),
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut partials: Vec<AudioChunk> = vec![];
+        let mut finals = 0;
+
+        for update in updates {
+            match update.unwrap() {
+                SpeechUpdate::Partial(chunk) => {
+                    if let Some(prev) = partials.last() {
+                        assert!(chunk.samples.len() > prev.samples.len());
+                    }
+                    partials.push(chunk);
+                }
+                SpeechUpdate::Final(chunk) => {
+                    for partial in partials.drain(..) {
+                        assert!(partial.end_timestamp_ms <= chunk.end_timestamp_ms);
+                    }
+                    finals += 1;
+                }
+            }
+        }
+
+        assert!(finals > 0);
+    }
 }
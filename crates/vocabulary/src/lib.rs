@@ -0,0 +1,241 @@
+use owhisper_interface::Word2;
+
+// Spans of words are compared to terms with spaces, punctuation and case removed,
+// so "hyper note," and "Hyprnote" are only one edit apart.
+pub struct Corrector {
+    terms: Vec<Term>,
+}
+
+struct Term {
+    text: String,
+    key: Vec<char>,
+    words: usize,
+}
+
+impl Corrector {
+    pub fn new(vocabulary: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let terms = vocabulary
+            .into_iter()
+            .filter_map(|term| {
+                let text = term
+                    .as_ref()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let key = normalize(&text);
+
+                if key.is_empty() {
+                    return None;
+                }
+
+                Some(Term {
+                    words: text.split(' ').count(),
+                    text,
+                    key,
+                })
+            })
+            .collect();
+
+        Self { terms }
+    }
+
+    pub fn correct(&self, words: Vec<Word2>) -> Vec<Word2> {
+        if self.terms.is_empty() {
+            return words;
+        }
+
+        let mut corrected = Vec::with_capacity(words.len());
+        let mut i = 0;
+
+        while i < words.len() {
+            match self.best_match(&words[i..]) {
+                Some((term, span)) => {
+                    corrected.push(replace(&words[i..i + span], &term.text));
+                    i += span;
+                }
+                None => {
+                    corrected.push(words[i].clone());
+                    i += 1;
+                }
+            }
+        }
+
+        corrected
+    }
+
+    fn best_match(&self, words: &[Word2]) -> Option<(&Term, usize)> {
+        let mut best: Option<(&Term, usize, usize)> = None;
+
+        for term in &self.terms {
+            // Recognizers tend to split unknown names into several words, or merge them with a neighbor.
+            let min_span = term.words.saturating_sub(1).max(1);
+            let max_span = (term.words + 1).min(words.len());
+
+            for span in min_span..=max_span {
+                let candidate = words[..span]
+                    .iter()
+                    .flat_map(|word| normalize(&word.text))
+                    .collect::<Vec<_>>();
+
+                if candidate.first() != term.key.first() {
+                    continue;
+                }
+
+                let distance = levenshtein(&candidate, &term.key);
+                if distance > max_distance(term.key.len()) {
+                    continue;
+                }
+
+                // A capitalized name like "Will" or "Mark" would otherwise capitalize every "will".
+                if distance == 0 && span == 1 && is_capitalized_word(&term.text) {
+                    continue;
+                }
+
+                if best.is_none_or(|(_, _, best_distance)| distance < best_distance) {
+                    best = Some((term, span, distance));
+                }
+            }
+        }
+
+        best.map(|(term, span, _)| (term, span))
+    }
+}
+
+// Short terms are too close to ordinary words to be matched loosely.
+fn max_distance(len: usize) -> usize {
+    match len {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+fn normalize(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_capitalized_word(text: &str) -> bool {
+    let mut chars = text.chars();
+    !text.contains(' ')
+        && chars.next().is_some_and(char::is_uppercase)
+        && chars.all(|c| !c.is_uppercase())
+}
+
+// Keeps the surrounding punctuation, e.g. `"hyper note,` becomes `"Hyprnote,`.
+fn replace(words: &[Word2], text: &str) -> Word2 {
+    let first = &words[0];
+    let last = &words[words.len() - 1];
+
+    let prefix = first
+        .text
+        .chars()
+        .take_while(|c| !c.is_alphanumeric())
+        .collect::<String>();
+    let suffix = last
+        .text
+        .chars()
+        .rev()
+        .take_while(|c| !c.is_alphanumeric())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect::<String>();
+
+    Word2 {
+        text: format!("{}{}{}", prefix, text, suffix),
+        speaker: first.speaker.clone(),
+        confidence: words
+            .iter()
+            .filter_map(|word| word.confidence)
+            .reduce(f32::min),
+        start_ms: first.start_ms,
+        end_ms: last.end_ms,
+    }
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<Word2> {
+        text.split(' ')
+            .enumerate()
+            .map(|(i, text)| Word2 {
+                text: text.to_string(),
+                speaker: None,
+                confidence: Some(0.9),
+                start_ms: Some(i as u64 * 100),
+                end_ms: Some(i as u64 * 100 + 90),
+            })
+            .collect()
+    }
+
+    fn correct(corrector: &Corrector, text: &str) -> String {
+        corrector
+            .correct(words(text))
+            .into_iter()
+            .map(|word| word.text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_correct() {
+        let corrector = Corrector::new(["Hyprnote", "Yujong Lee", "Kubernetes", "Will", "iPhone"]);
+
+        assert_eq!(
+            correct(&corrector, "I use hyper note, daily."),
+            "I use Hyprnote, daily."
+        );
+        assert_eq!(
+            correct(&corrector, "Ask yujong li about kubernetis"),
+            "Ask Yujong Lee about Kubernetes"
+        );
+        assert_eq!(
+            correct(&corrector, "we will buy an iphone"),
+            "we will buy an iPhone"
+        );
+        assert_eq!(
+            correct(&corrector, "the motion was passed"),
+            "the motion was passed"
+        );
+    }
+
+    #[test]
+    fn test_correct_timing() {
+        let corrector = Corrector::new(["Hyprnote"]);
+
+        let corrected = corrector.correct(words("open hyper note now"));
+        assert_eq!(corrected.len(), 3);
+        assert_eq!(corrected[1].text, "Hyprnote");
+        assert_eq!(corrected[1].start_ms, Some(100));
+        assert_eq!(corrected[1].end_ms, Some(290));
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("abc"), &chars("abc")), 0);
+    }
+}
@@ -18,6 +18,7 @@ lazy_static! {
 pub struct WhisperBuilder {
     model_path: Option<String>,
     languages: Option<Vec<Language>>,
+    vocabulary: Option<Vec<String>>,
 }
 
 impl WhisperBuilder {
@@ -31,6 +32,11 @@ impl WhisperBuilder {
         self
     }
 
+    pub fn vocabulary(mut self, vocabulary: Vec<String>) -> Self {
+        self.vocabulary = Some(vocabulary);
+        self
+    }
+
     pub fn build(self) -> Result<Whisper, crate::Error> {
         unsafe { Self::suppress_log() };
 
@@ -54,6 +60,7 @@ impl WhisperBuilder {
 
         Ok(Whisper {
             languages: self.languages.unwrap_or_default(),
+            static_prompt: vocabulary_prompt(&self.vocabulary.unwrap_or_default()),
             dynamic_prompt: "".to_string(),
             state,
             token_beg,
@@ -73,6 +80,7 @@ impl WhisperBuilder {
 
 pub struct Whisper {
     languages: Vec<Language>,
+    static_prompt: String,
     dynamic_prompt: String,
     state: WhisperState,
     token_beg: WhisperTokenId,
@@ -84,6 +92,29 @@ impl Whisper {
     }
 
     pub fn transcribe(&mut self, audio: &[f32]) -> Result<Vec<Segment>, super::Error> {
+        let segments = self.decode(audio)?;
+
+        let full_text = segments
+            .iter()
+            .map(|s| s.text())
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        if !full_text.is_empty() {
+            tracing::info!(text = ?full_text, "transcribe_completed");
+            self.dynamic_prompt = full_text;
+        }
+
+        Ok(segments)
+    }
+
+    // For audio that is still being spoken. The result is not used as the next prompt, since the
+    // same audio is transcribed again once the utterance ends.
+    pub fn transcribe_partial(&mut self, audio: &[f32]) -> Result<Vec<Segment>, super::Error> {
+        self.decode(audio)
+    }
+
+    fn decode(&mut self, audio: &[f32]) -> Result<Vec<Segment>, super::Error> {
         let input_audio_length_sec = audio.len() as f32 / 16000.0;
         if input_audio_length_sec < 0.1 {
             tracing::warn!(input_audio_length_sec = ?input_audio_length_sec, "transcribe_skipped");
@@ -96,7 +127,8 @@ impl Whisper {
         let params = {
             let mut p = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
 
-            let parts = [self.dynamic_prompt.trim()];
+            // whisper.cpp keeps the end of a prompt that is too long, so the vocabulary goes last.
+            let parts = [self.dynamic_prompt.trim(), self.static_prompt.trim()];
             let joined = parts.join("\n");
             let initial_prompt = joined.trim();
 
@@ -162,20 +194,7 @@ impl Whisper {
             });
         }
 
-        let segments = Self::filter_segments(segments);
-
-        let full_text = segments
-            .iter()
-            .map(|s| s.text())
-            .collect::<Vec<&str>>()
-            .join(" ");
-
-        if !full_text.is_empty() {
-            tracing::info!(text = ?full_text, "transcribe_completed");
-            self.dynamic_prompt = full_text;
-        }
-
-        Ok(segments)
+        Ok(Self::filter_segments(segments))
     }
 
     fn get_language(&mut self, audio: &[f32]) -> Result<Option<String>, super::Error> {
@@ -268,6 +287,7 @@ pub struct Segment {
     pub start: f64,
     pub end: f64,
     pub confidence: f32,
+    pub partial: bool,
     pub meta: Option<serde_json::Value>,
 }
 
@@ -296,16 +316,48 @@ impl Segment {
         self.confidence
     }
 
+    pub fn partial(&self) -> bool {
+        self.partial
+    }
+
     pub fn meta(&self) -> Option<serde_json::Value> {
         self.meta.clone()
     }
 }
 
+// Whisper has no keyword boosting, but spellings that appear in the prompt are likely to be reused.
+fn vocabulary_prompt(vocabulary: &[String]) -> String {
+    let terms = vocabulary
+        .iter()
+        .map(|term| term.trim())
+        .filter(|term| !term.is_empty())
+        .collect::<Vec<_>>();
+
+    if terms.is_empty() {
+        return "".to_string();
+    }
+
+    format!("Glossary: {}.", terms.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use futures_util::StreamExt;
 
+    #[test]
+    fn test_vocabulary_prompt() {
+        assert_eq!(vocabulary_prompt(&[]), "");
+        assert_eq!(
+            vocabulary_prompt(&[
+                "Hyprnote".to_string(),
+                " ".to_string(),
+                "Yujong".to_string()
+            ]),
+            "Glossary: Hyprnote, Yujong."
+        );
+    }
+
     #[test]
     fn test_whisper() {
         let mut whisper = Whisper::builder()
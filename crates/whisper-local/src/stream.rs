@@ -20,11 +20,17 @@ pub struct TranscriptionTask<S, T> {
 pub trait AudioChunk: Send + 'static {
     fn samples(&self) -> &[f32];
     fn meta(&self) -> Option<serde_json::Value>;
+
+    // Audio of an utterance that is still going, which will be sent again in full later.
+    fn partial(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Default)]
 pub struct SimpleAudioChunk {
     pub samples: Vec<f32>,
+    pub partial: bool,
     pub meta: Option<serde_json::Value>,
 }
 
@@ -33,6 +39,10 @@ impl AudioChunk for SimpleAudioChunk {
         &self.samples
     }
 
+    fn partial(&self) -> bool {
+        self.partial
+    }
+
     fn meta(&self) -> Option<serde_json::Value> {
         self.meta.clone()
     }
@@ -115,6 +125,7 @@ where
                         &mut this.whisper,
                         &samples,
                         &mut this.current_segment_task,
+                        false,
                         None,
                     ) {
                         Poll::Ready(result) => return Poll::Ready(result),
@@ -160,6 +171,7 @@ where
                         &mut this.whisper,
                         samples,
                         &mut this.current_segment_task,
+                        chunk.partial(),
                         meta,
                     ) {
                         Poll::Ready(result) => return Poll::Ready(result),
@@ -177,10 +189,17 @@ fn process_transcription<'a>(
     whisper: &'a mut Whisper,
     samples: &'a [f32],
     current_segment_task: &'a mut Option<Pin<Box<dyn Stream<Item = Segment> + Send>>>,
+    partial: bool,
     meta: Option<serde_json::Value>,
 ) -> Poll<Option<Segment>> {
     if !samples.is_empty() {
-        match whisper.transcribe(samples) {
+        let result = if partial {
+            whisper.transcribe_partial(samples)
+        } else {
+            whisper.transcribe(samples)
+        };
+
+        match result {
             Err(e) => {
                 tracing::error!("process_transcription: {:?}", e);
                 // Return Ready(None) to terminate the stream on error
@@ -189,6 +208,7 @@ fn process_transcription<'a>(
             }
             Ok(mut segments) => {
                 for segment in &mut segments {
+                    segment.partial = partial;
                     segment.meta = meta.clone();
                 }
 
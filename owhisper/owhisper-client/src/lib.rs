@@ -103,6 +103,11 @@ impl ListenClientBuilder {
                     "redemption_time_ms",
                     &params.redemption_time_ms.unwrap_or(400).to_string(),
                 );
+
+            // https://developers.deepgram.com/docs/keywords
+            for keyword in &params.keywords {
+                query_pairs.append_pair("keywords", keyword);
+            }
         }
 
         let host = url.host_str().unwrap();
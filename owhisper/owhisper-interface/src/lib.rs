@@ -126,6 +126,9 @@ common_derives! {
         #[serde(default)]
         pub languages: Vec<hypr_language::Language>,
         pub redemption_time_ms: Option<u64>,
+        // Names and jargon the transcript should spell out as given.
+        #[serde(default)]
+        pub keywords: Vec<String>,
     }
 }
 
@@ -136,6 +139,7 @@ impl Default for ListenParams {
             channels: 1,
             languages: vec![],
             redemption_time_ms: None,
+            keywords: vec![],
         }
     }
 }
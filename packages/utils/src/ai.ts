@@ -3,6 +3,7 @@ import { customProvider, extractReasoningMiddleware, wrapLanguageModel } from "a
 import { getLicenseKey } from "tauri-plugin-keygen-api";
 
 import { commands as connectorCommands } from "@hypr/plugin-connector";
+import { commands as localLlmCommands } from "@hypr/plugin-local-llm";
 import { fetch as customFetch } from "@hypr/utils";
 
 export {
@@ -59,6 +60,8 @@ const getModel = async ({ onboarding }: { onboarding: boolean }) => {
     ? "mock-onboarding"
     : (type === "Custom" && customModel)
     ? customModel
+    : type === "HyprLocal"
+    ? await localLlmCommands.getCurrentModelId()
     : "gpt-4";
 
   return wrapLanguageModel({
@@ -1,7 +1,12 @@
 import { create as mutate } from "mutative";
 import { createStore } from "zustand";
 
-import { commands as listenerCommands, events as listenerEvents } from "@hypr/plugin-listener";
+import {
+  commands as listenerCommands,
+  events as listenerEvents,
+  type NoiseSuppression,
+  type SessionErrorKind,
+} from "@hypr/plugin-listener";
 import { createSessionsStore } from "./sessions";
 
 type State = {
@@ -13,6 +18,10 @@ type State = {
   enhanceController: AbortController | null;
   micMuted: boolean;
   speakerMuted: boolean;
+  // The transcription stream dropped and is being retried. Audio is still being captured.
+  reconnecting: boolean;
+  // The last thing that went wrong in the listener. Unrecoverable errors also stop the session.
+  error: { kind: SessionErrorKind; message: string; recoverable: boolean } | null;
   autoEnhanceTemplate: string | null;
 };
 
@@ -21,7 +30,7 @@ type Actions = {
   cancelEnhance: () => void;
   setEnhanceController: (controller: AbortController | null) => void;
   setAutoEnhanceTemplate: (templateId: string | null) => void;
-  start: (sessionId: string) => void;
+  start: (sessionId: string, noiseSuppression?: NoiseSuppression) => void;
   stop: () => void;
   pause: () => void;
   resume: () => void;
@@ -35,6 +44,8 @@ const initialState: State = {
   enhanceController: null,
   micMuted: false,
   speakerMuted: false,
+  reconnecting: false,
+  error: null,
   autoEnhanceTemplate: null,
 };
 
@@ -71,7 +82,7 @@ export const createOngoingSessionStore = (
         })
       );
     },
-    start: (sessionId: string) => {
+    start: (sessionId: string, noiseSuppression?: NoiseSuppression) => {
       console.log("start", sessionId);
       set((state) =>
         mutate(state, (draft) => {
@@ -133,6 +144,31 @@ export const createOngoingSessionStore = (
               draft.speakerMuted = payload.value;
             })
           );
+        } else if (payload.type === "reconnecting") {
+          set((state) =>
+            mutate(state, (draft) => {
+              draft.reconnecting = true;
+            })
+          );
+        } else if (payload.type === "reconnected") {
+          set((state) =>
+            mutate(state, (draft) => {
+              draft.reconnecting = false;
+              draft.error = null;
+            })
+          );
+        } else if (payload.type === "error") {
+          const { kind, message, recoverable } = payload;
+
+          set((state) =>
+            mutate(state, (draft) => {
+              draft.error = { kind, message, recoverable };
+            })
+          );
+
+          if (!recoverable && callbacks?.onRecordingStartFailed) {
+            callbacks.onRecordingStartFailed({ kind, message });
+          }
         }
       }).then((unlisten) => {
         set((state) =>
@@ -142,7 +178,7 @@ export const createOngoingSessionStore = (
         );
       });
 
-      listenerCommands.startSession(sessionId).then(() => {
+      listenerCommands.startSession(sessionId, noiseSuppression ?? null).then(() => {
         set({ status: "running_active", loading: false });
       }).catch((error) => {
         console.error(error);
@@ -10,6 +10,8 @@ pub enum Error {
     ContactsAccessDenied,
     #[error("database error: {0}")]
     DatabaseError(#[from] hypr_db_user::Error),
+    #[error("calendar sync error: {0}")]
+    CalendarSyncError(#[from] hypr_calendar_sync::Error),
 }
 
 impl Serialize for Error {
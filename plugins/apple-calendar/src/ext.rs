@@ -16,6 +16,7 @@ pub trait AppleCalendarPluginExt<R: tauri::Runtime> {
     fn stop_worker(&self);
     fn sync_calendars(&self) -> impl Future<Output = Result<(), crate::Error>>;
     fn sync_events(&self) -> impl Future<Output = Result<(), crate::Error>>;
+    fn calendar_credentials(&self) -> impl Future<Output = crate::sync::Credentials>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AppleCalendarPluginExt<R> for T {
@@ -125,12 +126,13 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AppleCalendarPluginExt<R> f
         };
 
         let user_id = user_id.into();
+        let app = self.app_handle().clone();
 
         let state = self.state::<crate::ManagedState>();
         let mut s = state.lock().unwrap();
 
         s.worker_handle = Some(tokio::runtime::Handle::current().spawn(async move {
-            let _ = crate::worker::monitor(crate::worker::WorkerState { db, user_id }).await;
+            let _ = crate::worker::monitor(crate::worker::WorkerState { app, db, user_id }).await;
         }));
 
         Ok(())
@@ -154,7 +156,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AppleCalendarPluginExt<R> f
             (guard.db.clone().unwrap(), guard.user_id.clone().unwrap())
         };
 
-        crate::sync::sync_calendars(db, user_id).await
+        crate::sync::sync_calendars(db, user_id, &self.calendar_credentials().await).await
     }
 
     #[tracing::instrument(skip_all)]
@@ -165,6 +167,28 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AppleCalendarPluginExt<R> f
             (guard.db.clone().unwrap(), guard.user_id.clone().unwrap())
         };
 
-        crate::sync::sync_events(db, user_id).await
+        crate::sync::sync_events(db, user_id, &self.calendar_credentials().await).await
+    }
+
+    // Read on every sync, since the tokens expire and are refreshed by the auth plugin.
+    // The vault is only available when signed in, otherwise only Apple is synced.
+    #[tracing::instrument(skip_all)]
+    async fn calendar_credentials(&self) -> crate::sync::Credentials {
+        use tauri_plugin_auth::{AuthPluginExt, CalendarProvider};
+
+        let mut credentials = crate::sync::Credentials::default();
+        for (provider, token) in [
+            (CalendarProvider::Google, &mut credentials.google_token),
+            (CalendarProvider::Outlook, &mut credentials.outlook_token),
+        ] {
+            *token = self
+                .calendar_access_token(provider)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!(error = ?e, provider = ?provider, "calendar_token_unavailable");
+                    None
+                });
+        }
+        credentials
     }
 }
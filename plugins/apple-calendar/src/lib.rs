@@ -9,6 +9,7 @@ mod worker;
 
 pub use error::{Error, Result};
 pub use ext::AppleCalendarPluginExt;
+pub use sync::Credentials;
 
 pub type ManagedState = Mutex<State>;
 
@@ -1,14 +1,18 @@
 use apalis::prelude::{Data, Error, WorkerBuilder, WorkerFactoryFn};
 use chrono::{DateTime, Utc};
 
-use crate::sync::{sync_calendars, sync_events};
+use crate::{
+    sync::{sync_calendars, sync_events},
+    AppleCalendarPluginExt,
+};
 
 #[allow(unused)]
 #[derive(Default, Debug, Clone)]
 pub struct Job(DateTime<Utc>);
 
 #[derive(Clone)]
-pub struct WorkerState {
+pub struct WorkerState<R: tauri::Runtime> {
+    pub app: tauri::AppHandle<R>,
     pub db: hypr_db_user::UserDatabase,
     pub user_id: String,
 }
@@ -23,22 +27,30 @@ const CALENDARS_SYNC_WORKER_NAME: &str = "apple_calendar_calendars_sync";
 const EVENTS_SYNC_WORKER_NAME: &str = "apple_calendar_events_sync";
 
 #[tracing::instrument(skip(ctx), name = CALENDARS_SYNC_WORKER_NAME)]
-pub async fn perform_calendars_sync(_job: Job, ctx: Data<WorkerState>) -> Result<(), Error> {
-    sync_calendars(ctx.db.clone(), ctx.user_id.clone())
+pub async fn perform_calendars_sync<R: tauri::Runtime>(
+    _job: Job,
+    ctx: Data<WorkerState<R>>,
+) -> Result<(), Error> {
+    let credentials = ctx.app.calendar_credentials().await;
+    sync_calendars(ctx.db.clone(), ctx.user_id.clone(), &credentials)
         .await
         .map_err(|e| e.as_worker_error())?;
     Ok(())
 }
 
 #[tracing::instrument(skip(ctx), name = EVENTS_SYNC_WORKER_NAME)]
-pub async fn perform_events_sync(_job: Job, ctx: Data<WorkerState>) -> Result<(), Error> {
-    sync_events(ctx.db.clone(), ctx.user_id.clone())
+pub async fn perform_events_sync<R: tauri::Runtime>(
+    _job: Job,
+    ctx: Data<WorkerState<R>>,
+) -> Result<(), Error> {
+    let credentials = ctx.app.calendar_credentials().await;
+    sync_events(ctx.db.clone(), ctx.user_id.clone(), &credentials)
         .await
         .map_err(|e| e.as_worker_error())?;
     Ok(())
 }
 
-pub async fn monitor(state: WorkerState) -> Result<(), std::io::Error> {
+pub async fn monitor<R: tauri::Runtime>(state: WorkerState<R>) -> Result<(), std::io::Error> {
     #[cfg(target_os = "macos")]
     {
         use std::str::FromStr;
@@ -50,7 +62,7 @@ pub async fn monitor(state: WorkerState) -> Result<(), std::io::Error> {
                     .backend(apalis_cron::CronStream::new(
                         apalis_cron::Schedule::from_str("0 */10 * * * *").unwrap(),
                     ))
-                    .build_fn(perform_calendars_sync)
+                    .build_fn(perform_calendars_sync::<R>)
             })
             .register({
                 WorkerBuilder::new(EVENTS_SYNC_WORKER_NAME)
@@ -58,7 +70,7 @@ pub async fn monitor(state: WorkerState) -> Result<(), std::io::Error> {
                     .backend(apalis_cron::CronStream::new(
                         apalis_cron::Schedule::from_str("0 */5 * * * *").unwrap(),
                     ))
-                    .build_fn(perform_events_sync)
+                    .build_fn(perform_events_sync::<R>)
             })
             .run()
             .await?;
@@ -7,6 +7,8 @@ const COMMANDS: &[&str] = &[
     "get_from_vault",
     "set_in_store",
     "set_in_vault",
+    "start_calendar_oauth",
+    "disconnect_calendar",
 ];
 
 fn main() {
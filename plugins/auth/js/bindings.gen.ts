@@ -30,6 +30,12 @@ async setInVault(key: VaultKey, value: string) : Promise<null> {
 },
 async setInStore(key: StoreKey, value: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:auth|set_in_store", { key, value });
+},
+async startCalendarOauth(provider: CalendarProvider) : Promise<string> {
+    return await TAURI_INVOKE("plugin:auth|start_calendar_oauth", { provider });
+},
+async disconnectCalendar(provider: CalendarProvider) : Promise<null> {
+    return await TAURI_INVOKE("plugin:auth|disconnect_calendar", { provider });
 }
 }
 
@@ -48,11 +54,12 @@ authEvent: "plugin:auth:auth-event"
 
 /** user-defined types **/
 
-export type AuthEvent = "success" | { error: string }
+export type AuthEvent = "success" | { error: string } | { "calendar-connected": CalendarProvider }
+export type CalendarProvider = "google" | "outlook"
 export type RequestParams = { c: string; f: string; p: number }
 export type ResponseParams = { ui: string; ai: string; st: string; dt: string }
 export type StoreKey = "auth-user-id" | "auth-account-id"
-export type VaultKey = "remote-database" | "remote-server" | "twenty-api-key"
+export type VaultKey = "remote-database" | "remote-server" | "twenty-api-key" | "google-calendar-token" | "outlook-calendar-token"
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,230 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use sha2::{Digest, Sha256};
+
+use crate::vault::VaultKey;
+
+pub(crate) const CALLBACK_HTML: &str = "<!DOCTYPE html><html><body style=\"font-family: sans-serif; text-align: center; margin-top: 20vh\">Calendar connected. You can close this tab and go back to Hyprnote.</body></html>";
+
+// Refresh a bit before the provider would start rejecting the token.
+const EXPIRY_MARGIN_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum CalendarProvider {
+    #[serde(rename = "google")]
+    Google,
+    #[serde(rename = "outlook")]
+    Outlook,
+}
+
+// What is kept in the vault, so that the calendars keep syncing after the access token expires.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CalendarToken {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_at: u64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct CallbackParams {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+}
+
+struct ProviderConfig {
+    auth_url: &'static str,
+    token_url: &'static str,
+    scope: &'static str,
+    client_id: Option<&'static str>,
+    client_secret: Option<&'static str>,
+}
+
+impl CalendarProvider {
+    pub fn vault_key(&self) -> VaultKey {
+        match self {
+            CalendarProvider::Google => VaultKey::GoogleCalendarToken,
+            CalendarProvider::Outlook => VaultKey::OutlookCalendarToken,
+        }
+    }
+
+    fn config(&self) -> ProviderConfig {
+        match self {
+            // https://developers.google.com/identity/protocols/oauth2/native-app
+            CalendarProvider::Google => ProviderConfig {
+                auth_url: "https://accounts.google.com/o/oauth2/v2/auth",
+                token_url: "https://oauth2.googleapis.com/token",
+                scope: "https://www.googleapis.com/auth/calendar.readonly",
+                client_id: option_env!("GOOGLE_CALENDAR_CLIENT_ID"),
+                client_secret: option_env!("GOOGLE_CALENDAR_CLIENT_SECRET"),
+            },
+            // https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-auth-code-flow
+            CalendarProvider::Outlook => ProviderConfig {
+                auth_url: "https://login.microsoftonline.com/common/oauth2/v2.0/authorize",
+                token_url: "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+                scope: "offline_access Calendars.Read",
+                client_id: option_env!("OUTLOOK_CALENDAR_CLIENT_ID"),
+                client_secret: None,
+            },
+        }
+    }
+}
+
+// PKCE, since the client secret of a desktop app can not be kept secret.
+pub(crate) struct PendingAuthorization {
+    pub provider: CalendarProvider,
+    port: u16,
+    pub redirect_uri: String,
+    pub state: String,
+    pub verifier: String,
+}
+
+impl PendingAuthorization {
+    pub fn new(provider: CalendarProvider, port: u16) -> Self {
+        Self {
+            provider,
+            port,
+            redirect_uri: format!("http://localhost:{}", port),
+            state: uuid::Uuid::new_v4().simple().to_string(),
+            verifier: format!(
+                "{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            ),
+        }
+    }
+
+    pub fn redirect_port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn authorize_url(&self) -> Result<String, crate::Error> {
+        let config = self.provider.config();
+        let client_id = config
+            .client_id
+            .ok_or(crate::Error::CalendarNotConfigured)?;
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(self.verifier.as_bytes()));
+
+        let mut url = url::Url::parse(config.auth_url).unwrap();
+        url.query_pairs_mut()
+            .append_pair("client_id", client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("response_type", "code")
+            .append_pair("scope", config.scope)
+            .append_pair("state", &self.state)
+            .append_pair("code_challenge", &challenge)
+            .append_pair("code_challenge_method", "S256")
+            // Google only hands out a refresh token with these.
+            .append_pair("access_type", "offline")
+            .append_pair("prompt", "consent");
+
+        Ok(url.to_string())
+    }
+
+    pub async fn exchange(&self, params: CallbackParams) -> Result<CalendarToken, crate::Error> {
+        if let Some(error) = params.error {
+            return Err(crate::Error::CalendarOAuth(error));
+        }
+        if params.state.as_deref() != Some(self.state.as_str()) {
+            return Err(crate::Error::CalendarOAuth("state_mismatch".into()));
+        }
+        let code = params
+            .code
+            .ok_or_else(|| crate::Error::CalendarOAuth("missing_code".into()))?;
+
+        request_token(
+            self.provider,
+            &[
+                ("grant_type", "authorization_code"),
+                ("code", &code),
+                ("redirect_uri", &self.redirect_uri),
+                ("code_verifier", &self.verifier),
+            ],
+            None,
+        )
+        .await
+    }
+}
+
+impl CalendarToken {
+    // Tokens written before refresh support are plain access tokens.
+    pub fn parse(value: &str) -> Self {
+        serde_json::from_str(value).unwrap_or_else(|_| Self {
+            access_token: value.to_string(),
+            refresh_token: None,
+            expires_at: u64::MAX,
+        })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        now_secs() + EXPIRY_MARGIN_SECS >= self.expires_at
+    }
+
+    pub async fn refresh(&self, provider: CalendarProvider) -> Result<Self, crate::Error> {
+        let refresh_token = self
+            .refresh_token
+            .as_deref()
+            .ok_or_else(|| crate::Error::CalendarOAuth("missing_refresh_token".into()))?;
+
+        request_token(
+            provider,
+            &[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+            ],
+            // Providers may not rotate the refresh token.
+            self.refresh_token.clone(),
+        )
+        .await
+    }
+}
+
+async fn request_token(
+    provider: CalendarProvider,
+    form: &[(&str, &str)],
+    previous_refresh_token: Option<String>,
+) -> Result<CalendarToken, crate::Error> {
+    let config = provider.config();
+    let client_id = config
+        .client_id
+        .ok_or(crate::Error::CalendarNotConfigured)?;
+
+    let mut form = form.to_vec();
+    form.push(("client_id", client_id));
+    if let Some(client_secret) = config.client_secret {
+        form.push(("client_secret", client_secret));
+    }
+
+    let res = reqwest::Client::new()
+        .post(config.token_url)
+        .form(&form)
+        .send()
+        .await?;
+
+    if !res.status().is_success() {
+        let body = res.text().await.unwrap_or_default();
+        return Err(crate::Error::CalendarOAuth(body));
+    }
+
+    let res: TokenResponse = res.json().await?;
+    Ok(CalendarToken {
+        access_token: res.access_token,
+        refresh_token: res.refresh_token.or(previous_refresh_token),
+        expires_at: now_secs() + res.expires_in.unwrap_or(3600),
+    })
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
@@ -1,4 +1,4 @@
-use crate::{store::StoreKey, vault::VaultKey, AuthPluginExt};
+use crate::{store::StoreKey, vault::VaultKey, AuthPluginExt, CalendarProvider};
 
 #[tauri::command]
 #[specta::specta]
@@ -67,3 +67,22 @@ pub fn get_from_store<R: tauri::Runtime>(
 ) -> Result<Option<String>, String> {
     app.get_from_store(key).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn start_calendar_oauth<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    provider: CalendarProvider,
+) -> Result<String, String> {
+    app.start_calendar_oauth(provider)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn disconnect_calendar<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    provider: CalendarProvider,
+) -> Result<(), String> {
+    app.disconnect_calendar(provider).map_err(|e| e.to_string())
+}
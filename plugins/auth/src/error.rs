@@ -14,8 +14,14 @@ pub enum Error {
     Minijinja(#[from] minijinja::Error),
     #[error(transparent)]
     Store(#[from] tauri_plugin_store::Error),
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
     #[error("Vault not initialized")]
     VaultNotInitialized,
+    #[error("Calendar OAuth client is not configured")]
+    CalendarNotConfigured,
+    #[error("Calendar OAuth failed: {0}")]
+    CalendarOAuth(String),
 }
 
 impl Serialize for Error {
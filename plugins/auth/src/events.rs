@@ -4,4 +4,6 @@ pub enum AuthEvent {
     Success,
     #[serde(rename = "error")]
     Error(String),
+    #[serde(rename = "calendar-connected")]
+    CalendarConnected(crate::CalendarProvider),
 }
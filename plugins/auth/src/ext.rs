@@ -1,7 +1,10 @@
+use std::future::Future;
+
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use tauri_specta::Event;
 
 use crate::{
+    calendar::{self, CalendarProvider, CalendarToken, PendingAuthorization},
     events::AuthEvent,
     store::{self, StoreKey},
     vault::{Vault, VaultKey},
@@ -17,6 +20,13 @@ pub trait AuthPluginExt<R: tauri::Runtime> {
     fn get_from_store(&self, key: StoreKey) -> Result<Option<String>, crate::Error>;
     fn set_in_vault(&self, key: VaultKey, value: impl Into<String>) -> Result<(), crate::Error>;
     fn set_in_store(&self, key: StoreKey, value: impl Into<String>) -> Result<(), crate::Error>;
+
+    fn start_calendar_oauth(&self, provider: CalendarProvider) -> Result<String, crate::Error>;
+    fn calendar_access_token(
+        &self,
+        provider: CalendarProvider,
+    ) -> impl Future<Output = Result<Option<String>, crate::Error>>;
+    fn disconnect_calendar(&self, provider: CalendarProvider) -> Result<(), crate::Error>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> AuthPluginExt<R> for T {
@@ -121,6 +131,95 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> AuthPluginExt<R> for T {
 
         Ok(())
     }
+
+    // Returns the URL to open in the browser. The token lands in the vault once the user consents.
+    fn start_calendar_oauth(&self, provider: CalendarProvider) -> Result<String, crate::Error> {
+        let app = self.app_handle().clone();
+        let vault = self.state::<Vault>().inner().clone();
+
+        // The port is only known once the server is up, so the callback reads it from here.
+        let pending = std::sync::Arc::new(std::sync::Mutex::new(None::<PendingAuthorization>));
+
+        let port = tauri_plugin_oauth::start_with_config(
+            tauri_plugin_oauth::OauthConfig {
+                ports: None,
+                response: Some(calendar::CALLBACK_HTML.into()),
+            },
+            {
+                let pending = pending.clone();
+                move |u| {
+                    let Some(pending) = pending.lock().unwrap().take() else {
+                        return;
+                    };
+                    let app = app.clone();
+                    let vault = vault.clone();
+
+                    tauri::async_runtime::spawn(async move {
+                        let result = async {
+                            let url = url::Url::parse(&u)
+                                .map_err(|e| crate::Error::CalendarOAuth(e.to_string()))?;
+                            let params = serde_qs::from_str(url.query().unwrap_or(""))
+                                .map_err(|e| crate::Error::CalendarOAuth(e.to_string()))?;
+                            let token = pending.exchange(params).await?;
+                            vault.set(pending.provider.vault_key(), serde_json::to_string(&token)?)
+                        }
+                        .await;
+
+                        match result {
+                            Ok(_) => {
+                                AuthEvent::CalendarConnected(pending.provider)
+                                    .emit(&app)
+                                    .unwrap();
+                            }
+                            Err(err) => {
+                                tracing::error!(error = ?err, "calendar_oauth_failed");
+                                AuthEvent::Error(err.to_string()).emit(&app).unwrap();
+                            }
+                        }
+
+                        let _ = tauri_plugin_oauth::cancel(pending.redirect_port());
+                    });
+                }
+            },
+        )?;
+
+        let authorization = PendingAuthorization::new(provider, port);
+        let url = match authorization.authorize_url() {
+            Ok(url) => url,
+            Err(e) => {
+                let _ = tauri_plugin_oauth::cancel(port);
+                return Err(e);
+            }
+        };
+        pending.lock().unwrap().replace(authorization);
+
+        Ok(url)
+    }
+
+    // Refreshes and writes back the token when it is about to expire.
+    async fn calendar_access_token(
+        &self,
+        provider: CalendarProvider,
+    ) -> Result<Option<String>, crate::Error> {
+        let vault = self.state::<Vault>().inner().clone();
+        let Some(value) = vault.get(provider.vault_key())? else {
+            return Ok(None);
+        };
+
+        let token = CalendarToken::parse(&value);
+        if !token.is_expired() {
+            return Ok(Some(token.access_token));
+        }
+
+        let token = token.refresh(provider).await?;
+        vault.set(provider.vault_key(), serde_json::to_string(&token)?)?;
+        Ok(Some(token.access_token))
+    }
+
+    fn disconnect_calendar(&self, provider: CalendarProvider) -> Result<(), crate::Error> {
+        let vault = self.state::<Vault>();
+        vault.remove(provider.vault_key())
+    }
 }
 
 const KEYCHAIN_SAVE_PNG: &[u8] = include_bytes!("../assets/keychain_save.png");
@@ -1,5 +1,6 @@
 use tauri::Manager;
 
+mod calendar;
 mod commands;
 mod error;
 mod events;
@@ -7,6 +8,7 @@ mod ext;
 mod store;
 mod vault;
 
+pub use calendar::{CalendarProvider, CalendarToken};
 pub use error::*;
 pub use events::*;
 pub use ext::*;
@@ -33,6 +35,8 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::get_from_store::<tauri::Wry>,
             commands::set_in_vault::<tauri::Wry>,
             commands::set_in_store::<tauri::Wry>,
+            commands::start_calendar_oauth::<tauri::Wry>,
+            commands::disconnect_calendar::<tauri::Wry>,
         ])
         .typ::<RequestParams>()
         .typ::<ResponseParams>()
@@ -27,6 +27,14 @@ pub enum VaultKey {
     #[serde(rename = "twenty-api-key")]
     #[specta(rename = "twenty-api-key")]
     TwentyApiKey,
+    #[strum(serialize = "google-calendar-token")]
+    #[serde(rename = "google-calendar-token")]
+    #[specta(rename = "google-calendar-token")]
+    GoogleCalendarToken,
+    #[strum(serialize = "outlook-calendar-token")]
+    #[serde(rename = "outlook-calendar-token")]
+    #[specta(rename = "outlook-calendar-token")]
+    OutlookCalendarToken,
 }
 
 #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -37,6 +45,10 @@ pub struct VaultData {
     pub remote_server: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub twenty_api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_calendar_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outlook_calendar_token: Option<String>,
 }
 
 impl VaultData {
@@ -45,6 +57,8 @@ impl VaultData {
             VaultKey::RemoteDatabase => self.remote_database.clone(),
             VaultKey::RemoteServer => self.remote_server.clone(),
             VaultKey::TwentyApiKey => self.twenty_api_key.clone(),
+            VaultKey::GoogleCalendarToken => self.google_calendar_token.clone(),
+            VaultKey::OutlookCalendarToken => self.outlook_calendar_token.clone(),
         }
     }
 
@@ -53,6 +67,18 @@ impl VaultData {
             VaultKey::RemoteDatabase => self.remote_database = Some(value.into()),
             VaultKey::RemoteServer => self.remote_server = Some(value.into()),
             VaultKey::TwentyApiKey => self.twenty_api_key = Some(value.into()),
+            VaultKey::GoogleCalendarToken => self.google_calendar_token = Some(value.into()),
+            VaultKey::OutlookCalendarToken => self.outlook_calendar_token = Some(value.into()),
+        }
+    }
+
+    pub fn remove(&mut self, key: VaultKey) {
+        match key {
+            VaultKey::RemoteDatabase => self.remote_database = None,
+            VaultKey::RemoteServer => self.remote_server = None,
+            VaultKey::TwentyApiKey => self.twenty_api_key = None,
+            VaultKey::GoogleCalendarToken => self.google_calendar_token = None,
+            VaultKey::OutlookCalendarToken => self.outlook_calendar_token = None,
         }
     }
 }
@@ -93,6 +119,22 @@ impl Vault {
             .map_err(Into::into)
     }
 
+    pub fn remove(&self, key: VaultKey) -> Result<(), crate::Error> {
+        let guard = self.entry.lock().unwrap();
+        let entry = guard.as_ref().ok_or(crate::Error::VaultNotInitialized)?;
+
+        let mut v: VaultData = match entry.get_password() {
+            Ok(v) => serde_json::from_str(&v).unwrap_or_default(),
+            Err(keyring::Error::NoEntry) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        v.remove(key);
+
+        entry
+            .set_password(&serde_json::to_string(&v).unwrap())
+            .map_err(Into::into)
+    }
+
     pub fn clear(&self) -> Result<(), crate::Error> {
         let guard = self.entry.lock().unwrap();
         let entry = guard.as_ref().ok_or(crate::Error::VaultNotInitialized)?;
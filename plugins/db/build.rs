@@ -54,6 +54,16 @@ const COMMANDS: &[&str] = &[
     "list_session_tags",
     "assign_tag_to_session",
     "unassign_tag_from_session",
+    // action item
+    "list_action_items",
+    "upsert_action_item",
+    "set_action_item_done",
+    "delete_action_item",
+    "list_decisions",
+    "session_apply_extraction",
+    "list_bookmarks",
+    "upsert_bookmark",
+    "delete_bookmark",
 ];
 
 fn main() {
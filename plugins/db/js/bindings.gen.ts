@@ -147,6 +147,33 @@ async upsertTag(tag: Tag) : Promise<Tag> {
 },
 async deleteTag(tagId: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:db|delete_tag", { tagId });
+},
+async listActionItems(filter: ListActionItemFilter) : Promise<ActionItem[]> {
+    return await TAURI_INVOKE("plugin:db|list_action_items", { filter });
+},
+async upsertActionItem(item: ActionItem) : Promise<ActionItem> {
+    return await TAURI_INVOKE("plugin:db|upsert_action_item", { item });
+},
+async setActionItemDone(id: string, done: boolean) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|set_action_item_done", { id, done });
+},
+async deleteActionItem(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|delete_action_item", { id });
+},
+async listDecisions(sessionId: string) : Promise<Decision[]> {
+    return await TAURI_INVOKE("plugin:db|list_decisions", { sessionId });
+},
+async listBookmarks(sessionId: string) : Promise<Bookmark[]> {
+    return await TAURI_INVOKE("plugin:db|list_bookmarks", { sessionId });
+},
+async upsertBookmark(bookmark: Bookmark) : Promise<Bookmark> {
+    return await TAURI_INVOKE("plugin:db|upsert_bookmark", { bookmark });
+},
+async deleteBookmark(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|delete_bookmark", { id });
+},
+async sessionApplyExtraction(sessionId: string, extraction: SessionExtraction) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|session_apply_extraction", { sessionId, extraction });
 }
 }
 
@@ -160,6 +187,8 @@ async deleteTag(tagId: string) : Promise<null> {
 
 /** user-defined types **/
 
+export type ActionItem = { id: string; session_id: string; owner_id: string | null; text: string; due_date: string | null; source_quote: string | null; start_ms: number | null; end_ms: number | null; done: boolean; created_at: string }
+export type Bookmark = { id: string; session_id: string; label: string | null; offset_ms: number; created_at: string }
 export type Calendar = { id: string; tracking_id: string; user_id: string; platform: Platform; name: string; selected: boolean; source: string | null }
 export type ChatGroup = { id: string; user_id: string; name: string | null; created_at: string; session_id: string }
 export type ChatMessage = { id: string; group_id: string; created_at: string; role: ChatMessageRole; content: string; type: ChatMessageType }
@@ -167,18 +196,27 @@ export type ChatMessageRole = "User" | "Assistant"
 export type ChatMessageType = "text-delta" | "tool-start" | "tool-result" | "tool-error"
 export type Config = { id: string; user_id: string; general: ConfigGeneral; notification: ConfigNotification; ai: ConfigAI }
 export type ConfigAI = { api_base: string | null; api_key: string | null; ai_specificity: number | null; redemption_time_ms: number | null }
-export type ConfigGeneral = { autostart: boolean; display_language: string; spoken_languages?: string[]; jargons?: string[]; telemetry_consent: boolean; save_recordings: boolean | null; selected_template_id: string | null; summary_language?: string }
-export type ConfigNotification = { before: boolean; auto: boolean; ignoredPlatforms: string[] | null }
-export type Event = { id: string; user_id: string; tracking_id: string; calendar_id: string | null; name: string; note: string; start_date: string; end_date: string; google_event_url: string | null; participants: string | null; is_recurring: boolean }
+export type ConfigGeneral = { autostart: boolean; display_language: string; spoken_languages?: string[]; jargons?: string[]; telemetry_consent: boolean; save_recordings: boolean | null; selected_template_id: string | null; summary_language?: string; recording_format?: RecordingFormat; recording_tracks?: RecordingTracks; recording_bitrate_kbps?: number; noise_suppression?: NoiseSuppression }
+export type ConfigNotification = { before: boolean; auto: boolean; ignoredPlatforms: string[] | null; lead_minutes?: number[]; auto_start?: boolean }
+export type Decision = { id: string; session_id: string; kind: DecisionKind; text: string; source_quote: string | null; start_ms: number | null; end_ms: number | null; created_at: string }
+export type DecisionKind = "decision" | "open-question"
+export type Event = { id: string; user_id: string; tracking_id: string; calendar_id: string | null; name: string; note: string; start_date: string; end_date: string; google_event_url: string | null; participants: string | null; is_recurring: boolean; meeting_link: string | null }
+export type ExtractedActionItem = { owner: string | null; task: string; due: string | null; quote: string }
+export type ExtractedStatement = { text: string; quote: string }
 export type GetSessionFilter = { id: string } | { calendarEventId: string } | { tagId: string }
 export type Human = { id: string; organization_id: string | null; is_user: boolean; full_name: string | null; email: string | null; job_title: string | null; linkedin_username: string | null }
+export type ListActionItemFilter = { type: "session"; session_id: string } | { type: "open"; user_id: string; limit: number | null }
 export type ListEventFilter = ({ user_id: string; limit: number | null }) & ({ type: "simple" } | { type: "search"; query: string } | { type: "dateRange"; start: string; end: string } | { type: "not-assigned-past" })
 export type ListHumanFilter = { search: [number, string] }
 export type ListOrganizationFilter = { search: [number, string] }
 export type ListSessionFilter = ({ user_id: string; limit: number | null }) & ({ type: "search"; query: string } | { type: "recentlyVisited" } | { type: "dateRange"; start: string; end: string } | { type: "tagFilter"; tag_ids: string[] })
+export type NoiseSuppression = "off" | "low" | "medium" | "high"
 export type Organization = { id: string; name: string; description: string | null }
-export type Platform = "Apple" | "Google" | "Outlook"
+export type Platform = "Apple" | "Google" | "Outlook" | "CalDav"
+export type RecordingFormat = "wav" | "flac" | "opus"
+export type RecordingTracks = "mixed" | "stereo" | "separate"
 export type Session = { id: string; created_at: string; visited_at: string; user_id: string; calendar_event_id: string | null; title: string; raw_memo_html: string; enhanced_memo_html: string | null; words: Word2[]; record_start: string | null; record_end: string | null; pre_meeting_memo_html: string | null }
+export type SessionExtraction = { action_items: ExtractedActionItem[]; decisions: ExtractedStatement[]; open_questions: ExtractedStatement[] }
 export type SpeakerIdentity = { type: "unassigned"; value: { index: number } } | { type: "assigned"; value: { id: string; label: string } }
 export type Tag = { id: string; name: string }
 export type Template = { id: string; user_id: string; title: string; description: string; sections: TemplateSection[]; tags: string[] }
@@ -1,3 +1,5 @@
+pub mod action_items;
+pub mod bookmarks;
 pub mod calendars;
 pub mod chats;
 pub mod configs;
@@ -93,8 +93,9 @@ pub async fn get_words(
 
 #[tauri::command]
 #[specta::specta]
-#[tracing::instrument(skip(state))]
-pub async fn upsert_session(
+#[tracing::instrument(skip(app, state))]
+pub async fn upsert_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
     state: tauri::State<'_, crate::ManagedState>,
     session: hypr_db_user::Session,
 ) -> Result<hypr_db_user::Session, String> {
@@ -106,7 +107,31 @@ pub async fn upsert_session(
         .ok_or(crate::Error::NoneDatabase)
         .map_err(|e| e.to_string())?;
 
-    db.upsert_session(session).await.map_err(|e| e.to_string())
+    let (session, inserted) = db
+        .upsert_session_inserted(session)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if session.id != db.onboarding_session_id() && session.id != db.thank_you_session_id() {
+        use tauri_plugin_webhook::{NoteEvent, WebhookEventType, WebhookPluginExt};
+
+        let event = NoteEvent {
+            note_id: session.id.clone(),
+            title: session.title.clone(),
+            content: match &session.enhanced_memo_html {
+                Some(html) if !html.trim().is_empty() => html.clone(),
+                _ => session.raw_memo_html.clone(),
+            },
+        };
+
+        if inserted {
+            app.webhook_emit(WebhookEventType::NoteCreated, event);
+        } else {
+            app.webhook_emit_debounced(session.id.clone(), WebhookEventType::NoteUpdated, event);
+        }
+    }
+
+    Ok(session)
 }
 
 #[tauri::command]
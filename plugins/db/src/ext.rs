@@ -27,6 +27,11 @@ pub trait DatabasePluginExt<R: tauri::Runtime> {
     ) -> impl Future<Output = Result<(), crate::Error>>;
 
     fn db_onboarding_session_id(&self) -> impl Future<Output = Result<String, crate::Error>>;
+
+    fn db_upsert_bookmark(
+        &self,
+        bookmark: hypr_db_user::Bookmark,
+    ) -> impl Future<Output = Result<hypr_db_user::Bookmark, crate::Error>>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
@@ -142,4 +147,15 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
         let id = db.onboarding_session_id();
         Ok(id)
     }
+
+    async fn db_upsert_bookmark(
+        &self,
+        bookmark: hypr_db_user::Bookmark,
+    ) -> Result<hypr_db_user::Bookmark, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        Ok(db.upsert_bookmark(bookmark).await?)
+    }
 }
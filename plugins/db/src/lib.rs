@@ -28,7 +28,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::calendars::list_calendars,
             commands::calendars::upsert_calendar,
             commands::calendars::toggle_calendar_selected,
-            commands::sessions::upsert_session,
+            commands::sessions::upsert_session::<tauri::Wry>,
             commands::sessions::visit_session,
             commands::templates::list_templates,
             commands::templates::upsert_template,
@@ -69,6 +69,15 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::tags::unassign_tag_from_session,
             commands::tags::upsert_tag,
             commands::tags::delete_tag,
+            commands::action_items::list_action_items,
+            commands::action_items::upsert_action_item,
+            commands::action_items::set_action_item_done,
+            commands::action_items::delete_action_item,
+            commands::action_items::list_decisions,
+            commands::action_items::session_apply_extraction,
+            commands::bookmarks::list_bookmarks,
+            commands::bookmarks::upsert_bookmark,
+            commands::bookmarks::delete_bookmark,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
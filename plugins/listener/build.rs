@@ -16,7 +16,10 @@ const COMMANDS: &[&str] = &[
     "stop_session",
     "pause_session",
     "resume_session",
+    "add_bookmark",
     "get_state",
+    "import_audio",
+    "convert_recordings",
 ];
 
 fn main() {
@@ -13,7 +13,7 @@ async listMicrophoneDevices() : Promise<string[]> {
 async getCurrentMicrophoneDevice() : Promise<string | null> {
     return await TAURI_INVOKE("plugin:listener|get_current_microphone_device");
 },
-async setMicrophoneDevice(deviceName: string) : Promise<null> {
+async setMicrophoneDevice(deviceName: string | null) : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|set_microphone_device", { deviceName });
 },
 async checkMicrophoneAccess() : Promise<boolean> {
@@ -46,8 +46,8 @@ async getSpeakerMuted() : Promise<boolean> {
 async setSpeakerMuted(muted: boolean) : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|set_speaker_muted", { muted });
 },
-async startSession(sessionId: string) : Promise<null> {
-    return await TAURI_INVOKE("plugin:listener|start_session", { sessionId });
+async startSession(sessionId: string, noiseSuppression: NoiseSuppression | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|start_session", { sessionId, noiseSuppression });
 },
 async stopSession() : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|stop_session");
@@ -58,8 +58,17 @@ async pauseSession() : Promise<null> {
 async resumeSession() : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|resume_session");
 },
+async addBookmark(label: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|add_bookmark", { label });
+},
 async getState() : Promise<string> {
     return await TAURI_INVOKE("plugin:listener|get_state");
+},
+async importAudio(path: string) : Promise<ImportedSession> {
+    return await TAURI_INVOKE("plugin:listener|import_audio", { path });
+}
+async convertRecordings() : Promise<string> {
+    return await TAURI_INVOKE("plugin:listener|convert_recordings");
 }
 }
 
@@ -78,7 +87,10 @@ sessionEvent: "plugin:listener:session-event"
 
 /** user-defined types **/
 
-export type SessionEvent = { type: "inactive" } | { type: "running_active" } | { type: "running_paused" } | { type: "finalWords"; words: Word2[] } | { type: "partialWords"; words: Word2[] } | { type: "audioAmplitude"; mic: number; speaker: number } | { type: "micMuted"; value: boolean } | { type: "speakerMuted"; value: boolean }
+export type ImportedSession = { session_id: string; task_id: string }
+export type NoiseSuppression = "off" | "low" | "medium" | "high"
+export type SessionErrorKind = "mic_permission_denied" | "device_missing" | "stt_connection_failed" | "model_not_downloaded" | "db_write_failed" | "other"
+export type SessionEvent = { type: "inactive" } | { type: "running_active" } | { type: "running_paused" } | { type: "finalWords"; words: Word2[] } | { type: "partialWords"; words: Word2[] } | { type: "audioAmplitude"; mic: number; speaker: number } | { type: "micMuted"; value: boolean } | { type: "speakerMuted"; value: boolean } | { type: "reconnecting"; attempt: number; retry_in_ms: number } | { type: "reconnected" } | { type: "degraded"; dropped_ms: number } | { type: "bookmarked"; id: string; label: string | null; offset_ms: number } | { type: "error"; kind: SessionErrorKind; message: string; recoverable: boolean }
 export type SpeakerIdentity = { type: "unassigned"; value: { index: number } } | { type: "assigned"; value: { id: string; label: string } }
 export type Word2 = { text: string; speaker: SpeakerIdentity | null; confidence: number | null; start_ms: number | null; end_ms: number | null }
 
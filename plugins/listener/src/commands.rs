@@ -1,4 +1,4 @@
-use crate::ListenerPluginExt;
+use crate::{ImportedSession, ListenerPluginExt};
 
 #[tauri::command]
 #[specta::specta]
@@ -24,7 +24,7 @@ pub async fn get_current_microphone_device<R: tauri::Runtime>(
 #[specta::specta]
 pub async fn set_microphone_device<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
-    device_name: String,
+    device_name: Option<String>,
 ) -> Result<(), String> {
     app.set_microphone_device(device_name)
         .await
@@ -130,8 +130,9 @@ pub async fn set_speaker_muted<R: tauri::Runtime>(
 pub async fn start_session<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     session_id: String,
+    noise_suppression: Option<hypr_db_user::NoiseSuppression>,
 ) -> Result<(), String> {
-    app.start_session(session_id).await;
+    app.start_session(session_id, noise_suppression).await;
     match app.get_state().await {
         crate::fsm::State::RunningActive { .. } => Ok(()),
         _ => Err(crate::Error::StartSessionFailed.to_string()),
@@ -168,6 +169,15 @@ pub async fn resume_session<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Resu
     }
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn add_bookmark<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    label: Option<String>,
+) -> Result<(), String> {
+    app.add_bookmark(label).await.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_state<R: tauri::Runtime>(
@@ -175,3 +185,20 @@ pub async fn get_state<R: tauri::Runtime>(
 ) -> Result<crate::fsm::State, String> {
     Ok(app.get_state().await)
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn import_audio<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+) -> Result<ImportedSession, String> {
+    app.import_audio(path).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn convert_recordings<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<String, String> {
+    app.convert_recordings().await.map_err(|e| e.to_string())
+}
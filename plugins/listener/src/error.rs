@@ -9,6 +9,16 @@ pub enum Error {
     #[error(transparent)]
     HyprAudioError(#[from] hypr_audio::Error),
     #[error(transparent)]
+    AudioUtilsError(#[from] hypr_audio_utils::Error),
+    #[error(transparent)]
+    AudioCodecError(#[from] hypr_audio_codec::Error),
+    #[error(transparent)]
+    AecError(#[from] hypr_aec::Error),
+    #[error(transparent)]
+    WavError(#[from] hound::Error),
+    #[error(transparent)]
+    TauriError(#[from] tauri::Error),
+    #[error(transparent)]
     CpalDevicesError(#[from] hypr_audio::cpal::DevicesError),
     #[error(transparent)]
     DatabaseError(#[from] tauri_plugin_db::Error),
@@ -16,8 +26,18 @@ pub enum Error {
     LocalSttError(#[from] tauri_plugin_local_stt::Error),
     #[error(transparent)]
     ConnectorError(#[from] tauri_plugin_connector::Error),
+    #[error(transparent)]
+    JoinError(#[from] tokio::task::JoinError),
     #[error("no session")]
     NoneSession,
+    #[error("no user")]
+    NoneUser,
+    #[error("microphone access denied")]
+    MicPermissionDenied,
+    #[error("recordings are set to be saved as WAV")]
+    UncompressedRecordingFormat,
+    #[error("unsupported audio file: {0}")]
+    UnsupportedAudioFile(String),
     #[error("start session failed")]
     StartSessionFailed,
     #[error("stop session failed")]
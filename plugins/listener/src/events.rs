@@ -25,6 +25,55 @@ common_event_derives! {
         MicMuted { value: bool },
         #[serde(rename = "speakerMuted")]
         SpeakerMuted { value: bool },
+        #[serde(rename = "reconnecting")]
+        Reconnecting { attempt: u32, retry_in_ms: u64 },
+        #[serde(rename = "reconnected")]
+        Reconnected {},
+        #[serde(rename = "degraded")]
+        Degraded { dropped_ms: u64 },
+        #[serde(rename = "bookmarked")]
+        Bookmarked { id: String, label: Option<String>, offset_ms: u64 },
+        #[serde(rename = "error")]
+        Error { kind: SessionErrorKind, message: String, recoverable: bool },
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionErrorKind {
+    MicPermissionDenied,
+    DeviceMissing,
+    SttConnectionFailed,
+    ModelNotDownloaded,
+    DbWriteFailed,
+    Other,
+}
+
+impl From<&crate::Error> for SessionErrorKind {
+    fn from(error: &crate::Error) -> Self {
+        use crate::Error;
+
+        match error {
+            Error::MicPermissionDenied => Self::MicPermissionDenied,
+            Error::HyprAudioError(_) | Error::CpalDevicesError(_) => Self::DeviceMissing,
+            Error::LocalSttError(tauri_plugin_local_stt::Error::ModelNotDownloaded) => {
+                Self::ModelNotDownloaded
+            }
+            Error::LocalSttError(_) | Error::ConnectorError(_) => Self::SttConnectionFailed,
+            Error::DatabaseError(_) | Error::NoneUser | Error::NoneSession => Self::DbWriteFailed,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl SessionEvent {
+    // A session that failed to start is left inactive, so this is never recoverable.
+    pub(crate) fn from_setup_error(error: &crate::Error) -> Self {
+        Self::Error {
+            kind: error.into(),
+            message: error.to_string(),
+            recoverable: false,
+        }
     }
 }
 
@@ -53,3 +102,68 @@ impl From<(&Vec<f32>, &Vec<f32>)> for SessionEvent {
         Self::from((mic_chunk.as_slice(), speaker_chunk.as_slice()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Error;
+
+    #[test]
+    fn test_session_error_kind() {
+        let cases = [
+            (
+                Error::MicPermissionDenied,
+                SessionErrorKind::MicPermissionDenied,
+            ),
+            (
+                Error::HyprAudioError(hypr_audio::Error::NoInputDevice),
+                SessionErrorKind::DeviceMissing,
+            ),
+            (
+                Error::LocalSttError(tauri_plugin_local_stt::Error::ModelNotDownloaded),
+                SessionErrorKind::ModelNotDownloaded,
+            ),
+            (
+                Error::LocalSttError(tauri_plugin_local_stt::Error::AmApiKeyNotSet),
+                SessionErrorKind::SttConnectionFailed,
+            ),
+            (
+                Error::ConnectorError(tauri_plugin_connector::Error::NoModelsFound),
+                SessionErrorKind::SttConnectionFailed,
+            ),
+            (
+                Error::DatabaseError(tauri_plugin_db::Error::NoneDatabase),
+                SessionErrorKind::DbWriteFailed,
+            ),
+            (Error::NoneUser, SessionErrorKind::DbWriteFailed),
+            (Error::NoneSession, SessionErrorKind::DbWriteFailed),
+            (
+                Error::IoError(std::io::Error::other("disk full")),
+                SessionErrorKind::Other,
+            ),
+            (Error::StartSessionFailed, SessionErrorKind::Other),
+        ];
+
+        for (error, kind) in cases {
+            assert_eq!(SessionErrorKind::from(&error), kind, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_from_setup_error() {
+        let event = SessionEvent::from_setup_error(&Error::LocalSttError(
+            tauri_plugin_local_stt::Error::ModelNotDownloaded,
+        ));
+
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "type": "error",
+                "kind": "model_not_downloaded",
+                "message": "Model not downloaded",
+                "recoverable": false,
+            })
+        );
+    }
+}
@@ -1,6 +1,11 @@
-use std::future::Future;
+use std::{future::Future, path::PathBuf};
 
 use futures_util::StreamExt;
+use tauri_plugin_db::DatabasePluginExt;
+use tauri_plugin_local_stt::{LocalSttPluginExt, SupportedSttModel};
+use tauri_plugin_task::TaskPluginExt;
+
+use crate::{recording::RecordingConfig, ImportedSession};
 
 #[cfg(target_os = "macos")]
 use {
@@ -15,7 +20,7 @@ pub trait ListenerPluginExt<R: tauri::Runtime> {
     ) -> impl Future<Output = Result<Option<String>, crate::Error>>;
     fn set_microphone_device(
         &self,
-        device_name: impl Into<String>,
+        device_name: Option<String>,
     ) -> impl Future<Output = Result<(), crate::Error>>;
 
     fn check_microphone_access(&self) -> impl Future<Output = Result<bool, crate::Error>>;
@@ -32,9 +37,21 @@ pub trait ListenerPluginExt<R: tauri::Runtime> {
 
     fn get_state(&self) -> impl Future<Output = crate::fsm::State>;
     fn stop_session(&self) -> impl Future<Output = ()>;
-    fn start_session(&self, id: impl Into<String>) -> impl Future<Output = ()>;
+    fn start_session(
+        &self,
+        id: impl Into<String>,
+        noise_suppression: Option<hypr_db_user::NoiseSuppression>,
+    ) -> impl Future<Output = ()>;
     fn pause_session(&self) -> impl Future<Output = ()>;
     fn resume_session(&self) -> impl Future<Output = ()>;
+    fn add_bookmark(&self, label: Option<String>)
+        -> impl Future<Output = Result<(), crate::Error>>;
+
+    fn import_audio(
+        &self,
+        path: impl Into<PathBuf>,
+    ) -> impl Future<Output = Result<ImportedSession, crate::Error>>;
+    fn convert_recordings(&self) -> impl Future<Output = Result<String, crate::Error>>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
@@ -51,15 +68,12 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
     }
 
     #[tracing::instrument(skip_all)]
-    async fn set_microphone_device(
-        &self,
-        device_name: impl Into<String>,
-    ) -> Result<(), crate::Error> {
+    async fn set_microphone_device(&self, device_name: Option<String>) -> Result<(), crate::Error> {
         let state = self.state::<crate::SharedState>();
 
         {
             let mut guard = state.lock().await;
-            let event = crate::fsm::StateEvent::MicChange(Some(device_name.into()));
+            let event = crate::fsm::StateEvent::MicChange(device_name);
             guard.fsm.handle(&event).await;
         }
 
@@ -151,7 +165,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
 
         let stop = hypr_audio::AudioOutput::silence();
 
-        let mut speaker_sample_stream = hypr_audio::AudioInput::from_speaker().stream();
+        let mut speaker_sample_stream = hypr_audio::AudioInput::from_speaker()?.stream();
         speaker_sample_stream.next().await;
 
         let _ = stop.send(());
@@ -226,12 +240,19 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
     }
 
     #[tracing::instrument(skip_all)]
-    async fn start_session(&self, session_id: impl Into<String>) {
+    async fn start_session(
+        &self,
+        session_id: impl Into<String>,
+        noise_suppression: Option<hypr_db_user::NoiseSuppression>,
+    ) {
         let state = self.state::<crate::SharedState>();
 
         {
             let mut guard = state.lock().await;
-            let event = crate::fsm::StateEvent::Start(session_id.into());
+            let event = crate::fsm::StateEvent::Start {
+                id: session_id.into(),
+                noise_suppression,
+            };
             guard.fsm.handle(&event).await;
         }
     }
@@ -268,4 +289,147 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
             guard.fsm.handle(&event).await;
         }
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn add_bookmark(&self, label: Option<String>) -> Result<(), crate::Error> {
+        let state = self.state::<crate::SharedState>();
+        let (reply, result) = flume::bounded(1);
+
+        {
+            let mut guard = state.lock().await;
+            let event = crate::fsm::StateEvent::Bookmark { label, reply };
+            guard.fsm.handle(&event).await;
+        }
+
+        result
+            .recv_async()
+            .await
+            .unwrap_or(Err(crate::Error::NoneSession))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn import_audio(
+        &self,
+        path: impl Into<PathBuf>,
+    ) -> Result<ImportedSession, crate::Error> {
+        let path = path.into();
+        if !crate::import::is_supported(&path) {
+            return Err(crate::Error::UnsupportedAudioFile(
+                path.display().to_string(),
+            ));
+        }
+
+        // Imports are transcribed offline, so fail before decoding anything.
+        let model = self.get_current_model()?;
+        if !matches!(model, SupportedSttModel::Whisper(_)) {
+            return Err(tauri_plugin_local_stt::Error::UnsupportedModelType.into());
+        }
+        if !self.is_model_downloaded(&model).await? {
+            return Err(tauri_plugin_local_stt::Error::ModelNotDownloaded.into());
+        }
+
+        let user_id = self.db_user_id().await?.ok_or(crate::Error::NoneUser)?;
+        let session_id = uuid::Uuid::new_v4().to_string();
+        let session_dir = self.path().app_data_dir()?.join(&session_id);
+
+        let duration = tauri::async_runtime::spawn_blocking({
+            let path = path.clone();
+            let session_dir = session_dir.clone();
+            move || crate::import::write_session_audio(&path, &session_dir)
+        })
+        .await?;
+        let duration = match duration {
+            Ok(duration) => duration,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&session_dir);
+                return Err(e);
+            }
+        };
+
+        let (record_start, record_end) = crate::import::recorded_range(&path, duration);
+        let title = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        self.db_upsert_session(hypr_db_user::Session {
+            id: session_id.clone(),
+            created_at: record_start,
+            visited_at: chrono::Utc::now(),
+            user_id,
+            calendar_event_id: None,
+            title,
+            raw_memo_html: String::new(),
+            enhanced_memo_html: None,
+            conversations: vec![],
+            words: vec![],
+            record_start: Some(record_start),
+            record_end: Some(record_end),
+            pre_meeting_memo_html: None,
+        })
+        .await?;
+
+        let task_id = self
+            .retranscribe_session(session_id.clone(), model, true)
+            .await?;
+
+        Ok(ImportedSession {
+            session_id,
+            task_id,
+        })
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn convert_recordings(&self) -> Result<String, crate::Error> {
+        let user_id = self.db_user_id().await?.ok_or(crate::Error::NoneUser)?;
+        let encoding = self
+            .db_get_config(&user_id)
+            .await?
+            .map_or_else(RecordingConfig::default, |c| {
+                RecordingConfig::from_config(&c.general)
+            })
+            .encoding
+            .ok_or(crate::Error::UncompressedRecordingFormat)?;
+
+        // The session being recorded is encoded when it stops.
+        let recording_session_id = {
+            let state = self.state::<crate::SharedState>();
+            let guard = state.lock().await;
+            guard.fsm.get_current_session_id()
+        };
+
+        let dirs = std::fs::read_dir(self.path().app_data_dir()?)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|dir| dir.is_dir() && crate::recording::has_wav_tracks(dir))
+            .filter(|dir| {
+                recording_session_id.as_deref() != dir.file_name().and_then(|name| name.to_str())
+            })
+            .collect::<Vec<_>>();
+
+        let task_id = self.spawn_task_blocking(move |mut ctx| {
+            let _ = ctx.set_total(dirs.len() as u32);
+
+            let mut failed = 0;
+            for dir in dirs {
+                if ctx.is_cancelled() {
+                    return;
+                }
+
+                if let Err(e) = crate::recording::encode_session(&dir, encoding) {
+                    tracing::error!("failed_to_convert_recording: {:?} {:?}", dir, e);
+                    failed += 1;
+                }
+                let _ = ctx.advance(());
+            }
+
+            if failed == 0 {
+                let _ = ctx.complete();
+            } else {
+                let _ = ctx.fail(format!("{} recordings could not be converted", failed));
+            }
+        });
+
+        Ok(task_id)
+    }
 }
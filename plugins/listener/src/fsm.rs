@@ -1,5 +1,11 @@
 use statig::prelude::*;
-use std::time::{Duration, Instant};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 use tauri::Manager;
 use tauri_specta::Event;
@@ -8,14 +14,17 @@ use futures_util::StreamExt;
 use tokio::task::JoinSet;
 
 use hypr_audio::AsyncSource;
+use hypr_db_user::{NoiseSuppression, RecordingTracks};
 
-use crate::{manager::TranscriptManager, SessionEvent};
+use crate::{
+    recording::{RecordingConfig, MIC_TRACK, MIXED_TRACK, SPEAKER_TRACK},
+    SessionEvent,
+};
 
-const SAMPLE_RATE: u32 = 16000;
+pub(crate) const SAMPLE_RATE: u32 = 16000;
 const AUDIO_AMPLITUDE_THROTTLE: Duration = Duration::from_millis(100);
-const LISTEN_STREAM_TIMEOUT: Duration = Duration::from_secs(60 * 15);
 
-const WAV_SPEC: hound::WavSpec = hound::WavSpec {
+pub(crate) const WAV_SPEC: hound::WavSpec = hound::WavSpec {
     channels: 1,
     sample_rate: SAMPLE_RATE,
     bits_per_sample: 32,
@@ -30,20 +39,23 @@ impl AudioSaver {
         session_id: &str,
         app_dir: &std::path::Path,
         filename: &str,
+        spec: hound::WavSpec,
         append: bool,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let dir = app_dir.join(session_id);
         std::fs::create_dir_all(&dir)?;
         let path = dir.join(filename);
 
+        // Resuming keeps the layout the session was first recorded in.
         let mut wav = if append && path.exists() {
             hound::WavWriter::append(path)?
         } else {
-            hound::WavWriter::create(path, WAV_SPEC)?
+            hound::WavWriter::create(path, spec)?
         };
+        let channels = wav.spec().channels;
 
         while let Ok(chunk) = rx.recv_async().await {
-            for sample in chunk {
+            for sample in hypr_audio_codec::remix(&chunk, spec.channels, channels) {
                 wav.write_sample(sample)?;
             }
         }
@@ -60,6 +72,10 @@ struct AudioChannels {
     speaker_rx: flume::Receiver<Vec<f32>>,
     save_mixed_tx: flume::Sender<Vec<f32>>,
     save_mixed_rx: flume::Receiver<Vec<f32>>,
+    save_mic_tx: Option<flume::Sender<Vec<f32>>>,
+    save_mic_rx: Option<flume::Receiver<Vec<f32>>>,
+    save_speaker_tx: Option<flume::Sender<Vec<f32>>>,
+    save_speaker_rx: Option<flume::Receiver<Vec<f32>>>,
     save_mic_raw_tx: Option<flume::Sender<Vec<f32>>>,
     save_mic_raw_rx: Option<flume::Receiver<Vec<f32>>>,
     save_speaker_raw_tx: Option<flume::Sender<Vec<f32>>>,
@@ -71,7 +87,7 @@ struct AudioChannels {
 }
 
 impl AudioChannels {
-    fn new() -> Self {
+    fn new(separate_tracks: bool) -> Self {
         const CHUNK_BUFFER_SIZE: usize = 64;
 
         let (mic_tx, mic_rx) = flume::bounded::<Vec<f32>>(CHUNK_BUFFER_SIZE);
@@ -81,6 +97,20 @@ impl AudioChannels {
         let (process_speaker_tx, process_speaker_rx) =
             flume::bounded::<Vec<f32>>(CHUNK_BUFFER_SIZE);
 
+        let (save_mic_tx, save_mic_rx) = if separate_tracks {
+            let (tx, rx) = flume::bounded::<Vec<f32>>(CHUNK_BUFFER_SIZE);
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
+        let (save_speaker_tx, save_speaker_rx) = if separate_tracks {
+            let (tx, rx) = flume::bounded::<Vec<f32>>(CHUNK_BUFFER_SIZE);
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
         let (save_mic_raw_tx, save_mic_raw_rx) = if cfg!(debug_assertions) {
             let (tx, rx) = flume::bounded::<Vec<f32>>(CHUNK_BUFFER_SIZE);
             (Some(tx), Some(rx))
@@ -102,6 +132,10 @@ impl AudioChannels {
             speaker_rx,
             save_mixed_tx,
             save_mixed_rx,
+            save_mic_tx,
+            save_mic_rx,
+            save_speaker_tx,
+            save_speaker_rx,
             save_mic_raw_tx,
             save_mic_raw_rx,
             save_speaker_raw_tx,
@@ -113,32 +147,6 @@ impl AudioChannels {
         }
     }
 
-    async fn process_mic_stream(
-        mut mic_stream: impl futures_util::Stream<Item = Vec<f32>> + Unpin,
-        mic_muted_rx: tokio::sync::watch::Receiver<bool>,
-        mic_tx: flume::Sender<Vec<f32>>,
-    ) {
-        let mut is_muted = *mic_muted_rx.borrow();
-        let watch_rx = mic_muted_rx.clone();
-
-        while let Some(actual) = mic_stream.next().await {
-            if watch_rx.has_changed().unwrap_or(false) {
-                is_muted = *watch_rx.borrow();
-            }
-
-            let maybe_muted = if is_muted {
-                vec![0.0; actual.len()]
-            } else {
-                actual
-            };
-
-            if let Err(e) = mic_tx.send_async(maybe_muted).await {
-                tracing::error!("mic_tx_send_error: {:?}", e);
-                break;
-            }
-        }
-    }
-
     async fn process_speaker_stream(
         mut speaker_stream: impl futures_util::Stream<Item = Vec<f32>> + Unpin,
         speaker_muted_rx: tokio::sync::watch::Receiver<bool>,
@@ -166,27 +174,51 @@ impl AudioChannels {
     }
 }
 
-pub struct Session {
-    app: tauri::AppHandle,
+// The tray and windows plugins only exist for the real runtime, so tests stub them out.
+pub trait SessionRuntime: tauri::Runtime {
+    fn set_start_disabled(app: &tauri::AppHandle<Self>, disabled: bool);
+    fn hide_control_window(app: &tauri::AppHandle<Self>);
+}
+
+impl SessionRuntime for tauri::Wry {
+    fn set_start_disabled(app: &tauri::AppHandle<Self>, disabled: bool) {
+        use tauri_plugin_tray::TrayPluginExt;
+        let _ = app.set_start_disabled(disabled);
+    }
+
+    fn hide_control_window(app: &tauri::AppHandle<Self>) {
+        use tauri_plugin_windows::{HyprWindow, WindowsPluginExt};
+        let _ = app.window_hide(HyprWindow::Control);
+    }
+}
+
+pub struct Session<R: SessionRuntime = tauri::Wry> {
+    app: tauri::AppHandle<R>,
     session_id: Option<String>,
+    // `None` follows the system default.
     mic_device_name: Option<String>,
+    mic_device_tx: Option<tokio::sync::watch::Sender<Option<String>>>,
     mic_muted_tx: Option<tokio::sync::watch::Sender<bool>>,
     mic_muted_rx: Option<tokio::sync::watch::Receiver<bool>>,
     speaker_muted_tx: Option<tokio::sync::watch::Sender<bool>>,
     speaker_muted_rx: Option<tokio::sync::watch::Receiver<bool>>,
     silence_stream_tx: Option<std::sync::mpsc::Sender<()>>,
     session_state_tx: Option<tokio::sync::watch::Sender<State>>,
+    recording: Option<RecordingConfig>,
+    // Keeps the session's recording from being encoded while the savers write to it.
+    recording_guard: Option<tokio::sync::OwnedMutexGuard<()>>,
+    // Position in the session's audio in samples, counting what earlier runs recorded.
+    timeline: Option<Arc<AtomicU64>>,
     tasks: Option<JoinSet<()>>,
 }
 
-impl Session {
-    pub fn new(app: tauri::AppHandle) -> Self {
-        let mic_device_name = hypr_audio::AudioInput::get_default_mic_device_name();
-
+impl<R: SessionRuntime> Session<R> {
+    pub fn new(app: tauri::AppHandle<R>) -> Self {
         Self {
             app,
             session_id: None,
-            mic_device_name: Some(mic_device_name),
+            mic_device_name: None,
+            mic_device_tx: None,
             mic_muted_tx: None,
             mic_muted_rx: None,
             speaker_muted_tx: None,
@@ -194,33 +226,64 @@ impl Session {
             silence_stream_tx: None,
             tasks: None,
             session_state_tx: None,
+            recording: None,
+            recording_guard: None,
+            timeline: None,
         }
     }
 
     #[tracing::instrument(skip_all)]
-    async fn setup_resources(&mut self, id: impl Into<String>) -> Result<(), crate::Error> {
+    async fn setup_resources(
+        &mut self,
+        id: impl Into<String>,
+        noise_suppression: Option<NoiseSuppression>,
+    ) -> Result<(), crate::Error> {
         use tauri_plugin_db::DatabasePluginExt;
 
         let session_id = id.into();
         let onboarding_session_id = self.app.db_onboarding_session_id().await?;
 
-        let user_id = self.app.db_user_id().await?.unwrap();
+        let user_id = self.app.db_user_id().await?.ok_or(crate::Error::NoneUser)?;
         self.session_id = Some(session_id.clone());
 
-        let (record, languages) = {
+        let (recording, languages, jargons, noise_suppression) = {
             let config = self.app.db_get_config(&user_id).await?;
 
-            let record = config
+            let recording = config
                 .as_ref()
-                .is_none_or(|c| c.general.save_recordings.unwrap_or(true));
+                .is_none_or(|c| c.general.save_recordings.unwrap_or(true))
+                .then(|| {
+                    config.as_ref().map_or_else(RecordingConfig::default, |c| {
+                        RecordingConfig::from_config(&c.general)
+                    })
+                });
 
             let languages = config.as_ref().map_or_else(
                 || vec![hypr_language::ISO639::En.into()],
                 |c| c.general.spoken_languages.clone(),
             );
 
-            (record, languages)
+            let jargons = config
+                .as_ref()
+                .map_or_else(Vec::new, |c| c.general.jargons.clone());
+
+            let noise_suppression = noise_suppression.unwrap_or_else(|| {
+                config.as_ref().map_or_else(NoiseSuppression::default, |c| {
+                    c.general.noise_suppression.clone()
+                })
+            });
+
+            (recording, languages, jargons, noise_suppression)
         };
+        let record = recording.is_some();
+        let tracks = recording
+            .as_ref()
+            .map(|recording| recording.tracks.clone())
+            .unwrap_or_default();
+        let mixed_spec = recording
+            .as_ref()
+            .map_or(WAV_SPEC, |recording| recording.mixed_spec());
+        self.recording = recording;
 
         let session = self
             .app
@@ -233,35 +296,81 @@ impl Session {
         let (session_state_tx, session_state_rx) =
             tokio::sync::watch::channel(State::RunningActive {});
 
-        let (stop_tx, mut stop_rx) = tokio::sync::mpsc::channel::<()>(1);
-
         self.mic_muted_tx = Some(mic_muted_tx);
         self.mic_muted_rx = Some(mic_muted_rx_main.clone());
         self.speaker_muted_tx = Some(speaker_muted_tx);
         self.speaker_muted_rx = Some(speaker_muted_rx_main.clone());
+
+        // Waits for an encode of the previous run to finish, before reading what it left.
+        let session_dir = self.app.path().app_data_dir()?.join(&session_id);
+        self.recording_guard = Some(
+            crate::recording::session_lock(&session_dir)
+                .lock_owned()
+                .await,
+        );
+
+        // A resumed session continues after the audio it already has.
+        let recorded = {
+            let dir = session_dir.clone();
+            tokio::task::spawn_blocking(move || {
+                crate::recording::recorded_samples(&dir, SAMPLE_RATE)
+            })
+            .await?
+            .unwrap_or_else(|e| {
+                tracing::error!("failed_to_read_recorded_audio: {:?}", e);
+                0
+            })
+        };
+        let transcribed = session
+            .words
+            .iter()
+            .filter_map(|word| word.end_ms)
+            .max()
+            .unwrap_or(0)
+            * SAMPLE_RATE as u64
+            / 1000;
+        let start = recorded.max(transcribed);
+        let timeline = Arc::new(AtomicU64::new(start));
+        self.timeline = Some(timeline.clone());
         self.session_state_tx = Some(session_state_tx);
 
-        let listen_client =
-            setup_listen_client(&self.app, languages, session_id == onboarding_session_id).await?;
-        let mic_sample_stream = {
-            let mut input = hypr_audio::AudioInput::from_mic(self.mic_device_name.clone())?;
-            input.stream()
+        let corrector = hypr_vocabulary::Corrector::new(&jargons);
+        let listen_client = setup_listen_client(
+            &self.app,
+            languages,
+            jargons,
+            session_id == onboarding_session_id,
+        )
+        .await?;
+
+        #[cfg(target_os = "macos")]
+        {
+            use crate::ListenerPluginExt;
+
+            if !self.app.check_microphone_access().await? {
+                return Err(crate::Error::MicPermissionDenied);
+            }
+        }
+
+        let mic = {
+            let device_name = self.mic_device_name.clone();
+            tokio::task::spawn_blocking(move || crate::mic::MicCapture::open(device_name)).await??
         };
-        let mic_stream = mic_sample_stream
-            .resample(SAMPLE_RATE)
-            .chunks(hypr_aec::BLOCK_SIZE);
+        let (mic_device_tx, mic_device_rx) =
+            tokio::sync::watch::channel(self.mic_device_name.clone());
+        self.mic_device_tx = Some(mic_device_tx);
 
         // https://github.com/fastrepl/hyprnote/commit/7c8cf1c
         tokio::time::sleep(Duration::from_millis(65)).await;
         // We need some delay here for Airpod transition.
         // But if the delay is too long, AEC will not work.
 
-        let speaker_sample_stream = hypr_audio::AudioInput::from_speaker().stream();
+        let speaker_sample_stream = hypr_audio::AudioInput::from_speaker()?.stream();
         let speaker_stream = speaker_sample_stream
             .resample(SAMPLE_RATE)
             .chunks(hypr_aec::BLOCK_SIZE);
 
-        let channels = AudioChannels::new();
+        let channels = AudioChannels::new(tracks == RecordingTracks::Separate);
 
         {
             let silence_stream_tx = hypr_audio::AudioOutput::silence();
@@ -270,8 +379,10 @@ impl Session {
 
         let mut tasks = JoinSet::new();
 
-        tasks.spawn(AudioChannels::process_mic_stream(
-            mic_stream,
+        tasks.spawn(crate::mic::capture(
+            crate::mic::SystemMic,
+            mic,
+            mic_device_rx,
             mic_muted_rx_main.clone(),
             channels.mic_tx.clone(),
         ));
@@ -282,20 +393,28 @@ impl Session {
             channels.speaker_tx.clone(),
         ));
 
-        let app_dir = self.app.path().app_data_dir().unwrap();
+        let app_dir = self.app.path().app_data_dir()?;
+        let mut aec = hypr_aec::AEC::new()?;
+        // Denoising is an improvement on top, so a session still starts without it.
+        let mut denoiser = noise_suppression.strength().and_then(|strength| {
+            hypr_denoise::Denoiser::new(strength)
+                .map_err(|e| tracing::error!("denoiser_unavailable: {:?}", e))
+                .ok()
+        });
 
         tasks.spawn({
             let app = self.app.clone();
             let mic_rx = channels.mic_rx.clone();
             let speaker_rx = channels.speaker_rx.clone();
             let save_mixed_tx = channels.save_mixed_tx.clone();
+            let save_mic_tx = channels.save_mic_tx.clone();
+            let save_speaker_tx = channels.save_speaker_tx.clone();
             let save_mic_raw_tx = channels.save_mic_raw_tx.clone();
             let save_speaker_raw_tx = channels.save_speaker_raw_tx.clone();
             let process_mic_tx = channels.process_mic_tx.clone();
             let process_speaker_tx = channels.process_speaker_tx.clone();
 
             async move {
-                let mut aec = hypr_aec::AEC::new().unwrap();
                 let mut mic_agc = hypr_agc::Agc::default();
                 let mut speaker_agc = hypr_agc::Agc::default();
                 let mut last_broadcast = Instant::now();
@@ -320,12 +439,25 @@ impl Session {
                         }
                     };
 
+                    let mic_chunk = match denoiser.as_mut() {
+                        Some(denoiser) => match denoiser.process_streaming(&mic_chunk) {
+                            Ok(denoised) => denoised,
+                            Err(e) => {
+                                tracing::error!("denoise_error: {:?}", e);
+                                mic_chunk
+                            }
+                        },
+                        None => mic_chunk,
+                    };
+
                     if matches!(*session_state_rx.borrow(), State::RunningPaused {}) {
                         let mut rx = session_state_rx.clone();
                         let _ = rx.changed().await;
                         continue;
                     }
 
+                    timeline.fetch_add(mic_chunk.len() as u64, Ordering::Relaxed);
+
                     let processed_mic = mic_chunk.clone();
                     let processed_speaker = speaker_chunk.clone();
 
@@ -355,15 +487,28 @@ impl Session {
                     }
 
                     if record {
-                        let mixed: Vec<f32> = mic_chunk
-                            .iter()
-                            .zip(speaker_chunk.iter())
-                            .map(|(mic, speaker)| (mic + speaker).clamp(-1.0, 1.0))
-                            .collect();
+                        let mixed: Vec<f32> = match tracks {
+                            RecordingTracks::Stereo => mic_chunk
+                                .iter()
+                                .zip(speaker_chunk.iter())
+                                .flat_map(|(mic, speaker)| [*mic, *speaker])
+                                .collect(),
+                            RecordingTracks::Mixed | RecordingTracks::Separate => mic_chunk
+                                .iter()
+                                .zip(speaker_chunk.iter())
+                                .map(|(mic, speaker)| (mic + speaker).clamp(-1.0, 1.0))
+                                .collect(),
+                        };
                         if save_mixed_tx.send_async(mixed).await.is_err() {
                             tracing::error!("save_mixed_tx_send_error");
                         }
                     }
+                    if let Some(ref tx) = save_mic_tx {
+                        let _ = tx.send_async(mic_chunk.clone()).await;
+                    }
+                    if let Some(ref tx) = save_speaker_tx {
+                        let _ = tx.send_async(speaker_chunk.clone()).await;
+                    }
                 }
             }
         });
@@ -379,7 +524,8 @@ impl Session {
                         save_mixed_rx,
                         &session_id,
                         &app_dir,
-                        "audio.wav",
+                        &format!("{}.wav", MIXED_TRACK),
+                        mixed_spec,
                         true,
                     )
                     .await
@@ -390,6 +536,35 @@ impl Session {
             });
         }
 
+        for (track, rx) in [
+            (MIC_TRACK, channels.save_mic_rx.clone()),
+            (SPEAKER_TRACK, channels.save_speaker_rx.clone()),
+        ] {
+            let Some(rx) = rx else {
+                continue;
+            };
+
+            tasks.spawn({
+                let session_id = session_id.clone();
+                let app_dir = app_dir.clone();
+
+                async move {
+                    if let Err(e) = AudioSaver::save_to_wav(
+                        rx,
+                        &session_id,
+                        &app_dir,
+                        &format!("{}.wav", track),
+                        WAV_SPEC,
+                        true,
+                    )
+                    .await
+                    {
+                        tracing::error!("failed_to_save_{}_audio: {:?}", track, e);
+                    }
+                }
+            });
+        }
+
         if let Some(save_mic_raw_rx) = channels.save_mic_raw_rx.clone() {
             tasks.spawn({
                 let session_id = session_id.clone();
@@ -400,7 +575,8 @@ impl Session {
                         save_mic_raw_rx,
                         &session_id,
                         &app_dir,
-                        "audio_mic.wav",
+                        "audio_mic_raw.wav",
+                        WAV_SPEC,
                         false,
                     )
                     .await
@@ -421,7 +597,8 @@ impl Session {
                         save_speaker_raw_rx,
                         &session_id,
                         &app_dir,
-                        "audio_speaker.wav",
+                        "audio_speaker_raw.wav",
+                        WAV_SPEC,
                         false,
                     )
                     .await
@@ -442,99 +619,16 @@ impl Session {
             .into_stream()
             .map(|v| hypr_audio_utils::f32_to_i16_bytes(v.into_iter()));
 
-        let combined_audio_stream =
-            mic_audio_stream
-                .zip(speaker_audio_stream)
-                .map(|(mic, speaker)| {
-                    owhisper_interface::MixedMessage::Audio((mic.into(), speaker.into()))
-                });
-
-        tasks.spawn({
-            let app = self.app.clone();
-            let stop_tx = stop_tx.clone();
-
-            async move {
-                let (listen_stream, _listen_handle) = listen_client
-                    .from_realtime_audio(combined_audio_stream)
-                    .await
-                    .unwrap();
-
-                futures_util::pin_mut!(listen_stream);
-
-                let mut manager = TranscriptManager::default();
-
-                loop {
-                    match tokio::time::timeout(LISTEN_STREAM_TIMEOUT, listen_stream.next()).await {
-                        Ok(Some(response)) => {
-                            let diff = manager.append(response.clone());
-
-                            let mut partial_words = diff
-                                .partial_words
-                                .iter()
-                                .map(|w| owhisper_interface::Word2::from(w.clone()))
-                                .collect::<Vec<_>>();
-                            partial_words.sort_by(|a, b| {
-                                a.start_ms
-                                    .partial_cmp(&b.start_ms)
-                                    .unwrap_or(std::cmp::Ordering::Equal)
-                            });
-
-                            SessionEvent::PartialWords {
-                                words: partial_words,
-                            }
-                            .emit(&app)
-                            .unwrap();
-
-                            let mut final_words = diff
-                                .final_words
-                                .iter()
-                                .map(|w| owhisper_interface::Word2::from(w.clone()))
-                                .collect::<Vec<_>>();
-                            final_words.sort_by(|a, b| {
-                                a.start_ms
-                                    .partial_cmp(&b.start_ms)
-                                    .unwrap_or(std::cmp::Ordering::Equal)
-                            });
-
-                            update_session(&app, &session.id, final_words.clone())
-                                .await
-                                .unwrap();
-
-                            SessionEvent::FinalWords { words: final_words }
-                                .emit(&app)
-                                .unwrap();
-                        }
-                        Ok(None) => {
-                            tracing::info!("listen_stream_ended");
-
-                            // TODO: this not work - session still on ACTIVE
-                            if stop_tx.send(()).await.is_err() {
-                                tracing::warn!("failed_to_send_stop_signal");
-                            }
-                            break;
-                        }
-                        Err(_) => {
-                            tracing::info!("listen_stream_timeout");
-
-                            if let Some(state) = app.try_state::<crate::SharedState>() {
-                                let mut guard = state.lock().await;
-                                guard.fsm.handle(&crate::fsm::StateEvent::Pause).await;
-                            }
-                        }
-                    }
-                }
-            }
-        });
-
-        let app_handle = self.app.clone();
-        tasks.spawn(async move {
-            if stop_rx.recv().await.is_some() {
-                if let Some(state) = app_handle.try_state::<crate::SharedState>() {
-                    let mut guard = state.lock().await;
-                    guard.fsm.handle(&crate::fsm::StateEvent::Stop).await;
-                }
-            }
-        });
+        tasks.spawn(crate::listen::run(
+            mic_audio_stream.zip(speaker_audio_stream),
+            start,
+            listen_client,
+            corrector,
+            AppListenHandler {
+                app: self.app.clone(),
+                session_id: session.id.clone(),
+            },
+        ));
 
         self.tasks = Some(tasks);
 
@@ -544,6 +638,8 @@ impl Session {
     #[tracing::instrument(skip_all)]
     async fn teardown_resources(&mut self) {
         self.session_id = None;
+        self.mic_device_tx = None;
+        self.timeline = None;
 
         if let Some(tx) = self.silence_stream_tx.take() {
             let _ = tx.send(());
@@ -555,6 +651,9 @@ impl Session {
                 let _ = res;
             }
         }
+
+        // Only once the savers have finished writing.
+        self.recording_guard = None;
     }
 
     pub fn is_mic_muted(&self) -> bool {
@@ -578,11 +677,45 @@ impl Session {
     pub fn get_current_mic_device(&self) -> Option<String> {
         self.mic_device_name.clone()
     }
+
+    pub fn get_current_session_id(&self) -> Option<String> {
+        self.session_id.clone()
+    }
+
+    async fn add_bookmark(&self, label: Option<String>) -> Result<(), crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let (Some(session_id), Some(timeline)) = (&self.session_id, &self.timeline) else {
+            return Err(crate::Error::NoneSession);
+        };
+
+        let offset_ms = timeline.load(Ordering::Relaxed) * 1000 / SAMPLE_RATE as u64;
+        let bookmark = self
+            .app
+            .db_upsert_bookmark(hypr_db_user::Bookmark {
+                id: uuid::Uuid::new_v4().to_string(),
+                session_id: session_id.clone(),
+                label,
+                offset_ms,
+                created_at: chrono::Utc::now(),
+            })
+            .await?;
+
+        let _ = SessionEvent::Bookmarked {
+            id: bookmark.id,
+            label: bookmark.label,
+            offset_ms: bookmark.offset_ms,
+        }
+        .emit(&self.app);
+
+        Ok(())
+    }
 }
 
 async fn setup_listen_client<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
     languages: Vec<hypr_language::Language>,
+    keywords: Vec<String>,
     is_onboarding: bool,
 ) -> Result<owhisper_client::ListenClientDual, crate::Error> {
     let conn = {
@@ -596,11 +729,35 @@ async fn setup_listen_client<R: tauri::Runtime>(
         .params(owhisper_interface::ListenParams {
             languages,
             redemption_time_ms: Some(if is_onboarding { 60 } else { 400 }),
+            keywords,
             ..Default::default()
         })
         .build_dual())
 }
 
+struct AppListenHandler<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+    session_id: String,
+}
+
+impl<R: tauri::Runtime> crate::listen::ListenHandler for AppListenHandler<R> {
+    fn emit(&self, event: SessionEvent) {
+        let _ = event.emit(&self.app);
+    }
+
+    async fn save_words(&self, words: Vec<owhisper_interface::Word2>) -> Result<(), crate::Error> {
+        update_session(&self.app, &self.session_id, words).await?;
+        Ok(())
+    }
+
+    async fn on_idle(&self) {
+        if let Some(state) = self.app.try_state::<crate::SharedState>() {
+            let mut guard = state.lock().await;
+            guard.fsm.handle(&crate::fsm::StateEvent::Pause).await;
+        }
+    }
+}
+
 async fn update_session<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
     session_id: impl Into<String>,
@@ -616,19 +773,68 @@ async fn update_session<R: tauri::Runtime>(
         .ok_or(crate::Error::NoneSession)?;
 
     session.words.extend(words);
-    app.db_upsert_session(session.clone()).await.unwrap();
+    app.db_upsert_session(session.clone()).await?;
 
     Ok(session.words)
 }
 
+fn emit_recording_completed<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    session: &hypr_db_user::Session,
+) {
+    use tauri_plugin_webhook::{
+        RecordingEvent, TranscriptionEvent, WebhookEventType, WebhookPluginExt,
+    };
+
+    let duration_seconds = match (session.record_start, session.record_end) {
+        (Some(start), Some(end)) => (end - start).num_seconds().max(0) as u32,
+        _ => 0,
+    };
+
+    app.webhook_emit(
+        WebhookEventType::RecordingCompleted,
+        RecordingEvent {
+            recording_id: session.id.clone(),
+            duration_seconds,
+            status: "completed".to_string(),
+        },
+    );
+
+    if !session.words.is_empty() {
+        app.webhook_emit(
+            WebhookEventType::TranscriptionCompleted,
+            TranscriptionEvent {
+                recording_id: session.id.clone(),
+                transcription_id: session.id.clone(),
+                text: session
+                    .words
+                    .iter()
+                    .map(|word| word.text.trim())
+                    .filter(|text| !text.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            },
+        );
+    }
+}
+
 pub enum StateEvent {
-    Start(String),
+    // `noise_suppression` overrides the configured setting for this session only.
+    Start {
+        id: String,
+        noise_suppression: Option<NoiseSuppression>,
+    },
     Stop,
     Pause,
     Resume,
     MicMuted(bool),
     SpeakerMuted(bool),
     MicChange(Option<String>),
+    // The result goes back through `reply`, so callers can report a failed bookmark.
+    Bookmark {
+        label: Option<String>,
+        reply: flume::Sender<Result<(), crate::Error>>,
+    },
 }
 
 #[state_machine(
@@ -636,7 +842,7 @@ pub enum StateEvent {
     on_transition = "Self::on_transition",
     state(derive(Debug, Clone, PartialEq))
 )]
-impl Session {
+impl<R: SessionRuntime> Session<R> {
     #[superstate]
     async fn common(&mut self, event: &StateEvent) -> Response<State> {
         match event {
@@ -654,14 +860,16 @@ impl Session {
                 }
                 Handled
             }
+            StateEvent::Bookmark { label, reply } => {
+                let _ = reply.send(self.add_bookmark(label.clone()).await);
+                Handled
+            }
             StateEvent::MicChange(device_name) => {
                 self.mic_device_name = device_name.clone();
 
-                if self.session_id.is_some() && self.tasks.is_some() {
-                    if let Some(session_id) = self.session_id.clone() {
-                        self.teardown_resources().await;
-                        self.setup_resources(&session_id).await.unwrap();
-                    }
+                // A running session switches over in its capture task.
+                if let Some(tx) = &self.mic_device_tx {
+                    let _ = tx.send(device_name.clone());
                 }
 
                 Handled
@@ -673,7 +881,10 @@ impl Session {
     #[state(superstate = "common", entry_action = "enter_running_active")]
     async fn running_active(&mut self, event: &StateEvent) -> Response<State> {
         match event {
-            StateEvent::Start(incoming_session_id) => match &self.session_id {
+            StateEvent::Start {
+                id: incoming_session_id,
+                ..
+            } => match &self.session_id {
                 Some(current_id) if current_id != incoming_session_id => {
                     Transition(State::inactive())
                 }
@@ -689,7 +900,10 @@ impl Session {
     #[state(superstate = "common")]
     async fn running_paused(&mut self, event: &StateEvent) -> Response<State> {
         match event {
-            StateEvent::Start(incoming_session_id) => match &self.session_id {
+            StateEvent::Start {
+                id: incoming_session_id,
+                ..
+            } => match &self.session_id {
                 Some(current_id) if current_id != incoming_session_id => {
                     Transition(State::inactive())
                 }
@@ -709,11 +923,29 @@ impl Session {
     )]
     async fn inactive(&mut self, event: &StateEvent) -> Response<State> {
         match event {
-            StateEvent::Start(id) => match self.setup_resources(id).await {
-                Ok(_) => Transition(State::running_active()),
+            StateEvent::Start {
+                id,
+                noise_suppression,
+            } => match self.setup_resources(id, noise_suppression.clone()).await {
+                Ok(_) => {
+                    use tauri_plugin_webhook::{
+                        RecordingEvent, WebhookEventType, WebhookPluginExt,
+                    };
+
+                    self.app.webhook_emit(
+                        WebhookEventType::RecordingStarted,
+                        RecordingEvent {
+                            recording_id: id.clone(),
+                            duration_seconds: 0,
+                            status: "started".to_string(),
+                        },
+                    );
+
+                    Transition(State::running_active())
+                }
                 Err(e) => {
-                    // TODO: emit event
                     tracing::error!("error: {:?}", e);
+                    let _ = SessionEvent::from_setup_error(&e).emit(&self.app);
                     Transition(State::inactive())
                 }
             },
@@ -726,32 +958,46 @@ impl Session {
 
     #[action]
     async fn enter_inactive(&mut self) {
-        {
-            use tauri_plugin_tray::TrayPluginExt;
-            let _ = self.app.set_start_disabled(false);
-        }
+        R::set_start_disabled(&self.app, false);
+        R::hide_control_window(&self.app);
 
-        {
-            use tauri_plugin_windows::{HyprWindow, WindowsPluginExt};
-            let _ = self.app.window_hide(HyprWindow::Control);
-        }
+        let session_id = self.session_id.clone();
+        let encoding = self
+            .recording
+            .take()
+            .and_then(|recording| recording.encoding);
 
-        if let Some(session_id) = &self.session_id {
+        if let Some(session_id) = &session_id {
             use tauri_plugin_db::DatabasePluginExt;
 
             if let Ok(Some(mut session)) = self.app.db_get_session(session_id).await {
                 session.record_end = Some(chrono::Utc::now());
-                let _ = self.app.db_upsert_session(session).await;
+                let _ = self.app.db_upsert_session(session.clone()).await;
+
+                emit_recording_completed(&self.app, &session);
             }
         }
 
         self.teardown_resources().await;
+
+        // The WAVs are only complete once the savers are torn down.
+        if let (Some(session_id), Some(encoding)) = (session_id, encoding) {
+            let Ok(app_dir) = self.app.path().app_data_dir() else {
+                return;
+            };
+            let dir = app_dir.join(session_id);
+
+            tokio::task::spawn_blocking(move || {
+                if let Err(e) = crate::recording::encode_session(&dir, encoding) {
+                    tracing::error!("failed_to_encode_recording: {:?}", e);
+                }
+            });
+        }
     }
 
     #[action]
     async fn exit_inactive(&mut self) {
-        use tauri_plugin_tray::TrayPluginExt;
-        let _ = self.app.set_start_disabled(true);
+        R::set_start_disabled(&self.app, true);
     }
 
     #[action]
@@ -776,9 +1022,15 @@ impl Session {
         tracing::info!("transitioned from `{:?}` to `{:?}`", source, target);
 
         match target {
-            State::RunningActive {} => SessionEvent::RunningActive {}.emit(&self.app).unwrap(),
-            State::RunningPaused {} => SessionEvent::RunningPaused {}.emit(&self.app).unwrap(),
-            State::Inactive {} => SessionEvent::Inactive {}.emit(&self.app).unwrap(),
+            State::RunningActive {} => {
+                let _ = SessionEvent::RunningActive {}.emit(&self.app);
+            }
+            State::RunningPaused {} => {
+                let _ = SessionEvent::RunningPaused {}.emit(&self.app);
+            }
+            State::Inactive {} => {
+                let _ = SessionEvent::Inactive {}.emit(&self.app);
+            }
         }
 
         if let Some(tx) = &self.session_state_tx {
@@ -808,3 +1060,59 @@ impl specta::Type for State {
         specta::datatype::PrimitiveType::String.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    use statig::awaitable::IntoStateMachineExt;
+    use tauri::{test::MockRuntime, Listener};
+
+    impl SessionRuntime for MockRuntime {
+        fn set_start_disabled(_app: &tauri::AppHandle<Self>, _disabled: bool) {}
+        fn hide_control_window(_app: &tauri::AppHandle<Self>) {}
+    }
+
+    #[tokio::test]
+    async fn test_setup_error_returns_to_inactive() {
+        let app = tauri::test::mock_builder()
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap();
+        tauri_specta::Builder::<MockRuntime>::new()
+            .plugin_name(crate::PLUGIN_NAME)
+            .events(tauri_specta::collect_events![SessionEvent])
+            .mount_events(&app);
+        // Without a database, setup fails on its first query.
+        app.manage(tauri_plugin_db::ManagedState::default());
+
+        let events = Arc::new(Mutex::new(Vec::<serde_json::Value>::new()));
+        app.listen_any("plugin:listener:session-event", {
+            let events = events.clone();
+            move |event| {
+                let payload = serde_json::from_str(event.payload()).unwrap();
+                events.lock().unwrap().push(payload);
+            }
+        });
+
+        let mut fsm = Session::new(app.handle().clone()).state_machine();
+        fsm.handle(&StateEvent::Start {
+            id: "session".to_string(),
+            noise_suppression: None,
+        })
+        .await;
+
+        assert_eq!(fsm.state(), &State::Inactive {});
+        assert!(fsm.get_current_session_id().is_none());
+
+        let events = events.lock().unwrap();
+        let error = events
+            .iter()
+            .find(|event| event["type"] == "error")
+            .unwrap();
+        assert_eq!(error["kind"], "db_write_failed");
+        assert_eq!(error["recoverable"], false);
+        assert_eq!(events.last().unwrap()["type"], "inactive");
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::fsm::{SAMPLE_RATE, WAV_SPEC};
+
+// Voice memos, call recordings, and the audio track of screen or meeting recordings.
+const SUPPORTED_EXTENSIONS: &[&str] = &["mp3", "m4a", "wav", "ogg", "mp4"];
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ImportedSession {
+    pub session_id: String,
+    pub task_id: String,
+}
+
+pub(crate) fn is_supported(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+// Stored the same way as a live recording, so playback and re-transcription work on both.
+pub(crate) fn write_session_audio(
+    source: &Path,
+    session_dir: &Path,
+) -> Result<chrono::Duration, crate::Error> {
+    let samples = hypr_audio_utils::decode_mono(source, SAMPLE_RATE)?;
+
+    std::fs::create_dir_all(session_dir)?;
+    let mut writer = hound::WavWriter::create(session_dir.join("audio.wav"), WAV_SPEC)?;
+    for sample in &samples {
+        writer.write_sample(*sample)?;
+    }
+    writer.finalize()?;
+
+    Ok(duration(samples.len()))
+}
+
+// MP4 and M4A files carry their creation time, which for a recording is when it started.
+// Otherwise fall back to the modification time, which copies and downloads keep, and which is
+// when the recording stopped.
+pub(crate) fn recorded_range(
+    source: &Path,
+    duration: chrono::Duration,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    if let Some(start) = creation_time(source) {
+        return (start, start + duration);
+    }
+
+    let end = std::fs::metadata(source)
+        .and_then(|metadata| metadata.modified())
+        .map(DateTime::<Utc>::from)
+        .unwrap_or_else(|_| Utc::now());
+
+    (end - duration, end)
+}
+
+fn creation_time(source: &Path) -> Option<DateTime<Utc>> {
+    let mut file = std::io::BufReader::new(std::fs::File::open(source).ok()?);
+    mvhd_creation_time(&mut file)
+}
+
+// https://developer.apple.com/documentation/quicktime-file-format/movie_header_atom
+fn mvhd_creation_time(reader: &mut (impl Read + Seek)) -> Option<DateTime<Utc>> {
+    // Seconds between 1904-01-01 and 1970-01-01.
+    const MAC_EPOCH_OFFSET: i64 = 2_082_844_800;
+
+    let moov_end = find_box(reader, *b"moov", u64::MAX)?;
+    find_box(reader, *b"mvhd", moov_end)?;
+
+    let mut version = [0; 4];
+    reader.read_exact(&mut version).ok()?;
+
+    let seconds = if version[0] == 1 {
+        let mut buf = [0; 8];
+        reader.read_exact(&mut buf).ok()?;
+        u64::from_be_bytes(buf)
+    } else {
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf).ok()?;
+        u32::from_be_bytes(buf).into()
+    };
+
+    // Zero means the writer did not set it.
+    if seconds == 0 {
+        return None;
+    }
+
+    DateTime::from_timestamp(i64::try_from(seconds).ok()? - MAC_EPOCH_OFFSET, 0)
+}
+
+// Leaves the reader at the start of the box body, and returns where the box ends.
+fn find_box(reader: &mut (impl Read + Seek), name: [u8; 4], end: u64) -> Option<u64> {
+    loop {
+        let start = reader.stream_position().ok()?;
+        if start >= end {
+            return None;
+        }
+
+        let mut header = [0; 8];
+        reader.read_exact(&mut header).ok()?;
+        let kind = [header[4], header[5], header[6], header[7]];
+
+        let size = match u32::from_be_bytes([header[0], header[1], header[2], header[3]]) {
+            0 => end.saturating_sub(start),
+            1 => {
+                let mut buf = [0; 8];
+                reader.read_exact(&mut buf).ok()?;
+                u64::from_be_bytes(buf)
+            }
+            size => size.into(),
+        };
+
+        let box_end = start.checked_add(size)?;
+        if kind == name {
+            return Some(box_end);
+        }
+        if size < 8 {
+            return None;
+        }
+
+        reader.seek(SeekFrom::Start(box_end)).ok()?;
+    }
+}
+
+fn duration(samples: usize) -> chrono::Duration {
+    chrono::Duration::milliseconds((samples as u64 * 1000 / SAMPLE_RATE as u64) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_supported() {
+        assert!(is_supported(Path::new("/tmp/Voice Memo.m4a")));
+        assert!(is_supported(Path::new("zoom_0.MP4")));
+        assert!(!is_supported(Path::new("notes.txt")));
+        assert!(!is_supported(Path::new("mp3")));
+    }
+
+    #[test]
+    fn test_mvhd_creation_time() {
+        fn mp4_box(name: &[u8; 4], body: &[u8]) -> Vec<u8> {
+            let mut data = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+            data.extend_from_slice(name);
+            data.extend_from_slice(body);
+            data
+        }
+
+        // 2025-01-06T10:00:00Z, as seconds since 1904.
+        let mut mvhd = vec![0, 0, 0, 0];
+        mvhd.extend_from_slice(&(1736157600u32 + 2_082_844_800).to_be_bytes());
+        mvhd.extend_from_slice(&[0; 8]);
+
+        let mut data = mp4_box(b"ftyp", b"M4A \0\0\0\0");
+        data.extend(mp4_box(b"free", &[]));
+        data.extend(mp4_box(
+            b"moov",
+            &[mp4_box(b"trak", &[0; 16]), mp4_box(b"mvhd", &mvhd)].concat(),
+        ));
+
+        assert_eq!(
+            mvhd_creation_time(&mut std::io::Cursor::new(&data)),
+            DateTime::from_timestamp(1736157600, 0)
+        );
+
+        let unset = mp4_box(b"moov", &mp4_box(b"mvhd", &[0; 12]));
+        assert_eq!(mvhd_creation_time(&mut std::io::Cursor::new(&unset)), None);
+        assert_eq!(mvhd_creation_time(&mut std::io::Cursor::new(b"RIFF")), None);
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration(16000 * 90), chrono::Duration::seconds(90));
+        assert_eq!(duration(8000), chrono::Duration::milliseconds(500));
+    }
+}
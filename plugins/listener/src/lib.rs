@@ -7,11 +7,18 @@ mod error;
 mod events;
 mod ext;
 mod fsm;
+mod import;
+mod listen;
 mod manager;
+mod mic;
+mod recording;
+mod replay;
 
 pub use error::*;
 pub use events::*;
 pub use ext::*;
+pub use fsm::State as SessionState;
+pub use import::ImportedSession;
 
 const PLUGIN_NAME: &str = "listener";
 
@@ -42,7 +49,10 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::stop_session::<tauri::Wry>,
             commands::pause_session::<tauri::Wry>,
             commands::resume_session::<tauri::Wry>,
+            commands::add_bookmark::<tauri::Wry>,
             commands::get_state::<tauri::Wry>,
+            commands::import_audio::<tauri::Wry>,
+            commands::convert_recordings::<tauri::Wry>,
         ])
         .events(tauri_specta::collect_events![SessionEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
@@ -60,6 +70,21 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
             let fsm = fsm::Session::new(handle.clone()).state_machine();
             let state: SharedState = Mutex::new(State { fsm });
             app.manage(state);
+
+            {
+                use tauri::Listener;
+
+                let handle = handle.clone();
+                app.listen_any(tauri_plugin_tray::TRAY_BOOKMARK_EVENT, move |_| {
+                    let handle = handle.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = handle.add_bookmark(None).await {
+                            tracing::error!("failed_to_add_bookmark: {:?}", e);
+                        }
+                    });
+                });
+            }
+
             Ok(())
         })
         .build()
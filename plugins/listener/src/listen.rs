@@ -0,0 +1,429 @@
+use std::{future::Future, time::Duration};
+
+use bytes::Bytes;
+use futures_util::{stream::BoxStream, Stream, StreamExt};
+use owhisper_interface::{StreamResponse, Word2};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::{
+    manager::TranscriptManager,
+    replay::{Replay, ReplayBuffer},
+    SessionErrorKind, SessionEvent,
+};
+
+const LISTEN_STREAM_TIMEOUT: Duration = Duration::from_secs(60 * 15);
+const REPLAY_BUFFER_DURATION: Duration = Duration::from_secs(60 * 2);
+
+pub(crate) enum LinkEvent {
+    Connected,
+    Failed(String),
+    Response(StreamResponse),
+}
+
+// Opens a connection to the STT server, streaming `audio` to it. Audio sent before the
+// connection is up is queued.
+pub(crate) trait ListenConnector: Send + 'static {
+    fn connect(
+        &self,
+        audio: UnboundedReceiverStream<(Bytes, Bytes)>,
+    ) -> BoxStream<'static, LinkEvent>;
+}
+
+impl ListenConnector for owhisper_client::ListenClientDual {
+    fn connect(
+        &self,
+        audio: UnboundedReceiverStream<(Bytes, Bytes)>,
+    ) -> BoxStream<'static, LinkEvent> {
+        let client = self.clone();
+        let input = audio.map(owhisper_interface::MixedMessage::Audio);
+
+        futures_util::stream::once(async move {
+            match client.from_realtime_audio(input).await {
+                Ok((responses, _handle)) => {
+                    futures_util::stream::once(async { LinkEvent::Connected })
+                        .chain(responses.map(LinkEvent::Response))
+                        .boxed()
+                }
+                Err(e) => {
+                    futures_util::stream::once(async move { LinkEvent::Failed(e.to_string()) })
+                        .boxed()
+                }
+            }
+        })
+        .flatten()
+        .boxed()
+    }
+}
+
+// Where the transcript and connection status of a session go.
+pub(crate) trait ListenHandler: Send {
+    fn emit(&self, event: SessionEvent);
+    fn save_words(
+        &self,
+        words: Vec<Word2>,
+    ) -> impl Future<Output = Result<(), crate::Error>> + Send;
+    fn on_idle(&self) -> impl Future<Output = ()> + Send;
+}
+
+// One connection. Its transcript times count from `offset` seconds into the session, and
+// words ending before `floor` were finalized on an earlier connection.
+struct ListenLink {
+    audio_tx: tokio::sync::mpsc::UnboundedSender<(Bytes, Bytes)>,
+    events: BoxStream<'static, LinkEvent>,
+    offset: f64,
+    floor: [f64; crate::replay::CHANNELS],
+}
+
+impl ListenLink {
+    fn connect(
+        connector: &impl ListenConnector,
+        handler: &impl ListenHandler,
+        replay: &mut ReplayBuffer,
+    ) -> Self {
+        let Replay {
+            offset,
+            chunks,
+            dropped,
+        } = replay.replay();
+
+        if !dropped.is_zero() {
+            tracing::warn!("listen_stream_audio_dropped: {:?}", dropped);
+            handler.emit(SessionEvent::Degraded {
+                dropped_ms: dropped.as_millis() as u64,
+            });
+        }
+
+        let (audio_tx, audio_rx) = tokio::sync::mpsc::unbounded_channel();
+        for chunk in chunks {
+            let _ = audio_tx.send(chunk);
+        }
+
+        Self {
+            audio_tx,
+            events: connector.connect(UnboundedReceiverStream::new(audio_rx)),
+            offset,
+            floor: replay.acked(),
+        }
+    }
+}
+
+async fn next_link_event(link: &mut Option<ListenLink>) -> Option<LinkEvent> {
+    match link {
+        Some(link) => link.events.next().await,
+        None => std::future::pending().await,
+    }
+}
+
+// Streams the session's audio for transcription until the audio ends. A dropped connection is
+// retried with backoff, and the audio it had not finalized is sent again. Transcript times
+// count from `start` samples, where a resumed session left off.
+pub(crate) async fn run(
+    mut audio_stream: impl Stream<Item = (Bytes, Bytes)> + Unpin,
+    start: u64,
+    connector: impl ListenConnector,
+    corrector: hypr_vocabulary::Corrector,
+    handler: impl ListenHandler,
+) {
+    let mut replay = ReplayBuffer::new(REPLAY_BUFFER_DURATION, start);
+    let mut manager = TranscriptManager::default();
+
+    let mut link = Some(ListenLink::connect(&connector, &handler, &mut replay));
+    let mut attempt = 0u32;
+    let mut retry_at = None;
+    let mut last_response = tokio::time::Instant::now();
+
+    loop {
+        tokio::select! {
+            audio = audio_stream.next() => {
+                let Some((mic, speaker)) = audio else {
+                    break;
+                };
+
+                // Keeps buffering while disconnected, so nothing said meanwhile is lost.
+                replay.push(mic.clone(), speaker.clone());
+                if let Some(link) = &link {
+                    let _ = link.audio_tx.send((mic, speaker));
+                }
+            }
+            event = next_link_event(&mut link) => match event {
+                Some(LinkEvent::Connected) => {
+                    if attempt > 0 {
+                        tracing::info!("listen_stream_reconnected");
+                        handler.emit(SessionEvent::Reconnected {});
+                    }
+                }
+                Some(LinkEvent::Failed(message)) => {
+                    tracing::error!("listen_stream_connect_failed: {}", message);
+                    handler.emit(SessionEvent::Error {
+                        kind: SessionErrorKind::SttConnectionFailed,
+                        message,
+                        recoverable: true,
+                    });
+                }
+                Some(LinkEvent::Response(mut response)) => {
+                    last_response = tokio::time::Instant::now();
+                    attempt = 0;
+
+                    if let Some(link) = &link {
+                        crate::replay::shift_response(&mut response, link.offset, &link.floor);
+                    }
+                    if let Some((channel, end)) = crate::replay::final_end(&response) {
+                        replay.ack(channel, end);
+                    }
+
+                    let diff = manager.append(response);
+
+                    let partial_words = corrector.correct(sorted_words(diff.partial_words));
+                    handler.emit(SessionEvent::PartialWords {
+                        words: partial_words,
+                    });
+
+                    let final_words = corrector.correct(sorted_words(diff.final_words));
+                    if final_words.is_empty() {
+                        continue;
+                    }
+
+                    if let Err(e) = handler.save_words(final_words.clone()).await {
+                        tracing::error!("failed_to_save_words: {:?}", e);
+                        handler.emit(SessionEvent::Error {
+                            kind: SessionErrorKind::DbWriteFailed,
+                            message: e.to_string(),
+                            recoverable: true,
+                        });
+                    }
+                    handler.emit(SessionEvent::FinalWords { words: final_words });
+                }
+                None => {
+                    link = None;
+                    attempt += 1;
+
+                    let delay = crate::replay::backoff(attempt);
+                    tracing::warn!("listen_stream_ended: retry {} in {:?}", attempt, delay);
+
+                    handler.emit(SessionEvent::Reconnecting {
+                        attempt,
+                        retry_in_ms: delay.as_millis() as u64,
+                    });
+                    retry_at = Some(tokio::time::Instant::now() + delay);
+                }
+            },
+            _ = tokio::time::sleep_until(retry_at.unwrap_or_else(tokio::time::Instant::now)), if retry_at.is_some() => {
+                retry_at = None;
+                link = Some(ListenLink::connect(&connector, &handler, &mut replay));
+            }
+            _ = tokio::time::sleep_until(last_response + LISTEN_STREAM_TIMEOUT) => {
+                tracing::info!("listen_stream_timeout");
+                last_response = tokio::time::Instant::now();
+                handler.on_idle().await;
+            }
+        }
+    }
+}
+
+fn sorted_words(words: Vec<owhisper_interface::Word>) -> Vec<Word2> {
+    let mut words = words.into_iter().map(Word2::from).collect::<Vec<_>>();
+    words.sort_by(|a, b| {
+        a.start_ms
+            .partial_cmp(&b.start_ms)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::{Arc, Mutex};
+
+    use owhisper_interface::{Alternatives, Channel, Metadata, Word};
+
+    // Each connection plays back the next script, then stays open.
+    struct MockConnector {
+        scripts: Mutex<Vec<Vec<LinkEvent>>>,
+    }
+
+    impl ListenConnector for MockConnector {
+        fn connect(
+            &self,
+            _audio: UnboundedReceiverStream<(Bytes, Bytes)>,
+        ) -> BoxStream<'static, LinkEvent> {
+            let mut scripts = self.scripts.lock().unwrap();
+            if scripts.is_empty() {
+                return futures_util::stream::pending().boxed();
+            }
+
+            let script = scripts.remove(0);
+            let last = scripts.is_empty();
+            // Lets the audio sent so far reach the replay buffer first.
+            let events = futures_util::stream::iter(script).then(|event| async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                event
+            });
+
+            if last {
+                events.chain(futures_util::stream::pending()).boxed()
+            } else {
+                events.boxed()
+            }
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockHandler {
+        events: Arc<Mutex<Vec<SessionEvent>>>,
+        words: Arc<Mutex<Vec<Word2>>>,
+    }
+
+    impl ListenHandler for MockHandler {
+        fn emit(&self, event: SessionEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+
+        async fn save_words(&self, words: Vec<Word2>) -> Result<(), crate::Error> {
+            self.words.lock().unwrap().extend(words);
+            Ok(())
+        }
+
+        async fn on_idle(&self) {}
+    }
+
+    fn final_response(words: &[(&str, f64, f64)]) -> LinkEvent {
+        let start = words.first().map_or(0.0, |w| w.1);
+        let end = words.last().map_or(0.0, |w| w.2);
+
+        LinkEvent::Response(StreamResponse::TranscriptResponse {
+            type_field: "Results".to_string(),
+            start,
+            duration: end - start,
+            is_final: true,
+            speech_final: true,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript: String::new(),
+                    words: words
+                        .iter()
+                        .map(|(word, start, end)| Word {
+                            word: word.to_string(),
+                            start: *start,
+                            end: *end,
+                            confidence: 1.0,
+                            speaker: None,
+                            punctuated_word: None,
+                            language: None,
+                        })
+                        .collect(),
+                    confidence: 1.0,
+                    languages: vec![],
+                }],
+            },
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        })
+    }
+
+    // One second of silence, in 100ms chunks.
+    fn audio() -> impl Stream<Item = (Bytes, Bytes)> + Unpin {
+        let chunk = Bytes::from(vec![0u8; 16000 / 10 * 2]);
+        futures_util::stream::iter(vec![(chunk.clone(), chunk); 10])
+            .chain(futures_util::stream::pending())
+    }
+
+    async fn run_for(connector: MockConnector, handler: MockHandler) {
+        run_from(0, connector, handler).await;
+    }
+
+    async fn run_from(start_secs: u64, connector: MockConnector, handler: MockHandler) {
+        let _ = tokio::time::timeout(
+            Duration::from_secs(60),
+            run(
+                audio(),
+                start_secs * 16000,
+                connector,
+                hypr_vocabulary::Corrector::new(Vec::<String>::new()),
+                handler,
+            ),
+        )
+        .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_keeps_times_monotonic() {
+        let connector = MockConnector {
+            scripts: Mutex::new(vec![
+                vec![LinkEvent::Connected, final_response(&[("hello", 0.0, 0.3)])],
+                // The replay starts at 0.3s, so the server's times count from there.
+                vec![LinkEvent::Connected, final_response(&[("world", 0.1, 0.4)])],
+            ]),
+        };
+        let handler = MockHandler::default();
+
+        run_for(connector, handler.clone()).await;
+
+        let words = handler.words.lock().unwrap();
+        assert_eq!(
+            words
+                .iter()
+                .map(|w| (w.text.as_str(), w.start_ms))
+                .collect::<Vec<_>>(),
+            vec![("hello", Some(0)), ("world", Some(400))]
+        );
+
+        let events = handler.events.lock().unwrap();
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, SessionEvent::Reconnecting { attempt: 1, .. })));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, SessionEvent::Reconnected {})));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_resumed_session_continues_times() {
+        let connector = MockConnector {
+            scripts: Mutex::new(vec![vec![
+                LinkEvent::Connected,
+                final_response(&[("again", 0.1, 0.4)]),
+            ]]),
+        };
+        let handler = MockHandler::default();
+
+        run_from(10, connector, handler.clone()).await;
+
+        let words = handler.words.lock().unwrap();
+        assert_eq!(
+            words
+                .iter()
+                .map(|w| (w.text.as_str(), w.start_ms))
+                .collect::<Vec<_>>(),
+            vec![("again", Some(10100))]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_connect_failure_is_reported() {
+        let connector = MockConnector {
+            scripts: Mutex::new(vec![
+                vec![LinkEvent::Failed("connection refused".to_string())],
+                vec![LinkEvent::Connected],
+            ]),
+        };
+        let handler = MockHandler::default();
+
+        run_for(connector, handler.clone()).await;
+
+        let events = handler.events.lock().unwrap();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            SessionEvent::Error {
+                kind: SessionErrorKind::SttConnectionFailed,
+                recoverable: true,
+                ..
+            }
+        )));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, SessionEvent::Reconnected {})));
+    }
+}
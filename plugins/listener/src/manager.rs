@@ -101,18 +101,19 @@ impl TranscriptManager {
             };
 
             if is_final {
-                let last_final_word_end = words.last().unwrap().end;
-
-                let channel_partial_words = self
-                    .partial_words_by_channel
-                    .entry(channel_idx)
-                    .or_insert_with(Vec::new);
-
-                *channel_partial_words = channel_partial_words
-                    .iter()
-                    .filter(|w| w.end > last_final_word_end)
-                    .cloned()
-                    .collect::<Vec<_>>();
+                // Replayed audio can come back final with every word already saved.
+                if let Some(last_final_word_end) = words.last().map(|w| w.end) {
+                    let channel_partial_words = self
+                        .partial_words_by_channel
+                        .entry(channel_idx)
+                        .or_insert_with(Vec::new);
+
+                    *channel_partial_words = channel_partial_words
+                        .iter()
+                        .filter(|w| w.end > last_final_word_end)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                }
 
                 return Diff {
                     final_words: words,
@@ -0,0 +1,235 @@
+use std::time::{Duration, Instant};
+
+use futures_util::{stream::BoxStream, StreamExt};
+use hypr_aec::BLOCK_SIZE;
+use hypr_audio::AsyncSource;
+
+use crate::fsm::SAMPLE_RATE;
+
+// How often to check whether the selected mic, or the system default, has changed.
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// An open mic, resampled and chunked for AEC.
+pub(crate) struct MicCapture {
+    device: String,
+    stream: BoxStream<'static, Vec<f32>>,
+}
+
+impl MicCapture {
+    // `None` follows the system default. Blocks on the audio host, so call it off the runtime.
+    pub fn open(device_name: Option<String>) -> Result<Self, hypr_audio::Error> {
+        let mut input = hypr_audio::AudioInput::from_mic(device_name)?;
+        let device = input.device_name();
+        let stream = input
+            .stream()
+            .resample(SAMPLE_RATE)
+            .chunks(BLOCK_SIZE)
+            .boxed();
+
+        Ok(Self { device, stream })
+    }
+}
+
+// Looks up and opens mics. Both block on the audio host, so `capture` runs them on the blocking pool.
+pub(crate) trait MicDevices: Clone + Send + Sync + 'static {
+    fn resolve(&self, device_name: Option<String>) -> Option<String>;
+    fn open(&self, device_name: Option<String>) -> Result<MicCapture, hypr_audio::Error>;
+}
+
+#[derive(Clone)]
+pub(crate) struct SystemMic;
+
+impl MicDevices for SystemMic {
+    fn resolve(&self, device_name: Option<String>) -> Option<String> {
+        hypr_audio::MicInput::resolve_device_name(device_name)
+    }
+
+    fn open(&self, device_name: Option<String>) -> Result<MicCapture, hypr_audio::Error> {
+        MicCapture::open(device_name)
+    }
+}
+
+// Owns the mic for the whole session. Switching devices only restarts the capture, so AEC, the
+// listen stream and the recorders keep running, and the audio missed meanwhile is sent as silence.
+pub(crate) async fn capture(
+    devices: impl MicDevices,
+    mut mic: MicCapture,
+    mut device_rx: tokio::sync::watch::Receiver<Option<String>>,
+    muted_rx: tokio::sync::watch::Receiver<bool>,
+    mic_tx: flume::Sender<Vec<f32>>,
+) {
+    let mut poll = tokio::time::interval(DEVICE_POLL_INTERVAL);
+    let mut last_chunk_at = Instant::now();
+    let mut switched = false;
+    let mut ended = false;
+
+    loop {
+        let reopen = tokio::select! {
+            chunk = mic.stream.next(), if !ended => {
+                let Some(chunk) = chunk else {
+                    tracing::warn!("mic_stream_ended: {}", mic.device);
+                    ended = true;
+                    continue;
+                };
+
+                let now = Instant::now();
+                if std::mem::take(&mut switched) {
+                    for _ in 0..missed_chunks(now - last_chunk_at) {
+                        if mic_tx.send_async(vec![0.0; BLOCK_SIZE]).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                last_chunk_at = now;
+
+                let chunk = if *muted_rx.borrow() {
+                    vec![0.0; chunk.len()]
+                } else {
+                    chunk
+                };
+
+                if let Err(e) = mic_tx.send_async(chunk).await {
+                    tracing::error!("mic_tx_send_error: {:?}", e);
+                    break;
+                }
+                false
+            }
+            changed = device_rx.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                true
+            }
+            _ = poll.tick() => {
+                if ended {
+                    true
+                } else {
+                    let selected = device_rx.borrow().clone();
+                    let devices = devices.clone();
+                    tokio::task::spawn_blocking(move || devices.resolve(selected))
+                        .await
+                        .ok()
+                        .flatten()
+                        .is_some_and(|device| device != mic.device)
+                }
+            }
+        };
+
+        if !reopen {
+            continue;
+        }
+
+        let selected = device_rx.borrow_and_update().clone();
+        let opened = {
+            let devices = devices.clone();
+            tokio::task::spawn_blocking(move || devices.open(selected)).await
+        };
+        match opened {
+            Ok(Ok(next)) => {
+                tracing::info!("mic_switched: {} -> {}", mic.device, next.device);
+                mic = next;
+                switched = true;
+                ended = false;
+            }
+            Ok(Err(e)) => tracing::error!("mic_switch_failed: {:?}", e),
+            Err(e) => tracing::error!("mic_switch_failed: {:?}", e),
+        }
+    }
+}
+
+// Chunks arrive once per chunk of audio, so a longer gap is audio that was never captured.
+fn missed_chunks(gap: Duration) -> usize {
+    let chunk = BLOCK_SIZE as u128 * 1_000_000 / SAMPLE_RATE as u128;
+    ((gap.as_micros() + chunk / 2) / chunk).saturating_sub(1) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use tokio::sync::mpsc::UnboundedSender;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    #[test]
+    fn test_missed_chunks() {
+        // 512 samples at 16kHz.
+        let chunk = Duration::from_millis(32);
+
+        assert_eq!(missed_chunks(Duration::ZERO), 0);
+        assert_eq!(missed_chunks(chunk), 0);
+        assert_eq!(missed_chunks(chunk * 5), 4);
+        assert_eq!(missed_chunks(chunk * 5 + Duration::from_millis(10)), 4);
+        assert_eq!(missed_chunks(chunk * 5 + Duration::from_millis(20)), 5);
+    }
+
+    // Each opened device plays whatever the test sends to it.
+    #[derive(Clone, Default)]
+    struct FakeDevices {
+        senders: Arc<Mutex<HashMap<String, UnboundedSender<Vec<f32>>>>>,
+    }
+
+    impl FakeDevices {
+        fn send(&self, device: &str, value: f32) {
+            let senders = self.senders.lock().unwrap();
+            senders[device].send(vec![value; BLOCK_SIZE]).unwrap();
+        }
+    }
+
+    impl MicDevices for FakeDevices {
+        fn resolve(&self, device_name: Option<String>) -> Option<String> {
+            device_name
+        }
+
+        fn open(&self, device_name: Option<String>) -> Result<MicCapture, hypr_audio::Error> {
+            let device = device_name.ok_or(hypr_audio::Error::NoInputDevice)?;
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            self.senders.lock().unwrap().insert(device.clone(), tx);
+
+            Ok(MicCapture {
+                device,
+                stream: UnboundedReceiverStream::new(rx).boxed(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capture_switch_fills_silence() {
+        let devices = FakeDevices::default();
+        let mic = devices.open(Some("a".to_string())).unwrap();
+        let (device_tx, device_rx) = tokio::sync::watch::channel(Some("a".to_string()));
+        let (_muted_tx, muted_rx) = tokio::sync::watch::channel(false);
+        let (mic_tx, mic_rx) = flume::unbounded();
+
+        tokio::spawn(capture(devices.clone(), mic, device_rx, muted_rx, mic_tx));
+
+        devices.send("a", 0.1);
+        assert_eq!(mic_rx.recv_async().await.unwrap(), vec![0.1; BLOCK_SIZE]);
+
+        device_tx.send(Some("b".to_string())).unwrap();
+        while !devices.senders.lock().unwrap().contains_key("b") {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        // About 5 chunks pass before the new device delivers its first one.
+        tokio::time::sleep(Duration::from_millis(160)).await;
+        devices.send("b", 0.2);
+
+        let mut silent = 0;
+        loop {
+            let chunk = mic_rx.recv_async().await.unwrap();
+            if chunk == vec![0.0; BLOCK_SIZE] {
+                silent += 1;
+                continue;
+            }
+
+            assert_eq!(chunk, vec![0.2; BLOCK_SIZE]);
+            break;
+        }
+        assert!((4..=6).contains(&silent), "{}", silent);
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock},
+};
+
+use hypr_audio_codec::{Encoding, RECORDING_TRACKS};
+use hypr_db_user::{ConfigGeneral, RecordingFormat, RecordingTracks};
+
+use crate::fsm::WAV_SPEC;
+
+pub(crate) const MIXED_TRACK: &str = RECORDING_TRACKS[0];
+pub(crate) const MIC_TRACK: &str = RECORDING_TRACKS[1];
+pub(crate) const SPEAKER_TRACK: &str = RECORDING_TRACKS[2];
+
+// Two sessions stopping at once would otherwise both hold a full recording in memory.
+static ENCODE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+// Held while a session records and while it is encoded, so that a resumed session never
+// appends to a WAV that encoding is about to remove.
+static SESSION_LOCKS: LazyLock<std::sync::Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>> =
+    LazyLock::new(Default::default);
+
+pub(crate) fn session_lock(dir: &Path) -> Arc<tokio::sync::Mutex<()>> {
+    let mut locks = SESSION_LOCKS.lock().unwrap_or_else(|e| e.into_inner());
+    // Nobody holds or waits on a lock that only the map still refers to.
+    locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+    locks.entry(dir.to_path_buf()).or_default().clone()
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RecordingConfig {
+    pub tracks: RecordingTracks,
+    pub encoding: Option<Encoding>,
+}
+
+impl RecordingConfig {
+    pub fn from_config(general: &ConfigGeneral) -> Self {
+        let encoding = match general.recording_format {
+            RecordingFormat::Wav => None,
+            RecordingFormat::Flac => Some(Encoding::Flac),
+            RecordingFormat::Opus => Some(Encoding::Opus {
+                bitrate_kbps: general.recording_bitrate_kbps,
+            }),
+        };
+
+        Self {
+            tracks: general.recording_tracks.clone(),
+            encoding,
+        }
+    }
+
+    pub fn mixed_spec(&self) -> hound::WavSpec {
+        hound::WavSpec {
+            channels: match self.tracks {
+                RecordingTracks::Stereo => 2,
+                RecordingTracks::Mixed | RecordingTracks::Separate => 1,
+            },
+            ..WAV_SPEC
+        }
+    }
+}
+
+// Encodes whichever tracks of the session are still WAV. Returns how many were encoded.
+// A session that is recording again is skipped, since it is encoded once that stops.
+pub(crate) fn encode_session(dir: &Path, encoding: Encoding) -> Result<usize, crate::Error> {
+    let session = session_lock(dir);
+    let Ok(_session_guard) = session.try_lock() else {
+        return Ok(0);
+    };
+    let _guard = ENCODE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let mut encoded = 0;
+    for track in RECORDING_TRACKS {
+        let wav = dir.join(format!("{}.wav", track));
+        if wav.exists() {
+            hypr_audio_codec::encode(&wav, encoding)?;
+            encoded += 1;
+        }
+    }
+
+    Ok(encoded)
+}
+
+pub(crate) fn has_wav_tracks(dir: &Path) -> bool {
+    RECORDING_TRACKS
+        .iter()
+        .any(|track| dir.join(format!("{}.wav", track)).exists())
+}
+
+// Length of the mix recorded so far, in samples at `sample_rate`. Zero when nothing was saved.
+pub(crate) fn recorded_samples(dir: &Path, sample_rate: u32) -> Result<u64, crate::Error> {
+    let Some(path) = hypr_audio_codec::find_recording(dir, MIXED_TRACK) else {
+        return Ok(0);
+    };
+
+    let (frames, rate) = if path.extension().is_some_and(|ext| ext == "wav") {
+        let reader = hound::WavReader::open(&path)?;
+        (reader.duration() as u64, reader.spec().sample_rate)
+    } else {
+        let audio = hypr_audio_codec::decode(&path)?;
+        let channels = audio.channels.max(1) as u64;
+        (audio.samples.len() as u64 / channels, audio.sample_rate)
+    };
+
+    Ok(frames * sample_rate as u64 / rate.max(1) as u64)
+}
@@ -0,0 +1,261 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bytes::Bytes;
+use owhisper_interface::StreamResponse;
+
+use crate::fsm::SAMPLE_RATE;
+
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+// Mic and speaker, in the order they are streamed.
+pub(crate) const CHANNELS: usize = 2;
+
+// Audio the server has not returned a final transcript for yet, kept so it can be sent
+// again after a reconnect. Times are seconds of audio streamed since the session started.
+// Channels are finalized independently, so a chunk is kept until every channel is past it.
+pub(crate) struct ReplayBuffer {
+    chunks: VecDeque<Chunk>,
+    capacity: u64,
+    buffered: u64,
+    streamed: u64,
+    acked: [f64; CHANNELS],
+    dropped: u64,
+}
+
+struct Chunk {
+    start: u64,
+    mic: Bytes,
+    speaker: Bytes,
+}
+
+impl Chunk {
+    fn samples(&self) -> u64 {
+        (self.mic.len() / 2) as u64
+    }
+
+    fn end_secs(&self) -> f64 {
+        to_secs(self.start + self.samples())
+    }
+}
+
+pub(crate) struct Replay {
+    pub offset: f64,
+    pub chunks: Vec<(Bytes, Bytes)>,
+    // Audio that fell out of the buffer before it was transcribed.
+    pub dropped: Duration,
+}
+
+impl ReplayBuffer {
+    // `start` is how many samples the session had before this run, for resumed sessions.
+    pub fn new(capacity: Duration, start: u64) -> Self {
+        Self {
+            chunks: VecDeque::new(),
+            capacity: (capacity.as_secs_f64() * SAMPLE_RATE as f64) as u64,
+            buffered: 0,
+            streamed: start,
+            acked: [to_secs(start); CHANNELS],
+            dropped: 0,
+        }
+    }
+
+    pub fn push(&mut self, mic: Bytes, speaker: Bytes) {
+        let chunk = Chunk {
+            start: self.streamed,
+            mic,
+            speaker,
+        };
+        self.streamed += chunk.samples();
+        self.buffered += chunk.samples();
+        self.chunks.push_back(chunk);
+
+        while self.buffered > self.capacity {
+            let Some(chunk) = self.chunks.pop_front() else {
+                break;
+            };
+            self.buffered -= chunk.samples();
+            self.dropped += chunk.samples();
+        }
+    }
+
+    // Everything of `channel` before `secs` has a final transcript. `None` is every channel.
+    pub fn ack(&mut self, channel: Option<usize>, secs: f64) {
+        for (i, acked) in self.acked.iter_mut().enumerate() {
+            if channel.is_none_or(|channel| channel == i) {
+                *acked = acked.max(secs);
+            }
+        }
+
+        let acked = self.min_acked();
+        while self
+            .chunks
+            .front()
+            .is_some_and(|chunk| chunk.end_secs() <= acked)
+        {
+            let chunk = self.chunks.pop_front().unwrap();
+            self.buffered -= chunk.samples();
+        }
+    }
+
+    pub fn acked(&self) -> [f64; CHANNELS] {
+        self.acked
+    }
+
+    fn min_acked(&self) -> f64 {
+        self.acked.iter().copied().fold(f64::INFINITY, f64::min)
+    }
+
+    // The new connection counts from zero, so its times are shifted by where the replay starts.
+    pub fn replay(&mut self) -> Replay {
+        let offset = self
+            .chunks
+            .front()
+            .map_or(to_secs(self.streamed), |chunk| to_secs(chunk.start));
+
+        Replay {
+            offset,
+            chunks: self
+                .chunks
+                .iter()
+                .map(|chunk| (chunk.mic.clone(), chunk.speaker.clone()))
+                .collect(),
+            dropped: Duration::from_micros(
+                std::mem::take(&mut self.dropped) * 1_000_000 / SAMPLE_RATE as u64,
+            ),
+        }
+    }
+}
+
+// Moves a response from connection time to session time. Words that end before the floor of
+// their channel were already finalized on an earlier connection, and are dropped.
+pub(crate) fn shift_response(response: &mut StreamResponse, offset: f64, floor: &[f64; CHANNELS]) {
+    let floor = match response_channel(response) {
+        Some(index) => floor[index],
+        None => floor.iter().copied().fold(f64::INFINITY, f64::min),
+    };
+
+    if let StreamResponse::TranscriptResponse { start, channel, .. } = response {
+        *start += offset;
+
+        for alternative in &mut channel.alternatives {
+            for word in &mut alternative.words {
+                word.start += offset;
+                word.end += offset;
+            }
+            alternative.words.retain(|word| word.end > floor);
+        }
+    }
+}
+
+// The channel and end of a final response.
+pub(crate) fn final_end(response: &StreamResponse) -> Option<(Option<usize>, f64)> {
+    match response {
+        StreamResponse::TranscriptResponse {
+            is_final: true,
+            start,
+            duration,
+            ..
+        } => Some((response_channel(response), start + duration)),
+        _ => None,
+    }
+}
+
+// `channel_index` is `[index, total]`. A response of a single-channel stream covers every channel.
+fn response_channel(response: &StreamResponse) -> Option<usize> {
+    match response {
+        StreamResponse::TranscriptResponse { channel_index, .. } => {
+            match channel_index.as_slice() {
+                [index, total, ..] if *total > 1 => usize::try_from(*index)
+                    .ok()
+                    .filter(|index| *index < CHANNELS),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn backoff(attempt: u32) -> Duration {
+    BACKOFF_BASE
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(BACKOFF_MAX)
+}
+
+fn to_secs(samples: u64) -> f64 {
+    samples as f64 / SAMPLE_RATE as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 100ms of 16-bit audio.
+    fn chunk() -> Bytes {
+        Bytes::from(vec![0u8; SAMPLE_RATE as usize / 10 * 2])
+    }
+
+    #[test]
+    fn test_replay_from_ack() {
+        let mut buffer = ReplayBuffer::new(Duration::from_secs(1), 0);
+        for _ in 0..5 {
+            buffer.push(chunk(), chunk());
+        }
+
+        buffer.ack(None, 0.25);
+        let replay = buffer.replay();
+        assert_eq!(replay.chunks.len(), 3);
+        assert!((replay.offset - 0.2).abs() < 1e-9);
+        assert_eq!(replay.dropped, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_replay_from_slowest_channel() {
+        let mut buffer = ReplayBuffer::new(Duration::from_secs(1), 0);
+        for _ in 0..5 {
+            buffer.push(chunk(), chunk());
+        }
+
+        buffer.ack(Some(0), 0.45);
+        buffer.ack(Some(1), 0.15);
+        assert_eq!(buffer.acked(), [0.45, 0.15]);
+
+        let replay = buffer.replay();
+        assert_eq!(replay.chunks.len(), 4);
+        assert!((replay.offset - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_replay_after_resume() {
+        let mut buffer = ReplayBuffer::new(Duration::from_secs(1), SAMPLE_RATE as u64 * 10);
+        assert!((buffer.replay().offset - 10.0).abs() < 1e-9);
+
+        buffer.push(chunk(), chunk());
+        buffer.push(chunk(), chunk());
+        buffer.ack(None, 10.1);
+
+        let replay = buffer.replay();
+        assert_eq!(replay.chunks.len(), 1);
+        assert!((replay.offset - 10.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_replay_overflow() {
+        let mut buffer = ReplayBuffer::new(Duration::from_millis(300), 0);
+        for _ in 0..5 {
+            buffer.push(chunk(), chunk());
+        }
+
+        let replay = buffer.replay();
+        assert_eq!(replay.chunks.len(), 3);
+        assert!((replay.offset - 0.2).abs() < 1e-9);
+        assert_eq!(replay.dropped, Duration::from_millis(200));
+        assert_eq!(buffer.replay().dropped, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(1), Duration::from_millis(500));
+        assert_eq!(backoff(3), Duration::from_secs(2));
+        assert_eq!(backoff(20), BACKOFF_MAX);
+    }
+}
@@ -9,8 +9,14 @@ const COMMANDS: &[&str] = &[
     "restart_server",
     "get_current_model",
     "set_current_model",
+    "get_current_model_id",
+    "set_current_custom_model",
     "list_downloaded_model",
     "list_supported_model",
+    "list_custom_models",
+    "add_custom_model",
+    "remove_custom_model",
+    "list_loaded_models",
 ];
 
 fn main() {
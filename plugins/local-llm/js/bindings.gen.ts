@@ -40,8 +40,26 @@ async getCurrentModel() : Promise<SupportedModel> {
 async setCurrentModel(model: SupportedModel) : Promise<null> {
     return await TAURI_INVOKE("plugin:local-llm|set_current_model", { model });
 },
+async getCurrentModelId() : Promise<string> {
+    return await TAURI_INVOKE("plugin:local-llm|get_current_model_id");
+},
+async setCurrentCustomModel(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:local-llm|set_current_custom_model", { id });
+},
 async listDownloadedModel() : Promise<SupportedModel[]> {
     return await TAURI_INVOKE("plugin:local-llm|list_downloaded_model");
+},
+async listCustomModels() : Promise<CustomModel[]> {
+    return await TAURI_INVOKE("plugin:local-llm|list_custom_models");
+},
+async addCustomModel(path: string) : Promise<CustomModel> {
+    return await TAURI_INVOKE("plugin:local-llm|add_custom_model", { path });
+},
+async removeCustomModel(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:local-llm|remove_custom_model", { id });
+},
+async listLoadedModels() : Promise<string[]> {
+    return await TAURI_INVOKE("plugin:local-llm|list_loaded_models");
 }
 }
 
@@ -55,6 +73,7 @@ async listDownloadedModel() : Promise<SupportedModel[]> {
 
 /** user-defined types **/
 
+export type CustomModel = { id: string; name: string; path: string; size_bytes: number; context_length: number | null }
 export type ModelInfo = { key: SupportedModel; name: string; description: string; size_bytes: number }
 export type SupportedModel = "Llama3p2_3bQ4" | "HyprLLM" | "Gemma3_4bQ4"
 export type TAURI_CHANNEL<TSend> = null
@@ -9,13 +9,23 @@ pub enum Error {
     #[error(transparent)]
     HyprFileError(#[from] hypr_file::Error),
     #[error(transparent)]
+    HyprGgufError(#[from] hypr_gguf::Error),
+    #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
     #[error(transparent)]
+    JoinError(#[from] tokio::task::JoinError),
+    #[error(transparent)]
     StoreError(#[from] tauri_plugin_store2::Error),
     #[error("Model not downloaded")]
     ModelNotDownloaded,
+    #[error("Model has no chat template")]
+    ChatTemplateNotFound,
+    #[error("Custom model not found")]
+    CustomModelNotFound,
+    #[error("Not enough memory budget to load the model")]
+    MemoryBudgetExceeded,
 }
 
 impl Serialize for Error {
@@ -21,6 +21,19 @@ pub trait LocalLlmPluginExt<R: Runtime> {
     ) -> impl Future<Output = Result<Vec<crate::SupportedModel>, crate::Error>>;
     fn get_current_model(&self) -> Result<crate::SupportedModel, crate::Error>;
     fn set_current_model(&self, model: crate::SupportedModel) -> Result<(), crate::Error>;
+    fn get_current_model_id(&self) -> Result<String, crate::Error>;
+    fn set_current_custom_model(&self, id: impl Into<String>) -> Result<(), crate::Error>;
+
+    fn list_custom_models(&self) -> Result<Vec<crate::CustomModel>, crate::Error>;
+    fn add_custom_model(
+        &self,
+        path: impl Into<PathBuf>,
+    ) -> impl Future<Output = Result<crate::CustomModel, crate::Error>>;
+    fn remove_custom_model(
+        &self,
+        id: impl Into<String>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn list_loaded_models(&self) -> impl Future<Output = Vec<String>>;
 
     fn download_model(
         &self,
@@ -173,13 +186,23 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
 
     #[tracing::instrument(skip_all)]
     async fn start_server(&self) -> Result<String, crate::Error> {
-        let current_model = self.get_current_model()?;
+        let current_model = self.get_current_model_id()?;
+
+        let mut registry = crate::model::SUPPORTED_MODELS
+            .iter()
+            .map(|model| (model.to_string(), self.models_dir().join(model.file_name())))
+            .collect::<std::collections::HashMap<_, _>>();
+        for model in self.list_custom_models()? {
+            registry.insert(model.id, PathBuf::from(model.path));
+        }
+
+        // Leave the other half for the app itself, the STT model and the rest of the system.
+        let memory_budget = hypr_host::total_memory() / 2;
 
-        let model_path = self.models_dir().join(current_model.file_name());
-        let model_manager = crate::ModelManager::new(model_path);
+        let model_manager = crate::ModelManager::new(registry, current_model, memory_budget);
         let state = self.state::<crate::SharedState>();
 
-        let server_state = crate::ServerState::new(model_manager);
+        let server_state = crate::ServerState::new(model_manager.clone());
         let server = crate::server::run_server(server_state).await?;
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
@@ -189,6 +212,7 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
             let mut s = state.lock().await;
             s.api_base = Some(api_base.clone());
             s.server = Some(server);
+            s.model_manager = Some(model_manager);
         }
 
         Ok(api_base)
@@ -202,6 +226,7 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
         if let Some(server) = s.server.take() {
             let _ = server.shutdown.send(());
         }
+        s.model_manager = None;
         Ok(())
     }
 
@@ -241,6 +266,124 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
     fn set_current_model(&self, model: crate::SupportedModel) -> Result<(), crate::Error> {
         let store = self.local_llm_store();
         store.set(crate::StoreKey::Model, model)?;
+        store.set(crate::StoreKey::CurrentCustomModel, None::<String>)?;
         Ok(())
     }
+
+    #[tracing::instrument(skip_all)]
+    fn get_current_model_id(&self) -> Result<String, crate::Error> {
+        let custom: Option<Option<String>> = self
+            .local_llm_store()
+            .get(crate::StoreKey::CurrentCustomModel)?;
+
+        match custom.flatten() {
+            Some(id) if self.list_custom_models()?.iter().any(|m| m.id == id) => Ok(id),
+            _ => Ok(self.get_current_model()?.to_string()),
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    fn set_current_custom_model(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let id = id.into();
+        if !self.list_custom_models()?.iter().any(|m| m.id == id) {
+            return Err(crate::Error::CustomModelNotFound);
+        }
+
+        self.local_llm_store()
+            .set(crate::StoreKey::CurrentCustomModel, id)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    fn list_custom_models(&self) -> Result<Vec<crate::CustomModel>, crate::Error> {
+        let store = self.local_llm_store();
+        let models: Option<Vec<crate::CustomModel>> = store.get(crate::StoreKey::CustomModels)?;
+        Ok(models.unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn add_custom_model(
+        &self,
+        path: impl Into<PathBuf>,
+    ) -> Result<crate::CustomModel, crate::Error> {
+        use hypr_gguf::GgufExt;
+
+        let path = path.into();
+        let path = std::fs::canonicalize(&path).unwrap_or(path);
+
+        if path.gguf_chat_format()?.is_none() {
+            return Err(crate::Error::ChatTemplateNotFound);
+        }
+        let metadata = path.gguf_metadata()?;
+
+        let file_stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let model = crate::CustomModel {
+            // Keyed on the path, since different directories often have models with the same file name.
+            id: format!("custom:{}", path.display()),
+            name: metadata.name.unwrap_or(file_stem),
+            path: path.to_string_lossy().to_string(),
+            size_bytes: metadata.size_bytes,
+            context_length: metadata.context_length,
+        };
+
+        let mut models = self.list_custom_models()?;
+        models.retain(|m| m.id != model.id);
+        models.push(model.clone());
+        self.local_llm_store()
+            .set(crate::StoreKey::CustomModels, models)?;
+
+        let manager = {
+            let state = self.state::<crate::SharedState>();
+            let s = state.lock().await;
+            s.model_manager.clone()
+        };
+        if let Some(manager) = manager {
+            manager.register(&model.id, &path).await;
+        }
+
+        Ok(model)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn remove_custom_model(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let id = id.into();
+
+        let mut models = self.list_custom_models()?;
+        let before = models.len();
+        models.retain(|m| m.id != id);
+        if models.len() == before {
+            return Err(crate::Error::CustomModelNotFound);
+        }
+        self.local_llm_store()
+            .set(crate::StoreKey::CustomModels, models)?;
+
+        let manager = {
+            let state = self.state::<crate::SharedState>();
+            let s = state.lock().await;
+            s.model_manager.clone()
+        };
+        if let Some(manager) = manager {
+            manager.unregister(&id).await;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn list_loaded_models(&self) -> Vec<String> {
+        let manager = {
+            let state = self.state::<crate::SharedState>();
+            let s = state.lock().await;
+            s.model_manager.clone()
+        };
+
+        match manager {
+            Some(manager) => manager.loaded_models().await,
+            None => vec![],
+        }
+    }
 }
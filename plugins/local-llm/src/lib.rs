@@ -29,6 +29,7 @@ pub type SharedState = std::sync::Arc<tokio::sync::Mutex<State>>;
 pub struct State {
     pub api_base: Option<String>,
     pub server: Option<crate::server::ServerHandle>,
+    pub model_manager: Option<crate::ModelManager>,
     pub download_task: HashMap<SupportedModel, tokio::task::JoinHandle<()>>,
 }
 
@@ -47,7 +48,13 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::restart_server::<Wry>,
             commands::get_current_model::<Wry>,
             commands::set_current_model::<Wry>,
+            commands::get_current_model_id::<Wry>,
+            commands::set_current_custom_model::<Wry>,
             commands::list_downloaded_model::<Wry>,
+            commands::list_custom_models::<Wry>,
+            commands::add_custom_model::<Wry>,
+            commands::remove_custom_model::<Wry>,
+            commands::list_loaded_models::<Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
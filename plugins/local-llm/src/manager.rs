@@ -1,11 +1,41 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::{watch, Mutex};
+
+use hypr_gguf::GgufExt;
+use tokio::sync::{watch, Mutex, Notify};
+use tokio::time::Instant;
+
+struct LoadedModel {
+    model: Arc<hypr_llama::Llama>,
+    memory_bytes: u64,
+    last_used: Instant,
+}
+
+impl LoadedModel {
+    // The manager holds one reference, anything beyond that is an in-flight request.
+    fn in_use(&self) -> bool {
+        Arc::strong_count(&self.model) > 1
+    }
+}
+
+// A model that is being loaded, with the memory reserved for it.
+struct LoadingModel {
+    memory_bytes: u64,
+    done: Arc<Notify>,
+}
+
+struct Models {
+    registry: HashMap<String, PathBuf>,
+    loaded: HashMap<String, LoadedModel>,
+    loading: HashMap<String, LoadingModel>,
+}
 
 #[derive(Clone)]
 pub struct ModelManager {
-    model_path: std::path::PathBuf,
-    model: Arc<Mutex<Option<Arc<hypr_llama::Llama>>>>,
-    last_activity: Arc<Mutex<Option<tokio::time::Instant>>>,
+    default_model: String,
+    memory_budget: u64,
+    models: Arc<Mutex<Models>>,
     _drop_guard: Arc<DropGuard>,
 }
 
@@ -20,13 +50,21 @@ impl Drop for DropGuard {
 }
 
 impl ModelManager {
-    pub fn new(model_path: impl Into<std::path::PathBuf>) -> Self {
+    pub fn new(
+        registry: HashMap<String, PathBuf>,
+        default_model: impl Into<String>,
+        memory_budget: u64,
+    ) -> Self {
         let (shutdown_tx, shutdown_rx) = watch::channel(());
 
         let manager = Self {
-            model_path: model_path.into(),
-            model: Arc::new(tokio::sync::Mutex::new(None)),
-            last_activity: Arc::new(tokio::sync::Mutex::new(None)),
+            default_model: default_model.into(),
+            memory_budget,
+            models: Arc::new(Mutex::new(Models {
+                registry,
+                loaded: HashMap::new(),
+                loading: HashMap::new(),
+            })),
             _drop_guard: Arc::new(DropGuard { shutdown_tx }),
         };
 
@@ -34,35 +72,140 @@ impl ModelManager {
         manager
     }
 
-    pub async fn update_activity(&self) {
-        *self.last_activity.lock().await = Some(tokio::time::Instant::now());
+    pub fn default_model(&self) -> &str {
+        &self.default_model
     }
 
-    pub async fn get_model(&self) -> Result<std::sync::Arc<hypr_llama::Llama>, crate::Error> {
-        self.update_activity().await;
+    pub async fn register(&self, id: impl Into<String>, path: impl Into<PathBuf>) {
+        let mut models = self.models.lock().await;
+        models.registry.insert(id.into(), path.into());
+    }
 
-        let mut guard = self.model.lock().await;
+    pub async fn unregister(&self, id: impl AsRef<str>) {
+        let mut models = self.models.lock().await;
+        models.registry.remove(id.as_ref());
+        models.loaded.remove(id.as_ref());
+    }
 
-        match guard.as_ref() {
-            Some(model) => Ok(model.clone()),
-            None => {
-                if !self.model_path.exists() {
-                    return Err(crate::Error::ModelNotDownloaded);
-                }
+    pub async fn loaded_models(&self) -> Vec<String> {
+        let models = self.models.lock().await;
+        models.loaded.keys().cloned().collect()
+    }
+
+    // Unknown ids (e.g. "gpt-4" sent by OpenAI-compatible clients) fall back to the default model.
+    // Loading takes seconds, so it happens without holding the lock, and concurrent requests for
+    // the same model wait for that load instead of starting another one.
+    pub async fn get_model(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<std::sync::Arc<hypr_llama::Llama>, crate::Error> {
+        let (id, path, done) = loop {
+            let mut models = self.models.lock().await;
 
-                let model = Arc::new(hypr_llama::Llama::new(&self.model_path)?);
-                *guard = Some(model.clone());
-                Ok(model)
+            let id = if models.registry.contains_key(id.as_ref()) {
+                id.as_ref().to_string()
+            } else {
+                self.default_model.clone()
+            };
+
+            if let Some(loaded) = models.loaded.get_mut(&id) {
+                loaded.last_used = Instant::now();
+                return Ok(loaded.model.clone());
             }
-        }
+
+            if let Some(loading) = models.loading.get(&id) {
+                let done = loading.done.clone();
+                let notified = done.notified();
+                drop(models);
+
+                notified.await;
+                continue;
+            }
+
+            let path = match models.registry.get(&id) {
+                Some(path) if path.exists() => path.clone(),
+                _ => return Err(crate::Error::ModelNotDownloaded),
+            };
+
+            let memory_bytes = estimate_memory(&path)?;
+
+            let now = Instant::now();
+            let residents = models
+                .loaded
+                .iter()
+                .map(|(id, m)| Resident {
+                    id: id.clone(),
+                    memory_bytes: m.memory_bytes,
+                    last_used: m.last_used,
+                    in_use: m.in_use(),
+                })
+                .chain(models.loading.iter().map(|(id, m)| Resident {
+                    id: id.clone(),
+                    memory_bytes: m.memory_bytes,
+                    last_used: now,
+                    in_use: true,
+                }))
+                .collect::<Vec<_>>();
+
+            let evictions = select_evictions(&residents, memory_bytes, self.memory_budget)
+                .ok_or(crate::Error::MemoryBudgetExceeded)?;
+
+            for evicted in evictions {
+                tracing::info!(model = %evicted, "unloading_model_for_memory");
+                models.loaded.remove(&evicted);
+            }
+
+            let done = Arc::new(Notify::new());
+            models.loading.insert(
+                id.clone(),
+                LoadingModel {
+                    memory_bytes,
+                    done: done.clone(),
+                },
+            );
+
+            tracing::info!(model = %id, memory_bytes, "loading_model");
+            break (id, path, done);
+        };
+
+        // Detached, so that the `loading` entry is cleared even if this request is dropped.
+        let models = self.models.clone();
+        let task = tokio::spawn(async move {
+            let loaded = tokio::task::spawn_blocking(move || hypr_llama::Llama::new(&path)).await;
+
+            let mut models = models.lock().await;
+            let memory_bytes = models
+                .loading
+                .remove(&id)
+                .map(|m| m.memory_bytes)
+                .unwrap_or_default();
+            done.notify_waiters();
+
+            let model = Arc::new(loaded??);
+
+            // Unregistered while it was loading.
+            if models.registry.contains_key(&id) {
+                models.loaded.insert(
+                    id,
+                    LoadedModel {
+                        model: model.clone(),
+                        memory_bytes,
+                        last_used: Instant::now(),
+                    },
+                );
+            }
+
+            Ok::<_, crate::Error>(model)
+        });
+
+        task.await?
     }
 
     fn monitor(&self, shutdown_rx: watch::Receiver<()>) {
         let activity_check_interval = std::time::Duration::from_secs(3);
         let inactivity_threshold = std::time::Duration::from_secs(150);
 
-        let model = self.model.clone();
-        let last_activity = self.last_activity.clone();
+        let models = self.models.clone();
 
         let _handle = tokio::spawn(async move {
             let mut shutdown_rx = shutdown_rx;
@@ -76,19 +219,97 @@ impl ModelManager {
                         break;
                     },
                     _ = interval.tick() => {
-                        let should_unload = match *last_activity.lock().await {
-                            Some(last_time) if last_time.elapsed() > inactivity_threshold => {
-                                model.lock().await.is_some()
-                            },
-                            _ => false
-                        };
-
-                        if should_unload {
-                            *model.lock().await = None;
-                        }
+                        let mut models = models.lock().await;
+                        models.loaded.retain(|_, m| {
+                            m.in_use() || m.last_used.elapsed() <= inactivity_threshold
+                        });
                     }
                 }
             }
         });
     }
 }
+
+// Weights are mmapped as a whole, plus roughly 20% for the KV cache and compute buffers.
+fn estimate_memory(path: &std::path::Path) -> Result<u64, crate::Error> {
+    let size_bytes = path.gguf_metadata()?.size_bytes;
+    Ok(size_bytes + size_bytes / 5)
+}
+
+struct Resident {
+    id: String,
+    memory_bytes: u64,
+    last_used: Instant,
+    in_use: bool,
+}
+
+// Picks the least recently used idle models to unload so that `required` more bytes fit in `budget`.
+// A model that is bigger than the whole budget can still be loaded once everything else is unloaded.
+// Returns `None` when models that are serving requests would have to be unloaded.
+fn select_evictions(residents: &[Resident], required: u64, budget: u64) -> Option<Vec<String>> {
+    let mut used: u64 = residents.iter().map(|r| r.memory_bytes).sum();
+    let mut remaining = residents.len();
+
+    let mut idle = residents.iter().filter(|r| !r.in_use).collect::<Vec<_>>();
+    idle.sort_by_key(|r| r.last_used);
+
+    let mut evictions = Vec::new();
+    let mut idle = idle.into_iter();
+
+    while remaining > 0 && used + required > budget {
+        let victim = idle.next()?;
+        used -= victim.memory_bytes;
+        remaining -= 1;
+        evictions.push(victim.id.clone());
+    }
+
+    Some(evictions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resident(id: &str, memory_bytes: u64, age_secs: u64, in_use: bool) -> Resident {
+        Resident {
+            id: id.to_string(),
+            memory_bytes,
+            last_used: Instant::now() - std::time::Duration::from_secs(age_secs),
+            in_use,
+        }
+    }
+
+    #[test]
+    fn test_select_evictions() {
+        let residents = vec![
+            resident("a", 4, 10, false),
+            resident("b", 4, 30, false),
+            resident("c", 4, 20, false),
+        ];
+
+        assert_eq!(select_evictions(&residents, 2, 16), Some(vec![]));
+        assert_eq!(
+            select_evictions(&residents, 6, 16),
+            Some(vec!["b".to_string()])
+        );
+        assert_eq!(
+            select_evictions(&residents, 10, 16),
+            Some(vec!["b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            select_evictions(&residents, 32, 16),
+            Some(vec!["b".to_string(), "c".to_string(), "a".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_select_evictions_in_use() {
+        let residents = vec![resident("a", 4, 10, true), resident("b", 4, 30, false)];
+
+        assert_eq!(
+            select_evictions(&residents, 8, 12),
+            Some(vec!["b".to_string()])
+        );
+        assert_eq!(select_evictions(&residents, 12, 12), None);
+    }
+}
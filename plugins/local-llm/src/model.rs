@@ -12,7 +12,17 @@ pub struct ModelInfo {
     pub size_bytes: u64,
 }
 
-#[derive(Debug, Eq, Hash, PartialEq, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+#[derive(
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+    strum::Display,
+)]
 pub enum SupportedModel {
     Llama3p2_3bQ4,
     HyprLLM,
@@ -53,6 +63,16 @@ impl SupportedModel {
     }
 }
 
+// User-supplied GGUF file. Requests whose `model` field equals `id` are routed to it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct CustomModel {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub size_bytes: u64,
+    pub context_length: Option<u64>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, specta::Type)]
 pub enum ModelIdentifier {
     #[serde(rename = "local")]
@@ -105,7 +105,9 @@ pub async fn run_server(state: ServerState) -> Result<ServerHandle, crate::Error
 }
 
 async fn health(AxumState(state): AxumState<ServerState>) -> impl IntoResponse {
-    match state.model_manager.get_model().await {
+    let manager = &state.model_manager;
+
+    match manager.get_model(manager.default_model()).await {
         Ok(_) => (StatusCode::OK, "OK".to_string()),
         Err(e) => (StatusCode::SERVICE_UNAVAILABLE, e.to_string()),
     }
@@ -151,7 +153,7 @@ impl LocalProvider {
         request: CreateChatCompletionRequest,
         state: &ServerState,
     ) -> Result<ChatCompletionResponse, crate::Error> {
-        let model = self.model_manager.get_model().await?;
+        let model = self.model_manager.get_model(&request.model).await?;
         tracing::info!("loaded_model: {:?}", model.name);
 
         build_chat_completion_response(&request, || {
@@ -215,6 +217,7 @@ impl LocalProvider {
             messages,
             grammar,
             tools,
+            sampling: hypr_llama::SamplingParams::from(request),
         };
 
         let (progress_sender, mut progress_receiver) = mpsc::unbounded_channel::<f64>();
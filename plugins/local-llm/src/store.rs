@@ -3,6 +3,9 @@ use tauri_plugin_store2::ScopedStoreKey;
 #[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
 pub enum StoreKey {
     Model,
+    CustomModels,
+    /// Id of the selected custom model, which takes precedence over `Model` when set
+    CurrentCustomModel,
     /// Track if user has been migrated from Llama3p2_3bQ4 to HyprLLM default
     DefaultModelMigrated,
     /// Track the last app version that ran migrations
@@ -11,6 +11,9 @@ const COMMANDS: &[&str] = &[
     "set_current_model",
     "list_supported_models",
     "list_supported_languages",
+    "retranscribe_session",
+    "get_retranscription_diff",
+    "apply_retranscription",
 ];
 
 fn main() {
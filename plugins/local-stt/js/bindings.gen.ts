@@ -42,6 +42,15 @@ async listSupportedModels() : Promise<SttModelInfo[]> {
 },
 async listSupportedLanguages(model: SupportedSttModel) : Promise<Language[]> {
     return await TAURI_INVOKE("plugin:local-stt|list_supported_languages", { model });
+},
+async retranscribeSession(sessionId: string, model: SupportedSttModel, replace: boolean) : Promise<string> {
+    return await TAURI_INVOKE("plugin:local-stt|retranscribe_session", { sessionId, model, replace });
+},
+async getRetranscriptionDiff(sessionId: string) : Promise<TranscriptDiff[]> {
+    return await TAURI_INVOKE("plugin:local-stt|get_retranscription_diff", { sessionId });
+},
+async applyRetranscription(sessionId: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:local-stt|apply_retranscription", { sessionId });
 }
 }
 
@@ -63,6 +72,7 @@ export type ServerType = "internal" | "external"
 export type SttModelInfo = { key: SupportedSttModel; display_name: string; size_bytes: number }
 export type SupportedSttModel = WhisperModel | AmModel
 export type TAURI_CHANNEL<TSend> = null
+export type TranscriptDiff = { type: "equal"; text: string } | { type: "removed"; text: string } | { type: "added"; text: string }
 export type WhisperModel = "QuantizedTiny" | "QuantizedTinyEn" | "QuantizedBase" | "QuantizedBaseEn" | "QuantizedSmall" | "QuantizedSmallEn" | "QuantizedLargeTurbo"
 
 /** tauri-specta globals **/
@@ -3,7 +3,7 @@ use tauri::ipc::Channel;
 
 use crate::{
     server::{ServerHealth, ServerType},
-    LocalSttPluginExt, SttModelInfo, SupportedSttModel, SUPPORTED_MODELS,
+    LocalSttPluginExt, SttModelInfo, SupportedSttModel, TranscriptDiff, SUPPORTED_MODELS,
 };
 
 #[tauri::command]
@@ -110,3 +110,38 @@ pub async fn get_servers<R: tauri::Runtime>(
 pub fn list_supported_languages(model: SupportedSttModel) -> Vec<hypr_language::Language> {
     model.supported_languages()
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    model: SupportedSttModel,
+    replace: bool,
+) -> Result<String, String> {
+    app.retranscribe_session(session_id, model, replace)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_retranscription_diff<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<Vec<TranscriptDiff>, String> {
+    app.get_retranscription_diff(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_retranscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<(), String> {
+    app.apply_retranscription(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
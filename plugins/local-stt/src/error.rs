@@ -16,6 +16,12 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error(transparent)]
     StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] tauri_plugin_db::Error),
+    #[error(transparent)]
+    TranscribeError(#[from] hypr_transcribe_whisper_local::Error),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
     #[error("Model not downloaded")]
     ModelNotDownloaded,
     #[error("Server already running")]
@@ -26,6 +32,12 @@ pub enum Error {
     AmApiKeyNotSet,
     #[error("Internal server only supports Whisper models")]
     UnsupportedModelType,
+    #[error("Session not found: {0}")]
+    SessionNotFound(String),
+    #[error("Recording not found: {0}")]
+    RecordingNotFound(String),
+    #[error("Re-transcription not found: {0}")]
+    RetranscriptionNotFound(String),
 }
 
 impl Serialize for Error {
@@ -1,8 +1,10 @@
 use std::{collections::HashMap, future::Future, path::PathBuf};
 
 use tauri::{ipc::Channel, Manager, Runtime};
+use tauri_plugin_db::DatabasePluginExt;
 use tauri_plugin_shell::ShellExt;
 use tauri_plugin_store2::StorePluginExt;
+use tauri_plugin_task::TaskPluginExt;
 
 use hypr_download_interface::DownloadProgress;
 use hypr_file::download_file_parallel_cancellable;
@@ -11,6 +13,7 @@ use tokio_util::sync::CancellationToken;
 
 use crate::{
     model::SupportedSttModel,
+    retranscribe::{carry_over_speakers, diff_words, TranscriptDiff},
     server::{external, internal, ServerHealth, ServerType},
     Connection,
 };
@@ -51,6 +54,21 @@ pub trait LocalSttPluginExt<R: Runtime> {
         &self,
         model: &SupportedSttModel,
     ) -> impl Future<Output = Result<bool, crate::Error>>;
+
+    fn retranscribe_session(
+        &self,
+        session_id: String,
+        model: SupportedSttModel,
+        replace: bool,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
+    fn get_retranscription_diff(
+        &self,
+        session_id: String,
+    ) -> impl Future<Output = Result<Vec<TranscriptDiff>, crate::Error>>;
+    fn apply_retranscription(
+        &self,
+        session_id: String,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
 }
 
 impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
@@ -466,4 +484,147 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
         self.start_server(Some(model)).await?;
         Ok(())
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn retranscribe_session(
+        &self,
+        session_id: String,
+        model: SupportedSttModel,
+        replace: bool,
+    ) -> Result<String, crate::Error> {
+        let SupportedSttModel::Whisper(whisper_model) = &model else {
+            return Err(crate::Error::UnsupportedModelType);
+        };
+
+        if !self.is_model_downloaded(&model).await? {
+            return Err(crate::Error::ModelNotDownloaded);
+        }
+
+        if self.db_get_session(&session_id).await?.is_none() {
+            return Err(crate::Error::SessionNotFound(session_id));
+        }
+
+        let session_dir = self.path().app_data_dir()?.join(&session_id);
+        let Some(audio_path) = hypr_audio_codec::find_recording(&session_dir, "audio") else {
+            return Err(crate::Error::RecordingNotFound(session_id));
+        };
+
+        let config = match self.db_user_id().await? {
+            Some(user_id) => self.db_get_config(user_id).await?,
+            None => None,
+        };
+        let params = owhisper_interface::ListenParams {
+            languages: config
+                .as_ref()
+                .map_or_else(Vec::new, |c| c.general.spoken_languages.clone()),
+            keywords: config.map_or_else(Vec::new, |c| c.general.jargons),
+            ..Default::default()
+        };
+
+        let model_path = self.models_dir().join(whisper_model.file_name());
+        let app = self.app_handle().clone();
+
+        let task_id = self.spawn_task_blocking(move |mut ctx| {
+            let result = hypr_transcribe_whisper_local::process_recorded(
+                &model_path,
+                &audio_path,
+                &params,
+                |done, total| {
+                    if done == 0 {
+                        let _ = ctx.set_total(total as u32);
+                    } else {
+                        let _ = ctx.advance(());
+                    }
+                    !ctx.is_cancelled()
+                },
+            );
+
+            let result = result.map_err(crate::Error::from).and_then(|words| {
+                std::fs::write(
+                    session_dir.join(RETRANSCRIPTION_FILE),
+                    serde_json::to_string(&words)?,
+                )?;
+
+                if replace {
+                    tauri::async_runtime::block_on(app.apply_retranscription(session_id))
+                } else {
+                    Ok(())
+                }
+            });
+
+            match result {
+                Ok(()) => {
+                    let _ = ctx.complete();
+                }
+                // `cancel_task` already marked the task as cancelled.
+                Err(crate::Error::TranscribeError(
+                    hypr_transcribe_whisper_local::Error::Cancelled,
+                )) => {}
+                Err(e) => {
+                    tracing::error!("retranscribe_failed: {}", e);
+                    let _ = ctx.fail(e.to_string());
+                }
+            }
+        });
+
+        Ok(task_id)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn get_retranscription_diff(
+        &self,
+        session_id: String,
+    ) -> Result<Vec<TranscriptDiff>, crate::Error> {
+        let words = read_retranscription(self, &session_id)?;
+        let session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::SessionNotFound(session_id))?;
+
+        Ok(diff_words(&session.words, &words))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn apply_retranscription(&self, session_id: String) -> Result<(), crate::Error> {
+        let mut words = read_retranscription(self, &session_id)?;
+        let mut session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::SessionNotFound(session_id.clone()))?;
+
+        carry_over_speakers(&session.words, &mut words);
+        session.words = words;
+        self.db_upsert_session(session).await?;
+
+        std::fs::remove_file(retranscription_path(self, &session_id)?)?;
+        Ok(())
+    }
+}
+
+// Kept next to the recording until it is applied, so it can be reviewed as a diff first.
+const RETRANSCRIPTION_FILE: &str = "retranscription.json";
+
+fn retranscription_path<R: Runtime>(
+    app: &impl Manager<R>,
+    session_id: &str,
+) -> Result<PathBuf, crate::Error> {
+    Ok(app
+        .path()
+        .app_data_dir()?
+        .join(session_id)
+        .join(RETRANSCRIPTION_FILE))
+}
+
+fn read_retranscription<R: Runtime>(
+    app: &impl Manager<R>,
+    session_id: &str,
+) -> Result<Vec<owhisper_interface::Word2>, crate::Error> {
+    let path = retranscription_path(app, session_id)?;
+    if !path.exists() {
+        return Err(crate::Error::RetranscriptionNotFound(
+            session_id.to_string(),
+        ));
+    }
+
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
 }
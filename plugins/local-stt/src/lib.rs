@@ -7,6 +7,7 @@ mod error;
 mod events;
 mod ext;
 mod model;
+mod retranscribe;
 mod server;
 mod store;
 mod types;
@@ -15,6 +16,7 @@ pub use error::*;
 use events::*;
 pub use ext::*;
 pub use model::*;
+pub use retranscribe::*;
 pub use store::*;
 pub use types::*;
 
@@ -46,6 +48,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::stop_server::<Wry>,
             commands::list_supported_models,
             commands::list_supported_languages,
+            commands::retranscribe_session::<Wry>,
+            commands::get_retranscription_diff::<Wry>,
+            commands::apply_retranscription::<Wry>,
         ])
         .typ::<hypr_whisper_local_model::WhisperModel>()
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
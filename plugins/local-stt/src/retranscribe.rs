@@ -0,0 +1,190 @@
+use owhisper_interface::Word2;
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TranscriptDiff {
+    Equal { text: String },
+    Removed { text: String },
+    Added { text: String },
+}
+
+// Words are compared without case and punctuation, which the two transcripts rarely agree on.
+pub fn diff_words(old: &[Word2], new: &[Word2]) -> Vec<TranscriptDiff> {
+    let key = |word: &Word2| {
+        word.text
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+    let join = |words: &[Word2]| {
+        words
+            .iter()
+            .map(|word| word.text.trim())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let old_keys = old.iter().map(key).collect::<Vec<_>>();
+    let new_keys = new.iter().map(key).collect::<Vec<_>>();
+
+    let mut diffs: Vec<TranscriptDiff> = Vec::new();
+    let mut push = |diff: TranscriptDiff| match (diffs.last_mut(), &diff) {
+        (Some(TranscriptDiff::Equal { text: last }), TranscriptDiff::Equal { text })
+        | (Some(TranscriptDiff::Removed { text: last }), TranscriptDiff::Removed { text })
+        | (Some(TranscriptDiff::Added { text: last }), TranscriptDiff::Added { text }) => {
+            last.push(' ');
+            last.push_str(text);
+        }
+        _ => diffs.push(diff),
+    };
+
+    for op in capture_diff_slices(Algorithm::Myers, &old_keys, &new_keys) {
+        let (tag, old_range, new_range) = op.as_tag_tuple();
+
+        match tag {
+            DiffTag::Equal => push(TranscriptDiff::Equal {
+                text: join(&new[new_range]),
+            }),
+            DiffTag::Delete => push(TranscriptDiff::Removed {
+                text: join(&old[old_range]),
+            }),
+            DiffTag::Insert => push(TranscriptDiff::Added {
+                text: join(&new[new_range]),
+            }),
+            DiffTag::Replace => {
+                push(TranscriptDiff::Removed {
+                    text: join(&old[old_range]),
+                });
+                push(TranscriptDiff::Added {
+                    text: join(&new[new_range]),
+                });
+            }
+        }
+    }
+
+    diffs
+}
+
+// The new words only carry timestamps, so each takes the speaker of the old word it overlaps the most.
+pub fn carry_over_speakers(old: &[Word2], new: &mut [Word2]) {
+    let span = |word: &Word2| match (word.start_ms, word.end_ms) {
+        (Some(start), Some(end)) => Some((start, end.max(start))),
+        _ => None,
+    };
+
+    let mut old = old
+        .iter()
+        .filter(|word| word.speaker.is_some())
+        .filter_map(|word| span(word).map(|span| (span, word)))
+        .collect::<Vec<_>>();
+    old.sort_by_key(|((start, _), _)| *start);
+
+    for word in new.iter_mut() {
+        let Some((start, end)) = span(word) else {
+            continue;
+        };
+
+        let best = old
+            .iter()
+            .take_while(|((old_start, _), _)| *old_start <= end)
+            .filter_map(|((old_start, old_end), old_word)| {
+                let overlap = end.min(*old_end).saturating_sub(start.max(*old_start));
+                // Zero-length words still count when they touch.
+                let touches = *old_start <= end && start <= *old_end;
+                touches.then_some((overlap, *old_word))
+            })
+            .max_by_key(|(overlap, _)| *overlap);
+
+        if let Some((_, old_word)) = best {
+            word.speaker = old_word.speaker.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use owhisper_interface::SpeakerIdentity;
+
+    fn words(text: &str) -> Vec<Word2> {
+        text.split(' ')
+            .map(|text| Word2 {
+                text: text.to_string(),
+                speaker: None,
+                confidence: None,
+                start_ms: None,
+                end_ms: None,
+            })
+            .collect()
+    }
+
+    fn timed(words: &[(&str, u64, u64, Option<u8>)]) -> Vec<Word2> {
+        words
+            .iter()
+            .map(|(text, start_ms, end_ms, speaker)| Word2 {
+                text: text.to_string(),
+                speaker: speaker.map(|index| SpeakerIdentity::Unassigned { index }),
+                confidence: None,
+                start_ms: Some(*start_ms),
+                end_ms: Some(*end_ms),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_carry_over_speakers() {
+        let old = timed(&[
+            ("hello", 0, 400, Some(0)),
+            ("there", 400, 900, Some(0)),
+            ("hi", 1000, 1300, Some(1)),
+            ("back", 1300, 1600, Some(1)),
+        ]);
+        let mut new = timed(&[
+            ("Hello", 0, 380, None),
+            ("there.", 380, 950, None),
+            ("Hi", 950, 1250, None),
+            ("back.", 1250, 1700, None),
+            ("Bye.", 3000, 3200, None),
+        ]);
+
+        carry_over_speakers(&old, &mut new);
+
+        assert_eq!(
+            new.iter()
+                .map(|word| match word.speaker {
+                    Some(SpeakerIdentity::Unassigned { index }) => Some(index),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            vec![Some(0), Some(0), Some(1), Some(1), None]
+        );
+    }
+
+    #[test]
+    fn test_diff_words() {
+        let diffs = diff_words(
+            &words("so the hyper note team is shipping today"),
+            &words("So the Hyprnote team is shipping today."),
+        );
+
+        assert_eq!(
+            diffs,
+            vec![
+                TranscriptDiff::Equal {
+                    text: "So the".to_string()
+                },
+                TranscriptDiff::Removed {
+                    text: "hyper note".to_string()
+                },
+                TranscriptDiff::Added {
+                    text: "Hyprnote".to_string()
+                },
+                TranscriptDiff::Equal {
+                    text: "team is shipping today.".to_string()
+                },
+            ]
+        );
+    }
+}
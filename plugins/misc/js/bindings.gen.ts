@@ -19,7 +19,7 @@ async opinionatedMdToHtml(text: string) : Promise<string> {
 async deleteSessionFolder(sessionId: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:misc|delete_session_folder", { sessionId });
 },
-async parseMeetingLink(text: string) : Promise<string | null> {
+async parseMeetingLink(text: string) : Promise<MeetingLink | null> {
     return await TAURI_INVOKE("plugin:misc|parse_meeting_link", { text });
 },
 async audioExist(sessionId: string) : Promise<boolean> {
@@ -43,7 +43,8 @@ async audioDelete(sessionId: string) : Promise<null> {
 
 /** user-defined types **/
 
-
+export type MeetingLink = { platform: MeetingPlatform; url: string; meeting_id: string | null; passcode: string | null; dial_in: string[] }
+export type MeetingPlatform = "Zoom" | "GoogleMeet" | "Teams" | "Webex" | "Chime" | "Jitsi" | "Whereby" | "SlackHuddle" | "CalCom"
 
 /** tauri-specta globals **/
 
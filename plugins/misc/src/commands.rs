@@ -33,9 +33,7 @@ pub async fn audio_exist<R: tauri::Runtime>(
     session_id: String,
 ) -> Result<bool, String> {
     let data_dir = app.path().app_data_dir().unwrap();
-    let audio_path = data_dir.join(session_id).join("audio.wav");
-
-    let v = std::fs::exists(audio_path).map_err(|e| e.to_string())?;
+    let v = hypr_audio_codec::find_recording(data_dir.join(session_id), "audio").is_some();
     Ok(v)
 }
 
@@ -46,9 +44,13 @@ pub async fn audio_delete<R: tauri::Runtime>(
     session_id: String,
 ) -> Result<(), String> {
     let data_dir = app.path().app_data_dir().unwrap();
-    let audio_path = data_dir.join(session_id).join("audio.wav");
+    let session_dir = data_dir.join(session_id);
 
-    std::fs::remove_file(audio_path).map_err(|e| e.to_string())?;
+    for track in hypr_audio_codec::RECORDING_TRACKS {
+        while let Some(path) = hypr_audio_codec::find_recording(&session_dir, track) {
+            std::fs::remove_file(path).map_err(|e| e.to_string())?;
+        }
+    }
     Ok(())
 }
 
@@ -59,7 +61,8 @@ pub async fn audio_open<R: tauri::Runtime>(
     session_id: String,
 ) -> Result<(), String> {
     let data_dir = app.path().app_data_dir().unwrap();
-    let audio_path = data_dir.join(session_id).join("audio.wav");
+    let audio_path = hypr_audio_codec::find_recording(data_dir.join(&session_id), "audio")
+        .ok_or_else(|| format!("no recording for session {}", session_id))?;
 
     app.opener()
         .reveal_item_in_dir(&audio_path)
@@ -89,6 +92,6 @@ pub async fn delete_session_folder<R: tauri::Runtime>(
 pub async fn parse_meeting_link<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     text: String,
-) -> Option<String> {
+) -> Option<hypr_meeting_link::MeetingLink> {
     app.parse_meeting_link(&text)
 }
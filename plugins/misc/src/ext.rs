@@ -4,7 +4,7 @@ pub trait MiscPluginExt<R: Runtime> {
     fn get_git_hash(&self) -> String;
     fn get_fingerprint(&self) -> String;
     fn opinionated_md_to_html(&self, text: impl AsRef<str>) -> Result<String, String>;
-    fn parse_meeting_link(&self, text: impl AsRef<str>) -> Option<String>;
+    fn parse_meeting_link(&self, text: impl AsRef<str>) -> Option<hypr_meeting_link::MeetingLink>;
 }
 
 impl<R: Runtime, T: Manager<R>> MiscPluginExt<R> for T {
@@ -20,22 +20,7 @@ impl<R: Runtime, T: Manager<R>> MiscPluginExt<R> for T {
         hypr_buffer::opinionated_md_to_html(text.as_ref()).map_err(|e| e.to_string())
     }
 
-    fn parse_meeting_link(&self, text: impl AsRef<str>) -> Option<String> {
-        let text = text.as_ref();
-
-        for regex in hypr_detect::MEETING_REGEXES.iter() {
-            if let Some(capture) = regex.find(text) {
-                return Some(capture.as_str().to_string());
-            }
-        }
-
-        let url_pattern = r"https?://[^\s]+";
-        if let Ok(regex) = regex::Regex::new(url_pattern) {
-            if let Some(capture) = regex.find(text) {
-                return Some(capture.as_str().to_string());
-            }
-        }
-
-        None
+    fn parse_meeting_link(&self, text: impl AsRef<str>) -> Option<hypr_meeting_link::MeetingLink> {
+        hypr_meeting_link::parse(text.as_ref())
     }
 }
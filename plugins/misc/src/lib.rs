@@ -194,7 +194,7 @@ mod test {
         ];
 
         for test_case in test_cases {
-            let result = app.parse_meeting_link(test_case.input);
+            let result = app.parse_meeting_link(test_case.input).map(|link| link.url);
             assert_eq!(
                 result,
                 Some(test_case.expected.to_string()),
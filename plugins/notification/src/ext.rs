@@ -76,11 +76,13 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
             )
         };
 
+        let app = self.app_handle().clone();
+
         let state = self.state::<crate::SharedState>();
         let mut s = state.lock().unwrap();
 
         s.worker_handle = Some(tokio::runtime::Handle::current().spawn(async move {
-            let _ = crate::worker::monitor(crate::worker::WorkerState { db, user_id }).await;
+            let _ = crate::worker::monitor(crate::worker::WorkerState { app, db, user_id }).await;
         }));
 
         Ok(())
@@ -100,15 +102,35 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
 
     #[tracing::instrument(skip(self))]
     fn start_detect_notification(&self) -> Result<(), Error> {
+        let app = self.app_handle().clone();
+
         let cb = hypr_detect::new_callback(move |_bundle_id| {
-            let notif = hypr_notification2::Notification {
-                title: "Meeting detected".to_string(),
-                message: "Click here to start writing a note".to_string(),
-                url: Some("hypr://hyprnote.com/notification".to_string()),
-                timeout: Some(std::time::Duration::from_secs(10)),
-            };
-
-            hypr_notification2::show(notif);
+            let app = app.clone();
+
+            tauri::async_runtime::spawn(async move {
+                let db_state = app.state::<tauri_plugin_db::ManagedState>();
+                let (db, user_id) = {
+                    let guard = db_state.lock().await;
+                    (guard.db.clone(), guard.user_id.clone())
+                };
+
+                if let (Some(db), Some(user_id)) = (db, user_id) {
+                    match crate::worker::auto_start_detected(&app, &db, &user_id).await {
+                        Ok(true) => return,
+                        Ok(false) => {}
+                        Err(e) => tracing::error!("auto_start_failed: {:?}", e),
+                    }
+                }
+
+                let notif = hypr_notification2::Notification {
+                    title: "Meeting detected".to_string(),
+                    message: "Click here to start writing a note".to_string(),
+                    url: Some("hypr://hyprnote.com/notification".to_string()),
+                    timeout: Some(std::time::Duration::from_secs(10)),
+                };
+
+                hypr_notification2::show(notif);
+            });
         });
 
         let state = self.state::<crate::SharedState>();
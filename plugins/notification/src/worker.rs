@@ -1,14 +1,19 @@
 use apalis::prelude::{Data, Error, WorkerBuilder, WorkerFactoryFn};
 use chrono::{DateTime, Duration, Utc};
-use hypr_db_user::{ListEventFilter, ListEventFilterCommon, ListEventFilterSpecific};
+use hypr_db_user::{
+    Event, EventNotificationKind, GetSessionFilter, ListEventFilter, ListEventFilterCommon,
+    ListEventFilterSpecific, Session, UserDatabase,
+};
+use tauri_plugin_listener::{ListenerPluginExt, SessionState};
 
 #[allow(unused)]
 #[derive(Default, Debug, Clone)]
 pub struct Job(DateTime<Utc>);
 
 #[derive(Clone)]
-pub struct WorkerState {
-    pub db: hypr_db_user::UserDatabase,
+pub struct WorkerState<R: tauri::Runtime> {
+    pub app: tauri::AppHandle<R>,
+    pub db: UserDatabase,
     pub user_id: String,
 }
 
@@ -20,57 +25,254 @@ impl From<DateTime<Utc>> for Job {
 
 const EVENT_NOTIFICATION_WORKER_NAKE: &str = "event_notification_worker";
 
+// The worker ticks every minute, so this also covers the app being opened shortly after the meeting started.
+const AUTO_START_GRACE_MINUTES: i64 = 10;
+// Meeting apps are often opened a bit before the scheduled start.
+const DETECT_EARLY_MINUTES: i64 = 5;
+
 #[tracing::instrument(skip(ctx), name = EVENT_NOTIFICATION_WORKER_NAKE)]
-pub async fn perform_event_notification(_job: Job, ctx: Data<WorkerState>) -> Result<(), Error> {
-    tracing::info!("Event notification worker executing - checking for events in next 5 minutes");
-    let latest_event = ctx
+pub async fn perform_event_notification<R: tauri::Runtime>(
+    _job: Job,
+    ctx: Data<WorkerState<R>>,
+) -> Result<(), Error> {
+    let config = ctx
+        .db
+        .get_config(&ctx.user_id)
+        .await
+        .map_err(|e| crate::Error::Db(e).as_worker_error())?
+        .unwrap_or_default()
+        .notification;
+
+    let now = Utc::now();
+    let max_lead = config.lead_minutes.iter().copied().max().unwrap_or(0);
+
+    let events = ctx
         .db
         .list_events(Some(ListEventFilter {
             common: ListEventFilterCommon {
                 user_id: ctx.user_id.clone(),
-                limit: Some(1),
+                limit: None,
             },
             specific: ListEventFilterSpecific::DateRange {
-                start: Utc::now(),
-                end: Utc::now() + Duration::minutes(5),
+                start: now - Duration::minutes(AUTO_START_GRACE_MINUTES),
+                end: now + Duration::minutes(max_lead.into()),
             },
         }))
         .await
         .map_err(|e| crate::Error::Db(e).as_worker_error())?;
 
-    if let Some(event) = latest_event.first() {
-        tracing::info!("Found upcoming event - showing notification");
-
-        // Wrap in AssertUnwindSafe and handle the panic properly
-        if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            hypr_notification2::show(hypr_notification2::Notification {
-                title: "Meeting starting in 5 minutes".to_string(),
-                message: event.name.clone(),
-                url: Some(format!(
-                    "hypr://hyprnote.com/notification?event_id={}",
-                    event.id
-                )),
-                timeout: Some(std::time::Duration::from_secs(10)),
-            });
-        })) {
-            // Convert panic payload to string for logging
-            let panic_msg = if let Some(s) = e.downcast_ref::<&str>() {
-                s.to_string()
-            } else if let Some(s) = e.downcast_ref::<String>() {
-                s.clone()
-            } else {
-                "Unknown panic".to_string()
-            };
-            tracing::error!("Notification panic: {}", panic_msg);
-        } else {
-            tracing::info!("Notification shown");
+    for event in events {
+        if config.before {
+            remind(&ctx.db, &event, &config.lead_minutes, now)
+                .await
+                .map_err(|e| e.as_worker_error())?;
+        }
+
+        if config.auto_start && is_starting(&event, now) {
+            auto_start(&ctx.app, &ctx.db, &ctx.user_id, &event)
+                .await
+                .map_err(|e| e.as_worker_error())?;
         }
     }
 
     Ok(())
 }
 
-pub async fn monitor(state: WorkerState) -> Result<(), std::io::Error> {
+// Called when a meeting app is detected. Returns `true` if listening was started.
+pub async fn auto_start_detected<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    db: &UserDatabase,
+    user_id: &str,
+) -> Result<bool, crate::Error> {
+    let config = db.get_config(user_id).await?.unwrap_or_default();
+    if !config.notification.auto_start {
+        return Ok(false);
+    }
+
+    let now = Utc::now();
+
+    // `DateRange` filters on the start date, so look back far enough to catch long meetings.
+    let events = db
+        .list_events(Some(ListEventFilter {
+            common: ListEventFilterCommon {
+                user_id: user_id.to_string(),
+                limit: None,
+            },
+            specific: ListEventFilterSpecific::DateRange {
+                start: now - Duration::hours(12),
+                end: now + Duration::minutes(DETECT_EARLY_MINUTES),
+            },
+        }))
+        .await?;
+
+    let ongoing = events
+        .into_iter()
+        .filter(|e| e.end_date > now)
+        .min_by_key(|e| (e.start_date - now).abs());
+
+    match ongoing {
+        Some(event) => auto_start(app, db, user_id, &event).await,
+        None => Ok(false),
+    }
+}
+
+async fn remind(
+    db: &UserDatabase,
+    event: &Event,
+    lead_minutes: &[u32],
+    now: DateTime<Utc>,
+) -> Result<(), crate::Error> {
+    // Every due lead time is claimed, so that a late tick does not leave an earlier reminder pending.
+    let mut claimed = false;
+    for lead in due_leads(event.start_date, now, lead_minutes) {
+        claimed |= db
+            .claim_event_notification(
+                &event.id,
+                EventNotificationKind::Reminder(lead),
+                event.start_date,
+            )
+            .await?;
+    }
+
+    if claimed {
+        show(hypr_notification2::Notification {
+            title: reminder_title(event.start_date, now),
+            message: event.name.clone(),
+            url: Some(event_url(event)),
+            timeout: Some(std::time::Duration::from_secs(10)),
+        });
+    }
+
+    Ok(())
+}
+
+async fn auto_start<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    db: &UserDatabase,
+    user_id: &str,
+    event: &Event,
+) -> Result<bool, crate::Error> {
+    // Sending `Start` while another session is running would stop it.
+    if !matches!(app.get_state().await, SessionState::Inactive {}) {
+        return Ok(false);
+    }
+
+    let selected = match &event.calendar_id {
+        Some(calendar_id) => db
+            .get_calendar(calendar_id)
+            .await?
+            .is_some_and(|c| c.selected),
+        None => false,
+    };
+    if !selected {
+        return Ok(false);
+    }
+
+    // Claimed once per occurrence, so that stopping the recording is respected.
+    if !db
+        .claim_event_notification(
+            &event.id,
+            EventNotificationKind::AutoStart,
+            event.start_date,
+        )
+        .await?
+    {
+        return Ok(false);
+    }
+
+    let session = match db
+        .get_session(GetSessionFilter::CalendarEventId(event.id.clone()))
+        .await?
+    {
+        Some(session) => session,
+        None => {
+            let now = Utc::now();
+
+            db.upsert_session(Session {
+                id: uuid::Uuid::new_v4().to_string(),
+                created_at: now,
+                visited_at: now,
+                user_id: user_id.to_string(),
+                calendar_event_id: Some(event.id.clone()),
+                title: event.name.clone(),
+                raw_memo_html: "".to_string(),
+                enhanced_memo_html: None,
+                conversations: vec![],
+                words: vec![],
+                record_start: None,
+                record_end: None,
+                pre_meeting_memo_html: None,
+            })
+            .await?
+        }
+    };
+
+    app.start_session(session.id, None).await;
+
+    show(hypr_notification2::Notification {
+        title: "Recording started".to_string(),
+        message: event.name.clone(),
+        url: Some(event_url(event)),
+        timeout: Some(std::time::Duration::from_secs(10)),
+    });
+
+    Ok(true)
+}
+
+// Lead times whose reminder is due, for an event that has not started yet.
+fn due_leads(start: DateTime<Utc>, now: DateTime<Utc>, lead_minutes: &[u32]) -> Vec<u32> {
+    if start <= now {
+        return vec![];
+    }
+
+    lead_minutes
+        .iter()
+        .copied()
+        .filter(|lead| start - Duration::minutes((*lead).into()) <= now)
+        .collect()
+}
+
+fn is_starting(event: &Event, now: DateTime<Utc>) -> bool {
+    event.start_date <= now
+        && now < event.end_date
+        && now - event.start_date <= Duration::minutes(AUTO_START_GRACE_MINUTES)
+}
+
+fn reminder_title(start: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (start - now).num_seconds();
+    let minutes = (seconds + 59) / 60;
+
+    match minutes {
+        ..=0 => "Meeting starting now".to_string(),
+        1 => "Meeting starting in 1 minute".to_string(),
+        n => format!("Meeting starting in {} minutes", n),
+    }
+}
+
+fn event_url(event: &Event) -> String {
+    format!("hypr://hyprnote.com/notification?event_id={}", event.id)
+}
+
+fn show(notification: hypr_notification2::Notification) {
+    // Wrap in AssertUnwindSafe and handle the panic properly
+    if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        hypr_notification2::show(notification);
+    })) {
+        // Convert panic payload to string for logging
+        let panic_msg = if let Some(s) = e.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = e.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown panic".to_string()
+        };
+        tracing::error!("Notification panic: {}", panic_msg);
+    } else {
+        tracing::info!("Notification shown");
+    }
+}
+
+pub async fn monitor<R: tauri::Runtime>(state: WorkerState<R>) -> Result<(), std::io::Error> {
     use std::str::FromStr;
 
     apalis::prelude::Monitor::new()
@@ -80,10 +282,50 @@ pub async fn monitor(state: WorkerState) -> Result<(), std::io::Error> {
                 .backend(apalis_cron::CronStream::new(
                     apalis_cron::Schedule::from_str("0 * * * * *").unwrap(),
                 ))
-                .build_fn(perform_event_notification)
+                .build_fn(perform_event_notification::<R>)
         })
         .run()
         .await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-01T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+            + Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn test_due_leads() {
+        let leads = [1, 5, 15];
+
+        assert_eq!(due_leads(at(0), at(-30), &leads), Vec::<u32>::new());
+        assert_eq!(due_leads(at(0), at(-15), &leads), vec![15]);
+        assert_eq!(due_leads(at(0), at(-3), &leads), vec![5, 15]);
+        assert_eq!(due_leads(at(0), at(-1), &leads), vec![1, 5, 15]);
+        assert_eq!(due_leads(at(0), at(0), &leads), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_reminder_title() {
+        assert_eq!(
+            reminder_title(at(0), at(-5)),
+            "Meeting starting in 5 minutes"
+        );
+        assert_eq!(
+            reminder_title(at(0), at(-5) + Duration::seconds(30)),
+            "Meeting starting in 5 minutes"
+        );
+        assert_eq!(
+            reminder_title(at(0), at(-1)),
+            "Meeting starting in 1 minute"
+        );
+        assert_eq!(reminder_title(at(0), at(0)), "Meeting starting now");
+    }
+}
@@ -0,0 +1,11 @@
+const COMMANDS: &[&str] = &[
+    "get_token",
+    "set_token",
+    "get_settings",
+    "set_settings",
+    "export_session",
+];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
@@ -0,0 +1,46 @@
+use crate::{NotionPluginExt, NotionSettings};
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<String>, String> {
+    app.get_token().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    token: String,
+) -> Result<(), String> {
+    app.set_token(token).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_settings<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<NotionSettings, String> {
+    app.get_settings().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_settings<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    settings: NotionSettings,
+) -> Result<(), String> {
+    app.set_settings(settings).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<Option<String>, String> {
+    app.export_session(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,28 @@
+use serde::{ser::Serializer, Serialize};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] hypr_db_user::Error),
+    #[error(transparent)]
+    SerdeError(#[from] serde_json::Error),
+    #[error(transparent)]
+    NotionError(#[from] anyhow::Error),
+    #[error("Database is not ready")]
+    DatabaseNotReady,
+    #[error("Notion token is not configured")]
+    TokenNotConfigured,
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
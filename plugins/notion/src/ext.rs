@@ -0,0 +1,137 @@
+use std::future::Future;
+
+use tauri::Manager;
+use tauri_plugin_store2::StorePluginExt;
+
+use hypr_db_user::{ExtensionMapping, UserDatabase};
+use hypr_notion::{NotionClient, NotionConfig, EXTENSION_ID};
+
+use crate::NotionSettings;
+
+pub trait NotionPluginExt<R: tauri::Runtime> {
+    fn notion_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
+
+    fn get_token(&self) -> Result<Option<String>, crate::Error>;
+    fn set_token(&self, token: String) -> Result<(), crate::Error>;
+
+    fn get_settings(&self) -> impl Future<Output = Result<NotionSettings, crate::Error>>;
+    fn set_settings(
+        &self,
+        settings: NotionSettings,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+
+    fn export_session(
+        &self,
+        session_id: String,
+    ) -> impl Future<Output = Result<Option<String>, crate::Error>>;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> NotionPluginExt<R> for T {
+    fn notion_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn get_token(&self) -> Result<Option<String>, crate::Error> {
+        let store = self.notion_store();
+        let v = store.get::<String>(crate::StoreKey::Token)?;
+        Ok(v)
+    }
+
+    fn set_token(&self, token: String) -> Result<(), crate::Error> {
+        let store = self.notion_store();
+        store.set(crate::StoreKey::Token, token)?;
+        store.save()?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_settings(&self) -> Result<NotionSettings, crate::Error> {
+        let (db, user_id) = user_db(self).await?;
+
+        let config = match db.get_extension_mapping(&user_id, EXTENSION_ID).await? {
+            Some(mapping) => serde_json::from_value::<NotionConfig>(mapping.config)?,
+            None => NotionConfig::default(),
+        };
+
+        Ok(NotionSettings {
+            parent_page_id: config.parent_page_id,
+            database_id: config.database_id,
+        })
+    }
+
+    // Creates the `notion` extension mapping on first save. Exported pages are kept.
+    #[tracing::instrument(skip(self))]
+    async fn set_settings(&self, settings: NotionSettings) -> Result<(), crate::Error> {
+        let (db, user_id) = user_db(self).await?;
+
+        let mapping = db.get_extension_mapping(&user_id, EXTENSION_ID).await?;
+        let mut config = match &mapping {
+            Some(mapping) => serde_json::from_value::<NotionConfig>(mapping.config.clone())?,
+            None => NotionConfig::default(),
+        };
+        config.parent_page_id = settings.parent_page_id.as_deref().and_then(parse_id);
+        config.database_id = settings.database_id.as_deref().and_then(parse_id);
+
+        let mapping = match mapping {
+            Some(mapping) => ExtensionMapping {
+                config: serde_json::to_value(&config)?,
+                ..mapping
+            },
+            None => ExtensionMapping {
+                id: uuid::Uuid::new_v4().to_string(),
+                user_id,
+                extension_id: EXTENSION_ID.to_string(),
+                config: serde_json::to_value(&config)?,
+                widgets: vec![],
+            },
+        };
+        db.upsert_extension_mapping(mapping).await?;
+
+        Ok(())
+    }
+
+    // Returns the URL of the page the session was exported to.
+    #[tracing::instrument(skip(self))]
+    async fn export_session(&self, session_id: String) -> Result<Option<String>, crate::Error> {
+        let token = self.get_token()?.ok_or(crate::Error::TokenNotConfigured)?;
+        let (db, user_id) = user_db(self).await?;
+
+        let client = NotionClient::new(token);
+        let page = hypr_notion::export_session(&client, &db, user_id, session_id).await?;
+
+        Ok(page.url)
+    }
+}
+
+async fn user_db<R: tauri::Runtime>(
+    app: &impl tauri::Manager<R>,
+) -> Result<(UserDatabase, String), crate::Error> {
+    let state = app.state::<tauri_plugin_db::ManagedState>();
+    let guard = state.lock().await;
+
+    match (guard.db.clone(), guard.user_id.clone()) {
+        (Some(db), Some(user_id)) => Ok((db, user_id)),
+        _ => Err(crate::Error::DatabaseNotReady),
+    }
+}
+
+// Accepts either an id or the URL of the page, as copied from Notion.
+pub(crate) fn parse_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    let segment = input
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+
+    let chars: Vec<char> = segment.chars().filter(|c| *c != '-').collect();
+    if let Some(tail) = chars.get(chars.len().saturating_sub(32)..) {
+        if tail.len() == 32 && tail.iter().all(char::is_ascii_hexdigit) {
+            return Some(tail.iter().collect());
+        }
+    }
+
+    (!input.is_empty()).then(|| input.to_string())
+}
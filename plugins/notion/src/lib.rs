@@ -0,0 +1,81 @@
+mod commands;
+mod error;
+mod ext;
+mod store;
+
+pub use error::*;
+pub use ext::*;
+use store::*;
+
+const PLUGIN_NAME: &str = "notion";
+
+// Where sessions are exported to. Kept in the `notion` extension mapping, next to the exported pages.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct NotionSettings {
+    pub parent_page_id: Option<String>,
+    pub database_id: Option<String>,
+}
+
+fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
+    tauri_specta::Builder::<R>::new()
+        .plugin_name(PLUGIN_NAME)
+        .commands(tauri_specta::collect_commands![
+            commands::get_token::<tauri::Wry>,
+            commands::set_token::<tauri::Wry>,
+            commands::get_settings::<tauri::Wry>,
+            commands::set_settings::<tauri::Wry>,
+            commands::export_session::<tauri::Wry>,
+        ])
+        .error_handling(tauri_specta::ErrorHandlingMode::Throw)
+}
+
+pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let specta_builder = make_specta_builder();
+
+    tauri::plugin::Builder::new(PLUGIN_NAME)
+        .invoke_handler(specta_builder.invoke_handler())
+        .build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn export_types() {
+        make_specta_builder::<tauri::Wry>()
+            .export(
+                specta_typescript::Typescript::default()
+                    .header("// @ts-nocheck\n\n")
+                    .formatter(specta_typescript::formatter::prettier)
+                    .bigint(specta_typescript::BigIntExportBehavior::Number),
+                "./js/bindings.gen.ts",
+            )
+            .unwrap()
+    }
+
+    fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R> {
+        builder
+            .plugin(tauri_plugin_store::Builder::default().build())
+            .plugin(init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_notion() {
+        let _app = create_app(tauri::test::mock_builder());
+    }
+
+    #[test]
+    fn test_parse_id() {
+        let id = "1c2f8a0b3d4e5f60718293a4b5c6d7e8";
+
+        assert_eq!(ext::parse_id(id), Some(id.to_string()));
+        assert_eq!(
+            ext::parse_id(&format!("https://www.notion.so/acme/Meetings-{}?v=123", id)),
+            Some(id.to_string())
+        );
+        assert_eq!(ext::parse_id("  "), None);
+    }
+}
@@ -10,6 +10,9 @@ const COMMANDS: &[&str] = &[
     "get_deep_link_url",
     "get_base_folder",
     "set_base_folder",
+    "get_vault_path",
+    "set_vault_path",
+    "sync_session",
 ];
 
 fn main() {
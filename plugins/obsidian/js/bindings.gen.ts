@@ -39,6 +39,15 @@ async getBaseFolder() : Promise<string | null> {
 },
 async setBaseFolder(baseFolder: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:obsidian|set_base_folder", { baseFolder });
+},
+async getVaultPath() : Promise<string | null> {
+    return await TAURI_INVOKE("plugin:obsidian|get_vault_path");
+},
+async setVaultPath(vaultPath: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_vault_path", { vaultPath });
+},
+async syncSession(sessionId: string, folder: string | null, includeTranscript: boolean, force: boolean) : Promise<SyncResult> {
+    return await TAURI_INVOKE("plugin:obsidian|sync_session", { sessionId, folder, includeTranscript, force });
 }
 }
 
@@ -52,6 +61,8 @@ async setBaseFolder(baseFolder: string) : Promise<null> {
 
 /** user-defined types **/
 
+export type SyncResult = { status: SyncStatus; path: string; deep_link_url: string | null }
+export type SyncStatus = "created" | "updated" | "unchanged" | "conflict"
 
 
 /** tauri-specta globals **/
@@ -1,4 +1,4 @@
-use crate::ObsidianPluginExt;
+use crate::{ObsidianPluginExt, SyncResult};
 
 #[tauri::command]
 #[specta::specta]
@@ -93,3 +93,34 @@ pub(crate) async fn get_deep_link_url<R: tauri::Runtime>(
 ) -> Result<String, String> {
     app.get_deep_link_url(note_name).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_vault_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<String>, String> {
+    app.get_vault_path().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_vault_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    vault_path: Option<String>,
+) -> Result<(), String> {
+    app.set_vault_path(vault_path).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn sync_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    folder: Option<String>,
+    include_transcript: bool,
+    force: bool,
+) -> Result<SyncResult, String> {
+    app.sync_session(session_id, folder, include_transcript, force)
+        .await
+        .map_err(|e| e.to_string())
+}
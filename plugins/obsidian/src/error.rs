@@ -6,8 +6,18 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error(transparent)]
     StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    ObsidianError(#[from] hypr_obsidian::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] hypr_db_user::Error),
     #[error("Vault name is not configured")]
     VaultNameNotConfigured,
+    #[error("Obsidian is not configured")]
+    NotConfigured,
+    #[error("Database is not ready")]
+    DatabaseNotReady,
+    #[error("Session not found: {0}")]
+    SessionNotFound(String),
 }
 
 impl Serialize for Error {
@@ -1,5 +1,10 @@
+use std::{collections::HashMap, future::Future};
+
+use tauri::Manager;
 use tauri_plugin_store2::StorePluginExt;
 
+use crate::SyncResult;
+
 pub trait ObsidianPluginExt<R: tauri::Runtime> {
     fn obsidian_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
     fn is_configured(&self) -> Result<bool, crate::Error>;
@@ -19,7 +24,19 @@ pub trait ObsidianPluginExt<R: tauri::Runtime> {
     fn get_enabled(&self) -> Result<bool, crate::Error>;
     fn set_enabled(&self, enabled: bool) -> Result<(), crate::Error>;
 
+    fn get_vault_path(&self) -> Result<Option<String>, crate::Error>;
+    fn set_vault_path(&self, vault_path: Option<String>) -> Result<(), crate::Error>;
+
     fn get_deep_link_url(&self, note_name: String) -> Result<String, crate::Error>;
+
+    fn vault(&self) -> Result<hypr_obsidian::Vault, crate::Error>;
+    fn sync_session(
+        &self,
+        session_id: String,
+        folder: Option<String>,
+        include_transcript: bool,
+        force: bool,
+    ) -> impl Future<Output = Result<SyncResult, crate::Error>>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
@@ -28,10 +45,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
     }
 
     fn is_configured(&self) -> Result<bool, crate::Error> {
-        let api_key = self.get_api_key()?;
-        let base_url = self.get_base_url()?;
-
-        Ok(api_key.is_some() && base_url.is_some())
+        Ok(self.vault().is_ok())
     }
 
     fn get_api_key(&self) -> Result<Option<String>, crate::Error> {
@@ -99,10 +113,31 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
         Ok(())
     }
 
+    fn get_vault_path(&self) -> Result<Option<String>, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<String>(crate::StoreKey::VaultPath)?;
+        Ok(v.filter(|v| !v.trim().is_empty()))
+    }
+
+    fn set_vault_path(&self, vault_path: Option<String>) -> Result<(), crate::Error> {
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::VaultPath, vault_path)?;
+        store.save()?;
+        Ok(())
+    }
+
     fn get_deep_link_url(&self, note_name: String) -> Result<String, crate::Error> {
         let store = self.obsidian_store();
+        // A vault opened in Obsidian is named after its folder.
         let vault_name = store
             .get::<String>(crate::StoreKey::VaultName)?
+            .filter(|v| !v.trim().is_empty())
+            .or_else(|| {
+                let vault_path = self.get_vault_path().ok()??;
+                std::path::Path::new(&vault_path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
             .ok_or(crate::Error::VaultNameNotConfigured)?;
 
         Ok(format!(
@@ -110,4 +145,73 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
             vault_name, note_name
         ))
     }
+
+    // A vault folder on disk takes precedence, since it works without Obsidian running.
+    fn vault(&self) -> Result<hypr_obsidian::Vault, crate::Error> {
+        if let Some(vault_path) = self.get_vault_path()? {
+            return Ok(hypr_obsidian::Vault::filesystem(vault_path));
+        }
+
+        match (self.get_base_url()?, self.get_api_key()?) {
+            (Some(base_url), Some(api_key)) if !api_key.is_empty() => {
+                Ok(hypr_obsidian::Vault::rest(base_url, api_key)?)
+            }
+            _ => Err(crate::Error::NotConfigured),
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn sync_session(
+        &self,
+        session_id: String,
+        folder: Option<String>,
+        include_transcript: bool,
+        force: bool,
+    ) -> Result<SyncResult, crate::Error> {
+        let vault = self.vault()?;
+
+        let db = {
+            let state = self.state::<tauri_plugin_db::ManagedState>();
+            let guard = state.lock().await;
+            guard.db.clone().ok_or(crate::Error::DatabaseNotReady)?
+        };
+
+        let session = db
+            .get_session(hypr_db_user::GetSessionFilter::Id(session_id.clone()))
+            .await?
+            .ok_or_else(|| crate::Error::SessionNotFound(session_id.clone()))?;
+        let note = hypr_obsidian::Note {
+            participants: db.session_list_participants(&session_id).await?,
+            tags: db.list_session_tags(&session_id).await?,
+            event: db.session_get_event(&session_id).await?,
+            bookmarks: db.list_bookmarks(&session_id).await?,
+            session,
+            include_transcript,
+        };
+
+        let store = self.obsidian_store();
+        let mut synced = store
+            .get::<HashMap<String, hypr_obsidian::SyncRecord>>(crate::StoreKey::SyncedSessions)?
+            .unwrap_or_default();
+
+        let folder = folder.or(self.get_base_folder()?);
+        let outcome = hypr_obsidian::sync_note(
+            &vault,
+            &note,
+            folder.as_deref(),
+            synced.get(&session_id),
+            force,
+        )
+        .await?;
+
+        synced.insert(session_id, outcome.record.clone());
+        store.set(crate::StoreKey::SyncedSessions, synced)?;
+        store.save()?;
+
+        Ok(SyncResult {
+            status: outcome.status,
+            deep_link_url: self.get_deep_link_url(outcome.record.path.clone()).ok(),
+            path: outcome.record.path,
+        })
+    }
 }
@@ -17,6 +17,13 @@ pub type SharedState = Mutex<State>;
 #[derive(Default)]
 pub struct State {}
 
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct SyncResult {
+    pub status: hypr_obsidian::SyncStatus,
+    pub path: String,
+    pub deep_link_url: Option<String>,
+}
+
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
@@ -32,6 +39,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::get_deep_link_url::<tauri::Wry>,
             commands::get_base_folder::<tauri::Wry>,
             commands::set_base_folder::<tauri::Wry>,
+            commands::get_vault_path::<tauri::Wry>,
+            commands::set_vault_path::<tauri::Wry>,
+            commands::sync_session::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
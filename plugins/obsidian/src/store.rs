@@ -7,6 +7,8 @@ pub enum StoreKey {
     BaseFolder,
     BaseUrl,
     ApiKey,
+    VaultPath,
+    SyncedSessions,
 }
 
 impl ScopedStoreKey for StoreKey {}
@@ -0,0 +1,15 @@
+const COMMANDS: &[&str] = &[
+    "get_token",
+    "set_token",
+    "get_channel",
+    "set_channel",
+    "get_tag_ids",
+    "set_tag_ids",
+    "list_channels",
+    "post_session",
+    "post_session_if_tagged",
+];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
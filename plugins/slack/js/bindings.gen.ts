@@ -0,0 +1,110 @@
+// @ts-nocheck
+
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+async getToken() : Promise<string | null> {
+    return await TAURI_INVOKE("plugin:slack|get_token");
+},
+async setToken(token: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:slack|set_token", { token });
+},
+async getChannel() : Promise<Channel | null> {
+    return await TAURI_INVOKE("plugin:slack|get_channel");
+},
+async setChannel(channel: Channel | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:slack|set_channel", { channel });
+},
+async getTagIds() : Promise<string[]> {
+    return await TAURI_INVOKE("plugin:slack|get_tag_ids");
+},
+async setTagIds(tagIds: string[]) : Promise<null> {
+    return await TAURI_INVOKE("plugin:slack|set_tag_ids", { tagIds });
+},
+async listChannels() : Promise<Channel[]> {
+    return await TAURI_INVOKE("plugin:slack|list_channels");
+},
+async postSession(sessionId: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:slack|post_session", { sessionId });
+},
+async postSessionIfTagged(sessionId: string) : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:slack|post_session_if_tagged", { sessionId });
+}
+}
+
+/** user-defined events **/
+
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+export type Channel = { id: string; name: string }
+
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
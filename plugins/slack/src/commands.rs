@@ -0,0 +1,82 @@
+use crate::{Channel, SlackPluginExt};
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<String>, String> {
+    app.get_token().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    token: String,
+) -> Result<(), String> {
+    app.set_token(token).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_channel<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<Channel>, String> {
+    app.get_channel().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_channel<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    channel: Option<Channel>,
+) -> Result<(), String> {
+    app.set_channel(channel).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_tag_ids<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<String>, String> {
+    app.get_tag_ids().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_tag_ids<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    tag_ids: Vec<String>,
+) -> Result<(), String> {
+    app.set_tag_ids(tag_ids).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_channels<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<Channel>, String> {
+    app.list_channels().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn post_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<(), String> {
+    app.post_session(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn post_session_if_tagged<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<bool, String> {
+    app.post_session_if_tagged(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
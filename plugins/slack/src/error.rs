@@ -0,0 +1,30 @@
+use serde::{ser::Serializer, Serialize};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] hypr_db_user::Error),
+    #[error(transparent)]
+    SlackError(#[from] anyhow::Error),
+    #[error("Database is not ready")]
+    DatabaseNotReady,
+    #[error("Slack token is not configured")]
+    TokenNotConfigured,
+    #[error("Slack channel is not configured")]
+    ChannelNotConfigured,
+    #[error("Session not found: {0}")]
+    SessionNotFound(String),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
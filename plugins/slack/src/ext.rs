@@ -0,0 +1,200 @@
+use std::{collections::HashMap, future::Future};
+
+use tauri::Manager;
+use tauri_plugin_store2::StorePluginExt;
+
+use crate::{Channel, PostedSession};
+
+pub trait SlackPluginExt<R: tauri::Runtime> {
+    fn slack_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
+
+    fn get_token(&self) -> Result<Option<String>, crate::Error>;
+    fn set_token(&self, token: String) -> Result<(), crate::Error>;
+
+    fn get_channel(&self) -> Result<Option<Channel>, crate::Error>;
+    fn set_channel(&self, channel: Option<Channel>) -> Result<(), crate::Error>;
+
+    fn get_tag_ids(&self) -> Result<Vec<String>, crate::Error>;
+    fn set_tag_ids(&self, tag_ids: Vec<String>) -> Result<(), crate::Error>;
+
+    fn list_channels(&self) -> impl Future<Output = Result<Vec<Channel>, crate::Error>>;
+    fn post_session(&self, session_id: String) -> impl Future<Output = Result<(), crate::Error>>;
+    fn post_session_if_tagged(
+        &self,
+        session_id: String,
+    ) -> impl Future<Output = Result<bool, crate::Error>>;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> SlackPluginExt<R> for T {
+    fn slack_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn get_token(&self) -> Result<Option<String>, crate::Error> {
+        let store = self.slack_store();
+        let v = store.get::<String>(crate::StoreKey::Token)?;
+        Ok(v)
+    }
+
+    fn set_token(&self, token: String) -> Result<(), crate::Error> {
+        let store = self.slack_store();
+        store.set(crate::StoreKey::Token, token)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn get_channel(&self) -> Result<Option<Channel>, crate::Error> {
+        let store = self.slack_store();
+        let v = store.get::<Channel>(crate::StoreKey::Channel)?;
+        Ok(v)
+    }
+
+    fn set_channel(&self, channel: Option<Channel>) -> Result<(), crate::Error> {
+        let store = self.slack_store();
+        store.set(crate::StoreKey::Channel, channel)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn get_tag_ids(&self) -> Result<Vec<String>, crate::Error> {
+        let store = self.slack_store();
+        let v = store.get::<Vec<String>>(crate::StoreKey::TagIds)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn set_tag_ids(&self, tag_ids: Vec<String>) -> Result<(), crate::Error> {
+        let store = self.slack_store();
+        store.set(crate::StoreKey::TagIds, tag_ids)?;
+        store.save()?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_channels(&self) -> Result<Vec<Channel>, crate::Error> {
+        let token = self.get_token()?.ok_or(crate::Error::TokenNotConfigured)?;
+        let client = hypr_slack::Client::new(token);
+
+        let channels = client
+            .list_channels()
+            .await?
+            .into_iter()
+            .map(|channel| Channel {
+                id: channel.id,
+                name: channel.name,
+            })
+            .collect();
+
+        Ok(channels)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn post_session(&self, session_id: String) -> Result<(), crate::Error> {
+        let token = self.get_token()?.ok_or(crate::Error::TokenNotConfigured)?;
+        let channel = self
+            .get_channel()?
+            .ok_or(crate::Error::ChannelNotConfigured)?;
+
+        let db = {
+            let state = self.state::<tauri_plugin_db::ManagedState>();
+            let guard = state.lock().await;
+            guard.db.clone().ok_or(crate::Error::DatabaseNotReady)?
+        };
+
+        let session = db
+            .get_session(hypr_db_user::GetSessionFilter::Id(session_id.clone()))
+            .await?
+            .ok_or_else(|| crate::Error::SessionNotFound(session_id.clone()))?;
+        let participants = db.session_list_participants(&session_id).await?;
+        let action_items = db
+            .list_action_items(hypr_db_user::ListActionItemFilter::Session {
+                session_id: session_id.clone(),
+            })
+            .await?;
+
+        let store = self.slack_store();
+        let mut posted = store
+            .get::<HashMap<String, PostedSession>>(crate::StoreKey::PostedSessions)?
+            .unwrap_or_default();
+
+        // Continue in the thread of an earlier attempt that failed half-way.
+        let resume = posted
+            .get(&session_id)
+            .filter(|prev| !prev.is_complete())
+            .map(|prev| hypr_slack::Resume {
+                thread_ts: hypr_slack::slack_morphism::prelude::SlackTs::new(
+                    prev.thread_ts.clone(),
+                ),
+                pages_posted: prev.pages_posted,
+            });
+
+        let client = hypr_slack::Client::new(token);
+        client
+            .send_session(
+                channel.id,
+                hypr_slack::SessionMessage {
+                    session,
+                    participants,
+                    action_items,
+                },
+                resume,
+                |ts, pages_posted, pages_total| {
+                    posted.insert(
+                        session_id.clone(),
+                        PostedSession {
+                            thread_ts: ts.to_string(),
+                            pages_posted,
+                            pages_total,
+                        },
+                    );
+                    store.set(crate::StoreKey::PostedSessions, posted.clone())?;
+                    store.save()?;
+                    Ok(())
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // Called after every enhance, so a session is only ever posted once.
+    #[tracing::instrument(skip(self))]
+    async fn post_session_if_tagged(&self, session_id: String) -> Result<bool, crate::Error> {
+        if self.get_token()?.is_none() || self.get_channel()?.is_none() {
+            return Ok(false);
+        }
+
+        let tag_ids = self.get_tag_ids()?;
+        if tag_ids.is_empty() {
+            return Ok(false);
+        }
+
+        let posted = self
+            .slack_store()
+            .get::<HashMap<String, PostedSession>>(crate::StoreKey::PostedSessions)?
+            .unwrap_or_default();
+        if posted
+            .get(&session_id)
+            .is_some_and(|prev| prev.is_complete())
+        {
+            return Ok(false);
+        }
+
+        let db = {
+            let state = self.state::<tauri_plugin_db::ManagedState>();
+            let guard = state.lock().await;
+            guard.db.clone().ok_or(crate::Error::DatabaseNotReady)?
+        };
+
+        let tagged = db
+            .list_session_tags(&session_id)
+            .await?
+            .iter()
+            .any(|tag| tag_ids.contains(&tag.id));
+        if !tagged {
+            return Ok(false);
+        }
+
+        self.post_session(session_id).await?;
+        Ok(true)
+    }
+}
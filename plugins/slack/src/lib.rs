@@ -0,0 +1,72 @@
+mod commands;
+mod error;
+mod ext;
+mod store;
+
+pub use error::*;
+pub use ext::*;
+use store::*;
+
+const PLUGIN_NAME: &str = "slack";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Channel {
+    pub id: String,
+    pub name: String,
+}
+
+fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
+    tauri_specta::Builder::<R>::new()
+        .plugin_name(PLUGIN_NAME)
+        .commands(tauri_specta::collect_commands![
+            commands::get_token::<tauri::Wry>,
+            commands::set_token::<tauri::Wry>,
+            commands::get_channel::<tauri::Wry>,
+            commands::set_channel::<tauri::Wry>,
+            commands::get_tag_ids::<tauri::Wry>,
+            commands::set_tag_ids::<tauri::Wry>,
+            commands::list_channels::<tauri::Wry>,
+            commands::post_session::<tauri::Wry>,
+            commands::post_session_if_tagged::<tauri::Wry>,
+        ])
+        .error_handling(tauri_specta::ErrorHandlingMode::Throw)
+}
+
+pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let specta_builder = make_specta_builder();
+
+    tauri::plugin::Builder::new(PLUGIN_NAME)
+        .invoke_handler(specta_builder.invoke_handler())
+        .build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn export_types() {
+        make_specta_builder::<tauri::Wry>()
+            .export(
+                specta_typescript::Typescript::default()
+                    .header("// @ts-nocheck\n\n")
+                    .formatter(specta_typescript::formatter::prettier)
+                    .bigint(specta_typescript::BigIntExportBehavior::Number),
+                "./js/bindings.gen.ts",
+            )
+            .unwrap()
+    }
+
+    fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R> {
+        builder
+            .plugin(tauri_plugin_store::Builder::default().build())
+            .plugin(init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_slack() {
+        let _app = create_app(tauri::test::mock_builder());
+    }
+}
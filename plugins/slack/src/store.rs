@@ -0,0 +1,25 @@
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    Token,
+    Channel,
+    TagIds,
+    PostedSessions,
+}
+
+impl ScopedStoreKey for StoreKey {}
+
+// Kept per session under `StoreKey::PostedSessions`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PostedSession {
+    pub thread_ts: String,
+    pub pages_posted: usize,
+    pub pages_total: usize,
+}
+
+impl PostedSession {
+    pub fn is_complete(&self) -> bool {
+        self.pages_posted >= self.pages_total
+    }
+}
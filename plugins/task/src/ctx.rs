@@ -48,6 +48,14 @@ impl<R: Runtime> TaskCtx<R> {
         self.cancelled.load(Ordering::Relaxed)
     }
 
+    pub fn set_total(&mut self, total: u32) -> Result<(), crate::Error> {
+        self.total = total.max(1);
+        self.update_status(TaskStatus::Running {
+            current: self.current,
+            total: self.total,
+        })
+    }
+
     pub fn advance(&mut self, _data: impl serde::Serialize) -> Result<(), crate::Error> {
         if self.is_cancelled() {
             return Ok(());
@@ -1,4 +1,12 @@
-const COMMANDS: &[&str] = &["render", "register_template"];
+const COMMANDS: &[&str] = &[
+    "render",
+    "register_template",
+    "reset_template",
+    "list_template_versions",
+    "list_predefined_templates",
+    "get_default_template",
+    "chunk_transcript",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
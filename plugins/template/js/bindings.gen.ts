@@ -12,6 +12,21 @@ async render(name: string, ctx: Partial<{ [key in string]: JsonValue }>) : Promi
 },
 async registerTemplate(name: string, template: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:template|register_template", { name, template });
+},
+async resetTemplate(name: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:template|reset_template", { name });
+},
+async listTemplateVersions(name: string) : Promise<TemplateOverride[]> {
+    return await TAURI_INVOKE("plugin:template|list_template_versions", { name });
+},
+async listPredefinedTemplates() : Promise<string[]> {
+    return await TAURI_INVOKE("plugin:template|list_predefined_templates");
+},
+async getDefaultTemplate(name: string) : Promise<string> {
+    return await TAURI_INVOKE("plugin:template|get_default_template", { name });
+},
+async chunkTranscript(words: Word2[], maxTokens: number) : Promise<Word2[][]> {
+    return await TAURI_INVOKE("plugin:template|chunk_transcript", { words, maxTokens });
 }
 }
 
@@ -25,8 +40,11 @@ async registerTemplate(name: string, template: string) : Promise<null> {
 
 /** user-defined types **/
 
-export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" }
+export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "action-items" }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+export type SpeakerIdentity = { type: "unassigned"; value: { index: number } } | { type: "assigned"; value: { id: string; label: string } }
+export type TemplateOverride = { id: string; name: string; version: number; content: string | null; created_at: string }
+export type Word2 = { text: string; speaker: SpeakerIdentity | null; confidence: number | null; start_ms: number | null; end_ms: number | null }
 
 /** tauri-specta globals **/
 
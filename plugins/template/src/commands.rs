@@ -17,5 +17,48 @@ pub async fn register_template<R: tauri::Runtime>(
     name: String,
     template: String,
 ) -> Result<(), String> {
-    app.register_template(name, template)
+    app.register_template(name, template).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn reset_template<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    name: String,
+) -> Result<(), String> {
+    app.reset_template(name).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_template_versions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    name: String,
+) -> Result<Vec<hypr_db_user::TemplateOverride>, String> {
+    app.list_template_versions(name).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_predefined_templates() -> Result<Vec<String>, String> {
+    Ok(hypr_template::PredefinedTemplate::all()
+        .map(|template| template.to_string())
+        .collect())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_default_template(name: String) -> Result<String, String> {
+    name.parse::<hypr_template::PredefinedTemplate>()
+        .map(|template| template.source().to_string())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn chunk_transcript(
+    words: Vec<owhisper_interface::Word2>,
+    max_tokens: u32,
+) -> Result<Vec<Vec<owhisper_interface::Word2>>, String> {
+    Ok(hypr_template::chunk_words(&words, max_tokens as usize))
 }
@@ -1,3 +1,8 @@
+use std::future::Future;
+use std::str::FromStr;
+
+use tauri::Manager;
+
 pub trait TemplatePluginExt<R: tauri::Runtime> {
     fn render(
         &self,
@@ -8,7 +13,13 @@ pub trait TemplatePluginExt<R: tauri::Runtime> {
         &self,
         name: impl Into<String>,
         template: impl Into<String>,
-    ) -> Result<(), String>;
+    ) -> impl Future<Output = Result<(), String>>;
+    fn reset_template(&self, name: impl Into<String>) -> impl Future<Output = Result<(), String>>;
+    fn list_template_versions(
+        &self,
+        name: impl Into<String>,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::TemplateOverride>, String>>;
+    fn load_template_overrides(&self) -> impl Future<Output = Result<(), String>>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::TemplatePluginExt<R> for T {
@@ -30,19 +41,102 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::TemplatePluginExt<R> for T
     }
 
     #[tracing::instrument(skip_all)]
-    fn register_template(
+    async fn register_template(
         &self,
         name: impl Into<String>,
         template: impl Into<String>,
     ) -> Result<(), String> {
-        let state = self.state::<crate::ManagedState>();
+        let (name, template) = (name.into(), template.into());
 
         {
+            let state = self.state::<crate::ManagedState>();
             let mut guard = state.lock().unwrap();
             guard
                 .env
-                .add_template_owned(name.into(), template.into())
-                .map_err(|e| e.to_string())
+                .add_template_owned(name.clone(), template.clone())
+                .map_err(|e| e.to_string())?;
+        }
+
+        if let Some(db) = user_db(self).await {
+            db.add_template_override(name, Some(template))
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn reset_template(&self, name: impl Into<String>) -> Result<(), String> {
+        let name = name.into();
+
+        if let Some(db) = user_db(self).await {
+            db.add_template_override(&name, None)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        {
+            let state = self.state::<crate::ManagedState>();
+            let mut guard = state.lock().unwrap();
+
+            match hypr_template::PredefinedTemplate::from_str(&name) {
+                Ok(predefined) => guard
+                    .env
+                    .add_template(predefined.as_ref(), predefined.source())
+                    .map_err(|e| e.to_string())?,
+                Err(_) => {
+                    guard.env.remove_template(&name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn list_template_versions(
+        &self,
+        name: impl Into<String>,
+    ) -> Result<Vec<hypr_db_user::TemplateOverride>, String> {
+        match user_db(self).await {
+            Some(db) => db
+                .list_template_overrides(name)
+                .await
+                .map_err(|e| e.to_string()),
+            None => Ok(vec![]),
         }
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn load_template_overrides(&self) -> Result<(), String> {
+        let Some(db) = user_db(self).await else {
+            return Ok(());
+        };
+
+        let overrides = db
+            .list_active_template_overrides()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let state = self.state::<crate::ManagedState>();
+        let mut guard = state.lock().unwrap();
+
+        for item in overrides {
+            let content = item.content.unwrap_or_default();
+            if let Err(e) = guard.env.add_template_owned(item.name.clone(), content) {
+                tracing::error!(name = %item.name, "invalid_template_override: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn user_db<R: tauri::Runtime>(
+    manager: &impl tauri::Manager<R>,
+) -> Option<hypr_db_user::UserDatabase> {
+    let state = manager.try_state::<tauri_plugin_db::ManagedState>()?;
+    let guard = state.lock().await;
+    guard.db.clone()
 }
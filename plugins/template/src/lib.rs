@@ -28,6 +28,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::render::<Wry>,
             commands::register_template::<Wry>,
+            commands::reset_template::<Wry>,
+            commands::list_template_versions::<Wry>,
+            commands::list_predefined_templates,
+            commands::get_default_template,
+            commands::chunk_transcript,
         ])
         .typ::<hypr_gbnf::Grammar>()
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
@@ -280,4 +285,33 @@ mod test {
         Note that above given informations like participants, transcript, etc. are already displayed in the UI, so you don't need to repeat them.
         "###);
     }
+
+    #[test]
+    fn test_predefined_templates_registered() {
+        let app = create_app(tauri::test::mock_builder());
+
+        let rendered = app
+            .render(
+                hypr_template::PredefinedTemplate::ShowAnnotationUser,
+                serde_json::json!({
+                    "sentence": "We agreed to ship on Friday.",
+                    "words": [],
+                })
+                .as_object()
+                .unwrap()
+                .clone(),
+            )
+            .unwrap();
+        assert!(rendered.contains("We agreed to ship on Friday."));
+
+        for template in [
+            hypr_template::PredefinedTemplate::PostprocessEnhanceSystem,
+            hypr_template::PredefinedTemplate::ShowAnnotationSystem,
+            hypr_template::PredefinedTemplate::AiChat2System,
+        ] {
+            let state = app.state::<ManagedState>();
+            let guard = state.lock().unwrap();
+            assert!(guard.env.get_template(template.as_ref()).is_ok());
+        }
+    }
 }
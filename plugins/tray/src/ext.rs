@@ -12,9 +12,13 @@ use tauri_plugin_misc::MiscPluginExt;
 
 const TRAY_ID: &str = "hypr-tray";
 
+// Emitted when "Bookmark this moment" is clicked. The listener adds the bookmark.
+pub const TRAY_BOOKMARK_EVENT: &str = "hypr-tray-bookmark";
+
 pub enum HyprMenuItem {
     TrayOpen,
     TrayStart,
+    TrayBookmark,
     TrayQuit,
     AppInfo,
     AppNew,
@@ -25,6 +29,7 @@ impl From<HyprMenuItem> for MenuId {
         match value {
             HyprMenuItem::TrayOpen => "hypr_tray_open",
             HyprMenuItem::TrayStart => "hypr_tray_start",
+            HyprMenuItem::TrayBookmark => "hypr_tray_bookmark",
             HyprMenuItem::TrayQuit => "hypr_tray_quit",
             HyprMenuItem::AppInfo => "hypr_app_info",
             HyprMenuItem::AppNew => "hypr_app_new",
@@ -39,6 +44,7 @@ impl From<MenuId> for HyprMenuItem {
         match id {
             "hypr_tray_open" => HyprMenuItem::TrayOpen,
             "hypr_tray_start" => HyprMenuItem::TrayStart,
+            "hypr_tray_bookmark" => HyprMenuItem::TrayBookmark,
             "hypr_tray_quit" => HyprMenuItem::TrayQuit,
             "hypr_app_info" => HyprMenuItem::AppInfo,
             "hypr_app_new" => HyprMenuItem::AppNew,
@@ -92,6 +98,7 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
             &[
                 &tray_open_menu(app)?,
                 &tray_start_menu(app, false)?,
+                &tray_bookmark_menu(app, false)?,
                 &PredefinedMenuItem::separator(app)?,
                 &tray_quit_menu(app)?,
             ],
@@ -127,6 +134,10 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
                             );
                         }
                     }
+                    HyprMenuItem::TrayBookmark => {
+                        use tauri::Emitter;
+                        let _ = app.emit(TRAY_BOOKMARK_EVENT, ());
+                    }
                     HyprMenuItem::TrayQuit => {
                         app.exit(0);
                     }
@@ -188,6 +199,7 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
                 &[
                     &tray_open_menu(app)?,
                     &tray_start_menu(app, disabled)?,
+                    &tray_bookmark_menu(app, disabled)?,
                     &PredefinedMenuItem::separator(app)?,
                     &tray_quit_menu(app)?,
                 ],
@@ -240,6 +252,20 @@ fn tray_start_menu<R: tauri::Runtime>(app: &AppHandle<R>, disabled: bool) -> Res
     )
 }
 
+// Only available while recording, which is exactly when starting one is disabled.
+fn tray_bookmark_menu<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    recording: bool,
+) -> Result<MenuItem<R>> {
+    MenuItem::with_id(
+        app,
+        HyprMenuItem::TrayBookmark,
+        "Bookmark this moment",
+        recording,
+        None::<&str>,
+    )
+}
+
 fn tray_quit_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<MenuItem<R>> {
     MenuItem::with_id(app, HyprMenuItem::TrayQuit, "Quit", true, Some("cmd+q"))
 }
@@ -1,4 +1,13 @@
-const COMMANDS: &[&str] = &["todo"];
+const COMMANDS: &[&str] = &[
+    "list_webhooks",
+    "create_webhook",
+    "update_webhook",
+    "delete_webhook",
+    "test_webhook",
+    "list_dead_letters",
+    "clear_dead_letters",
+    "retry_dead_letter",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
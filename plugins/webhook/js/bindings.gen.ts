@@ -7,8 +7,29 @@
 
 
 export const commands = {
-async todo() : Promise<string> {
-    return await TAURI_INVOKE("plugin:webhook|todo");
+async listWebhooks() : Promise<WebhookResponse[]> {
+    return await TAURI_INVOKE("plugin:webhook|list_webhooks");
+},
+async createWebhook(config: WebhookConfig) : Promise<WebhookResponse> {
+    return await TAURI_INVOKE("plugin:webhook|create_webhook", { config });
+},
+async updateWebhook(id: string, config: WebhookConfig) : Promise<WebhookResponse> {
+    return await TAURI_INVOKE("plugin:webhook|update_webhook", { id, config });
+},
+async deleteWebhook(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|delete_webhook", { id });
+},
+async testWebhook(id: string) : Promise<DeliveryReport> {
+    return await TAURI_INVOKE("plugin:webhook|test_webhook", { id });
+},
+async listDeadLetters() : Promise<DeadLetter[]> {
+    return await TAURI_INVOKE("plugin:webhook|list_dead_letters");
+},
+async clearDeadLetters() : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|clear_dead_letters");
+},
+async retryDeadLetter(id: string) : Promise<DeliveryReport> {
+    return await TAURI_INVOKE("plugin:webhook|retry_dead_letter", { id });
 }
 }
 
@@ -22,7 +43,44 @@ async todo() : Promise<string> {
 
 /** user-defined types **/
 
-
+export type DeadLetter = { id: string; webhook_id: string; url: string; event: WebhookEvent; attempts: number; status: number | null; error: string; failed_at: string }
+export type DeliveryReport = { attempts: number; status: number | null; error: string | null }
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+export type WebhookConfig = { 
+/**
+ * Your webhook endpoint URL
+ */
+url: string; 
+/**
+ * Events to subscribe to
+ */
+events: string[]; 
+/**
+ * Whether the webhook is active
+ */
+active: boolean }
+export type WebhookEvent = { 
+/**
+ * Unique event identifier
+ */
+id: string; 
+/**
+ * Event type
+ */
+event_type: string; 
+/**
+ * ISO 8601 timestamp
+ */
+timestamp: string; 
+/**
+ * Event payload
+ */
+data: JsonValue }
+export type WebhookResponse = { id: string; config: WebhookConfig; 
+/**
+ * Secret for verifying webhook signatures
+ */
+secret: string; created_at: string }
 
 /** tauri-specta globals **/
 
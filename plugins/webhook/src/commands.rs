@@ -1,7 +1,71 @@
-use crate::WebhookPluginExt;
+use crate::{DeadLetter, DeliveryReport, WebhookConfig, WebhookPluginExt, WebhookResponse};
 
 #[tauri::command]
 #[specta::specta]
-pub async fn todo<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<String, String> {
-    app.todo()
+pub(crate) async fn list_webhooks<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<WebhookResponse>, String> {
+    app.list_webhooks().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn create_webhook<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: WebhookConfig,
+) -> Result<WebhookResponse, String> {
+    app.create_webhook(config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn update_webhook<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    config: WebhookConfig,
+) -> Result<WebhookResponse, String> {
+    app.update_webhook(id, config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn delete_webhook<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.delete_webhook(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn test_webhook<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<DeliveryReport, String> {
+    app.test_webhook(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_dead_letters<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<DeadLetter>, String> {
+    app.list_dead_letters().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn clear_dead_letters<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.clear_dead_letters().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn retry_dead_letter<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<DeliveryReport, String> {
+    app.retry_dead_letter(id).await.map_err(|e| e.to_string())
 }
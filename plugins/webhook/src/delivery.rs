@@ -0,0 +1,317 @@
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use backon::{ExponentialBuilder, Retryable};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{WebhookEvent, WebhookResponse};
+
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+pub const ID_HEADER: &str = "X-Webhook-Id";
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    // Roughly 1s, 2s, 4s, ... giving up after about a minute.
+    fn default() -> Self {
+        Self {
+            max_retries: 6,
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DeliveryReport {
+    pub attempts: u32,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+impl DeliveryReport {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+// Signed over `{timestamp}.{body}`, so a captured request can not be replayed with a new timestamp.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+pub fn verify(secret: &str, timestamp: i64, body: &str, signature: &str) -> bool {
+    let Some(signature) = signature
+        .strip_prefix("sha256=")
+        .and_then(|hex| hex::decode(hex).ok())
+    else {
+        return false;
+    };
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    mac.verify_slice(&signature).is_ok()
+}
+
+struct Failure {
+    status: Option<u16>,
+    message: String,
+    retryable: bool,
+}
+
+pub async fn deliver(
+    client: &reqwest::Client,
+    webhook: &WebhookResponse,
+    event: &WebhookEvent,
+    policy: &RetryPolicy,
+) -> DeliveryReport {
+    let body = serde_json::to_string(event).unwrap();
+    let attempts = AtomicU32::new(0);
+
+    let result = (|| async {
+        attempts.fetch_add(1, Ordering::Relaxed);
+        send(client, webhook, event, &body).await
+    })
+    .retry(
+        ExponentialBuilder::default()
+            .with_min_delay(policy.min_delay)
+            .with_max_delay(policy.max_delay)
+            .with_max_times(policy.max_retries)
+            .with_jitter(),
+    )
+    .sleep(tokio::time::sleep)
+    .when(|failure: &Failure| failure.retryable)
+    .notify(|failure: &Failure, delay: Duration| {
+        tracing::warn!(
+            "webhook_delivery_retry: url={} event={} error={} delay={:?}",
+            webhook.config.url,
+            event.id,
+            failure.message,
+            delay
+        );
+    })
+    .await;
+
+    let attempts = attempts.into_inner();
+    match result {
+        Ok(status) => DeliveryReport {
+            attempts,
+            status: Some(status),
+            error: None,
+        },
+        Err(failure) => DeliveryReport {
+            attempts,
+            status: failure.status,
+            error: Some(failure.message),
+        },
+    }
+}
+
+async fn send(
+    client: &reqwest::Client,
+    webhook: &WebhookResponse,
+    event: &WebhookEvent,
+    body: &str,
+) -> Result<u16, Failure> {
+    let timestamp = chrono::Utc::now().timestamp();
+
+    let res = client
+        .post(&webhook.config.url)
+        .timeout(REQUEST_TIMEOUT)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, sign(&webhook.secret, timestamp, body))
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(ID_HEADER, &event.id)
+        .header(EVENT_HEADER, &event.event_type)
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(|e| Failure {
+            status: None,
+            message: e.to_string(),
+            retryable: true,
+        })?;
+
+    let status = res.status();
+    if status.is_success() {
+        return Ok(status.as_u16());
+    }
+
+    // Other client errors mean the receiver rejected the event, and would do so again.
+    let retryable = status.is_server_error()
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::REQUEST_TIMEOUT;
+
+    Err(Failure {
+        status: Some(status.as_u16()),
+        message: format!("{} {}", status, res.text().await.unwrap_or_default())
+            .trim()
+            .to_string(),
+        retryable,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::{Arc, Mutex};
+
+    use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+
+    use crate::{WebhookConfig, WebhookEventType};
+
+    #[derive(Clone, Default)]
+    struct Receiver {
+        failures_left: Arc<Mutex<u32>>,
+        failure_status: u16,
+        received: Arc<Mutex<Vec<WebhookEvent>>>,
+    }
+
+    const SECRET: &str = "whsec_test";
+
+    async fn receive(
+        State(receiver): State<Receiver>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        let header = |name: &str| headers.get(name).unwrap().to_str().unwrap().to_string();
+
+        let timestamp = header(TIMESTAMP_HEADER).parse::<i64>().unwrap();
+        if !verify(SECRET, timestamp, &body, &header(SIGNATURE_HEADER)) {
+            return StatusCode::UNAUTHORIZED;
+        }
+
+        {
+            let mut failures_left = receiver.failures_left.lock().unwrap();
+            if *failures_left > 0 {
+                *failures_left -= 1;
+                return StatusCode::from_u16(receiver.failure_status).unwrap();
+            }
+        }
+
+        let event: WebhookEvent = serde_json::from_str(&body).unwrap();
+        assert_eq!(header(ID_HEADER), event.id);
+        assert_eq!(header(EVENT_HEADER), event.event_type);
+        receiver.received.lock().unwrap().push(event);
+
+        StatusCode::NO_CONTENT
+    }
+
+    async fn serve(receiver: Receiver) -> WebhookResponse {
+        let app = Router::new()
+            .route("/hook", post(receive))
+            .with_state(receiver);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        WebhookResponse {
+            id: "webhook_1".to_string(),
+            config: WebhookConfig {
+                url: format!("http://{}/hook", addr),
+                events: vec!["note.created".to_string()],
+                active: true,
+            },
+            secret: SECRET.to_string(),
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            min_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+        }
+    }
+
+    fn event() -> WebhookEvent {
+        WebhookEvent::new(
+            WebhookEventType::NoteCreated,
+            serde_json::json!({ "note_id": "note_1", "title": "Standup", "content": "" }),
+        )
+    }
+
+    #[test]
+    fn test_sign() {
+        let signature = sign("secret", 1704880200, "{}");
+        assert!(verify("secret", 1704880200, "{}", &signature));
+        assert!(!verify("secret", 1704880201, "{}", &signature));
+        assert!(!verify("other", 1704880200, "{}", &signature));
+        assert!(!verify("secret", 1704880200, "{}", "sha256=zz"));
+    }
+
+    #[tokio::test]
+    async fn test_deliver_retries() {
+        let receiver = Receiver {
+            failures_left: Arc::new(Mutex::new(2)),
+            failure_status: 503,
+            ..Default::default()
+        };
+        let webhook = serve(receiver.clone()).await;
+        let event = event();
+
+        let report = deliver(&reqwest::Client::new(), &webhook, &event, &policy()).await;
+        assert!(report.is_success());
+        assert_eq!(report.attempts, 3);
+        assert_eq!(report.status, Some(204));
+
+        let received = receiver.received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].id, event.id);
+        assert_eq!(received[0].data["title"], "Standup");
+    }
+
+    #[tokio::test]
+    async fn test_deliver_gives_up() {
+        let receiver = Receiver {
+            failures_left: Arc::new(Mutex::new(10)),
+            failure_status: 500,
+            ..Default::default()
+        };
+        let webhook = serve(receiver.clone()).await;
+
+        let report = deliver(&reqwest::Client::new(), &webhook, &event(), &policy()).await;
+        assert!(!report.is_success());
+        assert_eq!(report.attempts, 4);
+        assert_eq!(report.status, Some(500));
+        assert!(receiver.received.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_rejected() {
+        let receiver = Receiver::default();
+        let mut webhook = serve(receiver.clone()).await;
+        webhook.secret = "whsec_wrong".to_string();
+
+        let report = deliver(&reqwest::Client::new(), &webhook, &event(), &policy()).await;
+        assert_eq!(report.attempts, 1);
+        assert_eq!(report.status, Some(401));
+        assert!(receiver.received.lock().unwrap().is_empty());
+    }
+}
@@ -3,7 +3,18 @@ use serde::{ser::Serializer, Serialize};
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    StoreError(#[from] tauri_plugin_store2::Error),
+    #[error("Invalid webhook URL: {0}")]
+    InvalidUrl(String),
+    #[error("Unknown webhook event: {0}")]
+    UnknownEvent(String),
+    #[error("Webhook not found: {0}")]
+    WebhookNotFound(String),
+    #[error("Dead letter not found: {0}")]
+    DeadLetterNotFound(String),
+}
 
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -0,0 +1,51 @@
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+)]
+pub enum WebhookEventType {
+    #[strum(serialize = "note.created")]
+    #[serde(rename = "note.created")]
+    NoteCreated,
+    #[strum(serialize = "note.updated")]
+    #[serde(rename = "note.updated")]
+    NoteUpdated,
+    #[strum(serialize = "recording.started")]
+    #[serde(rename = "recording.started")]
+    RecordingStarted,
+    #[strum(serialize = "recording.completed")]
+    #[serde(rename = "recording.completed")]
+    RecordingCompleted,
+    #[strum(serialize = "transcription.completed")]
+    #[serde(rename = "transcription.completed")]
+    TranscriptionCompleted,
+    // Only sent by `test_webhook`, regardless of subscriptions.
+    #[strum(serialize = "webhook.ping")]
+    #[serde(rename = "webhook.ping")]
+    Ping,
+}
+
+impl crate::WebhookEvent {
+    pub fn new(event_type: WebhookEventType, data: serde_json::Value) -> Self {
+        Self {
+            id: format!("evt_{}", uuid::Uuid::new_v4().simple()),
+            event_type: event_type.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            data,
+        }
+    }
+}
+
+impl crate::WebhookConfig {
+    pub fn subscribes_to(&self, event_type: &str) -> bool {
+        self.active && self.events.iter().any(|e| e == event_type)
+    }
+}
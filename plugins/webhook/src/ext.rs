@@ -1,9 +1,323 @@
+use std::{future::Future, str::FromStr, time::Duration};
+
+use tauri::Manager;
+use tauri_plugin_store2::StorePluginExt;
+
+use crate::{
+    deliver, DeadLetter, DeliveryReport, RetryPolicy, WebhookConfig, WebhookEvent,
+    WebhookEventType, WebhookResponse,
+};
+
+// Only the most recent dead letters are kept.
+const MAX_DEAD_LETTERS: usize = 100;
+// Notes are saved on every keystroke, so updates are sent once editing settles.
+const DEBOUNCE: Duration = Duration::from_secs(10);
+
 pub trait WebhookPluginExt<R: tauri::Runtime> {
-    fn todo(&self) -> Result<String, String>;
+    fn webhook_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
+
+    fn list_webhooks(&self) -> Result<Vec<WebhookResponse>, crate::Error>;
+    fn create_webhook(&self, config: WebhookConfig) -> Result<WebhookResponse, crate::Error>;
+    fn update_webhook(
+        &self,
+        id: String,
+        config: WebhookConfig,
+    ) -> Result<WebhookResponse, crate::Error>;
+    fn delete_webhook(&self, id: String) -> Result<(), crate::Error>;
+    fn test_webhook(
+        &self,
+        id: String,
+    ) -> impl Future<Output = Result<DeliveryReport, crate::Error>>;
+
+    fn list_dead_letters(&self) -> Result<Vec<DeadLetter>, crate::Error>;
+    fn clear_dead_letters(&self) -> Result<(), crate::Error>;
+    fn retry_dead_letter(
+        &self,
+        id: String,
+    ) -> impl Future<Output = Result<DeliveryReport, crate::Error>>;
+
+    fn webhook_emit(&self, event_type: WebhookEventType, data: impl serde::Serialize);
+    fn webhook_emit_debounced(
+        &self,
+        key: String,
+        event_type: WebhookEventType,
+        data: impl serde::Serialize,
+    );
 }
 
-impl<R: tauri::Runtime> WebhookPluginExt<R> for tauri::AppHandle<R> {
-    fn todo(&self) -> Result<String, String> {
-        Ok("Webhook todo functionality not yet implemented".to_string())
+impl<R: tauri::Runtime, T: tauri::Manager<R>> WebhookPluginExt<R> for T {
+    fn webhook_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn list_webhooks(&self) -> Result<Vec<WebhookResponse>, crate::Error> {
+        let store = self.webhook_store();
+        let v = store.get::<Vec<WebhookResponse>>(crate::StoreKey::Webhooks)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn create_webhook(&self, config: WebhookConfig) -> Result<WebhookResponse, crate::Error> {
+        validate(&config)?;
+
+        let webhook = WebhookResponse {
+            id: format!("webhook_{}", uuid::Uuid::new_v4().simple()),
+            config,
+            secret: format!("whsec_{}", uuid::Uuid::new_v4().simple()),
+            created_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        };
+
+        let mut webhooks = self.list_webhooks()?;
+        webhooks.push(webhook.clone());
+        save_webhooks(self, webhooks)?;
+
+        Ok(webhook)
+    }
+
+    fn update_webhook(
+        &self,
+        id: String,
+        config: WebhookConfig,
+    ) -> Result<WebhookResponse, crate::Error> {
+        validate(&config)?;
+
+        let mut webhooks = self.list_webhooks()?;
+        let webhook = webhooks
+            .iter_mut()
+            .find(|webhook| webhook.id == id)
+            .ok_or(crate::Error::WebhookNotFound(id))?;
+        webhook.config = config;
+
+        let webhook = webhook.clone();
+        save_webhooks(self, webhooks)?;
+
+        Ok(webhook)
+    }
+
+    fn delete_webhook(&self, id: String) -> Result<(), crate::Error> {
+        let mut webhooks = self.list_webhooks()?;
+        let len = webhooks.len();
+        webhooks.retain(|webhook| webhook.id != id);
+
+        if webhooks.len() == len {
+            return Err(crate::Error::WebhookNotFound(id));
+        }
+
+        save_webhooks(self, webhooks)
+    }
+
+    async fn test_webhook(&self, id: String) -> Result<DeliveryReport, crate::Error> {
+        let webhook = find_webhook(self, &id)?;
+
+        let event = WebhookEvent::new(
+            WebhookEventType::Ping,
+            serde_json::json!({ "webhook_id": webhook.id }),
+        );
+
+        let client = self.state::<crate::State>().client.clone();
+        Ok(deliver(&client, &webhook, &event, &RetryPolicy::none()).await)
     }
+
+    fn list_dead_letters(&self) -> Result<Vec<DeadLetter>, crate::Error> {
+        let store = self.webhook_store();
+        let v = store.get::<Vec<DeadLetter>>(crate::StoreKey::DeadLetters)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn clear_dead_letters(&self) -> Result<(), crate::Error> {
+        let store = self.webhook_store();
+        store.set(crate::StoreKey::DeadLetters, Vec::<DeadLetter>::new())?;
+        store.save()?;
+        Ok(())
+    }
+
+    async fn retry_dead_letter(&self, id: String) -> Result<DeliveryReport, crate::Error> {
+        let dead_letter = self
+            .list_dead_letters()?
+            .into_iter()
+            .find(|dead_letter| dead_letter.id == id)
+            .ok_or(crate::Error::DeadLetterNotFound(id.clone()))?;
+        let webhook = find_webhook(self, &dead_letter.webhook_id)?;
+
+        let state = self.state::<crate::State>();
+        let report = deliver(
+            &state.client,
+            &webhook,
+            &dead_letter.event,
+            &RetryPolicy::none(),
+        )
+        .await;
+
+        if report.is_success() {
+            let _guard = state.dead_letters.lock().await;
+
+            let mut dead_letters = self.list_dead_letters()?;
+            dead_letters.retain(|dead_letter| dead_letter.id != id);
+
+            let store = self.webhook_store();
+            store.set(crate::StoreKey::DeadLetters, dead_letters)?;
+            store.save()?;
+        }
+
+        Ok(report)
+    }
+
+    fn webhook_emit(&self, event_type: WebhookEventType, data: impl serde::Serialize) {
+        if self.try_state::<crate::State>().is_none() {
+            return;
+        }
+
+        let event = match serde_json::to_value(data) {
+            Ok(data) => WebhookEvent::new(event_type, data),
+            Err(e) => {
+                tracing::error!("webhook_event_serialize_failed: {}", e);
+                return;
+            }
+        };
+
+        let app = self.app_handle().clone();
+        tauri::async_runtime::spawn(dispatch(app, event));
+    }
+
+    fn webhook_emit_debounced(
+        &self,
+        key: String,
+        event_type: WebhookEventType,
+        data: impl serde::Serialize,
+    ) {
+        let Some(state) = self.try_state::<crate::State>() else {
+            return;
+        };
+
+        let data = match serde_json::to_value(data) {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::error!("webhook_event_serialize_failed: {}", e);
+                return;
+            }
+        };
+
+        let app = self.app_handle().clone();
+        let pending_key = key.clone();
+        let task = tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(DEBOUNCE).await;
+
+            app.state::<crate::State>()
+                .pending
+                .lock()
+                .unwrap()
+                .remove(&pending_key);
+            app.webhook_emit(event_type, data);
+        });
+
+        let mut pending = state.pending.lock().unwrap();
+        if let Some(previous) = pending.insert(key, task) {
+            previous.abort();
+        }
+    }
+}
+
+fn validate(config: &WebhookConfig) -> Result<(), crate::Error> {
+    match url::Url::parse(&config.url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+        _ => return Err(crate::Error::InvalidUrl(config.url.clone())),
+    }
+
+    for event in &config.events {
+        match WebhookEventType::from_str(event) {
+            Ok(WebhookEventType::Ping) | Err(_) => {
+                return Err(crate::Error::UnknownEvent(event.clone()))
+            }
+            Ok(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn find_webhook<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+    id: &str,
+) -> Result<WebhookResponse, crate::Error> {
+    app.list_webhooks()?
+        .into_iter()
+        .find(|webhook| webhook.id == id)
+        .ok_or(crate::Error::WebhookNotFound(id.to_string()))
+}
+
+fn save_webhooks<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+    webhooks: Vec<WebhookResponse>,
+) -> Result<(), crate::Error> {
+    let store = app.webhook_store();
+    store.set(crate::StoreKey::Webhooks, webhooks)?;
+    store.save()?;
+    Ok(())
+}
+
+async fn dispatch<R: tauri::Runtime>(app: tauri::AppHandle<R>, event: WebhookEvent) {
+    let webhooks = match app.list_webhooks() {
+        Ok(webhooks) => webhooks,
+        Err(e) => {
+            tracing::error!("webhook_list_failed: {}", e);
+            return;
+        }
+    };
+
+    for webhook in webhooks
+        .into_iter()
+        .filter(|webhook| webhook.config.subscribes_to(&event.event_type))
+    {
+        let app = app.clone();
+        let event = event.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let state = app.state::<crate::State>();
+            let report = deliver(&state.client, &webhook, &event, &RetryPolicy::default()).await;
+            if report.is_success() {
+                return;
+            }
+
+            tracing::error!(
+                "webhook_delivery_failed: url={} event={} error={:?}",
+                webhook.config.url,
+                event.id,
+                report.error
+            );
+
+            let dead_letter = DeadLetter {
+                id: uuid::Uuid::new_v4().to_string(),
+                webhook_id: webhook.id,
+                url: webhook.config.url,
+                event,
+                attempts: report.attempts,
+                status: report.status,
+                error: report.error.unwrap_or_default(),
+                failed_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            };
+
+            if let Err(e) = push_dead_letter(&app, &state, dead_letter).await {
+                tracing::error!("webhook_dead_letter_failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn push_dead_letter<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    state: &crate::State,
+    dead_letter: DeadLetter,
+) -> Result<(), crate::Error> {
+    // Concurrent deliveries can fail at the same time.
+    let _guard = state.dead_letters.lock().await;
+
+    let mut dead_letters = app.list_dead_letters()?;
+    dead_letters.push(dead_letter);
+    if dead_letters.len() > MAX_DEAD_LETTERS {
+        dead_letters.drain(..dead_letters.len() - MAX_DEAD_LETTERS);
+    }
+
+    let store = app.webhook_store();
+    store.set(crate::StoreKey::DeadLetters, dead_letters)?;
+    store.save()?;
+    Ok(())
 }
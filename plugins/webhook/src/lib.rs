@@ -1,30 +1,62 @@
 mod commands;
+mod delivery;
 mod error;
+mod event;
 mod ext;
 mod openapi;
+mod store;
 
+pub use delivery::*;
 pub use error::*;
+pub use event::*;
 pub use ext::*;
 pub use openapi::*;
+use store::*;
 
 const PLUGIN_NAME: &str = "webhook";
 
+use std::collections::HashMap;
+
 use tauri::Manager;
 
 #[derive(Default)]
-pub struct State {}
+pub struct State {
+    client: reqwest::Client,
+    pending: std::sync::Mutex<HashMap<String, tauri::async_runtime::JoinHandle<()>>>,
+    dead_letters: tokio::sync::Mutex<()>,
+}
+
+// An event that could not be delivered after all retries.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DeadLetter {
+    pub id: String,
+    pub webhook_id: String,
+    pub url: String,
+    pub event: WebhookEvent,
+    pub attempts: u32,
+    pub status: Option<u16>,
+    pub error: String,
+    pub failed_at: String,
+}
 
-fn make_specta_builder() -> tauri_specta::Builder<tauri::Wry> {
-    tauri_specta::Builder::<tauri::Wry>::new()
+fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
+    tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
         .events(tauri_specta::collect_events![])
         .commands(tauri_specta::collect_commands![
-            commands::todo::<tauri::Wry>,
+            commands::list_webhooks::<tauri::Wry>,
+            commands::create_webhook::<tauri::Wry>,
+            commands::update_webhook::<tauri::Wry>,
+            commands::delete_webhook::<tauri::Wry>,
+            commands::test_webhook::<tauri::Wry>,
+            commands::list_dead_letters::<tauri::Wry>,
+            commands::clear_dead_letters::<tauri::Wry>,
+            commands::retry_dead_letter::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
 
-pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
+pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
     let specta_builder = make_specta_builder();
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
@@ -47,7 +79,7 @@ mod test {
 
     #[test]
     fn export_types() {
-        make_specta_builder()
+        make_specta_builder::<tauri::Wry>()
             .export(
                 specta_typescript::Typescript::default()
                     .header("// @ts-nocheck\n\n")
@@ -64,4 +96,45 @@ mod test {
         let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("openapi.json");
         std::fs::write(&path, openapi_json).unwrap();
     }
+
+    fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R> {
+        builder
+            .plugin(tauri_plugin_store::Builder::default().build())
+            .plugin(init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_webhook() {
+        let app = create_app(tauri::test::mock_builder());
+
+        let invalid = WebhookConfig {
+            url: "ftp://example.com".to_string(),
+            events: vec![],
+            active: true,
+        };
+        assert!(app.create_webhook(invalid).is_err());
+
+        let unknown = WebhookConfig {
+            url: "https://example.com/hook".to_string(),
+            events: vec!["note.deleted".to_string()],
+            active: true,
+        };
+        assert!(app.create_webhook(unknown).is_err());
+
+        let webhook = app
+            .create_webhook(WebhookConfig {
+                url: "https://example.com/hook".to_string(),
+                events: vec!["note.created".to_string()],
+                active: true,
+            })
+            .unwrap();
+        assert!(webhook.secret.starts_with("whsec_"));
+        assert_eq!(app.list_webhooks().unwrap().len(), 1);
+
+        app.delete_webhook(webhook.id.clone()).unwrap();
+        assert!(app.list_webhooks().unwrap().is_empty());
+        assert!(app.delete_webhook(webhook.id).is_err());
+    }
 }
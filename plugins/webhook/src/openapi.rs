@@ -5,7 +5,7 @@ use utoipa::{
 };
 
 // Core webhook event structure
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, specta::Type)]
 pub struct WebhookEvent {
     /// Unique event identifier
     #[schema(example = "evt_01234567890")]
@@ -61,7 +61,7 @@ pub struct TranscriptionEvent {
 }
 
 // Webhook configuration
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, specta::Type)]
 pub struct WebhookConfig {
     /// Your webhook endpoint URL
     #[schema(example = "https://your-app.com/webhooks")]
@@ -81,7 +81,7 @@ pub struct CreateWebhookRequest {
     pub config: WebhookConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, specta::Type)]
 pub struct WebhookResponse {
     #[schema(example = "webhook_123")]
     pub id: String,
@@ -105,11 +105,11 @@ pub struct WebhookListResponse {
 // Webhook verification example
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct WebhookVerification {
-    /// HMAC-SHA256 signature
+    /// HMAC-SHA256 of `{timestamp}.{body}`, keyed with the webhook secret
     #[schema(example = "sha256=abcdef1234567890")]
     pub signature: String,
 
-    /// Unix timestamp
+    /// Unix timestamp, sent as `X-Webhook-Timestamp`
     #[schema(example = "1704880200")]
     pub timestamp: String,
 }